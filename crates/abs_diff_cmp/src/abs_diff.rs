@@ -0,0 +1,61 @@
+//! Absolute difference comparisons of floating point numbers.
+//!
+//! The functions in this module compare floating point numbers the same way the
+//! absolute difference comparisons of this crate compare [`f32`] and [`f64`].
+//! They are generic over [`Float`], so a number type implementing it, such as a
+//! half precision or fixed point number type, compares with exactly the same
+//! semantics as the primitive floating point types.
+//!
+//! # Example
+//!
+//! ```
+//! # use abs_diff_cmp::abs_diff;
+//! #
+//! assert!(abs_diff::abs_diff_eq(1.0_f64, 1.5_f64, 0.5_f64));
+//! assert!(!abs_diff::abs_diff_eq(1.0_f64, 1.5_f64, 0.25_f64));
+//! assert_eq!(abs_diff::abs_diff(1.0_f64, 1.5_f64), 0.5_f64);
+//! ```
+use approx_cmp_float::Float;
+
+/// Compare two floating point numbers with an absolute difference tolerance.
+///
+/// This is the comparison of [`AbsDiffEq::abs_diff_eq`](crate::AbsDiffEq::abs_diff_eq)
+/// for [`f32`] and [`f64`], available to every number type implementing
+/// [`Float`]. Equal numbers, including infinities of the same sign, are equal
+/// with any tolerance. Otherwise, the numbers are equal when their absolute
+/// difference is within `max_abs_diff`. NaN is not equal to any number.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::abs_diff;
+/// #
+/// assert!(abs_diff::abs_diff_eq(f32::INFINITY, f32::INFINITY, 0.0_f32));
+/// assert!(!abs_diff::abs_diff_eq(f32::MAX, f32::INFINITY, f32::MAX));
+/// assert!(!abs_diff::abs_diff_eq(f32::NAN, f32::NAN, f32::INFINITY));
+/// ```
+#[inline]
+pub fn abs_diff_eq<T>(lhs: T, rhs: T, max_abs_diff: T) -> bool
+where
+    T: Float,
+{
+    (lhs == rhs) || (abs_diff(lhs, rhs) <= max_abs_diff)
+}
+
+/// The absolute difference of two floating point numbers.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::abs_diff;
+/// #
+/// assert_eq!(abs_diff::abs_diff(-1.0_f32, 2.0_f32), 3.0_f32);
+/// assert!(abs_diff::abs_diff(f32::NAN, 2.0_f32).is_nan());
+/// ```
+#[inline]
+pub fn abs_diff<T>(lhs: T, rhs: T) -> T
+where
+    T: Float,
+{
+    (lhs - rhs).abs()
+}
@@ -2,6 +2,14 @@ use crate::traits::{
     AssertAbsDiffAllEq,
     AssertAbsDiffEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::ops;
 use std::vec::Vec;
 
 impl<A, B> AssertAbsDiffEq<[B]> for [A]
@@ -42,6 +50,32 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, ((a, b), max_abs_diff)) in self.iter().zip(other.iter()).zip(max_abs_diff.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<[B]> for [A]
@@ -64,4 +98,30 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
@@ -4,6 +4,14 @@ use crate::traits::{
     AssertAbsDiffAllEq,
     AssertAbsDiffEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::ops;
 use std::boxed::Box;
 use std::collections::BTreeMap;
 use std::collections::LinkedList;
@@ -259,6 +267,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &Box<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(&**self, &**other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(&**self, &**other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<Rc<B>> for Rc<A>
@@ -278,6 +297,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &Rc<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(&**self, &**other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(&**self, &**other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<Arc<B>> for Arc<A>
@@ -297,6 +327,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &Arc<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(&**self, &**other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(&**self, &**other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<Vec<B>> for Vec<A>
@@ -337,6 +378,41 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, ((a, b), max_abs_diff)) in self.iter().zip(other.iter()).zip(max_abs_diff.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<VecDeque<B>> for VecDeque<A>
@@ -377,6 +453,41 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, ((a, b), max_abs_diff)) in self.iter().zip(other.iter()).zip(max_abs_diff.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<LinkedList<B>> for LinkedList<A>
@@ -417,6 +528,41 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, ((a, b), max_abs_diff)) in self.iter().zip(other.iter()).zip(max_abs_diff.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<K, VA, VB> AssertAbsDiffEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
@@ -454,6 +600,42 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match (other.get(key), max_abs_diff.get(key)) {
+                (Some(b), Some(max_abs_diff)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+                _ => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<Box<B>> for Box<A>
@@ -467,6 +649,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &Box<B>, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&**self, &**other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(&**self, &**other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<Rc<B>> for Rc<A>
@@ -480,6 +673,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &Rc<B>, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&**self, &**other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(&**self, &**other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<Arc<B>> for Arc<A>
@@ -493,6 +697,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &Arc<B>, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&**self, &**other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(&**self, &**other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<Vec<B>> for Vec<A>
@@ -516,6 +731,32 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<VecDeque<B>> for VecDeque<A>
@@ -539,6 +780,32 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<LinkedList<B>> for LinkedList<A>
@@ -562,6 +829,32 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<K, VA, VB> AssertAbsDiffAllEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
@@ -584,4 +877,31 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
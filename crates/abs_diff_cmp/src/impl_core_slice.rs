@@ -1,7 +1,9 @@
+use crate::ordering::AbsDiffOrd;
 use crate::traits::{
     AbsDiffAllEq,
     AbsDiffEq,
 };
+use approx_cmp_report::SearchMatch;
 
 impl<A, B> AbsDiffEq<[B]> for [A]
 where
@@ -37,3 +39,93 @@ where
                 .all(|(a, b)| a.abs_diff_all_eq(b, max_abs_diff))
     }
 }
+
+/// A data type for searching slices for the elements absolute difference equal
+/// to a value.
+///
+/// The search compares every element with the value using the semantics of
+/// [`AbsDiffEq`], e.g. to find the sample of a time series at `t ≈ 0.35`. When
+/// several elements match, the search reports them as
+/// [`SearchMatch::Ambiguous`] instead of returning one of them.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::{
+/// #     AbsDiffSearch,
+/// #     SearchMatch,
+/// # };
+/// #
+/// let times = [0.0_f64, 0.1_f64, 0.2_f64, 0.1_f64 + 0.2_f64, 0.4_f64];
+///
+/// assert_eq!(times.abs_diff_binary_search(&0.3_f64, &0.01_f64), SearchMatch::Found(3));
+/// assert_eq!(times.abs_diff_position(&0.3_f64, &0.01_f64), SearchMatch::Found(3));
+/// assert!(times.abs_diff_contains(&0.3_f64, &0.01_f64));
+/// assert!(!times.abs_diff_contains(&0.35_f64, &0.01_f64));
+///
+/// let samples = vec![0.3_f64, 0.349_f64, 0.351_f64, 0.4_f64];
+///
+/// assert_eq!(
+///     samples.abs_diff_binary_search(&0.35_f64, &0.01_f64),
+///     SearchMatch::Ambiguous { first: 1, last: 2, count: 2 }
+/// );
+/// ```
+pub trait AbsDiffSearch<A> {
+    /// Search a sorted slice for the elements absolute difference equal to `value`
+    /// with a binary search.
+    ///
+    /// The slice must be sorted in increasing order, so that the elements matching
+    /// `value` are contiguous. If the slice is not sorted, the outcome is
+    /// unspecified.
+    fn abs_diff_binary_search<B>(&self, value: &B, max_abs_diff: &<A as AbsDiffEq<B>>::Tolerance) -> SearchMatch
+    where
+        A: AbsDiffOrd<B>;
+
+    /// Search the slice for the elements absolute difference equal to `value` with
+    /// a linear search.
+    ///
+    /// The slice does not need to be sorted.
+    fn abs_diff_position<B>(&self, value: &B, max_abs_diff: &<A as AbsDiffEq<B>>::Tolerance) -> SearchMatch
+    where
+        A: AbsDiffEq<B>;
+
+    /// Determine whether an element of the slice is absolute difference equal to
+    /// `value`.
+    fn abs_diff_contains<B>(&self, value: &B, max_abs_diff: &<A as AbsDiffEq<B>>::Tolerance) -> bool
+    where
+        A: AbsDiffEq<B>;
+}
+
+impl<A> AbsDiffSearch<A> for [A] {
+    #[inline]
+    fn abs_diff_binary_search<B>(&self, value: &B, max_abs_diff: &<A as AbsDiffEq<B>>::Tolerance) -> SearchMatch
+    where
+        A: AbsDiffOrd<B>,
+    {
+        let start = self.partition_point(|element| element.abs_diff_lt(value, max_abs_diff));
+        let end = start + self[start..].partition_point(|element| element.abs_diff_le(value, max_abs_diff));
+
+        SearchMatch::from_indices(start..end)
+    }
+
+    #[inline]
+    fn abs_diff_position<B>(&self, value: &B, max_abs_diff: &<A as AbsDiffEq<B>>::Tolerance) -> SearchMatch
+    where
+        A: AbsDiffEq<B>,
+    {
+        SearchMatch::from_indices(
+            self.iter()
+                .enumerate()
+                .filter(|&(_, element)| element.abs_diff_eq(value, max_abs_diff))
+                .map(|(i, _)| i),
+        )
+    }
+
+    #[inline]
+    fn abs_diff_contains<B>(&self, value: &B, max_abs_diff: &<A as AbsDiffEq<B>>::Tolerance) -> bool
+    where
+        A: AbsDiffEq<B>,
+    {
+        self.iter().any(|element| element.abs_diff_eq(value, max_abs_diff))
+    }
+}
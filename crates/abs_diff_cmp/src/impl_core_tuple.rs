@@ -4,7 +4,14 @@ use crate::traits::{
     AssertAbsDiffAllEq,
     AssertAbsDiffEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    Visitor,
+};
+
 use core::fmt;
+use core::ops;
 
 impl AbsDiffEq for () {
     type Tolerance = ();
@@ -79,6 +86,23 @@ macro_rules! impl_abs_diff_tuple {
             fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
                 ($(self.$idx.debug_abs_diff_tolerance(&other.$idx, &max_abs_diff.$idx),)+)
                 }
+
+            #[inline]
+            fn visit_abs_diff<'a>(
+                &'a self,
+                other: &Self,
+                max_abs_diff: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                $(
+                    path.with_segment(PathSegment::Field(stringify!($idx)), |path| {
+                        AssertAbsDiffEq::visit_abs_diff(&self.$idx, &other.$idx, &max_abs_diff.$idx, path, visitor)
+                    })?;
+                )+
+
+                ops::ControlFlow::Continue(())
+            }
         }
     )+};
 }
@@ -215,6 +239,21 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &(B,), max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         (self.0.debug_abs_diff_all_tolerance(&other.0, max_abs_diff),)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B,),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B)> for (A, A)
@@ -242,6 +281,24 @@ where
             self.1.debug_abs_diff_all_tolerance(&other.1, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B)> for (A, A, A)
@@ -272,6 +329,27 @@ where
             self.2.debug_abs_diff_all_tolerance(&other.2, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B)> for (A, A, A, A)
@@ -309,6 +387,30 @@ where
             self.3.debug_abs_diff_all_tolerance(&other.3, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B)> for (A, A, A, A, A)
@@ -349,6 +451,33 @@ where
             self.4.debug_abs_diff_all_tolerance(&other.4, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
@@ -392,6 +521,36 @@ where
             self.5.debug_abs_diff_all_tolerance(&other.5, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
@@ -438,6 +597,39 @@ where
             self.6.debug_abs_diff_all_tolerance(&other.6, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.6, &other.6, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
@@ -487,6 +679,42 @@ where
             self.7.debug_abs_diff_all_tolerance(&other.7, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.6, &other.6, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.7, &other.7, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
@@ -543,6 +771,45 @@ where
             self.8.debug_abs_diff_all_tolerance(&other.8, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.6, &other.6, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.7, &other.7, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.8, &other.8, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
@@ -602,6 +869,48 @@ where
             self.9.debug_abs_diff_all_tolerance(&other.9, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.6, &other.6, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.7, &other.7, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.8, &other.8, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.9, &other.9, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
@@ -664,6 +973,51 @@ where
             self.10.debug_abs_diff_all_tolerance(&other.10, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.6, &other.6, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.7, &other.7, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.8, &other.8, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.9, &other.9, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.10, &other.10, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AbsDiffAllEq<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
@@ -728,4 +1082,52 @@ where
             self.11.debug_abs_diff_all_tolerance(&other.11, max_abs_diff),
         )
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.0, &other.0, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.1, &other.1, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.2, &other.2, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.3, &other.3, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.4, &other.4, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.5, &other.5, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.6, &other.6, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.7, &other.7, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.8, &other.8, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.9, &other.9, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.10, &other.10, max_abs_diff, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("11"), |path| {
+            AssertAbsDiffAllEq::visit_abs_diff_all(&self.11, &other.11, max_abs_diff, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
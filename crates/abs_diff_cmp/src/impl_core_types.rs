@@ -1,12 +1,23 @@
+use crate::abs_diff;
 use crate::traits::{
     AbsDiffAllEq,
     AbsDiffEq,
     AssertAbsDiffAllEq,
     AssertAbsDiffEq,
 };
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
 
 use core::cell;
 use core::mem;
+use core::ops;
 
 #[inline(always)]
 fn uninit_array<T, const N: usize>() -> [mem::MaybeUninit<T>; N] {
@@ -40,19 +51,53 @@ macro_rules! impl_abs_diff_eq_unsigned {
 impl_abs_diff_eq_unsigned!(u8, u16, u32, u64, u128, usize);
 
 macro_rules! impl_abs_diff_eq_signed {
+    ($($T:ident => $U:ident),* $(,)?) => {$(
+        impl AbsDiffEq for $T {
+            type Tolerance = $U;
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T, max_abs_diff: &Self::Tolerance) -> bool {
+                // The distance between any two signed integers fits in the unsigned
+                // integer type of the same width, so it never overflows.
+                $T::abs_diff(*self, *other) <= *max_abs_diff
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_eq_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! impl_abs_diff_eq_float {
     ($($T:ident),* $(,)?) => {$(
         impl AbsDiffEq for $T {
             type Tolerance = $T;
 
             #[inline]
             fn abs_diff_eq(&self, other: &$T, max_abs_diff: &Self::Tolerance) -> bool {
-                (self == other) || ($T::abs(self - other) <= *max_abs_diff)
+                abs_diff::abs_diff_eq(*self, *other, *max_abs_diff)
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_eq_float!(f32, f64);
+
+macro_rules! impl_abs_diff_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AbsDiffEq<$Rhs> for $T {
+            type Tolerance = f64;
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance) -> bool {
+                // Every `f32` widens to an `f64` exactly, so the absolute difference is
+                // measured in `f64` precision.
+                abs_diff::abs_diff_eq(f64::from(*self), f64::from(*other), *max_abs_diff)
             }
         }
     )*};
 }
 
-impl_abs_diff_eq_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+impl_abs_diff_eq_mixed_float!(f32 => f64, f64 => f32);
 
 impl<A, B> AbsDiffEq<&B> for &A
 where
@@ -168,6 +213,25 @@ where
     }
 }
 
+impl<A, B, E, F> AbsDiffEq<Result<B, F>> for Result<A, E>
+where
+    A: AbsDiffEq<B>,
+    E: AbsDiffEq<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    type Tolerance = Result<A::Tolerance, E::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance) -> bool {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => AbsDiffEq::abs_diff_eq(a, b, max_abs_diff),
+            (Err(a), Err(b), Err(max_abs_diff)) => AbsDiffEq::abs_diff_eq(a, b, max_abs_diff),
+            _ => false,
+        }
+    }
+}
+
 impl<A, B> AbsDiffEq<cell::OnceCell<B>> for cell::OnceCell<A>
 where
     A: AbsDiffEq<B>,
@@ -203,7 +267,7 @@ impl_abs_diff_all_eq_unsigned!(u8, u16, u32, u64, u128, usize);
 macro_rules! impl_abs_diff_all_eq_signed {
     ($($T:ident),* $(,)?) => {$(
         impl AbsDiffAllEq for $T {
-            type AllTolerance = $T;
+            type AllTolerance = <$T as AbsDiffEq>::Tolerance;
 
             #[inline]
             fn abs_diff_all_eq(&self, other: &$T, max_abs_diff: &Self::AllTolerance) -> bool {
@@ -215,6 +279,21 @@ macro_rules! impl_abs_diff_all_eq_signed {
 
 impl_abs_diff_all_eq_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
 
+macro_rules! impl_abs_diff_all_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AbsDiffAllEq<$Rhs> for $T {
+            type AllTolerance = f64;
+
+            #[inline]
+            fn abs_diff_all_eq(&self, other: &$Rhs, max_abs_diff: &Self::AllTolerance) -> bool {
+                AbsDiffEq::abs_diff_eq(self, other, max_abs_diff)
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_all_eq_mixed_float!(f32 => f64, f64 => f32);
+
 impl<A, B> AbsDiffAllEq<&B> for &A
 where
     A: AbsDiffAllEq<B> + ?Sized,
@@ -322,6 +401,25 @@ where
     }
 }
 
+impl<A, B, E, F> AbsDiffAllEq<Result<B, F>> for Result<A, E>
+where
+    A: AbsDiffAllEq<B>,
+    E: AbsDiffAllEq<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+{
+    type AllTolerance = Result<A::AllTolerance, E::AllTolerance>;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Result<B, F>, max_abs_diff: &Self::AllTolerance) -> bool {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => AbsDiffAllEq::abs_diff_all_eq(a, b, max_abs_diff),
+            (Err(a), Err(b), Err(max_abs_diff)) => AbsDiffAllEq::abs_diff_all_eq(a, b, max_abs_diff),
+            _ => false,
+        }
+    }
+}
+
 impl<A, B> AbsDiffAllEq<cell::OnceCell<B>> for cell::OnceCell<A>
 where
     A: AbsDiffAllEq<B>,
@@ -358,6 +456,26 @@ macro_rules! impl_assert_abs_diff_eq_unsigned {
             fn debug_abs_diff_tolerance(&self, _other: &$T, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
                 *max_abs_diff
             }
+
+            #[inline]
+            fn visit_abs_diff<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffEq::abs_diff_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     )*}
 }
@@ -365,6 +483,47 @@ macro_rules! impl_assert_abs_diff_eq_unsigned {
 impl_assert_abs_diff_eq_unsigned!(u8, u16, u32, u64, u128, usize);
 
 macro_rules! impl_assert_abs_diff_eq_signed {
+    ($($T:ident => $U:ident),* $(,)?) => {$(
+        impl AssertAbsDiffEq for $T {
+            type DebugAbsDiff = $U;
+            type DebugTolerance = Self::Tolerance;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $T::abs_diff(*self, *other)
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, _other: &$T, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                *max_abs_diff
+            }
+
+            #[inline]
+            fn visit_abs_diff<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffEq::abs_diff_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_abs_diff_eq_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! impl_assert_abs_diff_eq_float {
     ($($T:ident),* $(,)?) => {$(
         impl AssertAbsDiffEq for $T {
             type DebugAbsDiff = $T;
@@ -372,18 +531,79 @@ macro_rules! impl_assert_abs_diff_eq_signed {
 
             #[inline]
             fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
-                $T::abs(self - other)
+                abs_diff::abs_diff(*self, *other)
             }
 
             #[inline]
             fn debug_abs_diff_tolerance(&self, _other: &$T, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
                 *max_abs_diff
             }
+
+            #[inline]
+            fn visit_abs_diff<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffEq::abs_diff_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     )*};
 }
 
-impl_assert_abs_diff_eq_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+impl_assert_abs_diff_eq_float!(f32, f64);
+
+macro_rules! impl_assert_abs_diff_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertAbsDiffEq<$Rhs> for $T {
+            type DebugAbsDiff = f64;
+            type DebugTolerance = f64;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$Rhs) -> Self::DebugAbsDiff {
+                abs_diff::abs_diff(f64::from(*self), f64::from(*other))
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, _other: &$Rhs, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                *max_abs_diff
+            }
+
+            #[inline]
+            fn visit_abs_diff<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffEq::abs_diff_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_abs_diff_eq_mixed_float!(f32 => f64, f64 => f32);
 
 impl<A, B> AssertAbsDiffEq<&B> for &A
 where
@@ -402,6 +622,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &&B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<&mut B> for &A
@@ -421,6 +652,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &&mut B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<&B> for &mut A
@@ -440,6 +682,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &&B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<&mut B> for &mut A
@@ -459,6 +712,17 @@ where
     fn debug_abs_diff_tolerance(&self, other: &&mut B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertAbsDiffEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffEq::visit_abs_diff(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B, const N: usize> AssertAbsDiffEq<[B; N]> for [A; N]
@@ -489,6 +753,23 @@ where
 
         unsafe { array_assume_init(result) }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffEq::visit_abs_diff(&self[i], &other[i], &max_abs_diff[i], path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<cell::Cell<B>> for cell::Cell<A>
@@ -553,6 +834,74 @@ where
 
         Some(AssertAbsDiffEq::debug_abs_diff_tolerance(ref_self, ref_other, ref_max_abs_diff))
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff) {
+            (Some(a), Some(b), Some(max_abs_diff)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertAbsDiffEq<Result<B, F>> for Result<A, E>
+where
+    A: AssertAbsDiffEq<B>,
+    E: AssertAbsDiffEq<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    type DebugAbsDiff = Option<Result<A::DebugAbsDiff, E::DebugAbsDiff>>;
+    type DebugTolerance = Option<Result<A::DebugTolerance, E::DebugTolerance>>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Result<B, F>) -> Self::DebugAbsDiff {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Some(Ok(AssertAbsDiffEq::debug_abs_diff(a, b))),
+            (Err(a), Err(b)) => Some(Err(AssertAbsDiffEq::debug_abs_diff(a, b))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => Some(Ok(AssertAbsDiffEq::debug_abs_diff_tolerance(a, b, max_abs_diff))),
+            (Err(a), Err(b), Err(max_abs_diff)) => Some(Err(AssertAbsDiffEq::debug_abs_diff_tolerance(a, b, max_abs_diff))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+            (Err(a), Err(b), Err(max_abs_diff)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<cell::OnceCell<B>> for cell::OnceCell<A>
@@ -580,6 +929,25 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 macro_rules! impl_assert_abs_diff_all_eq_unsigned {
@@ -591,6 +959,26 @@ macro_rules! impl_assert_abs_diff_all_eq_unsigned {
             fn debug_abs_diff_all_tolerance(&self, other: &$T, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
                 self.debug_abs_diff_tolerance(other, max_abs_diff)
             }
+
+            #[inline]
+            fn visit_abs_diff_all<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffAllEq::abs_diff_all_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff_all", &AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     )*}
 }
@@ -606,12 +994,67 @@ macro_rules! impl_assert_abs_diff_all_eq_signed {
             fn debug_abs_diff_all_tolerance(&self, other: &$T, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
                 self.debug_abs_diff_tolerance(other, max_abs_diff)
             }
+
+            #[inline]
+            fn visit_abs_diff_all<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffAllEq::abs_diff_all_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff_all", &AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     )*};
 }
 
 impl_assert_abs_diff_all_eq_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
 
+macro_rules! impl_assert_abs_diff_all_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertAbsDiffAllEq<$Rhs> for $T {
+            type AllDebugTolerance = f64;
+
+            #[inline]
+            fn debug_abs_diff_all_tolerance(&self, other: &$Rhs, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                AssertAbsDiffEq::debug_abs_diff_tolerance(self, other, max_abs_diff)
+            }
+
+            #[inline]
+            fn visit_abs_diff_all<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::AllTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AbsDiffAllEq::abs_diff_all_eq(self, other, max_abs_diff),
+                        &[("abs_diff", &AssertAbsDiffEq::debug_abs_diff(self, other))],
+                        &[("abs_diff_all", &AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_abs_diff_all_eq_mixed_float!(f32 => f64, f64 => f32);
+
 impl<A, B> AssertAbsDiffAllEq<&B> for &A
 where
     A: AssertAbsDiffAllEq<B> + ?Sized,
@@ -623,6 +1066,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &&B, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<&mut B> for &A
@@ -636,6 +1090,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &&mut B, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<&B> for &mut A
@@ -649,6 +1114,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &&B, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<&mut B> for &mut A
@@ -662,6 +1138,17 @@ where
     fn debug_abs_diff_all_tolerance(&self, other: &&mut B, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(*self, *other, max_abs_diff)
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAbsDiffAllEq::visit_abs_diff_all(*self, *other, max_abs_diff, path, visitor)
+    }
 }
 
 impl<A, B, const N: usize> AssertAbsDiffAllEq<[B; N]> for [A; N]
@@ -679,6 +1166,23 @@ where
 
         unsafe { array_assume_init(result) }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAbsDiffAllEq::visit_abs_diff_all(&self[i], &other[i], max_abs_diff, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<cell::Cell<B>> for cell::Cell<A>
@@ -726,6 +1230,64 @@ where
             ref_max_abs_diff,
         ))
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff) {
+            (Some(a), Some(b), Some(max_abs_diff)) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertAbsDiffAllEq<Result<B, F>> for Result<A, E>
+where
+    A: AssertAbsDiffAllEq<B>,
+    E: AssertAbsDiffAllEq<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+{
+    type AllDebugTolerance = Option<Result<A::AllDebugTolerance, E::AllDebugTolerance>>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Result<B, F>, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => Some(Ok(AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(a, b, max_abs_diff))),
+            (Err(a), Err(b), Err(max_abs_diff)) => Some(Err(AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(a, b, max_abs_diff))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+            (Err(a), Err(b), Err(max_abs_diff)) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<cell::OnceCell<B>> for cell::OnceCell<A>
@@ -743,4 +1305,23 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
@@ -4,9 +4,18 @@ use crate::traits::{
     AssertAbsDiffAllEq,
     AssertAbsDiffEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
+
 use std::collections::HashMap;
 use std::fmt;
 use std::hash;
+use std::ops;
 use std::sync;
 
 impl<K, VA, VB, S> AbsDiffEq<HashMap<K, VB, S>> for HashMap<K, VA, S>
@@ -127,6 +136,42 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match (other.get(key), max_abs_diff.get(key)) {
+                (Some(b), Some(max_abs_diff)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+                _ => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffEq<sync::OnceLock<B>> for sync::OnceLock<A>
@@ -154,6 +199,25 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertAbsDiffEq::visit_abs_diff(a, b, max_abs_diff, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<K, VA, VB, S> AssertAbsDiffAllEq<HashMap<K, VB, S>> for HashMap<K, VA, S>
@@ -177,6 +241,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertAbsDiffAllEq<sync::OnceLock<B>> for sync::OnceLock<A>
@@ -194,4 +285,23 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertAbsDiffAllEq::visit_abs_diff_all(a, b, max_abs_diff, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
@@ -12,8 +12,11 @@ extern crate std;
 mod impl_core_slice;
 mod impl_core_tuple;
 mod impl_core_types;
+mod ordering;
 mod traits;
 
+pub mod abs_diff;
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod impl_alloc_types;
 
@@ -26,8 +29,12 @@ mod impl_std_types;
 pub use impl_core_slice::*;
 pub use impl_core_tuple::*;
 pub use impl_core_types::*;
+pub use ordering::*;
 pub use traits::*;
 
+pub use approx_cmp_float::*;
+pub use approx_cmp_report::*;
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 pub use impl_alloc_types::*;
 
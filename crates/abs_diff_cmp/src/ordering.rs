@@ -0,0 +1,474 @@
+use crate::traits::AbsDiffEq;
+
+use core::cmp::Ordering;
+
+/// A data type for ordering values up to the tolerances of an absolute
+/// difference comparison.
+///
+/// A tolerance-aware ordering treats two values as equal when they are absolute
+/// difference equal, and otherwise orders them with [`PartialOrd`]. It checks
+/// bounds that hold up to rounding, e.g. that the energy of a simulation never
+/// increases, where an energy exceeding its previous value by a rounding error
+/// still satisfies the bound.
+///
+/// * [`abs_diff_partial_cmp`] returns `Some(Ordering::Equal)` for absolute
+///   difference equal values.
+/// * [`abs_diff_lt`] and [`abs_diff_gt`] hold for strictly ordered values that
+///   are not absolute difference equal.
+/// * [`abs_diff_le`] and [`abs_diff_ge`] hold for strictly ordered values, and
+///   for absolute difference equal values.
+///
+/// NaN is unordered, so it is neither less than, greater than, nor equal to any
+/// number. The integer and floating point primitives implement this trait, as do
+/// `f32` and `f64` against each other. Containers do not, since their elements
+/// compared under tolerance do not order them.
+///
+/// [`abs_diff_partial_cmp`]: AbsDiffOrd::abs_diff_partial_cmp
+/// [`abs_diff_lt`]: AbsDiffOrd::abs_diff_lt
+/// [`abs_diff_gt`]: AbsDiffOrd::abs_diff_gt
+/// [`abs_diff_le`]: AbsDiffOrd::abs_diff_le
+/// [`abs_diff_ge`]: AbsDiffOrd::abs_diff_ge
+///
+/// # Example
+///
+/// ```
+/// # use core::cmp::Ordering;
+/// # use abs_diff_cmp::AbsDiffOrd;
+/// #
+/// let previous = 0.3_f64;
+/// let energy = 0.1_f64 + 0.2_f64;
+///
+/// assert!(energy > previous);
+/// assert!(energy.abs_diff_le(&previous, &1e-12_f64));
+/// assert!(!energy.abs_diff_lt(&previous, &1e-12_f64));
+/// assert_eq!(energy.abs_diff_partial_cmp(&previous, &1e-12_f64), Some(Ordering::Equal));
+/// assert_eq!(energy.abs_diff_partial_cmp(&f64::NAN, &1e-12_f64), None);
+/// ```
+///
+/// The first elements of these vectors are equal up to the tolerances, which
+/// does not decide whether the vectors are ordered, so they are not comparable.
+///
+/// ```compile_fail
+/// # use abs_diff_cmp::abs_diff_lt;
+/// #
+/// abs_diff_lt!(vec![1.0_f64, 5.0_f64], vec![1.0000001_f64, 0.0_f64], abs_diff <= vec![1e-3_f64, 1e-3_f64]);
+/// ```
+pub trait AbsDiffOrd<Rhs = Self>: AbsDiffEq<Rhs> {
+    /// Order `self` and `other`, treating absolute difference equal values as
+    /// equal.
+    ///
+    /// Returns `None` when the values are not absolute difference equal and
+    /// unordered.
+    fn abs_diff_partial_cmp(&self, other: &Rhs, max_abs_diff: &Self::Tolerance) -> Option<Ordering>;
+
+    /// Determine whether `self` is less than `other` and not absolute difference
+    /// equal to it.
+    #[inline]
+    fn abs_diff_lt(&self, other: &Rhs, max_abs_diff: &Self::Tolerance) -> bool {
+        matches!(Self::abs_diff_partial_cmp(self, other, max_abs_diff), Some(Ordering::Less))
+    }
+
+    /// Determine whether `self` is less than `other` or absolute difference equal
+    /// to it.
+    #[inline]
+    fn abs_diff_le(&self, other: &Rhs, max_abs_diff: &Self::Tolerance) -> bool {
+        matches!(
+            Self::abs_diff_partial_cmp(self, other, max_abs_diff),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// Determine whether `self` is greater than `other` and not absolute difference
+    /// equal to it.
+    #[inline]
+    fn abs_diff_gt(&self, other: &Rhs, max_abs_diff: &Self::Tolerance) -> bool {
+        matches!(Self::abs_diff_partial_cmp(self, other, max_abs_diff), Some(Ordering::Greater))
+    }
+
+    /// Determine whether `self` is greater than `other` or absolute difference
+    /// equal to it.
+    #[inline]
+    fn abs_diff_ge(&self, other: &Rhs, max_abs_diff: &Self::Tolerance) -> bool {
+        matches!(
+            Self::abs_diff_partial_cmp(self, other, max_abs_diff),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+}
+
+macro_rules! impl_abs_diff_ord {
+    ($($T:ident),* $(,)?) => {$(
+        impl AbsDiffOrd for $T {
+            #[inline]
+            fn abs_diff_partial_cmp(&self, other: &$T, max_abs_diff: &Self::Tolerance) -> Option<Ordering> {
+                if AbsDiffEq::abs_diff_eq(self, other, max_abs_diff) {
+                    Some(Ordering::Equal)
+                } else {
+                    PartialOrd::partial_cmp(self, other)
+                }
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_ord!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_abs_diff_ord_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AbsDiffOrd<$Rhs> for $T {
+            #[inline]
+            fn abs_diff_partial_cmp(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance) -> Option<Ordering> {
+                if AbsDiffEq::abs_diff_eq(self, other, max_abs_diff) {
+                    Some(Ordering::Equal)
+                } else {
+                    // Every `f32` widens to an `f64` exactly, so the order is decided in
+                    // `f64` precision.
+                    PartialOrd::partial_cmp(&f64::from(*self), &f64::from(*other))
+                }
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_ord_mixed_float!(f32 => f64, f64 => f32);
+
+/// Determine whether a finite precision floating point expression is less than
+/// another, treating absolute difference equal values as equal.
+///
+/// For more details, see the documentation for [`AbsDiffOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::abs_diff_lt;
+/// #
+/// assert!(abs_diff_lt!(0.25_f64, 0.5_f64, abs_diff <= 1e-12_f64));
+/// assert!(!abs_diff_lt!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! abs_diff_lt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => $crate::AbsDiffOrd::abs_diff_lt(left_val, right_val, tol_1_val),
+        }
+    }};
+}
+
+/// Determine whether a finite precision floating point expression is less than
+/// or equal to another, treating absolute difference equal values as equal.
+///
+/// For more details, see the documentation for [`AbsDiffOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::abs_diff_le;
+/// #
+/// assert!(abs_diff_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 1e-12_f64));
+/// assert!(!abs_diff_le!(0.5_f64, 0.25_f64, abs_diff <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! abs_diff_le {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => $crate::AbsDiffOrd::abs_diff_le(left_val, right_val, tol_1_val),
+        }
+    }};
+}
+
+/// Determine whether a finite precision floating point expression is greater
+/// than another, treating absolute difference equal values as equal.
+///
+/// For more details, see the documentation for [`AbsDiffOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::abs_diff_gt;
+/// #
+/// assert!(abs_diff_gt!(0.5_f64, 0.25_f64, abs_diff <= 1e-12_f64));
+/// assert!(!abs_diff_gt!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! abs_diff_gt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => $crate::AbsDiffOrd::abs_diff_gt(left_val, right_val, tol_1_val),
+        }
+    }};
+}
+
+/// Determine whether a finite precision floating point expression is greater
+/// than or equal to another, treating absolute difference equal values as
+/// equal.
+///
+/// For more details, see the documentation for [`AbsDiffOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::abs_diff_ge;
+/// #
+/// assert!(abs_diff_ge!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 1e-12_f64));
+/// assert!(!abs_diff_ge!(0.25_f64, 0.5_f64, abs_diff <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! abs_diff_ge {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => $crate::AbsDiffOrd::abs_diff_ge(left_val, right_val, tol_1_val),
+        }
+    }};
+}
+
+/// Assert that a finite precision floating point expression is less than
+/// another, treating absolute difference equal values as equal.
+///
+/// See the documentation for [`AbsDiffOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::assert_abs_diff_lt;
+/// #
+/// assert_abs_diff_lt!(0.25_f64, 0.5_f64, abs_diff <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_lt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_lt(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_lt!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_lt(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_lt!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that a finite precision floating point expression is less than or
+/// equal to another, treating absolute difference equal values as equal.
+///
+/// See the documentation for [`AbsDiffOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::assert_abs_diff_le;
+/// #
+/// assert_abs_diff_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_le {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_le(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_le!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_le(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_le!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that a finite precision floating point expression is greater than
+/// another, treating absolute difference equal values as equal.
+///
+/// See the documentation for [`AbsDiffOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::assert_abs_diff_gt;
+/// #
+/// assert_abs_diff_gt!(0.5_f64, 0.25_f64, abs_diff <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_gt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_gt(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_gt!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_gt(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_gt!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that a finite precision floating point expression is greater than or
+/// equal to another, treating absolute difference equal values as equal.
+///
+/// See the documentation for [`AbsDiffOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::assert_abs_diff_ge;
+/// #
+/// assert_abs_diff_ge!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_abs_diff_ge {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_ge(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_ge!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1) {
+            (left_val, right_val, tol_1_val) => {
+                if !$crate::AbsDiffOrd::abs_diff_ge(&*left_val, &*right_val, &*tol_1_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_ge!(left, right, abs_diff <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+[abs_diff] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
@@ -1,4 +1,16 @@
+use approx_cmp_report::{
+    Element,
+    ErrorStats,
+    FirstMismatch,
+    FloatPolicy,
+    Mismatch,
+    Path,
+    PolicyVisitor,
+    Visitor,
+};
+
 use core::fmt;
+use core::ops;
 
 /// Compare two sequences of finite precision floating point numbers using
 /// per entry absolute difference tolerances.
@@ -72,6 +84,34 @@ use core::fmt;
 /// // Using the [`abs_diff_ne`] macro.
 /// assert!(abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff3));
 /// ```
+///
+/// # Examples (Mixed Precision Comparisons)
+///
+/// An [`f32`] compares with an [`f64`] in either order. The [`f32`] widens to an
+/// [`f64`] exactly, and the absolute difference is measured in [`f64`] precision,
+/// so the tolerance is an [`f64`].
+///
+/// ```
+/// # use abs_diff_cmp::{
+/// #     abs_diff_eq,
+/// #     abs_diff_ne,
+/// #     AbsDiffEq,
+/// # };
+/// #
+/// let result = 0.1_f32;
+/// let reference = 0.1_f64;
+///
+/// // `0.1_f32` is `0.100000001490116...` as an `f64`.
+/// assert!(result.abs_diff_eq(&reference, &2e-9_f64));
+/// assert!(result.abs_diff_ne(&reference, &1e-9_f64));
+/// assert!(abs_diff_eq!(reference, result, abs_diff <= 2e-9_f64));
+///
+/// // Containers of `f32` compare with containers of `f64` element by element.
+/// let results = vec![0.1_f32, 0.2_f32, 0.3_f32];
+/// let references = vec![0.1_f64, 0.2_f64, 0.3_f64];
+///
+/// assert!(abs_diff_eq!(results, references, abs_diff_all <= 2e-8_f64));
+/// ```
 pub trait AbsDiffEq<Rhs = Self>
 where
     Rhs: ?Sized,
@@ -409,6 +449,93 @@ where
     /// assert_eq!(result, expected);
     /// ```
     fn debug_abs_diff_tolerance(&self, other: &Rhs, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance;
+
+    /// Report every element compared by [`AbsDiffEq::abs_diff_eq`] to a visitor,
+    /// along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, so that a failed
+    /// comparison can be traced to the elements that failed it. The traversal
+    /// stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abs_diff_cmp::{
+    /// #     AssertAbsDiffEq,
+    /// #     Element,
+    /// #     Path,
+    /// # };
+    /// # use core::ops::ControlFlow;
+    /// #
+    /// let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    /// let rhs = [1.0_f64, 2.5_f64, 3.0_f64];
+    /// let max_abs_diff = [0.1_f64; 3];
+    /// let mut failed = Vec::new();
+    /// let _ = lhs.visit_abs_diff(&rhs, &max_abs_diff, &mut Path::new(), &mut |path: &Path<'_>, element: &Element<'_>| {
+    ///     if !element.passed {
+    ///         failed.push(path.to_string());
+    ///     }
+    ///
+    ///     ControlFlow::Continue(())
+    /// });
+    ///
+    /// assert_eq!(failed, vec!["[1]"]);
+    /// ```
+    fn visit_abs_diff<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        let abs_diff = self.debug_abs_diff(other);
+        let tolerance = self.debug_abs_diff_tolerance(other, max_abs_diff);
+        visitor.visit(
+            path,
+            &Element::new(
+                self.abs_diff_eq(other, max_abs_diff),
+                &[("abs_diff", &abs_diff)],
+                &[("abs_diff", &tolerance)],
+            ),
+        )
+    }
+
+    /// Compare two values for absolute difference equality, with the pairs of
+    /// numbers involving NaNs, infinities, or signed zeros decided by `policy`.
+    ///
+    /// The elements reported by [`visit_abs_diff`] decide the comparison, so the
+    /// policy reaches every number compared inside a container.
+    ///
+    /// [`visit_abs_diff`]: AssertAbsDiffEq::visit_abs_diff
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abs_diff_cmp::{
+    /// #     AssertAbsDiffEq,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = [1.0_f64, f64::NAN];
+    /// let rhs = [1.0_f64, f64::NAN];
+    /// let nan_eq = FloatPolicy {
+    ///     nan_eq: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(lhs.abs_diff_eq_with_policy(&rhs, &[0.1_f64; 2], &nan_eq));
+    /// assert!(!lhs.abs_diff_eq_with_policy(&rhs, &[0.1_f64; 2], &FloatPolicy::new()));
+    /// ```
+    fn abs_diff_eq_with_policy(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, policy: &FloatPolicy) -> bool {
+        policy.passes(|path, visitor| self.visit_abs_diff(other, max_abs_diff, path, visitor))
+    }
+
+    /// Compare two values for absolute difference inequality, with the pairs of
+    /// numbers involving NaNs, infinities, or signed zeros decided by `policy`.
+    fn abs_diff_ne_with_policy(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, policy: &FloatPolicy) -> bool {
+        !Self::abs_diff_eq_with_policy(self, other, max_abs_diff, policy)
+    }
 }
 
 /// Provides a debugging context for when an absolute difference comparison using
@@ -443,11 +570,98 @@ where
     /// assert_eq!(result, expected);
     /// ```
     fn debug_abs_diff_all_tolerance(&self, other: &Rhs, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance;
+
+    /// Report every element compared by [`AbsDiffAllEq::abs_diff_all_eq`] to a
+    /// visitor, along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, so that a failed
+    /// comparison can be traced to the elements that failed it. The traversal
+    /// stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abs_diff_cmp::{
+    /// #     AssertAbsDiffAllEq,
+    /// #     FirstMismatch,
+    /// #     Path,
+    /// # };
+    /// #
+    /// let lhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    /// let rhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    /// let mut first = FirstMismatch::new();
+    /// let _ = lhs.visit_abs_diff_all(&rhs, &0.1_f64, &mut Path::new(), &mut first);
+    ///
+    /// assert_eq!(first.path().map(|path| path.to_string()), Some(String::from("[1][1]")));
+    /// ```
+    fn visit_abs_diff_all<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        let tolerance = self.debug_abs_diff_all_tolerance(other, max_abs_diff);
+        visitor.visit(
+            path,
+            &Element::new(self.abs_diff_all_eq(other, max_abs_diff), &[], &[("abs_diff_all", &tolerance)]),
+        )
+    }
+
+    /// Compare two values for absolute difference equality using a uniform
+    /// tolerance, with the pairs of numbers involving NaNs, infinities, or signed
+    /// zeros decided by `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use abs_diff_cmp::{
+    /// #     AssertAbsDiffAllEq,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = [-0.0_f32, 1.0_f32];
+    /// let rhs = [0.0_f32, 1.0_f32];
+    /// let signed_zero_strict = FloatPolicy {
+    ///     signed_zero_strict: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(lhs.abs_diff_all_eq_with_policy(&rhs, &0.1_f32, &FloatPolicy::new()));
+    /// assert!(!lhs.abs_diff_all_eq_with_policy(&rhs, &0.1_f32, &signed_zero_strict));
+    /// ```
+    fn abs_diff_all_eq_with_policy(&self, other: &Rhs, max_abs_diff: &Self::AllTolerance, policy: &FloatPolicy) -> bool {
+        policy.passes(|path, visitor| self.visit_abs_diff_all(other, max_abs_diff, path, visitor))
+    }
+
+    /// Compare two values for absolute difference inequality using a uniform
+    /// tolerance, with the pairs of numbers involving NaNs, infinities, or signed
+    /// zeros decided by `policy`.
+    fn abs_diff_all_ne_with_policy(&self, other: &Rhs, max_abs_diff: &Self::AllTolerance, policy: &FloatPolicy) -> bool {
+        !Self::abs_diff_all_eq_with_policy(self, other, max_abs_diff, policy)
+    }
 }
 
+/// The failed absolute difference comparison returned by [`check_abs_diff_eq`] and
+/// [`check_abs_diff_ne`].
+///
+/// The debugging values are the ones computed by [`AssertAbsDiffEq`].
+pub type AbsDiffMismatch<'a, A, B> = Mismatch<'a, A, B, <A as AssertAbsDiffEq<B>>::DebugAbsDiff, <A as AssertAbsDiffEq<B>>::DebugTolerance>;
+
+/// The failed absolute difference comparison returned by [`check_abs_diff_eq`] and
+/// [`check_abs_diff_ne`] with `all` parameters.
+///
+/// The debugging values are the ones computed by [`AssertAbsDiffAllEq`].
+pub type AbsDiffAllMismatch<'a, A, B> =
+    Mismatch<'a, A, B, <A as AssertAbsDiffEq<B>>::DebugAbsDiff, <A as AssertAbsDiffAllEq<B>>::AllDebugTolerance>;
+
 #[doc(hidden)]
 pub struct AbsDiffCmp {}
 
+// The mismatches hold their paths inline, so that they can be returned without
+// allocating.
+#[allow(clippy::result_large_err)]
 impl AbsDiffCmp {
     #[must_use]
     #[inline]
@@ -488,6 +702,220 @@ impl AbsDiffCmp {
     {
         A::abs_diff_all_ne(lhs, rhs, max_abs_diff)
     }
+
+    #[inline]
+    pub fn check_eq<'a, A, B>(lhs: &'a A, rhs: &'a B, max_abs_diff: &A::Tolerance) -> Result<(), AbsDiffMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::abs_diff_eq(lhs, rhs, max_abs_diff) {
+            return Ok(());
+        }
+
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_abs_diff(lhs, rhs, max_abs_diff, &mut Path::new(), &mut first);
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+            path: first.into_path().unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn check_ne<'a, A, B>(lhs: &'a A, rhs: &'a B, max_abs_diff: &A::Tolerance) -> Result<(), AbsDiffMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::abs_diff_ne(lhs, rhs, max_abs_diff) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_eq<'a, A, B>(lhs: &'a A, rhs: &'a B, max_abs_diff: &A::AllTolerance) -> Result<(), AbsDiffAllMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + AssertAbsDiffAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::abs_diff_all_eq(lhs, rhs, max_abs_diff) {
+            return Ok(());
+        }
+
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_abs_diff_all(lhs, rhs, max_abs_diff, &mut Path::new(), &mut first);
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+            path: first.into_path().unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_ne<'a, A, B>(lhs: &'a A, rhs: &'a B, max_abs_diff: &A::AllTolerance) -> Result<(), AbsDiffAllMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + AssertAbsDiffAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::abs_diff_all_ne(lhs, rhs, max_abs_diff) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_eq_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), AbsDiffMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_abs_diff(
+            lhs,
+            rhs,
+            max_abs_diff,
+            &mut Path::new(),
+            &mut PolicyVisitor::new(*policy, &mut first),
+        );
+        let Some(path) = first.into_path() else {
+            return Ok(());
+        };
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+            path,
+        })
+    }
+
+    #[inline]
+    pub fn check_ne_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), AbsDiffMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::abs_diff_ne_with_policy(lhs, rhs, max_abs_diff, policy) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_eq_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), AbsDiffAllMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + AssertAbsDiffAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_abs_diff_all(
+            lhs,
+            rhs,
+            max_abs_diff,
+            &mut Path::new(),
+            &mut PolicyVisitor::new(*policy, &mut first),
+        );
+        let Some(path) = first.into_path() else {
+            return Ok(());
+        };
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+            path,
+        })
+    }
+
+    #[inline]
+    pub fn check_all_ne_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), AbsDiffAllMismatch<'a, A, B>>
+    where
+        A: AssertAbsDiffEq<B> + AssertAbsDiffAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::abs_diff_all_ne_with_policy(lhs, rhs, max_abs_diff, policy) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+            path: Path::new(),
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn error_stats<'a, A, B>(lhs: &'a A, rhs: &B, max_abs_diff: &A::Tolerance) -> ErrorStats<'a>
+    where
+        A: AssertAbsDiffEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        ErrorStats::from_visit(|path, visitor| A::visit_abs_diff(lhs, rhs, max_abs_diff, path, visitor))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn error_stats_all<'a, A, B>(lhs: &'a A, rhs: &B, max_abs_diff: &A::AllTolerance) -> ErrorStats<'a>
+    where
+        A: AssertAbsDiffAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        ErrorStats::from_visit(|path, visitor| A::visit_abs_diff_all(lhs, rhs, max_abs_diff, path, visitor))
+    }
 }
 
 #[doc(hidden)]
@@ -511,21 +939,68 @@ impl AbsDiffCmpOpTol {
     }
 }
 
+#[doc(hidden)]
+pub struct AbsDiffCmpOpVisit {}
+
+impl AbsDiffCmpOpVisit {
+    #[inline]
+    pub fn abs_diff<'a, A, B>(
+        lhs: &'a A,
+        rhs: &B,
+        max_abs_diff: &A::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()>
+    where
+        A: AbsDiffEq<B> + AssertAbsDiffEq<B>,
+    {
+        A::visit_abs_diff(lhs, rhs, max_abs_diff, path, visitor)
+    }
+
+    #[inline]
+    pub fn abs_diff_all<'a, A, B>(
+        lhs: &'a A,
+        rhs: &B,
+        max_abs_diff: &A::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()>
+    where
+        A: AbsDiffAllEq<B> + AssertAbsDiffAllEq<B>,
+    {
+        A::visit_abs_diff_all(lhs, rhs, max_abs_diff, path, visitor)
+    }
+}
+
 /// Compare two finite precision floating point expressions for absolute difference
 /// equality.
 ///
 /// For more details, see the documentation for [`AbsDiffEq`] and [`AbsDiffAllEq`].
 ///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
 /// # Example
 ///
 /// ```
-/// # use abs_diff_cmp::abs_diff_eq;
+/// # use abs_diff_cmp::{
+/// #     FloatPolicy,
+/// #     abs_diff_eq,
+/// # };
 /// #
 /// let lhs = 98.0005_f32;
 /// let rhs = 98.0001_f32;
 ///
 /// assert!(abs_diff_eq!(lhs, rhs, abs_diff <= 0.0005_f32));
 /// assert!(abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.0005_f32));
+///
+/// let nan_eq = FloatPolicy {
+///     nan_eq: true,
+///     ..FloatPolicy::new()
+/// };
+///
+/// assert!(!abs_diff_eq!(f32::NAN, f32::NAN, abs_diff <= 0.0005_f32));
+/// assert!(abs_diff_eq!(f32::NAN, f32::NAN, abs_diff <= 0.0005_f32, policy = nan_eq));
 /// ```
 #[macro_export]
 macro_rules! abs_diff_eq {
@@ -539,6 +1014,13 @@ macro_rules! abs_diff_eq {
             (left_val, right_val) => $crate::AbsDiffCmp::all_eq(left_val, right_val, &$tol),
         }
     }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol, &$policy) {
+            (left_val, right_val, tol_val, policy_val) => $crate::FloatPolicy::passes(policy_val, |path, visitor| {
+                $crate::AbsDiffCmpOpVisit::$eq1(left_val, right_val, tol_val, path, visitor)
+            }),
+        }
+    }};
 }
 
 /// Compare two finite precision floating point expressions for absolute difference
@@ -569,6 +1051,103 @@ macro_rules! abs_diff_ne {
             (left_val, right_val) => $crate::AbsDiffCmp::all_ne(left_val, right_val, &$tol),
         }
     }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol, &$policy) {
+            (left_val, right_val, tol_val, policy_val) => !$crate::FloatPolicy::passes(policy_val, |path, visitor| {
+                $crate::AbsDiffCmpOpVisit::$eq1(left_val, right_val, tol_val, path, visitor)
+            }),
+        }
+    }};
+}
+
+/// Check whether two finite precision floating point expressions are absolute
+/// difference equal, without panicking.
+///
+/// The macro returns `Ok(())` when the comparison succeeds, and a [`Mismatch`]
+/// otherwise. The mismatch holds the compared values, their absolute difference,
+/// their tolerance, and the path of the first element that failed the comparison.
+/// The mismatch borrows the compared values, so they must outlive it.
+///
+/// See the documentation for [`AbsDiffEq`] and [`AbsDiffAllEq`] for details about
+/// absolute difference comparisons. See the documentation for [`AssertAbsDiffEq`] and
+/// [`AssertAbsDiffAllEq`] for details about the debugging values reported when a
+/// comparison fails.
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::check_abs_diff_eq;
+/// #
+/// let lhs = [98.0_f32, 99.0_f32, 100.0_f32];
+/// let rhs = [98.0199_f32, 99.0_f32, 100.5_f32];
+///
+/// assert!(check_abs_diff_eq!(lhs, rhs, abs_diff <= [0.03_f32; 3]).is_err());
+/// assert!(check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.6_f32).is_ok());
+///
+/// let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.03_f32).unwrap_err();
+///
+/// assert_eq!(mismatch.path.to_string(), "[2]");
+/// assert_eq!(mismatch.diffs[2], 0.5_f32);
+/// ```
+#[macro_export]
+macro_rules! check_abs_diff_eq {
+    ($left:expr, $right:expr, abs_diff <= $tol:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_eq(&$left, &$right, &$tol)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_all_eq(&$left, &$right, &$tol)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol:expr, policy = $policy:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_eq_with_policy(&$left, &$right, &$tol, &$policy)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol:expr, policy = $policy:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_all_eq_with_policy(&$left, &$right, &$tol, &$policy)
+    };
+}
+
+/// Check whether two finite precision floating point expressions are absolute
+/// difference unequal, without panicking.
+///
+/// The macro returns `Ok(())` when the comparison succeeds, and a [`Mismatch`]
+/// otherwise. Since no single element fails an inequality comparison, the path
+/// of the mismatch is the path of the compared values themselves.
+///
+/// See the documentation for [`AbsDiffEq`] and [`AbsDiffAllEq`] for details about
+/// absolute difference comparisons. See the documentation for [`AssertAbsDiffEq`] and
+/// [`AssertAbsDiffAllEq`] for details about the debugging values reported when a
+/// comparison fails.
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use abs_diff_cmp::check_abs_diff_ne;
+/// #
+/// let lhs = 98.0_f32;
+/// let rhs = 98.0199_f32;
+///
+/// assert!(check_abs_diff_ne!(lhs, rhs, abs_diff <= 0.01_f32).is_ok());
+/// assert!(check_abs_diff_ne!(lhs, rhs, abs_diff_all <= 0.03_f32).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_abs_diff_ne {
+    ($left:expr, $right:expr, abs_diff <= $tol:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_ne(&$left, &$right, &$tol)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_all_ne(&$left, &$right, &$tol)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol:expr, policy = $policy:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_ne_with_policy(&$left, &$right, &$tol, &$policy)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol:expr, policy = $policy:expr $(,)?) => {
+        $crate::AbsDiffCmp::check_all_ne_with_policy(&$left, &$right, &$tol, &$policy)
+    };
 }
 
 /// Assert that two finite precision floating point expressions are absolute
@@ -579,6 +1158,12 @@ macro_rules! abs_diff_ne {
 /// [`AssertAbsDiffAllEq`] for details about the debugging context provided
 /// when an assertion fails.
 ///
+/// A failed assertion prints the path and the values of its first failing
+/// element, followed by the number of failing elements and a list of the
+/// failing elements, so that the panic message stays short for large
+/// containers. Enable the `full_dump` feature to print the compared values in
+/// full as well.
+///
 /// # Example
 ///
 /// ```
@@ -592,6 +1177,77 @@ macro_rules! abs_diff_ne {
 /// ```
 #[macro_export]
 macro_rules! assert_abs_diff_eq {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$policy) {
+            (left_val, right_val, tol_1_val, policy_val) => {
+                if !$crate::abs_diff_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_eq!(left, right, ", stringify!($eq1), " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+{}
+{}{}"#),
+                        &*policy_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        )),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, policy = $policy:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$policy) {
+            (left_val, right_val, tol_1_val, policy_val) => {
+                if !$crate::abs_diff_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_eq!(left, right, ", stringify!($eq1), " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+{}
+{}{}: {}"#),
+                        &*policy_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        )),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr $(,)?) => {{
         match (&$left, &$right, &$tol_1) {
             (left_val, right_val, tol_1_val) => {
@@ -601,15 +1257,25 @@ macro_rules! assert_abs_diff_eq {
                     // noticeable slow down. See the documentation for `core::assert_eq`.
                     panic!(concat!(
 "assertion failed: `abs_diff_eq!(left, right, ", stringify!($eq1), " <= t)`", r#"
+{}
+{}{}"#),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
         left: `{:?}`,
        right: `{:?}`,
     abs_diff: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        )),
                     )
                 }
             }
@@ -624,15 +1290,25 @@ macro_rules! assert_abs_diff_eq {
                     // noticeable slow down. See the documentation for `core::assert_eq`.
                     panic!(concat!(
 "assertion failed: `abs_diff_eq!(left, right, ", stringify!($eq1), " <= t)`", r#"
+{}
+{}{}: {}"#),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AbsDiffCmpOpVisit::$eq1(&*left_val, &*right_val, &*tol_1_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
         left: `{:?}`,
        right: `{:?}`,
     abs_diff: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        )),
                         format_args!($($arg)+),
                     )
                 }
@@ -662,6 +1338,57 @@ macro_rules! assert_abs_diff_eq {
 /// ```
 #[macro_export]
 macro_rules! assert_abs_diff_ne {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$policy) {
+            (left_val, right_val, tol_1_val, policy_val) => {
+                if !$crate::abs_diff_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_ne!(left, right, ", stringify!($eq1), " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+{:>10} t: `{:?}`"#),
+                        &*policy_val,
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, policy = $policy:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$policy) {
+            (left_val, right_val, tol_1_val, policy_val) => {
+                if !$crate::abs_diff_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `abs_diff_ne!(left, right, ", stringify!($eq1), " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+{:>10} t: `{:?}`: {}"#),
+                        &*policy_val,
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr $(,)?) => {{
         match (&$left, &$right, &$tol_1) {
             (left_val, right_val, tol_1_val) => {
@@ -700,7 +1427,7 @@ macro_rules! assert_abs_diff_ne {
 {:>10} t: `{:?}`: {}"#),
                         &*left_val,
                         &*right_val,
-                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertAbsDiffEq::debug_abs_diff(&*left_val, &*right_val),
                         concat!("[", stringify!($eq1), "]"),
                         $crate::AbsDiffCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
                         format_args!($($arg)+),
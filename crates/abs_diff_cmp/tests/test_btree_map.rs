@@ -408,7 +408,7 @@ fn test_ne_empty1() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = BTreeMap::from([
         ("0", 1.0_f32 * f32::EPSILON),
         ("1", 0.5_f32 * f32::EPSILON),
@@ -479,7 +479,7 @@ fn test_all_ne_empty1() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = 4.0_f32 * f32::EPSILON;
 
     assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= max_abs_diff);
@@ -521,7 +521,7 @@ fn test_debug_abs_diff_empty1() {
         ("7", 4.00_f32),
         ("8", 5.00_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -530,7 +530,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = BTreeMap::new();
+    let lhs = BTreeMap::<&str, f32>::new();
     let rhs = BTreeMap::from([
         ("0", 0.0000000_f32),
         ("1", 1.0000000_f32),
@@ -577,7 +577,7 @@ fn test_debug_abs_diff_all_tolerance_empty() {
         ("2", 4.50_f32),
         ("3", 5.75_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = 0.20_f32;
 
     assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), None);
@@ -0,0 +1,171 @@
+use abs_diff_cmp::{
+    AssertAbsDiffEq,
+    Element,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    check_abs_diff_eq,
+    check_abs_diff_ne,
+};
+use core::ops::ControlFlow;
+
+#[test]
+fn test_check_eq_ok() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = [1.0_f64, 2.0_f64, 3.0000000000000004_f64];
+
+    assert!(check_abs_diff_eq!(lhs, rhs, abs_diff <= [4.0_f64 * f64::EPSILON; 3]).is_ok());
+    assert!(check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 4.0_f64 * f64::EPSILON).is_ok());
+}
+
+#[test]
+fn test_check_eq_scalar() {
+    let lhs = 1.0_f32;
+    let rhs = 1.5_f32;
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= 0.25_f32).unwrap_err();
+
+    assert_eq!(mismatch.left, &lhs);
+    assert_eq!(mismatch.right, &rhs);
+    assert_eq!(mismatch.diffs, 0.5_f32);
+    assert_eq!(mismatch.tolerances, 0.25_f32);
+    assert!(mismatch.path.is_root());
+}
+
+#[test]
+fn test_check_eq_array_path() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = [1.0_f64, 2.0_f64, 3.5_f64, 4.5_f64];
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= [0.1_f64; 4]).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+    assert_eq!(mismatch.diffs, [0.0_f64, 0.0_f64, 0.5_f64, 0.5_f64]);
+    assert_eq!(mismatch.tolerances, [0.1_f64; 4]);
+}
+
+#[test]
+fn test_check_all_eq_nested_path() {
+    let lhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    let rhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert!(matches!(mismatch.path.segments(), [PathSegment::Index(1), PathSegment::Index(1)]));
+    assert_eq!(mismatch.path.to_string(), "[1][1]");
+}
+
+#[test]
+fn test_check_eq_slice_path() {
+    let lhs: &[f64] = &[1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs: &[f64] = &[1.0_f64, 2.5_f64, 3.0_f64];
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+}
+
+#[test]
+fn test_check_eq_option_path() {
+    let lhs = Some([1.0_f64, 2.0_f64]);
+    let rhs = Some([1.0_f64, 2.5_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= Some([0.1_f64; 2])).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+}
+
+#[test]
+fn test_check_ne() {
+    let lhs = [1.0_f64, 2.0_f64];
+    let rhs = [1.0_f64, 2.5_f64];
+
+    assert!(check_abs_diff_ne!(lhs, rhs, abs_diff <= [0.1_f64; 2]).is_ok());
+    assert!(check_abs_diff_ne!(lhs, rhs, abs_diff_all <= 0.1_f64).is_ok());
+
+    let mismatch = check_abs_diff_ne!(lhs, rhs, abs_diff_all <= 1.0_f64).unwrap_err();
+
+    assert!(mismatch.path.is_root());
+}
+
+#[test]
+fn test_mismatch_display() {
+    let lhs = [1.0_f64, 2.0_f64];
+    let rhs = [1.0_f64, 2.5_f64];
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+    let message = mismatch.to_string();
+
+    assert!(message.contains("comparison failed at `[1]`"));
+    assert!(message.contains("[1.0, 2.5]"));
+}
+
+#[test]
+fn test_visit_every_element() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = [1.5_f64, 2.0_f64, 3.5_f64];
+    let mut visited = Vec::new();
+    let _ = lhs.visit_abs_diff(
+        &rhs,
+        &[0.1_f64; 3],
+        &mut Path::new(),
+        &mut |path: &Path<'_>, element: &Element<'_>| {
+            visited.push((path.to_string(), element.passed));
+
+            ControlFlow::Continue(())
+        },
+    );
+
+    let expected = vec![(String::from("[0]"), false), (String::from("[1]"), true), (String::from("[2]"), false)];
+
+    assert_eq!(visited, expected);
+}
+
+#[test]
+fn test_visit_break() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = [1.5_f64, 2.0_f64, 3.5_f64];
+    let mut visited = 0;
+    let result = lhs.visit_abs_diff(
+        &rhs,
+        &[0.1_f64; 3],
+        &mut Path::new(),
+        &mut |_path: &Path<'_>, _element: &Element<'_>| {
+            visited += 1;
+
+            ControlFlow::Break(())
+        },
+    );
+
+    assert_eq!(result, ControlFlow::Break(()));
+    assert_eq!(visited, 1);
+}
+
+#[test]
+fn test_visit_variant_mismatch() {
+    let lhs = Some(1.0_f64);
+    let rhs: Option<f64> = None;
+    let mut structure = None;
+    let _ = lhs.visit_abs_diff(
+        &rhs,
+        &Some(0.1_f64),
+        &mut Path::new(),
+        &mut |_path: &Path<'_>, element: &Element<'_>| {
+            structure = Some(format!("{:?}", element.diffs[0].1));
+
+            ControlFlow::Continue(())
+        },
+    );
+
+    let expected = StructureMismatch::Variant(VariantMismatch {
+        left: "Some",
+        right: "None",
+        tolerance: Some("Some"),
+    });
+
+    assert_eq!(structure, Some(format!("{:?}", expected)));
+}
+
+#[test]
+fn test_visit_slice_len_mismatch() {
+    let lhs: &[f64] = &[1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs: &[f64] = &[1.0_f64, 2.0_f64];
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert!(mismatch.path.is_root());
+}
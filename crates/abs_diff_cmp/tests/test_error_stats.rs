@@ -0,0 +1,62 @@
+use abs_diff_cmp::{
+    AbsDiffCmp,
+    assert_abs_diff_eq,
+};
+use std::collections::BTreeMap;
+
+#[test]
+fn test_error_stats_vec() {
+    let lhs = vec![1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = vec![1.0_f64, 2.5_f64, 3.0_f64, 2.0_f64];
+    let stats = AbsDiffCmp::error_stats_all(&lhs, &rhs, &0.1_f64);
+    let max_abs_diff = stats.max_abs_diff().unwrap();
+    let max_relative = stats.max_relative().unwrap();
+
+    assert_eq!(stats.elements(), 4);
+    assert_eq!(stats.failed(), 2);
+    assert_eq!(max_abs_diff.value, 2.0_f64);
+    assert_eq!(max_abs_diff.path.to_string(), "[3]");
+    assert_eq!(max_relative.value, 0.5_f64);
+    assert_eq!(max_relative.path.to_string(), "[3]");
+    assert_eq!(stats.mean_abs_diff(), Some(0.625_f64));
+}
+
+#[test]
+fn test_error_stats_array_passing() {
+    let lhs = [1.0_f32, 2.0_f32, 3.0_f32];
+    let rhs = [1.0_f32, 2.0_f32, 3.0_f32];
+    let stats = AbsDiffCmp::error_stats(&lhs, &rhs, &[0.1_f32; 3]);
+
+    assert_eq!(stats.failed(), 0);
+    assert_eq!(stats.max_abs_diff().map(|max| max.value), Some(0.0_f64));
+    assert_eq!(stats.max_ulps().map(|max| max.value), Some(0));
+    assert_eq!(stats.rms_abs_diff(), Some(0.0_f64));
+}
+
+#[test]
+fn test_error_stats_map() {
+    let lhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64), ("c", 3.0_f64)]);
+    let rhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64), ("c", 3.5_f64)]);
+    let stats = AbsDiffCmp::error_stats_all(&lhs, &rhs, &0.1_f64);
+
+    assert_eq!(stats.max_abs_diff().unwrap().path.to_string(), "[\"c\"]");
+}
+
+#[test]
+fn test_error_stats_ints() {
+    let lhs = [1_i32, -5_i32, 3_i32];
+    let rhs = [1_i32, 5_i32, 4_i32];
+    let stats = AbsDiffCmp::error_stats_all(&lhs, &rhs, &0_u32);
+
+    assert_eq!(stats.max_abs_diff().map(|max| max.value), Some(10.0_f64));
+    assert!(stats.max_ulps().is_none());
+}
+
+#[test]
+#[should_panic(expected = "error statistics over 4 elements:\n     max abs_diff: `2.0` at `[3]`,")]
+fn test_assert_eq_panic_message_stats() {
+    let lhs = vec![1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = vec![1.0_f64, 2.5_f64, 3.0_f64, 2.0_f64];
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64);
+}
@@ -0,0 +1,169 @@
+use abs_diff_cmp::{
+    AssertAbsDiffEq,
+    FirstFailure,
+    assert_abs_diff_eq,
+    check_abs_diff_eq,
+};
+use std::cell::OnceCell;
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    LinkedList,
+    VecDeque,
+};
+use std::rc::Rc;
+use std::sync::{
+    Arc,
+    OnceLock,
+};
+
+#[test]
+fn test_tuple_path() {
+    let lhs = (1.0_f64, 2.0_f64, 3.0_f64);
+    let rhs = (1.0_f64, 2.5_f64, 3.0_f64);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= (0.1_f64, 0.1_f64, 0.1_f64)).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), ".1");
+
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), ".1");
+}
+
+#[test]
+fn test_vec_of_arrays_path() {
+    let lhs = vec![[1.0_f32, 2.0_f32, 3.0_f32]; 3];
+    let mut rhs = lhs.clone();
+    rhs[1][2] = 3.5_f32;
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f32).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1][2]");
+}
+
+#[test]
+fn test_sequence_paths() {
+    let lhs = VecDeque::from([1.0_f64, 2.0_f64, 3.0_f64]);
+    let rhs = VecDeque::from([1.0_f64, 2.0_f64, 3.5_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+
+    let lhs = LinkedList::from([1.0_f64, 2.0_f64, 3.0_f64]);
+    let rhs = LinkedList::from([1.5_f64, 2.0_f64, 3.0_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[0]");
+}
+
+#[test]
+fn test_map_paths() {
+    let lhs = BTreeMap::from([("a", (1.0_f64, 2.0_f64)), ("b", (3.0_f64, 4.0_f64))]);
+    let rhs = BTreeMap::from([("a", (1.0_f64, 2.0_f64)), ("b", (3.0_f64, 4.5_f64))]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[\"b\"].1");
+
+    let lhs = HashMap::from([("key", 1.0_f64)]);
+    let rhs = HashMap::from([("key", 1.5_f64)]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[\"key\"]");
+}
+
+#[test]
+fn test_map_missing_key() {
+    let lhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64)]);
+    let rhs = BTreeMap::from([("a", 1.0_f64), ("c", 2.0_f64)]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[\"b\"]");
+}
+
+#[test]
+fn test_smart_pointer_paths() {
+    let lhs = Box::new([1.0_f64, 2.0_f64]);
+    let rhs = Box::new([1.0_f64, 2.5_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+
+    let lhs = Rc::new([1.0_f64, 2.0_f64]);
+    let rhs = Rc::new([1.5_f64, 2.0_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[0]");
+
+    let lhs = Arc::new(([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]));
+    let rhs = Arc::new(([1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]));
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), ".1[1]");
+}
+
+#[test]
+fn test_once_paths() {
+    let lhs = OnceLock::from([1.0_f64, 2.0_f64]);
+    let rhs = OnceLock::from([1.0_f64, 2.5_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+
+    let lhs = OnceCell::from([1.0_f64, 2.0_f64]);
+    let rhs = OnceCell::from([1.5_f64, 2.0_f64]);
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[0]");
+}
+
+#[test]
+fn test_first_failure_display() {
+    let lhs = vec![(1.0_f64, 2.0_f64), (3.0_f64, 4.0_f64)];
+    let rhs = vec![(1.0_f64, 2.0_f64), (3.0_f64, 4.5_f64)];
+    let tolerance = vec![(0.1_f64, 0.1_f64); 2];
+    let failure = FirstFailure::new(|path, visitor| AssertAbsDiffEq::visit_abs_diff(&lhs, &rhs, &tolerance, path, visitor));
+    let expected = "\
+first failure at `[1].1`:
+        left: `4.0`,
+       right: `4.5`,
+    abs_diff: `0.5`,
+[abs_diff] t: `0.1`,";
+
+    assert_eq!(failure.to_string(), expected);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[1][2]`:\n        left: `3.0`,\n       right: `3.5`,")]
+fn test_assert_eq_panic_message() {
+    let lhs = vec![[1.0_f32, 2.0_f32, 3.0_f32]; 3];
+    let mut rhs = lhs.clone();
+    rhs[1][2] = 3.5_f32;
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f32);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[\"a\"].1`")]
+fn test_assert_eq_panic_message_map() {
+    let lhs = HashMap::from([("a", (1.0_f64, 2.0_f64))]);
+    let rhs = HashMap::from([("a", (1.0_f64, 2.5_f64))]);
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff <= HashMap::from([("a", (0.1_f64, 0.1_f64))]));
+}
+
+#[test]
+#[should_panic(expected = "failed elements: 2 of 6\n         [0]: left: `1.0`, right: `1.5`")]
+fn test_assert_eq_panic_message_summary() {
+    let lhs = vec![1.0_f64; 6];
+    let rhs = vec![1.5_f64, 1.0_f64, 1.0_f64, 1.0_f64, 1.0_f64, 1.5_f64];
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[1]`:\n        left: `-2147483648`,\n       right: `1`,\n    abs_diff: `2147483649`,")]
+fn test_assert_eq_panic_message_signed_integer_range() {
+    let lhs = [0_i32, i32::MIN];
+    let rhs = [0_i32, 1_i32];
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 1_u32);
+}
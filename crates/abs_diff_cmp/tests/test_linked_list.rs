@@ -204,7 +204,7 @@ fn test_ne_empty1() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = LinkedList::new();
+    let rhs = LinkedList::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = LinkedList::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -250,7 +250,7 @@ fn test_all_ne_empty1() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = LinkedList::new();
+    let rhs = LinkedList::<f32>::new();
     let max_abs_diff = 4.0_f32 * f32::EPSILON;
 
     assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= max_abs_diff);
@@ -278,7 +278,7 @@ fn test_debug_abs_diff_empty1() {
         1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32,
         2.50_f32, 3.00_f32, 4.00_f32, 5.00_f32,
     ]);
-    let rhs = LinkedList::new();
+    let rhs = LinkedList::<f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -287,7 +287,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = LinkedList::new();
+    let lhs = LinkedList::<f32>::new();
     let rhs = LinkedList::from([
         1.0000000_f32, 1.9999500_f32, 3.0000000_f32, 4.0000005_f32,
         5.0000000_f32, 6.0000000_f32, 7.0000000_f32, 8.0000000_f32,
@@ -310,7 +310,7 @@ fn test_debug_abs_diff_tolerance_empty() {
 #[test]
 fn test_debug_abs_diff_all_tolerance_empty() {
     let lhs = LinkedList::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = LinkedList::new();
+    let rhs = LinkedList::<f32>::new();
     let max_abs_diff = 0.20_f32;
 
     assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), None);
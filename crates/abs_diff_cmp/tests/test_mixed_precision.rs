@@ -0,0 +1,79 @@
+use abs_diff_cmp::{
+    AbsDiffAllEq,
+    AbsDiffEq,
+    AssertAbsDiffEq,
+    abs_diff_eq,
+    abs_diff_ne,
+    assert_abs_diff_eq,
+    assert_abs_diff_ne,
+};
+
+#[test]
+fn test_eq_f32_f64() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+
+    assert!(lhs.abs_diff_eq(&rhs, &2e-9_f64));
+    assert!(rhs.abs_diff_eq(&lhs, &2e-9_f64));
+    assert!(lhs.abs_diff_all_eq(&rhs, &2e-9_f64));
+    assert!(abs_diff_eq!(lhs, rhs, abs_diff <= 2e-9_f64));
+    assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= 2e-9_f64);
+}
+
+#[test]
+fn test_ne_f32_f64() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+
+    assert!(lhs.abs_diff_ne(&rhs, &1e-9_f64));
+    assert!(rhs.abs_diff_ne(&lhs, &1e-9_f64));
+    assert!(lhs.abs_diff_all_ne(&rhs, &1e-9_f64));
+    assert!(abs_diff_ne!(lhs, rhs, abs_diff <= 1e-9_f64));
+    assert_abs_diff_ne!(rhs, lhs, abs_diff_all <= 1e-9_f64);
+}
+
+#[test]
+fn test_eq_exactly_representable() {
+    for value in [0.0_f32, -0.0_f32, 1.0_f32, -2.5_f32, f32::MAX, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(value.abs_diff_eq(&f64::from(value), &0.0_f64));
+        assert!(f64::from(value).abs_diff_eq(&value, &0.0_f64));
+    }
+}
+
+#[test]
+fn test_ne_nan() {
+    assert!(f32::NAN.abs_diff_ne(&f64::NAN, &f64::INFINITY));
+    assert!(1.0_f64.abs_diff_ne(&f32::NAN, &f64::INFINITY));
+}
+
+#[test]
+fn test_debug_abs_diff_measured_in_f64() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+
+    assert_eq!(lhs.debug_abs_diff(&rhs), f64::from(lhs) - rhs);
+    assert_eq!(rhs.debug_abs_diff(&lhs), f64::from(lhs) - rhs);
+    assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &0.5_f64), 0.5_f64);
+}
+
+#[test]
+fn test_eq_containers() {
+    let lhs = vec![0.1_f32, 0.2_f32, 0.3_f32];
+    let rhs = vec![0.1_f64, 0.2_f64, 0.3_f64];
+    let max_abs_diff = vec![2e-9_f64, 3e-9_f64, 2e-8_f64];
+
+    assert!(lhs.abs_diff_eq(&rhs, &max_abs_diff));
+    assert!(rhs.abs_diff_eq(&lhs, &max_abs_diff));
+    assert!(abs_diff_eq!(lhs, rhs, abs_diff_all <= 2e-8_f64));
+    assert!(abs_diff_ne!(lhs, rhs, abs_diff_all <= 1e-9_f64));
+    assert!(abs_diff_eq!([1.0_f32, 2.0_f32], [1.0_f64, 2.0_f64], abs_diff_all <= 0.0_f64));
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[1]`:\n        left: `0.2`,\n       right: `0.2`,\n    abs_diff: `2.980232227667301e-9`,")]
+fn test_assert_eq_panic_message() {
+    let lhs = vec![0.5_f32, 0.2_f32];
+    let rhs = vec![0.5_f64, 0.2_f64];
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 1e-9_f64);
+}
@@ -248,7 +248,7 @@ fn test_ne_none5() {
 #[test]
 fn test_ne_none6() {
     let lhs = Some(1.0_f32);
-    let rhs = None;
+    let rhs: Option<f32> = None;
     let max_abs_diff = Some(f32::EPSILON);
 
     assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
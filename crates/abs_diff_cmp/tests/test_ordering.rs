@@ -0,0 +1,101 @@
+use abs_diff_cmp::{
+    AbsDiffOrd,
+    abs_diff_ge,
+    abs_diff_gt,
+    abs_diff_le,
+    abs_diff_lt,
+    assert_abs_diff_ge,
+    assert_abs_diff_gt,
+    assert_abs_diff_le,
+    assert_abs_diff_lt,
+};
+use core::cmp::Ordering;
+
+#[test]
+fn test_abs_diff_partial_cmp() {
+    assert_eq!(1.0_f64.abs_diff_partial_cmp(&1.05_f64, &0.1_f64), Some(Ordering::Equal));
+    assert_eq!(1.0_f64.abs_diff_partial_cmp(&1.5_f64, &0.1_f64), Some(Ordering::Less));
+    assert_eq!(1.5_f64.abs_diff_partial_cmp(&1.0_f64, &0.1_f64), Some(Ordering::Greater));
+    assert_eq!(f64::INFINITY.abs_diff_partial_cmp(&f64::INFINITY, &0.0_f64), Some(Ordering::Equal));
+    assert_eq!(f64::NAN.abs_diff_partial_cmp(&f64::NAN, &f64::INFINITY), None);
+    assert_eq!(1.0_f64.abs_diff_partial_cmp(&f64::NAN, &f64::INFINITY), None);
+}
+
+#[test]
+fn test_abs_diff_lt_le() {
+    assert!(abs_diff_lt!(1.0_f32, 1.5_f32, abs_diff <= 0.1_f32));
+    assert!(!abs_diff_lt!(1.0_f32, 1.05_f32, abs_diff <= 0.1_f32));
+    assert!(!abs_diff_lt!(1.5_f32, 1.0_f32, abs_diff <= 0.1_f32));
+    assert!(abs_diff_le!(1.0_f32, 1.5_f32, abs_diff <= 0.1_f32));
+    assert!(abs_diff_le!(1.05_f32, 1.0_f32, abs_diff <= 0.1_f32));
+    assert!(!abs_diff_le!(1.5_f32, 1.0_f32, abs_diff <= 0.1_f32));
+    assert!(!abs_diff_le!(f32::NAN, 1.0_f32, abs_diff <= f32::INFINITY));
+}
+
+#[test]
+fn test_abs_diff_gt_ge() {
+    assert!(abs_diff_gt!(1.5_f64, 1.0_f64, abs_diff <= 0.1_f64));
+    assert!(!abs_diff_gt!(1.05_f64, 1.0_f64, abs_diff <= 0.1_f64));
+    assert!(!abs_diff_gt!(1.0_f64, 1.5_f64, abs_diff <= 0.1_f64));
+    assert!(abs_diff_ge!(1.5_f64, 1.0_f64, abs_diff <= 0.1_f64));
+    assert!(abs_diff_ge!(1.0_f64, 1.05_f64, abs_diff <= 0.1_f64));
+    assert!(!abs_diff_ge!(1.0_f64, 1.5_f64, abs_diff <= 0.1_f64));
+    assert!(!abs_diff_ge!(f64::NAN, f64::NAN, abs_diff <= f64::INFINITY));
+}
+
+#[test]
+fn test_abs_diff_ordering_integers() {
+    assert!(abs_diff_le!(3_i32, 2_i32, abs_diff <= 1_u32));
+    assert!(!abs_diff_le!(4_i32, 2_i32, abs_diff <= 1_u32));
+    assert!(abs_diff_lt!(-4_i64, 2_i64, abs_diff <= 1_u64));
+    assert_eq!(7_u8.abs_diff_partial_cmp(&5_u8, &2_u8), Some(Ordering::Equal));
+}
+
+#[test]
+fn test_energy_never_increases() {
+    let energies = [1.0_f64, 0.75_f64, 0.5_f64 + 1e-15_f64, 0.5_f64, 0.25_f64];
+
+    for window in energies.windows(2) {
+        assert_abs_diff_le!(window[1], window[0], abs_diff <= 1e-12_f64);
+        assert_abs_diff_ge!(window[0], window[1], abs_diff <= 1e-12_f64);
+    }
+}
+
+#[test]
+fn test_abs_diff_ordering_mixed_precision() {
+    assert_eq!(0.1_f32.abs_diff_partial_cmp(&0.1_f64, &1e-8_f64), Some(Ordering::Equal));
+    assert_eq!(0.1_f32.abs_diff_partial_cmp(&0.1_f64, &0.0_f64), Some(Ordering::Greater));
+    assert!(abs_diff_lt!(0.1_f64, 0.1_f32, abs_diff <= 0.0_f64));
+    assert!(abs_diff_le!(0.1_f64, 0.1_f32, abs_diff <= 1e-8_f64));
+    assert!(!abs_diff_lt!(0.1_f64, 0.1_f32, abs_diff <= 1e-8_f64));
+}
+
+#[test]
+fn test_assert_abs_diff_ordering() {
+    assert_abs_diff_lt!(1.0_f64, 2.0_f64, abs_diff <= 0.5_f64);
+    assert_abs_diff_le!(2.0_f64, 1.75_f64, abs_diff <= 0.5_f64, "message {}", 1);
+    assert_abs_diff_gt!(2.0_f64, 1.0_f64, abs_diff <= 0.5_f64, "message {}", 2);
+    assert_abs_diff_ge!(1.75_f64, 2.0_f64, abs_diff <= 0.5_f64);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `abs_diff_le!(left, right, abs_diff <= t)`
+        left: `2.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+[abs_diff] t: `0.5`")]
+fn test_assert_abs_diff_le_panic_message() {
+    assert_abs_diff_le!(2.0_f64, 1.0_f64, abs_diff <= 0.5_f64);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `abs_diff_lt!(left, right, abs_diff <= t)`")]
+fn test_assert_abs_diff_lt_within_tolerance_panic_message() {
+    assert_abs_diff_lt!(1.0_f64, 1.25_f64, abs_diff <= 0.5_f64);
+}
+
+#[test]
+#[should_panic(expected = "[abs_diff] t: `0.5`: custom message")]
+fn test_assert_abs_diff_gt_panic_custom_message() {
+    assert_abs_diff_gt!(f64::NAN, 1.0_f64, abs_diff <= 0.5_f64, "custom message");
+}
@@ -0,0 +1,206 @@
+use abs_diff_cmp::{
+    AssertAbsDiffAllEq,
+    AssertAbsDiffEq,
+    FloatPolicy,
+    abs_diff_eq,
+    abs_diff_ne,
+    assert_abs_diff_eq,
+    assert_abs_diff_ne,
+    check_abs_diff_eq,
+    check_abs_diff_ne,
+};
+
+#[test]
+fn test_default_policy() {
+    let policy = FloatPolicy::default();
+
+    assert_eq!(policy, FloatPolicy::new());
+    assert!(abs_diff_eq!(1.0_f64, 1.05_f64, abs_diff <= 0.1_f64, policy = policy));
+    assert!(!abs_diff_eq!(1.0_f64, 1.5_f64, abs_diff <= 0.1_f64, policy = policy));
+    assert!(!abs_diff_eq!(f64::NAN, f64::NAN, abs_diff <= f64::INFINITY, policy = policy));
+    assert!(abs_diff_eq!(-0.0_f64, 0.0_f64, abs_diff <= 0.0_f64, policy = policy));
+}
+
+#[test]
+fn test_nan_eq() {
+    let lhs = [1.0_f64, f64::NAN];
+    let rhs = [1.0_f64, f64::NAN];
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(!abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64));
+    assert!(abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64, policy = policy));
+    assert!(abs_diff_eq!(lhs, rhs, abs_diff <= [0.1_f64; 2], policy = policy));
+    assert!(!abs_diff_eq!(
+        lhs,
+        [1.0_f64, 2.0_f64],
+        abs_diff_all <= f64::INFINITY,
+        policy = policy
+    ));
+}
+
+#[test]
+fn test_inf_eq() {
+    let strict = FloatPolicy {
+        inf_eq: false,
+        ..FloatPolicy::new()
+    };
+
+    assert!(abs_diff_eq!(f64::INFINITY, f64::INFINITY, abs_diff <= 0.0_f64));
+    assert!(abs_diff_eq!(
+        f64::INFINITY,
+        f64::INFINITY,
+        abs_diff <= 0.0_f64,
+        policy = FloatPolicy::new()
+    ));
+    assert!(!abs_diff_eq!(f64::INFINITY, f64::INFINITY, abs_diff <= 0.0_f64, policy = strict));
+    assert!(!abs_diff_eq!(
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        abs_diff <= 0.0_f64,
+        policy = FloatPolicy::new()
+    ));
+
+    // An infinite tolerance accepts an infinity and a finite number, but no
+    // policy does.
+    assert!(abs_diff_eq!(f64::INFINITY, f64::MAX, abs_diff <= f64::INFINITY));
+    assert!(!abs_diff_eq!(
+        f64::INFINITY,
+        f64::MAX,
+        abs_diff <= f64::INFINITY,
+        policy = FloatPolicy::new()
+    ));
+}
+
+#[test]
+fn test_signed_zero_strict() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(!abs_diff_eq!(-0.0_f32, 0.0_f32, abs_diff <= 0.1_f32, policy = policy));
+    assert!(abs_diff_eq!(0.0_f32, 0.0_f32, abs_diff <= 0.1_f32, policy = policy));
+    assert!(abs_diff_eq!(-0.0_f32, -0.0_f32, abs_diff <= 0.1_f32, policy = policy));
+    assert!(abs_diff_ne!(
+        vec![-0.0_f32],
+        vec![0.0_f32],
+        abs_diff_all <= 0.1_f32,
+        policy = policy
+    ));
+}
+
+#[test]
+fn test_mixed_precision_and_integers() {
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(abs_diff_eq!(f32::NAN, f64::NAN, abs_diff <= 0.0_f64, policy = policy));
+    assert!(abs_diff_eq!(1_i32, 2_i32, abs_diff <= 1_u32, policy = policy));
+    assert!(!abs_diff_eq!(1_i32, 3_i32, abs_diff <= 1_u32, policy = policy));
+}
+
+#[test]
+fn test_abs_diff_eq_with_policy() {
+    let lhs = [1.0_f64, f64::NAN, -0.0_f64];
+    let rhs = [1.0_f64, f64::NAN, 0.0_f64];
+    let nan_eq = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+    let strict = FloatPolicy {
+        signed_zero_strict: true,
+        ..nan_eq
+    };
+
+    assert!(lhs.abs_diff_eq_with_policy(&rhs, &[0.1_f64; 3], &nan_eq));
+    assert!(!lhs.abs_diff_ne_with_policy(&rhs, &[0.1_f64; 3], &nan_eq));
+    assert!(!lhs.abs_diff_eq_with_policy(&rhs, &[0.1_f64; 3], &FloatPolicy::new()));
+    assert!(lhs.abs_diff_all_eq_with_policy(&rhs, &0.1_f64, &nan_eq));
+    assert!(lhs.abs_diff_all_ne_with_policy(&rhs, &0.1_f64, &strict));
+}
+
+#[test]
+fn test_check_abs_diff_with_policy() {
+    let lhs = [1.0_f64, f64::NAN, -0.0_f64];
+    let rhs = [1.0_f64, f64::NAN, 0.0_f64];
+    let nan_eq = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+    let strict = FloatPolicy {
+        signed_zero_strict: true,
+        ..nan_eq
+    };
+
+    assert!(check_abs_diff_eq!(lhs, rhs, abs_diff <= [0.1_f64; 3], policy = nan_eq).is_ok());
+    assert!(check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64, policy = nan_eq).is_ok());
+    assert!(check_abs_diff_ne!(lhs, rhs, abs_diff_all <= 0.1_f64, policy = strict).is_ok());
+    assert!(check_abs_diff_ne!(lhs, rhs, abs_diff <= [0.1_f64; 3], policy = nan_eq).is_err());
+
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= [0.1_f64; 3], policy = FloatPolicy::new()).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+
+    let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64, policy = strict).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+}
+
+#[test]
+fn test_assert_with_policy() {
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_abs_diff_eq!(f64::NAN, f64::NAN, abs_diff <= 0.0_f64, policy = policy);
+    assert_abs_diff_eq!([f64::NAN], [f64::NAN], abs_diff_all <= 0.0_f64, policy = policy, "message {}", 1);
+    assert_abs_diff_ne!(f64::NAN, f64::NAN, abs_diff <= 0.0_f64, policy = FloatPolicy::new());
+    assert_abs_diff_ne!(f64::NAN, 1.0_f64, abs_diff <= 1.0_f64, policy = policy, "message {}", 2);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `abs_diff_eq!(left, right, abs_diff_all <= t, policy = p)`
+      policy: `FloatPolicy { nan_eq: false, signed_zero_strict: true, inf_eq: true }`,
+first failure at `[1]`:
+        left: `-0.0`,
+       right: `0.0`,
+    abs_diff: `0.0`,
+      policy: `rejected by signed_zero_strict`,")]
+fn test_assert_abs_diff_eq_policy_panic_message() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_abs_diff_eq!([1.0_f64, -0.0_f64], [1.0_f64, 0.0_f64], abs_diff_all <= 0.1_f64, policy = policy);
+}
+
+#[test]
+#[should_panic(expected = "policy: `rejected by nan_eq`,")]
+fn test_assert_abs_diff_eq_policy_panic_custom_message() {
+    assert_abs_diff_eq!(
+        vec![f64::NAN],
+        vec![f64::NAN],
+        abs_diff_all <= 0.1_f64,
+        policy = FloatPolicy::new(),
+        "custom message"
+    );
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `abs_diff_ne!(left, right, abs_diff <= t, policy = p)`
+      policy: `FloatPolicy { nan_eq: true, signed_zero_strict: false, inf_eq: true }`,")]
+fn test_assert_abs_diff_ne_policy_panic_message() {
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_abs_diff_ne!(f64::NAN, f64::NAN, abs_diff <= 0.0_f64, policy = policy);
+}
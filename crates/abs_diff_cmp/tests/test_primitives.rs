@@ -1620,3 +1620,84 @@ impl_abs_diff_eq_float_exact_exhaustive_tests!(
     (abs_diff_eq_f64_i8_exact_exhaustive_tests, f64, i8),
     (abs_diff_eq_f64_i16_exact_exhaustive_tests, f64, i16)
 );
+
+macro_rules! impl_abs_diff_eq_signed_integer_range_tests {
+    ($(($module_name:ident, $T:ty, $U:ty)),*) => {$(
+        #[cfg(test)]
+        mod $module_name {
+            use abs_diff_cmp::{
+                AbsDiffEq,
+                AssertAbsDiffEq,
+                assert_abs_diff_eq,
+                assert_abs_diff_ne,
+                abs_diff_eq,
+                abs_diff_ne,
+            };
+
+            #[test]
+            fn test_eq_full_range() {
+                assert!(<$T>::MIN.abs_diff_eq(&<$T>::MAX, &<$U>::MAX));
+                assert!(<$T>::MAX.abs_diff_eq(&<$T>::MIN, &<$U>::MAX));
+                assert!(abs_diff_eq!(<$T>::MIN, <$T>::MAX, abs_diff <= <$U>::MAX));
+                assert_abs_diff_eq!(<$T>::MIN, <$T>::MAX, abs_diff <= <$U>::MAX);
+            }
+
+            #[test]
+            fn test_ne_full_range() {
+                assert!(<$T>::MIN.abs_diff_ne(&<$T>::MAX, &(<$U>::MAX - 1)));
+                assert!(<$T>::MAX.abs_diff_ne(&<$T>::MIN, &(<$U>::MAX - 1)));
+                assert!(abs_diff_ne!(<$T>::MIN, <$T>::MAX, abs_diff <= <$U>::MAX - 1));
+                assert_abs_diff_ne!(<$T>::MIN, <$T>::MAX, abs_diff <= <$U>::MAX - 1);
+            }
+
+            #[test]
+            fn test_eq_min_one() {
+                let distance = (<$T>::MAX as $U) + 2;
+
+                assert!(<$T>::MIN.abs_diff_eq(&1, &distance));
+                assert!(<$T>::MIN.abs_diff_ne(&1, &(distance - 1)));
+                assert!(<$T>::MIN.abs_diff_eq(&<$T>::MIN, &0));
+            }
+
+            #[test]
+            fn test_debug_abs_diff() {
+                assert_eq!(<$T>::MIN.debug_abs_diff(&<$T>::MAX), <$U>::MAX);
+                assert_eq!(<$T>::MAX.debug_abs_diff(&<$T>::MIN), <$U>::MAX);
+                assert_eq!(<$T>::MIN.debug_abs_diff(&1), (<$T>::MAX as $U) + 2);
+                assert_eq!((-1 as $T).debug_abs_diff(&1), 2);
+            }
+        }
+    )*};
+}
+
+impl_abs_diff_eq_signed_integer_range_tests!(
+    (abs_diff_eq_i8_range_tests, i8, u8),
+    (abs_diff_eq_i16_range_tests, i16, u16),
+    (abs_diff_eq_i32_range_tests, i32, u32),
+    (abs_diff_eq_i64_range_tests, i64, u64),
+    (abs_diff_eq_i128_range_tests, i128, u128),
+    (abs_diff_eq_isize_range_tests, isize, usize)
+);
+
+#[cfg(test)]
+mod abs_diff_eq_i8_pairs_exhaustive_tests {
+    use abs_diff_cmp::{
+        AbsDiffEq,
+        AssertAbsDiffEq,
+    };
+
+    #[test]
+    fn test_abs_diff_eq_pairs_exhaustive() {
+        for lhs in i8::MIN..=i8::MAX {
+            for rhs in i8::MIN..=i8::MAX {
+                let distance = (i16::from(lhs) - i16::from(rhs)).unsigned_abs();
+
+                assert_eq!(u16::from(lhs.debug_abs_diff(&rhs)), distance);
+                assert!(lhs.abs_diff_eq(&rhs, &lhs.debug_abs_diff(&rhs)));
+                if distance > 0 {
+                    assert!(lhs.abs_diff_ne(&rhs, &(lhs.debug_abs_diff(&rhs) - 1)));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use abs_diff_cmp::{
+    AssertAbsDiffAllEq,
+    AssertAbsDiffEq,
+    assert_abs_diff_eq,
+    assert_abs_diff_ne,
+};
+
+#[rustfmt::skip]
+#[test]
+fn test_eq_ok() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let eps = f32::EPSILON;
+    let max_abs_diff = Ok([1.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps]);
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+    assert_abs_diff_eq!(rhs, lhs, abs_diff <= max_abs_diff);
+}
+
+#[test]
+fn test_eq_err() {
+    let lhs: Result<[f32; 4], f32> = Err(1.0_f32);
+    let rhs: Result<[f32; 4], f32> = Err(1.0000001_f32);
+    let max_abs_diff = Err(f32::EPSILON);
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+    assert_abs_diff_eq!(rhs, lhs, abs_diff <= max_abs_diff);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_ne_ok() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let eps = f32::EPSILON;
+    let max_abs_diff = Ok([0.5_f32 * eps, 2.0_f32 * eps, 2.0_f32 * eps, 2.0_f32 * eps]);
+
+    assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+    assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+}
+
+#[test]
+fn test_ne_ok_err() {
+    let lhs: Result<f32, f32> = Ok(1.0_f32);
+    let rhs: Result<f32, f32> = Err(1.0_f32);
+
+    assert_abs_diff_ne!(lhs, rhs, abs_diff <= Ok(1.0_f32));
+    assert_abs_diff_ne!(rhs, lhs, abs_diff <= Ok(1.0_f32));
+    assert_abs_diff_ne!(lhs, rhs, abs_diff <= Err(1.0_f32));
+    assert_abs_diff_ne!(rhs, lhs, abs_diff <= Err(1.0_f32));
+}
+
+#[test]
+fn test_ne_tolerance_mismatch() {
+    let lhs: Result<f32, f32> = Ok(1.0_f32);
+    let rhs: Result<f32, f32> = Ok(1.0_f32);
+    let max_abs_diff = Err(1.0_f32);
+
+    assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+    assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_all_eq() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok(4.0_f32 * f32::EPSILON);
+
+    assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= max_abs_diff);
+    assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= max_abs_diff);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_all_ne() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok(2.0_f32 * f32::EPSILON);
+
+    assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= max_abs_diff);
+    assert_abs_diff_ne!(rhs, lhs, abs_diff_all <= max_abs_diff);
+}
+
+#[test]
+fn test_debug_abs_diff() {
+    let lhs: Result<[f32; 4], f32> = Ok([1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.50_f32, 1.00_f32, 2.00_f32, 1.75_f32]);
+    let abs_diff = Some(Ok([0.50_f32, 0.25_f32, 0.50_f32, 0.25_f32]));
+
+    assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+    assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+}
+
+#[test]
+fn test_debug_abs_diff_err() {
+    let lhs: Result<[f32; 4], f32> = Err(1.00_f32);
+    let rhs: Result<[f32; 4], f32> = Err(1.25_f32);
+    let abs_diff = Some(Err(0.25_f32));
+
+    assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+    assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+}
+
+#[test]
+fn test_debug_abs_diff_ok_err() {
+    let lhs: Result<[f32; 4], f32> = Ok([1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32]);
+    let rhs: Result<[f32; 4], f32> = Err(1.00_f32);
+
+    assert_eq!(lhs.debug_abs_diff(&rhs), None);
+    assert_eq!(rhs.debug_abs_diff(&lhs), None);
+}
+
+#[test]
+fn test_debug_abs_diff_tolerance() {
+    let lhs: Result<[f32; 4], f32> = Ok([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
+    let max_abs_diff = Ok([0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32]);
+    let tolerance = Some(max_abs_diff);
+
+    assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+    assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &Err(0.10_f32)), None);
+}
+
+#[test]
+fn test_debug_abs_diff_all_tolerance() {
+    let lhs: Result<[f32; 4], f32> = Ok([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
+    let max_abs_diff = 0.20_f32;
+    let tolerance = Some(Ok([max_abs_diff; 4]));
+
+    assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &Ok(max_abs_diff)), tolerance);
+    assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &Ok(max_abs_diff)), tolerance);
+    assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &Err(max_abs_diff)), None);
+}
@@ -0,0 +1,85 @@
+use abs_diff_cmp::{
+    AbsDiffSearch,
+    SearchMatch,
+};
+
+#[test]
+fn test_abs_diff_binary_search() {
+    let samples = [0.0_f64, 0.1_f64, 0.2_f64, 0.1_f64 + 0.2_f64, 0.4_f64];
+
+    assert_eq!(samples.abs_diff_binary_search(&0.3_f64, &1e-12_f64), SearchMatch::Found(3));
+    assert_eq!(samples.abs_diff_binary_search(&0.0_f64, &1e-12_f64), SearchMatch::Found(0));
+    assert_eq!(samples.abs_diff_binary_search(&0.4_f64, &1e-12_f64), SearchMatch::Found(4));
+    assert_eq!(samples.abs_diff_binary_search(&0.35_f64, &1e-12_f64), SearchMatch::NotFound);
+    assert_eq!(samples.abs_diff_binary_search(&-1.0_f64, &1e-12_f64), SearchMatch::NotFound);
+    assert_eq!(samples.abs_diff_binary_search(&f64::NAN, &f64::INFINITY), SearchMatch::NotFound);
+}
+
+#[test]
+fn test_abs_diff_binary_search_ambiguous() {
+    let samples = Vec::from([0.3_f64, 0.349_f64, 0.35_f64, 0.351_f64, 0.4_f64]);
+
+    assert_eq!(
+        samples.abs_diff_binary_search(&0.35_f64, &0.01_f64),
+        SearchMatch::Ambiguous {
+            first: 1,
+            last: 3,
+            count: 3
+        }
+    );
+    assert_eq!(samples.abs_diff_binary_search(&0.35_f64, &0.0005_f64), SearchMatch::Found(2));
+    assert_eq!(
+        samples.abs_diff_binary_search(&0.35_f64, &f64::INFINITY),
+        SearchMatch::Ambiguous {
+            first: 0,
+            last: 4,
+            count: 5
+        }
+    );
+}
+
+#[test]
+fn test_abs_diff_binary_search_empty() {
+    let samples: [f32; 0] = [];
+
+    assert_eq!(samples.abs_diff_binary_search(&1.0_f32, &1.0_f32), SearchMatch::NotFound);
+    assert_eq!(samples.abs_diff_position(&1.0_f32, &1.0_f32), SearchMatch::NotFound);
+    assert!(!samples.abs_diff_contains(&1.0_f32, &1.0_f32));
+}
+
+#[test]
+fn test_abs_diff_position() {
+    let samples = [0.4_f32, 0.349_f32, 0.2_f32, 0.351_f32];
+
+    assert_eq!(samples.abs_diff_position(&0.2_f32, &1e-6_f32), SearchMatch::Found(2));
+    assert_eq!(samples.abs_diff_position(&0.3_f32, &1e-6_f32), SearchMatch::NotFound);
+    assert_eq!(
+        samples.abs_diff_position(&0.35_f32, &0.01_f32),
+        SearchMatch::Ambiguous {
+            first: 1,
+            last: 3,
+            count: 2
+        }
+    );
+    assert_eq!(samples.abs_diff_position(&0.35_f32, &0.01_f32).found(), None);
+    assert!(samples.abs_diff_position(&0.35_f32, &0.01_f32).is_ambiguous());
+}
+
+#[test]
+fn test_abs_diff_contains() {
+    let samples = Vec::from([0.4_f64, 0.1_f64 + 0.2_f64, f64::NAN]);
+
+    assert!(samples.abs_diff_contains(&0.3_f64, &1e-12_f64));
+    assert!(!samples.abs_diff_contains(&0.3_f64, &0.0_f64));
+    assert!(!samples.abs_diff_contains(&f64::NAN, &f64::INFINITY));
+}
+
+#[test]
+fn test_abs_diff_search_integers() {
+    let samples = [1_i32, 4_i32, 9_i32, 16_i32];
+
+    assert_eq!(samples.abs_diff_binary_search(&10_i32, &1_u32), SearchMatch::Found(2));
+    assert_eq!(samples.abs_diff_binary_search(&12_i32, &1_u32), SearchMatch::NotFound);
+    assert_eq!(samples.abs_diff_position(&5_i32, &1_u32), SearchMatch::Found(1));
+    assert_eq!(samples.abs_diff_position(&12_i32, &4_u32).count(), 2);
+}
@@ -0,0 +1,732 @@
+use crate::spec::{
+    AssocKind,
+    Method,
+    Output,
+    Shape,
+    TraitSpec,
+};
+use proc_macro2::{
+    Span,
+    TokenStream,
+};
+use quote::{
+    ToTokens,
+    format_ident,
+    quote,
+};
+use syn::parse_quote;
+
+/// How a field of a type deriving a comparison trait is compared.
+enum Compare {
+    /// Compare the field approximately, using the trait being derived.
+    Approx,
+    /// Compare the field with `PartialEq`.
+    Exact,
+    /// Compare the field with a custom comparator of type `fn(&T, &T) -> bool`.
+    With(syn::Path),
+}
+
+/// A field of a type deriving a comparison trait.
+struct Field {
+    /// The member used to access the field on a value of the deriving type.
+    member: syn::Member,
+    /// The member used to access the field on the values of the generated types
+    /// that only hold approximately compared fields, if the field is compared
+    /// approximately.
+    tolerance_member: Option<syn::Member>,
+    /// The member used to access the field on the values of the generated types
+    /// that hold every compared field.
+    debug_member: syn::Member,
+    /// The position of the field among the compared fields of the deriving type,
+    /// used to name the bindings of the field in the derived methods.
+    index: usize,
+    vis: syn::Visibility,
+    ty: syn::Type,
+    compare: Compare,
+}
+
+impl Field {
+    fn is_approx(&self) -> bool {
+        matches!(self.compare, Compare::Approx)
+    }
+
+    /// The member used to access the field on values of the type `shape`, if the
+    /// type holds the field.
+    fn shape_member(&self, shape: &Shape) -> Option<&syn::Member> {
+        if shape.predicates {
+            Some(&self.debug_member)
+        } else {
+            self.tolerance_member.as_ref()
+        }
+    }
+
+    fn binding(&self, prefix: &str) -> syn::Ident {
+        format_ident!("__{}_{}", prefix, self.index)
+    }
+
+    /// The name of the field in the paths reported by the derived visit methods.
+    fn path_name(&self) -> String {
+        match &self.member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+}
+
+/// The syntax used to declare the fields of a struct or variant.
+#[derive(Copy, Clone)]
+enum Style {
+    Named,
+    Unnamed,
+    Unit,
+}
+
+/// A struct deriving a comparison trait, or a variant of an enum deriving a
+/// comparison trait.
+struct Variant {
+    /// The name of the variant, or `None` for a struct.
+    ident: Option<syn::Ident>,
+    style: Style,
+    fields: Vec<Field>,
+}
+
+impl Variant {
+    /// The path of the variant of `ty`, where `ty` is either the deriving type or
+    /// a generated type with the same variants.
+    fn path(&self, ty: TokenStream) -> TokenStream {
+        match &self.ident {
+            Some(ident) => quote!(#ty::#ident),
+            None => ty,
+        }
+    }
+
+    fn name(&self) -> String {
+        self.ident.as_ref().map(ToString::to_string).unwrap_or_default()
+    }
+}
+
+/// The deriving type, broken into the structs or variants whose values are
+/// compared field by field.
+struct Data {
+    is_enum: bool,
+    variants: Vec<Variant>,
+}
+
+impl Data {
+    fn fields(&self) -> impl Iterator<Item = &Field> {
+        self.variants.iter().flat_map(|variant| &variant.fields)
+    }
+}
+
+/// Parse the `#[approx(...)]` attributes of a field. Returns `None` when the field
+/// is skipped.
+fn compare(field: &syn::Field) -> syn::Result<Option<Compare>> {
+    let mut compare = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("approx")) {
+        attr.parse_nested_meta(|meta| {
+            let parsed = if meta.path.is_ident("skip") {
+                None
+            } else if meta.path.is_ident("exact") {
+                Some(Compare::Exact)
+            } else if meta.path.is_ident("with") {
+                Some(Compare::With(meta.value()?.parse()?))
+            } else {
+                return Err(meta.error("expected `skip`, `exact`, or `with = path`"));
+            };
+
+            if compare.is_some() {
+                return Err(meta.error("a field accepts at most one of `skip`, `exact`, or `with = path`"));
+            }
+            compare = Some(parsed);
+
+            Ok(())
+        })?;
+    }
+
+    Ok(compare.unwrap_or(Some(Compare::Approx)))
+}
+
+fn member(field: &syn::Field, index: usize) -> syn::Member {
+    match &field.ident {
+        Some(ident) => syn::Member::Named(ident.clone()),
+        None => syn::Member::Unnamed(syn::Index::from(index)),
+    }
+}
+
+fn variant(ident: Option<syn::Ident>, fields: &syn::Fields, index: &mut usize) -> syn::Result<Variant> {
+    let style = match fields {
+        syn::Fields::Named(_) => Style::Named,
+        syn::Fields::Unnamed(_) => Style::Unnamed,
+        syn::Fields::Unit => Style::Unit,
+    };
+    let mut variant = Variant {
+        ident,
+        style,
+        fields: Vec::new(),
+    };
+    let mut tolerance_index = 0;
+    let mut debug_index = 0;
+    for (i, field) in fields.iter().enumerate() {
+        let Some(compare) = compare(field)? else {
+            continue;
+        };
+        let tolerance_member = if matches!(compare, Compare::Approx) {
+            tolerance_index += 1;
+            Some(member(field, tolerance_index - 1))
+        } else {
+            None
+        };
+        debug_index += 1;
+        *index += 1;
+
+        variant.fields.push(Field {
+            member: member(field, i),
+            tolerance_member,
+            debug_member: member(field, debug_index - 1),
+            index: *index - 1,
+            vis: field.vis.clone(),
+            ty: field.ty.clone(),
+            compare,
+        });
+    }
+
+    Ok(variant)
+}
+
+fn data(input: &syn::DeriveInput) -> syn::Result<Data> {
+    let mut index = 0;
+    match &input.data {
+        syn::Data::Struct(data) => Ok(Data {
+            is_enum: false,
+            variants: vec![variant(None, &data.fields, &mut index)?],
+        }),
+        syn::Data::Enum(data) if data.variants.is_empty() => Err(syn::Error::new_spanned(
+            &input.ident,
+            "approximate comparison traits cannot be derived for enums without variants",
+        )),
+        syn::Data::Enum(data) => Ok(Data {
+            is_enum: true,
+            variants: data
+                .variants
+                .iter()
+                .map(|v| variant(Some(v.ident.clone()), &v.fields, &mut index))
+                .collect::<syn::Result<_>>()?,
+        }),
+        syn::Data::Union(data) => Err(syn::Error::new_spanned(
+            data.union_token,
+            "approximate comparison traits cannot be derived for unions",
+        )),
+    }
+}
+
+fn ident(name: &str) -> syn::Ident {
+    syn::Ident::new(name, Span::call_site())
+}
+
+fn trait_path(spec: &TraitSpec) -> syn::Path {
+    let name = ident(spec.name);
+
+    parse_quote!(::approx_cmp::#name)
+}
+
+/// The name of the type generated for the per field associated types of `spec`.
+fn shape_ident(spec: &TraitSpec, input: &syn::DeriveInput) -> Option<syn::Ident> {
+    spec.shape.map(|shape| format_ident!("{}{}", input.ident, shape.suffix))
+}
+
+/// The fields of `variant` held by the generated type `shape`, along with their
+/// members in the generated type.
+fn shape_fields<'a>(shape: &Shape, variant: &'a Variant) -> Vec<(&'a Field, &'a syn::Member)> {
+    variant
+        .fields
+        .iter()
+        .filter_map(|field| field.shape_member(shape).map(|member| (field, member)))
+        .collect()
+}
+
+/// Generate the definition of the type used by the per field associated types
+/// of `spec`.
+///
+/// The type mirrors the deriving type, with a type parameter in place of the
+/// type of each field, so that per field tolerances can be written by field name.
+/// The parameters are instantiated with the associated types of each field in the
+/// derived implementation. Skipped fields are left out, as are fields that are
+/// not compared approximately unless the type holds debugging values.
+fn shape_type(spec: &TraitSpec, input: &syn::DeriveInput, data: &Data) -> TokenStream {
+    let (Some(shape), Some(shape_name)) = (spec.shape, shape_ident(spec, input)) else {
+        return quote!();
+    };
+    let name = &input.ident;
+    let vis = &input.vis;
+    let doc = format!("The {} of [`{}`].", shape.contents, name);
+    let mut params = Vec::new();
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let fields = shape_fields(&shape, variant);
+            let variant_params = (params.len()..params.len() + fields.len())
+                .map(|i| format_ident!("T{}", i))
+                .collect::<Vec<_>>();
+            params.extend(variant_params.iter().cloned());
+            let field_docs = fields.iter().map(|(field, _)| {
+                let member = &field.member;
+                match &variant.ident {
+                    Some(ident) => format!("The entry for the field `{}` of [`{}::{}`].", quote!(#member), name, ident),
+                    None => format!("The entry for the field `{}` of [`{}`].", quote!(#member), name),
+                }
+            });
+            let field_vis = fields.iter().map(|(field, _)| &field.vis);
+            let members = fields.iter().map(|(_, member)| member);
+            let body = match variant.style {
+                Style::Named => quote!({ #(#[doc = #field_docs] #field_vis #members: #variant_params,)* }),
+                Style::Unnamed => quote!((#(#[doc = #field_docs] #field_vis #variant_params,)*)),
+                Style::Unit => quote!(),
+            };
+
+            (variant, body)
+        })
+        .collect::<Vec<_>>();
+    let generics = if params.is_empty() { quote!() } else { quote!(<#(#params),*>) };
+
+    if data.is_enum {
+        let variants = variants.iter().map(|(variant, body)| {
+            let ident = &variant.ident;
+            let doc = format!("The entries for the variant [`{}::{}`].", name, variant.name());
+
+            quote!(#[doc = #doc] #ident #body)
+        });
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            #vis enum #shape_name #generics { #(#variants,)* }
+        }
+    } else {
+        let (variant, body) = &variants[0];
+        let body = match variant.style {
+            Style::Named => quote!(#generics #body),
+            Style::Unnamed => quote!(#generics #body;),
+            Style::Unit => quote!(;),
+        };
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Copy, Clone, Debug, Default, PartialEq)]
+            #vis struct #shape_name #body
+        }
+    }
+}
+
+/// Determine whether a type mentions any of the type parameters of the deriving
+/// type.
+fn is_generic(ty: &syn::Type, generics: &syn::Generics) -> bool {
+    fn visit(tokens: proc_macro2::TokenStream, params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => params.contains(&&ident),
+            proc_macro2::TokenTree::Group(group) => visit(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    let params = generics.type_params().map(|param| &param.ident).collect::<Vec<_>>();
+
+    visit(quote!(#ty), &params)
+}
+
+fn is_same_type(lhs: &syn::Type, rhs: &syn::Type) -> bool {
+    quote!(#lhs).to_string() == quote!(#rhs).to_string()
+}
+
+/// The bounds every field type must satisfy for the derived implementation of
+/// `spec`, and of each of its supertraits, to hold.
+///
+/// Only fields whose types mention a type parameter are bounded. The bounds of
+/// the remaining fields are checked directly by the compiler. Bounding them
+/// anyway places projections like `<[f64; 3] as AbsDiffAllEq>::AllTolerance ==
+/// <f64 as AbsDiffAllEq>::AllTolerance` in the environment of the implementation,
+/// which sends the trait solver into a cycle when it normalizes either side.
+fn bounds(spec: &TraitSpec, data: &Data, generics: &syn::Generics) -> Vec<syn::WherePredicate> {
+    let mut predicates = Vec::new();
+    for field in data.fields().filter(|field| matches!(field.compare, Compare::Exact)) {
+        let ty = &field.ty;
+        if is_generic(ty, generics) {
+            predicates.push(parse_quote!(#ty: ::core::cmp::PartialEq));
+        }
+    }
+
+    // The debugging traits report the values of the fields not compared
+    // approximately, since they carry no differences of their own.
+    if spec.supertrait.is_some() {
+        for field in data
+            .fields()
+            .filter(|field| matches!(field.compare, Compare::Exact | Compare::With(_)))
+        {
+            let ty = &field.ty;
+            if is_generic(ty, generics) {
+                predicates.push(parse_quote!(#ty: ::core::fmt::Debug));
+            }
+        }
+    }
+
+    let approx_fields = data.fields().filter(|field| field.is_approx()).collect::<Vec<_>>();
+    let mut current = Some(spec);
+    while let Some(spec) = current {
+        let path = trait_path(spec);
+        for (i, field) in approx_fields.iter().enumerate() {
+            let ty = &field.ty;
+            if !is_generic(ty, generics) {
+                continue;
+            }
+
+            let first = &approx_fields[0].ty;
+            let uniform = spec
+                .assoc_types
+                .iter()
+                .filter(|assoc| assoc.kind == AssocKind::Uniform)
+                .map(|assoc| {
+                    let name = ident(assoc.name);
+
+                    quote!(#name = <#first as #path>::#name)
+                })
+                .collect::<Vec<_>>();
+
+            if i > 0 && !uniform.is_empty() && !is_same_type(ty, first) {
+                predicates.push(parse_quote!(#ty: #path<#(#uniform),*>));
+            } else {
+                predicates.push(parse_quote!(#ty: #path));
+            }
+
+            for assoc in spec.assoc_types {
+                if assoc.kind == AssocKind::PerField && assoc.maybe_unsized {
+                    let name = ident(assoc.name);
+                    predicates.push(parse_quote!(<#ty as #path>::#name: ::core::marker::Sized));
+                }
+            }
+        }
+
+        current = spec.supertrait;
+    }
+
+    predicates
+}
+
+fn assoc_types(spec: &TraitSpec, shape: Option<&syn::Ident>, data: &Data) -> Vec<TokenStream> {
+    let path = trait_path(spec);
+    let first = data.fields().find(|field| field.is_approx());
+
+    spec.assoc_types
+        .iter()
+        .map(|assoc| {
+            let name = ident(assoc.name);
+            let ty = match (assoc.kind, first) {
+                (AssocKind::PerField, _) => {
+                    let types = data
+                        .fields()
+                        .filter(|field| spec.shape.and_then(|shape| field.shape_member(&shape)).is_some())
+                        .map(|field| {
+                            let ty = &field.ty;
+                            match field.compare {
+                                Compare::Approx => quote!(<#ty as #path>::#name),
+                                _ if assoc.difference => quote!(::approx_cmp::FieldCmp),
+                                _ => quote!(()),
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    let ty = if types.is_empty() {
+                        quote!(#shape)
+                    } else {
+                        quote!(#shape<#(#types),*>)
+                    };
+
+                    // The debugging values of an enum report a variant mismatch
+                    // in place of the per field values.
+                    if data.is_enum && spec.supertrait.is_some() {
+                        quote!(::core::result::Result<#ty, ::approx_cmp::VariantMismatch>)
+                    } else {
+                        ty
+                    }
+                }
+                (AssocKind::Uniform, Some(first)) => {
+                    let ty = &first.ty;
+
+                    quote!(<#ty as #path>::#name)
+                }
+                (AssocKind::Uniform, None) => quote!(()),
+            };
+
+            quote!(type #name = #ty;)
+        })
+        .collect()
+}
+
+/// The pattern binding the fields of a value of `variant` of the type `ty`.
+fn pattern<'a>(ty: TokenStream, variant: &Variant, fields: impl Iterator<Item = (&'a syn::Member, syn::Ident)>) -> TokenStream {
+    let path = variant.path(ty);
+    let (members, bindings): (Vec<_>, Vec<_>) = fields.unzip();
+
+    quote!(#path { #(#members: #bindings,)* .. })
+}
+
+/// The expression matching a value of the deriving type, or of a generated type
+/// with the same variants, to the name of its variant.
+fn variant_name(value: TokenStream, ty: TokenStream, data: &Data) -> TokenStream {
+    let arms = data.variants.iter().map(|variant| {
+        let path = variant.path(ty.clone());
+        let name = variant.name();
+
+        quote!(#path { .. } => #name)
+    });
+
+    quote!(match #value { #(#arms,)* })
+}
+
+/// The expression determining whether a field not compared approximately is
+/// equal in `self` and `other`.
+fn predicate(field: &Field) -> TokenStream {
+    let lhs = field.binding("self");
+    let rhs = field.binding("other");
+    match &field.compare {
+        Compare::Approx => unreachable!("approximately compared fields have no predicate"),
+        Compare::Exact => quote!(#lhs == #rhs),
+        Compare::With(path) => quote!(#path(#lhs, #rhs)),
+    }
+}
+
+/// Generate the method `method` of the trait `spec`.
+///
+/// The method matches `self`, `other`, and each per field tolerance against the
+/// same struct or variant, and combines the results of comparing each field. When
+/// the values of an enum do not share a variant, the comparison fails, and the
+/// debugging methods report an `approx_cmp::VariantMismatch`. The visit methods
+/// report each field under its name, and report a variant mismatch as a
+/// `approx_cmp::StructureMismatch`.
+fn method(spec: &TraitSpec, input: &syn::DeriveInput, method: &Method, data: &Data) -> TokenStream {
+    let path = trait_path(spec);
+    let name = ident(method.name);
+    let shape = shape_ident(spec, input);
+    // The per field tolerances are held by the type generated for the trait
+    // declaring them, which is the supertrait of a debugging trait.
+    let tolerance_spec = spec.supertrait.unwrap_or(spec);
+    let tolerance_shape = shape_ident(tolerance_spec, input);
+    let mut params = method
+        .params
+        .iter()
+        .map(|param| {
+            let name = ident(param.name);
+            let ty = ident(param.ty);
+
+            quote!(#name: &Self::#ty)
+        })
+        .collect::<Vec<_>>();
+    if let Output::Visit = method.output {
+        params.push(quote!(path: &mut ::approx_cmp::Path<'__visit>));
+        params.push(quote!(visitor: &mut dyn ::approx_cmp::Visitor<'__visit>));
+    }
+    let per_field_params = method
+        .params
+        .iter()
+        .filter(|param| param.kind == AssocKind::PerField)
+        .map(|param| param.name)
+        .collect::<Vec<_>>();
+    let difference = match method.output {
+        Output::Bool | Output::Visit => true,
+        Output::Assoc(assoc) => spec.assoc_types.iter().any(|other| other.name == assoc && other.difference),
+    };
+
+    let arms = data
+        .variants
+        .iter()
+        .map(|variant| {
+            // Fields not compared approximately are only bound when their
+            // predicates are needed.
+            let bound = variant
+                .fields
+                .iter()
+                .filter(|field| field.is_approx() || difference)
+                .collect::<Vec<_>>();
+            let lhs = pattern(quote!(Self), variant, bound.iter().map(|f| (&f.member, f.binding("self"))));
+            let rhs = pattern(quote!(Self), variant, bound.iter().map(|f| (&f.member, f.binding("other"))));
+            let tolerances = per_field_params.iter().map(|param| {
+                let fields = variant
+                    .fields
+                    .iter()
+                    .filter_map(|f| f.tolerance_member.as_ref().map(|member| (member, f.binding(param))));
+
+                pattern(quote!(#tolerance_shape), variant, fields)
+            });
+            let call = |field: &Field| {
+                let lhs = field.binding("self");
+                let rhs = field.binding("other");
+                let args = method.params.iter().map(|param| match param.kind {
+                    AssocKind::PerField => field.binding(param.name).into_token_stream(),
+                    AssocKind::Uniform => ident(param.name).into_token_stream(),
+                });
+
+                match method.output {
+                    Output::Visit => quote!(#path::#name(#lhs, #rhs, #(#args,)* path, visitor)),
+                    _ => quote!(#path::#name(#lhs, #rhs, #(#args),*)),
+                }
+            };
+            let body = match method.output {
+                Output::Bool if variant.fields.is_empty() => quote!(true),
+                Output::Bool => {
+                    let terms = variant.fields.iter().map(|field| match field.compare {
+                        Compare::Approx => call(field),
+                        _ => predicate(field),
+                    });
+
+                    quote!(#(#terms)&&*)
+                }
+                Output::Assoc(_) => {
+                    let fields = spec.shape.map(|shape| shape_fields(&shape, variant)).unwrap_or_default();
+                    let members = fields.iter().map(|(_, member)| member);
+                    let values = fields.iter().map(|&(field, _)| match field.compare {
+                        Compare::Approx => call(field),
+                        _ if difference => {
+                            let predicate = predicate(field);
+
+                            quote!(::approx_cmp::FieldCmp::from(#predicate))
+                        }
+                        _ => quote!(()),
+                    });
+                    let shape_path = variant.path(quote!(#shape));
+                    let value = quote!(#shape_path { #(#members: #values,)* });
+
+                    if data.is_enum {
+                        quote!(::core::result::Result::Ok(#value))
+                    } else {
+                        value
+                    }
+                }
+                Output::Visit => {
+                    let visits = variant.fields.iter().map(|field| {
+                        let segment = field.path_name();
+                        let visit = match &field.compare {
+                            Compare::Approx => call(field),
+                            compare => {
+                                let label = if let Compare::Exact = compare { "exact" } else { "with" };
+                                let predicate = predicate(field);
+                                let lhs = field.binding("self");
+                                let rhs = field.binding("other");
+
+                                quote! {{
+                                    let passed = #predicate;
+                                    visitor.visit(
+                                        path,
+                                        &::approx_cmp::Element::new(
+                                            passed,
+                                            &[(#label, &::approx_cmp::FieldCmp::from(passed))],
+                                            &[],
+                                        )
+                                        .with_values(#lhs, #rhs),
+                                    )
+                                }}
+                            }
+                        };
+
+                        quote! {
+                            path.with_segment(::approx_cmp::PathSegment::Field(#segment), |path| #visit)?;
+                        }
+                    });
+
+                    quote!({
+                        #(#visits)*
+
+                        ::core::ops::ControlFlow::Continue(())
+                    })
+                }
+            };
+
+            quote!((#lhs, #rhs, #(#tolerances,)*) => #body)
+        })
+        .collect::<Vec<_>>();
+
+    let scrutinee = per_field_params.iter().map(|param| ident(param));
+    let mismatch = match method.output {
+        _ if !data.is_enum => quote!(),
+        Output::Bool => quote!(_ => false,),
+        Output::Assoc(_) | Output::Visit => {
+            let lhs = variant_name(quote!(self), quote!(Self), data);
+            let rhs = variant_name(quote!(other), quote!(Self), data);
+            let tolerance = match per_field_params.first() {
+                Some(param) => {
+                    let param = ident(param);
+                    let name = variant_name(quote!(#param), quote!(#tolerance_shape), data);
+
+                    quote!(::core::option::Option::Some(#name))
+                }
+                None => quote!(::core::option::Option::None),
+            };
+
+            let mismatch = quote! {
+                ::approx_cmp::VariantMismatch {
+                    left: #lhs,
+                    right: #rhs,
+                    tolerance: #tolerance,
+                }
+            };
+
+            if let Output::Visit = method.output {
+                quote!(_ => ::approx_cmp::StructureMismatch::Variant(#mismatch).report(path, visitor),)
+            } else {
+                quote!(_ => ::core::result::Result::Err(#mismatch),)
+            }
+        }
+    };
+    let output = match method.output {
+        Output::Bool => quote!(bool),
+        Output::Assoc(assoc) => {
+            let assoc = ident(assoc);
+
+            quote!(Self::#assoc)
+        }
+        Output::Visit => quote!(::core::ops::ControlFlow<()>),
+    };
+    let (lifetime, receiver) = match method.output {
+        Output::Visit => (quote!(<'__visit>), quote!(&'__visit self)),
+        _ => (quote!(), quote!(&self)),
+    };
+    let allow = if data.fields().any(|field| field.is_approx()) {
+        quote!()
+    } else {
+        quote!(#[allow(unused_variables)])
+    };
+
+    quote! {
+        #allow
+        #[inline]
+        fn #name #lifetime(#receiver, other: &Self, #(#params),*) -> #output {
+            match (self, other, #(#scrutinee,)*) {
+                #(#arms,)*
+                #mismatch
+            }
+        }
+    }
+}
+
+/// Derive the comparison trait described by `spec` for the type `input`, along
+/// with the type used by its per field associated types.
+pub(crate) fn derive(input: syn::DeriveInput, spec: &TraitSpec) -> syn::Result<TokenStream> {
+    let data = data(&input)?;
+    let name = &input.ident;
+    let path = trait_path(spec);
+    let shape = shape_ident(spec, &input);
+    let shape_type = shape_type(spec, &input, &data);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+    where_clause.predicates.extend(bounds(spec, &data, &input.generics));
+    let assoc_types = assoc_types(spec, shape.as_ref(), &data);
+    let methods = spec.methods.iter().map(|m| method(spec, &input, m, &data));
+
+    Ok(quote! {
+        #shape_type
+
+        #[automatically_derived]
+        impl #impl_generics #path for #name #ty_generics #where_clause {
+            #(#assoc_types)*
+
+            #(#methods)*
+        }
+    })
+}
@@ -0,0 +1,327 @@
+//! Derive macros for the approximate comparison traits of **approx_cmp**.
+//!
+//! Each comparison trait of the absolute difference, relative difference, and
+//! units in last place (ulps) comparison algorithms can be derived for structs,
+//! tuple structs, and enums whose fields implement the same trait. The derived
+//! implementations compare two values field by field, in declaration order,
+//! exactly like the tuple implementations provided by each comparison crate.
+//!
+//! The derive macros are re-exported by the `approx_cmp` crate when its `derive`
+//! feature is enabled.
+//!
+//! # Generated Tolerance Structs
+//!
+//! Per field tolerances and debugging values are stored in structs generated
+//! alongside each derived implementation. Each generated struct has the same
+//! fields as the deriving type, with the same visibility, and has one type
+//! parameter per field. The generated structs derive `Copy`, `Clone`, `Debug`,
+//! `Default`, and `PartialEq`. For a type `Pose`, the generated structs are
+//!
+//! | Derive                | Generated struct                | Associated types                 |
+//! |-----------------------|---------------------------------|----------------------------------|
+//! | `AbsDiffEq`           | `PoseAbsDiffTolerance`          | `Tolerance`                      |
+//! | `AssertAbsDiffEq`     | `PoseDebugAbsDiffTolerance`     | `DebugAbsDiff`, `DebugTolerance` |
+//! | `AssertAbsDiffAllEq`  | `PoseDebugAbsDiffAllTolerance`  | `AllDebugTolerance`              |
+//! | `RelativeEq`          | `PoseRelativeTolerance`         | `Tolerance`                      |
+//! | `AssertRelativeEq`    | `PoseDebugRelativeTolerance`    | every debugging associated type  |
+//! | `AssertRelativeAllEq` | `PoseDebugRelativeAllTolerance` | `AllDebugTolerance`              |
+//! | `UlpsEq`              | `PoseUlpsTolerance`             | `Tolerance`, `UlpsTolerance`     |
+//! | `AssertUlpsEq`        | `PoseDebugUlpsTolerance`        | every debugging associated type  |
+//! | `AssertUlpsAllEq`     | `PoseDebugUlpsAllTolerance`     | every debugging associated type  |
+//!
+//! The type parameters of each generated struct are instantiated with the
+//! associated types of the fields of the deriving type.
+//!
+//! # Enums
+//!
+//! Enums are compared variant by variant. Two values are approximately equal
+//! only when they are the same variant and their fields are approximately equal.
+//! For an enum, the generated types are enums with the same variants as the
+//! deriving type, and per field tolerances are written for a specific variant.
+//! A tolerance of a different variant than the values being compared fails the
+//! comparison.
+//!
+//! The debugging associated types of a derived enum are `Result`s, holding the
+//! generated debugging enum when the variants match, and an
+//! [`approx_cmp::VariantMismatch`] naming the variants of the left hand side,
+//! the right hand side, and the tolerance otherwise. This way, failed assertions
+//! report a variant mismatch separately from a difference between fields.
+//!
+//! ```
+//! use approx_cmp::{
+//!     AbsDiffEq,
+//!     AssertAbsDiffEq,
+//!     VariantMismatch,
+//!     assert_abs_diff_eq,
+//!     assert_abs_diff_ne,
+//! };
+//!
+//! #[derive(Clone, Debug, AbsDiffEq, AssertAbsDiffEq)]
+//! enum Shape {
+//!     Circle { r: f64 },
+//!     Rect { w: f64, h: f64 },
+//! }
+//!
+//! let circle = Shape::Circle { r: 1.0 };
+//! let rect = Shape::Rect { w: 1.0, h: 2.0 };
+//! let max_abs_diff = ShapeAbsDiffTolerance::Circle { r: 1e-12 };
+//!
+//! assert_abs_diff_eq!(circle, Shape::Circle { r: 1.0 }, abs_diff <= max_abs_diff);
+//! assert_abs_diff_ne!(circle, rect, abs_diff <= max_abs_diff);
+//! assert_eq!(
+//!     circle.debug_abs_diff(&rect),
+//!     Err(VariantMismatch { left: "Circle", right: "Rect", tolerance: None }),
+//! );
+//! ```
+//!
+//! Generated enums derive the same traits as generated structs except for
+//! `Default`.
+//!
+//! # Field Attributes
+//!
+//! The comparison of each field can be configured with the `approx` attribute.
+//!
+//! * `#[approx(skip)]` leaves the field out of every comparison.
+//! * `#[approx(exact)]` compares the field with `PartialEq`.
+//! * `#[approx(with = path)]` compares the field with the function `path`, of
+//!   type `fn(&T, &T) -> bool`, where `T` is the type of the field.
+//!
+//! Fields compared exactly or with a custom comparator take no tolerance, so
+//! they are left out of the generated tolerance structs. The generated debugging
+//! structs keep them, reporting an [`approx_cmp::FieldCmp`] in place of each
+//! difference, and `()` in place of each tolerance, so that failed assertions
+//! show which of them differ. The visit methods of the debugging traits report
+//! the values of these fields too, so their types must implement `Debug`.
+//!
+//! ```
+//! use approx_cmp::{
+//!     assert_relative_eq,
+//!     AssertRelativeEq,
+//!     FieldCmp,
+//!     RelativeEq,
+//! };
+//!
+//! fn same_unit(lhs: &&str, rhs: &&str) -> bool {
+//!     lhs.eq_ignore_ascii_case(rhs)
+//! }
+//!
+//! #[derive(Clone, Debug, RelativeEq, AssertRelativeEq)]
+//! struct Reading {
+//!     #[approx(exact)]
+//!     sensor: u32,
+//!     #[approx(with = same_unit)]
+//!     unit: &'static str,
+//!     #[approx(skip)]
+//!     received_at: u64,
+//!     value: f64,
+//! }
+//!
+//! let lhs = Reading { sensor: 7, unit: "kPa", received_at: 100, value: 101.325 };
+//! let rhs = Reading { sensor: 7, unit: "KPA", received_at: 250, value: 101.32500001 };
+//! let max_abs_diff = ReadingRelativeTolerance { value: 0.0 };
+//! let max_relative = ReadingRelativeTolerance { value: 1e-9 };
+//!
+//! assert_relative_eq!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+//!
+//! let other = Reading { sensor: 8, ..rhs.clone() };
+//! let abs_diff = lhs.debug_abs_diff(&other);
+//!
+//! assert_eq!(abs_diff.sensor, FieldCmp::NotEqual);
+//! assert_eq!(abs_diff.unit, FieldCmp::Equal);
+//! ```
+//!
+//! # Failure Paths
+//!
+//! The derived debugging traits implement the `visit_*` methods by visiting each
+//! compared field in turn under its name, so that the `check_*` macros locate the
+//! first failing element inside a derived type, e.g. `.end[1]` for the second
+//! entry of the array field `end`. Fields compared exactly or with a custom
+//! comparator are reported with an [`approx_cmp::FieldCmp`], and a variant
+//! mismatch is reported at the path of the enum.
+//!
+//! ```
+//! use approx_cmp::{
+//!     AbsDiffAllEq,
+//!     AbsDiffEq,
+//!     AssertAbsDiffAllEq,
+//!     AssertAbsDiffEq,
+//!     check_abs_diff_eq,
+//! };
+//!
+//! #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+//! struct Segment {
+//!     start: [f64; 2],
+//!     end: [f64; 2],
+//! }
+//!
+//! let lhs = Segment { start: [0.0, 0.0], end: [1.0, 1.0] };
+//! let rhs = Segment { start: [0.0, 0.0], end: [1.0, 1.5] };
+//! let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1).unwrap_err();
+//!
+//! assert_eq!(mismatch.path.to_string(), ".end[1]");
+//! ```
+//!
+//! [`approx_cmp::FieldCmp`]: https://docs.rs/approx_cmp/latest/approx_cmp/enum.FieldCmp.html
+//! [`approx_cmp::VariantMismatch`]: https://docs.rs/approx_cmp/latest/approx_cmp/struct.VariantMismatch.html
+//!
+//! # Example
+//!
+//! ```
+//! use approx_cmp::{
+//!     assert_abs_diff_eq,
+//!     assert_relative_eq,
+//!     assert_ulps_eq,
+//!     AbsDiffAllEq,
+//!     AbsDiffEq,
+//!     AssertAbsDiffAllEq,
+//!     AssertAbsDiffEq,
+//!     AssertRelativeAllEq,
+//!     AssertRelativeEq,
+//!     AssertUlpsAllEq,
+//!     AssertUlpsEq,
+//!     RelativeAllEq,
+//!     RelativeEq,
+//!     UlpsAllEq,
+//!     UlpsEq,
+//! };
+//!
+//! #[derive(
+//!     Copy, Clone, Debug, PartialEq,
+//!     AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq,
+//!     RelativeEq, RelativeAllEq, AssertRelativeEq, AssertRelativeAllEq,
+//!     UlpsEq, UlpsAllEq, AssertUlpsEq, AssertUlpsAllEq,
+//! )]
+//! struct Pose {
+//!     position: [f64; 3],
+//!     angle: f64,
+//! }
+//!
+//! let lhs = Pose { position: [1.0, 2.0, 3.0], angle: 0.5 };
+//! let rhs = Pose { position: [1.0, 2.0, 3.0000001], angle: 0.5000001 };
+//!
+//! // Per field tolerances are written by field name.
+//! let max_abs_diff = PoseAbsDiffTolerance {
+//!     position: [1e-6, 1e-6, 1e-6],
+//!     angle: 1e-6,
+//! };
+//! assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+//!
+//! // A uniform tolerance applies to every field.
+//! assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 1e-6);
+//! assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0, relative_all <= 1e-6);
+//! assert_ulps_eq!(lhs, rhs, abs_diff_all <= 1e-6, ulps_all <= 4);
+//!
+//! // The debugging values also have the fields of the deriving type.
+//! let abs_diff = AssertAbsDiffEq::debug_abs_diff(&lhs, &rhs);
+//!
+//! assert_eq!(abs_diff.position, [0.0, 0.0, f64::abs(3.0 - 3.0000001)]);
+//! assert_eq!(abs_diff.angle, f64::abs(0.5 - 0.5000001));
+//! ```
+//!
+//! # Uniform Tolerances
+//!
+//! The `all` traits (`AbsDiffAllEq`, `RelativeAllEq`, `UlpsAllEq`) compare
+//! every field against a single tolerance value. Deriving them requires every
+//! field to use the same `AllTolerance` (and `AllUlpsTolerance`) type.
+//!
+//! # Debugging Traits
+//!
+//! The debugging traits (`AssertAbsDiffEq`, `AssertRelativeEq`, `AssertUlpsEq`,
+//! and their `all` counterparts) index into the tolerances of the comparison
+//! traits they extend, so they must be derived together with them.
+extern crate proc_macro;
+
+mod expand;
+mod spec;
+
+use proc_macro::TokenStream;
+
+fn expand(input: TokenStream, spec: &spec::TraitSpec) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    expand::derive(input, spec).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive `AbsDiffEq` for a struct or enum by comparing its fields pairwise with
+/// per field tolerances.
+#[proc_macro_derive(AbsDiffEq, attributes(approx))]
+pub fn derive_abs_diff_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ABS_DIFF_EQ)
+}
+
+/// Derive `AbsDiffAllEq` for a struct or enum by comparing its fields pairwise with
+/// a uniform tolerance.
+#[proc_macro_derive(AbsDiffAllEq, attributes(approx))]
+pub fn derive_abs_diff_all_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ABS_DIFF_ALL_EQ)
+}
+
+/// Derive `AssertAbsDiffEq` for a struct or enum whose `AbsDiffEq` implementation
+/// is derived.
+#[proc_macro_derive(AssertAbsDiffEq, attributes(approx))]
+pub fn derive_assert_abs_diff_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ASSERT_ABS_DIFF_EQ)
+}
+
+/// Derive `AssertAbsDiffAllEq` for a struct or enum whose `AbsDiffAllEq` implementation
+/// is derived.
+#[proc_macro_derive(AssertAbsDiffAllEq, attributes(approx))]
+pub fn derive_assert_abs_diff_all_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ASSERT_ABS_DIFF_ALL_EQ)
+}
+
+/// Derive `RelativeEq` for a struct or enum by comparing its fields pairwise with
+/// per field tolerances.
+#[proc_macro_derive(RelativeEq, attributes(approx))]
+pub fn derive_relative_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::RELATIVE_EQ)
+}
+
+/// Derive `RelativeAllEq` for a struct or enum by comparing its fields pairwise with
+/// a uniform tolerance.
+#[proc_macro_derive(RelativeAllEq, attributes(approx))]
+pub fn derive_relative_all_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::RELATIVE_ALL_EQ)
+}
+
+/// Derive `AssertRelativeEq` for a struct or enum whose `RelativeEq` implementation
+/// is derived.
+#[proc_macro_derive(AssertRelativeEq, attributes(approx))]
+pub fn derive_assert_relative_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ASSERT_RELATIVE_EQ)
+}
+
+/// Derive `AssertRelativeAllEq` for a struct or enum whose `RelativeAllEq` implementation
+/// is derived.
+#[proc_macro_derive(AssertRelativeAllEq, attributes(approx))]
+pub fn derive_assert_relative_all_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ASSERT_RELATIVE_ALL_EQ)
+}
+
+/// Derive `UlpsEq` for a struct or enum by comparing its fields pairwise with
+/// per field tolerances.
+#[proc_macro_derive(UlpsEq, attributes(approx))]
+pub fn derive_ulps_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ULPS_EQ)
+}
+
+/// Derive `UlpsAllEq` for a struct or enum by comparing its fields pairwise with
+/// a uniform tolerance.
+#[proc_macro_derive(UlpsAllEq, attributes(approx))]
+pub fn derive_ulps_all_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ULPS_ALL_EQ)
+}
+
+/// Derive `AssertUlpsEq` for a struct or enum whose `UlpsEq` implementation
+/// is derived.
+#[proc_macro_derive(AssertUlpsEq, attributes(approx))]
+pub fn derive_assert_ulps_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ASSERT_ULPS_EQ)
+}
+
+/// Derive `AssertUlpsAllEq` for a struct or enum whose `UlpsAllEq` implementation
+/// is derived.
+#[proc_macro_derive(AssertUlpsAllEq, attributes(approx))]
+pub fn derive_assert_ulps_all_eq(input: TokenStream) -> TokenStream {
+    expand(input, &spec::ASSERT_ULPS_ALL_EQ)
+}
@@ -0,0 +1,409 @@
+/// The shape of an associated type in a derived implementation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum AssocKind {
+    /// The struct generated alongside the trait implementation, with one field
+    /// per field of the deriving type holding that field's associated type of
+    /// the same name.
+    PerField,
+    /// The associated type of the first field. Every other field must use the
+    /// same associated type, since a single tolerance value is shared by every
+    /// field.
+    Uniform,
+}
+
+/// An associated type of a comparison trait.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct AssocType {
+    pub(crate) name: &'static str,
+    pub(crate) kind: AssocKind,
+    /// Whether the trait declares the associated type as `?Sized`. Such types
+    /// must be bounded by `Sized` before they can be placed in a tuple.
+    pub(crate) maybe_unsized: bool,
+    /// Whether the associated type holds the difference between two values,
+    /// rather than a tolerance. Fields compared exactly or with a custom
+    /// comparator report an `approx_cmp::FieldCmp` for differences, and `()`
+    /// for tolerances.
+    pub(crate) difference: bool,
+}
+
+/// A tolerance parameter of a comparison trait method.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Param {
+    pub(crate) name: &'static str,
+    /// The associated type of the parameter, e.g. `Tolerance`.
+    pub(crate) ty: &'static str,
+    /// Whether the parameter holds one tolerance per field, or one tolerance
+    /// shared by every field.
+    pub(crate) kind: AssocKind,
+}
+
+/// The return value of a comparison trait method.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Output {
+    /// The conjunction of the method applied to every field.
+    Bool,
+    /// A tuple of the method applied to every field.
+    Assoc(&'static str),
+    /// The method applied to every field in turn, reporting each field to a
+    /// visitor under its name.
+    Visit,
+}
+
+/// A method of a comparison trait.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Method {
+    pub(crate) name: &'static str,
+    pub(crate) params: &'static [Param],
+    pub(crate) output: Output,
+}
+
+/// A struct generated by a derive macro to hold per field tolerances or
+/// debugging values. The generated struct has the same fields as the deriving
+/// type, and is generic over the type of each field.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Shape {
+    /// The suffix appended to the name of the deriving type to name the
+    /// generated struct.
+    pub(crate) suffix: &'static str,
+    /// A description of the contents of the generated struct, used in its
+    /// documentation.
+    pub(crate) contents: &'static str,
+    /// Whether the struct holds the fields compared exactly or with a custom
+    /// comparator, in addition to the fields compared approximately.
+    pub(crate) predicates: bool,
+}
+
+/// A description of a comparison trait, sufficient to derive it field by field.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct TraitSpec {
+    pub(crate) name: &'static str,
+    pub(crate) supertrait: Option<&'static TraitSpec>,
+    /// The struct used by every per field associated type of the trait.
+    pub(crate) shape: Option<Shape>,
+    pub(crate) assoc_types: &'static [AssocType],
+    pub(crate) methods: &'static [Method],
+}
+
+const fn per_field(name: &'static str) -> AssocType {
+    AssocType {
+        name,
+        kind: AssocKind::PerField,
+        maybe_unsized: true,
+        difference: false,
+    }
+}
+
+const fn uniform(name: &'static str) -> AssocType {
+    AssocType {
+        name,
+        kind: AssocKind::Uniform,
+        maybe_unsized: true,
+        difference: false,
+    }
+}
+
+const fn debug(name: &'static str) -> AssocType {
+    AssocType {
+        name,
+        kind: AssocKind::PerField,
+        maybe_unsized: false,
+        difference: false,
+    }
+}
+
+const fn debug_diff(name: &'static str) -> AssocType {
+    AssocType {
+        name,
+        kind: AssocKind::PerField,
+        maybe_unsized: false,
+        difference: true,
+    }
+}
+
+const fn param(name: &'static str, ty: &'static str) -> Param {
+    Param {
+        name,
+        ty,
+        kind: AssocKind::PerField,
+    }
+}
+
+const fn all_param(name: &'static str, ty: &'static str) -> Param {
+    Param {
+        name,
+        ty,
+        kind: AssocKind::Uniform,
+    }
+}
+
+pub(crate) static ABS_DIFF_EQ: TraitSpec = TraitSpec {
+    name: "AbsDiffEq",
+    supertrait: None,
+    shape: Some(Shape {
+        suffix: "AbsDiffTolerance",
+        contents: "per field absolute difference tolerances",
+        predicates: false,
+    }),
+    assoc_types: &[per_field("Tolerance")],
+    methods: &[Method {
+        name: "abs_diff_eq",
+        params: &[param("max_abs_diff", "Tolerance")],
+        output: Output::Bool,
+    }],
+};
+
+pub(crate) static ABS_DIFF_ALL_EQ: TraitSpec = TraitSpec {
+    name: "AbsDiffAllEq",
+    supertrait: None,
+    shape: None,
+    assoc_types: &[uniform("AllTolerance")],
+    methods: &[Method {
+        name: "abs_diff_all_eq",
+        params: &[all_param("max_abs_diff", "AllTolerance")],
+        output: Output::Bool,
+    }],
+};
+
+pub(crate) static ASSERT_ABS_DIFF_EQ: TraitSpec = TraitSpec {
+    name: "AssertAbsDiffEq",
+    supertrait: Some(&ABS_DIFF_EQ),
+    shape: Some(Shape {
+        suffix: "DebugAbsDiffTolerance",
+        contents: "per field absolute differences and absolute difference tolerances",
+        predicates: true,
+    }),
+    assoc_types: &[debug_diff("DebugAbsDiff"), debug("DebugTolerance")],
+    methods: &[
+        Method {
+            name: "debug_abs_diff",
+            params: &[],
+            output: Output::Assoc("DebugAbsDiff"),
+        },
+        Method {
+            name: "debug_abs_diff_tolerance",
+            params: &[param("max_abs_diff", "Tolerance")],
+            output: Output::Assoc("DebugTolerance"),
+        },
+        Method {
+            name: "visit_abs_diff",
+            params: &[param("max_abs_diff", "Tolerance")],
+            output: Output::Visit,
+        },
+    ],
+};
+
+pub(crate) static ASSERT_ABS_DIFF_ALL_EQ: TraitSpec = TraitSpec {
+    name: "AssertAbsDiffAllEq",
+    supertrait: Some(&ABS_DIFF_ALL_EQ),
+    shape: Some(Shape {
+        suffix: "DebugAbsDiffAllTolerance",
+        contents: "per field uniform absolute difference tolerances",
+        predicates: true,
+    }),
+    assoc_types: &[debug("AllDebugTolerance")],
+    methods: &[
+        Method {
+            name: "debug_abs_diff_all_tolerance",
+            params: &[all_param("max_abs_diff", "AllTolerance")],
+            output: Output::Assoc("AllDebugTolerance"),
+        },
+        Method {
+            name: "visit_abs_diff_all",
+            params: &[all_param("max_abs_diff", "AllTolerance")],
+            output: Output::Visit,
+        },
+    ],
+};
+
+pub(crate) static RELATIVE_EQ: TraitSpec = TraitSpec {
+    name: "RelativeEq",
+    supertrait: None,
+    shape: Some(Shape {
+        suffix: "RelativeTolerance",
+        contents: "per field absolute difference and relative tolerances",
+        predicates: false,
+    }),
+    assoc_types: &[per_field("Tolerance")],
+    methods: &[Method {
+        name: "relative_eq",
+        params: &[param("max_abs_diff", "Tolerance"), param("max_relative", "Tolerance")],
+        output: Output::Bool,
+    }],
+};
+
+pub(crate) static RELATIVE_ALL_EQ: TraitSpec = TraitSpec {
+    name: "RelativeAllEq",
+    supertrait: None,
+    shape: None,
+    assoc_types: &[uniform("AllTolerance")],
+    methods: &[Method {
+        name: "relative_all_eq",
+        params: &[all_param("max_abs_diff", "AllTolerance"), all_param("max_relative", "AllTolerance")],
+        output: Output::Bool,
+    }],
+};
+
+pub(crate) static ASSERT_RELATIVE_EQ: TraitSpec = TraitSpec {
+    name: "AssertRelativeEq",
+    supertrait: Some(&RELATIVE_EQ),
+    shape: Some(Shape {
+        suffix: "DebugRelativeTolerance",
+        contents: "per field absolute differences, relative differences, absolute difference tolerances, and relative tolerances",
+        predicates: true,
+    }),
+    assoc_types: &[debug_diff("DebugAbsDiff"), debug_diff("DebugRelativeDiff"), debug("DebugTolerance")],
+    methods: &[
+        Method {
+            name: "debug_abs_diff",
+            params: &[],
+            output: Output::Assoc("DebugAbsDiff"),
+        },
+        Method {
+            name: "debug_relative_diff",
+            params: &[],
+            output: Output::Assoc("DebugRelativeDiff"),
+        },
+        Method {
+            name: "debug_abs_diff_tolerance",
+            params: &[param("max_abs_diff", "Tolerance")],
+            output: Output::Assoc("DebugTolerance"),
+        },
+        Method {
+            name: "debug_relative_tolerance",
+            params: &[param("max_relative", "Tolerance")],
+            output: Output::Assoc("DebugTolerance"),
+        },
+        Method {
+            name: "visit_relative",
+            params: &[param("max_abs_diff", "Tolerance"), param("max_relative", "Tolerance")],
+            output: Output::Visit,
+        },
+    ],
+};
+
+pub(crate) static ASSERT_RELATIVE_ALL_EQ: TraitSpec = TraitSpec {
+    name: "AssertRelativeAllEq",
+    supertrait: Some(&RELATIVE_ALL_EQ),
+    shape: Some(Shape {
+        suffix: "DebugRelativeAllTolerance",
+        contents: "per field uniform absolute difference and relative tolerances",
+        predicates: true,
+    }),
+    assoc_types: &[debug("AllDebugTolerance")],
+    methods: &[
+        Method {
+            name: "debug_abs_diff_all_tolerance",
+            params: &[all_param("max_abs_diff", "AllTolerance")],
+            output: Output::Assoc("AllDebugTolerance"),
+        },
+        Method {
+            name: "debug_relative_all_tolerance",
+            params: &[all_param("max_relative", "AllTolerance")],
+            output: Output::Assoc("AllDebugTolerance"),
+        },
+        Method {
+            name: "visit_relative_all",
+            params: &[all_param("max_abs_diff", "AllTolerance"), all_param("max_relative", "AllTolerance")],
+            output: Output::Visit,
+        },
+    ],
+};
+
+pub(crate) static ULPS_EQ: TraitSpec = TraitSpec {
+    name: "UlpsEq",
+    supertrait: None,
+    shape: Some(Shape {
+        suffix: "UlpsTolerance",
+        contents: "per field absolute difference and ulps tolerances",
+        predicates: false,
+    }),
+    assoc_types: &[per_field("Tolerance"), per_field("UlpsTolerance")],
+    methods: &[Method {
+        name: "ulps_eq",
+        params: &[param("max_abs_diff", "Tolerance"), param("max_ulps", "UlpsTolerance")],
+        output: Output::Bool,
+    }],
+};
+
+pub(crate) static ULPS_ALL_EQ: TraitSpec = TraitSpec {
+    name: "UlpsAllEq",
+    supertrait: None,
+    shape: None,
+    assoc_types: &[uniform("AllTolerance"), uniform("AllUlpsTolerance")],
+    methods: &[Method {
+        name: "ulps_all_eq",
+        params: &[all_param("max_abs_diff", "AllTolerance"), all_param("max_ulps", "AllUlpsTolerance")],
+        output: Output::Bool,
+    }],
+};
+
+pub(crate) static ASSERT_ULPS_EQ: TraitSpec = TraitSpec {
+    name: "AssertUlpsEq",
+    supertrait: Some(&ULPS_EQ),
+    shape: Some(Shape {
+        suffix: "DebugUlpsTolerance",
+        contents: "per field absolute differences, ulps differences, absolute difference tolerances, and ulps tolerances",
+        predicates: true,
+    }),
+    assoc_types: &[
+        debug_diff("DebugAbsDiff"),
+        debug_diff("DebugUlpsDiff"),
+        debug("DebugTolerance"),
+        debug("DebugUlpsTolerance"),
+    ],
+    methods: &[
+        Method {
+            name: "debug_abs_diff",
+            params: &[],
+            output: Output::Assoc("DebugAbsDiff"),
+        },
+        Method {
+            name: "debug_ulps_diff",
+            params: &[],
+            output: Output::Assoc("DebugUlpsDiff"),
+        },
+        Method {
+            name: "debug_abs_diff_tolerance",
+            params: &[param("max_abs_diff", "Tolerance")],
+            output: Output::Assoc("DebugTolerance"),
+        },
+        Method {
+            name: "debug_ulps_tolerance",
+            params: &[param("max_ulps", "UlpsTolerance")],
+            output: Output::Assoc("DebugUlpsTolerance"),
+        },
+        Method {
+            name: "visit_ulps",
+            params: &[param("max_abs_diff", "Tolerance"), param("max_ulps", "UlpsTolerance")],
+            output: Output::Visit,
+        },
+    ],
+};
+
+pub(crate) static ASSERT_ULPS_ALL_EQ: TraitSpec = TraitSpec {
+    name: "AssertUlpsAllEq",
+    supertrait: Some(&ULPS_ALL_EQ),
+    shape: Some(Shape {
+        suffix: "DebugUlpsAllTolerance",
+        contents: "per field uniform absolute difference and ulps tolerances",
+        predicates: true,
+    }),
+    assoc_types: &[debug("AllDebugTolerance"), debug("AllDebugUlpsTolerance")],
+    methods: &[
+        Method {
+            name: "debug_abs_diff_all_tolerance",
+            params: &[all_param("max_abs_diff", "AllTolerance")],
+            output: Output::Assoc("AllDebugTolerance"),
+        },
+        Method {
+            name: "debug_ulps_all_tolerance",
+            params: &[all_param("max_ulps", "AllUlpsTolerance")],
+            output: Output::Assoc("AllDebugUlpsTolerance"),
+        },
+        Method {
+            name: "visit_ulps_all",
+            params: &[all_param("max_abs_diff", "AllTolerance"), all_param("max_ulps", "AllUlpsTolerance")],
+            output: Output::Visit,
+        },
+    ],
+};
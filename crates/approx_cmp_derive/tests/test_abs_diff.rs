@@ -0,0 +1,301 @@
+#[cfg(test)]
+mod abs_diff_eq_struct_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        assert_abs_diff_eq,
+        assert_abs_diff_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Vector2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2AbsDiffTolerance {
+            x: 10.0_f32 * f32::EPSILON,
+            y: 20.0_f32 * f32::EPSILON,
+        };
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 { x: 1.0_f32, y: 4.0_f32 };
+        let max_abs_diff = Vector2AbsDiffTolerance {
+            x: 10.0_f32 * f32::EPSILON,
+            y: 20.0_f32 * f32::EPSILON,
+        };
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = 20.0_f32 * f32::EPSILON;
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_all_ne() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 { x: 1.0_f32, y: 4.0_f32 };
+        let max_abs_diff = 8.0_f32 * f32::EPSILON;
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff_all <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let abs_diff = Vector2DebugAbsDiffTolerance {
+            x: 0.0000010728836_f32,
+            y: 0.0000021457672_f32,
+        };
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2AbsDiffTolerance { x: 0.2_f32, y: 0.4_f32 };
+        let tolerance = Vector2DebugAbsDiffTolerance { x: 0.2_f32, y: 0.4_f32 };
+
+        assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = 0.2_f32;
+        let tolerance = Vector2DebugAbsDiffAllTolerance { x: 0.2_f32, y: 0.2_f32 };
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let max_abs_diff = Vector2AbsDiffTolerance { x: 0.25_f32, y: 0.5_f32 };
+        let expected = "Vector2AbsDiffTolerance { x: 0.25, y: 0.5 }";
+        let result = format!("{:?}", max_abs_diff);
+
+        assert_eq!(result, expected);
+    }
+}
+
+#[cfg(test)]
+mod abs_diff_eq_tuple_struct_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        assert_abs_diff_eq,
+        assert_abs_diff_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Segment([f64; 2], [f64; 2]);
+
+    #[test]
+    fn test_eq() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000001_f64]);
+        let max_abs_diff = SegmentAbsDiffTolerance([0.0_f64, 0.0_f64], [0.0_f64, 4.0_f64 * f64::EPSILON]);
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000001_f64]);
+        let max_abs_diff = SegmentAbsDiffTolerance([4.0_f64 * f64::EPSILON, 4.0_f64 * f64::EPSILON], [4.0_f64 * f64::EPSILON, 0.0_f64]);
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000001_f64]);
+        let max_abs_diff = 4.0_f64 * f64::EPSILON;
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_all_ne() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000001_f64]);
+        let max_abs_diff = 0.0_f64;
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff_all <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]);
+        let abs_diff = SegmentDebugAbsDiffTolerance([0.0_f64, 0.0_f64], [0.0_f64, 0.5_f64]);
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]);
+        let max_abs_diff = 0.2_f64;
+        let tolerance = SegmentDebugAbsDiffAllTolerance([0.2_f64, 0.2_f64], [0.2_f64, 0.2_f64]);
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod abs_diff_eq_unit_struct_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        assert_abs_diff_eq,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Unit;
+
+    #[test]
+    fn test_eq() {
+        assert_abs_diff_eq!(Unit, Unit, abs_diff <= UnitAbsDiffTolerance);
+        assert_abs_diff_eq!(Unit, Unit, abs_diff_all <= ());
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        assert_eq!(Unit.debug_abs_diff(&Unit), UnitDebugAbsDiffTolerance);
+        assert_eq!(
+            Unit.debug_abs_diff_tolerance(&Unit, &UnitAbsDiffTolerance),
+            UnitDebugAbsDiffTolerance
+        );
+        assert_eq!(Unit.debug_abs_diff_all_tolerance(&Unit, &()), UnitDebugAbsDiffAllTolerance);
+    }
+}
+
+#[cfg(test)]
+mod abs_diff_eq_generic_struct_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        assert_abs_diff_eq,
+        assert_abs_diff_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Particle<T> {
+        position: [T; 3],
+        mass: T,
+    }
+
+    #[test]
+    fn test_eq_f32() {
+        let lhs = Particle {
+            position: [1.0_f32, 2.0_f32, 3.0_f32],
+            mass: 4.0_f32,
+        };
+        let rhs = Particle {
+            position: [1.0_f32, 2.0_f32, 3.0_f32],
+            mass: 4.0000005_f32,
+        };
+        let max_abs_diff = 5.0_f32 * f32::EPSILON;
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_ne_f64() {
+        let lhs = Particle {
+            position: [1.0_f64, 2.0_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let rhs = Particle {
+            position: [1.0_f64, 2.5_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let max_abs_diff = ParticleAbsDiffTolerance {
+            position: [0.5_f64, 0.25_f64, 0.5_f64],
+            mass: 0.5_f64,
+        };
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Particle {
+            position: [1.0_f64, 2.0_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let rhs = Particle {
+            position: [1.0_f64, 2.5_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let max_abs_diff = 0.5_f64;
+        let tolerance = ParticleDebugAbsDiffAllTolerance {
+            position: [0.5_f64, 0.5_f64, 0.5_f64],
+            mass: 0.5_f64,
+        };
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+}
@@ -0,0 +1,295 @@
+#[cfg(test)]
+mod abs_diff_eq_attributes_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        FieldCmp,
+        assert_abs_diff_eq,
+        assert_abs_diff_ne,
+    };
+
+    fn same_len(lhs: &&str, rhs: &&str) -> bool {
+        lhs.len() == rhs.len()
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Sample {
+        #[approx(exact)]
+        id: u32,
+        #[approx(with = same_len)]
+        label: &'static str,
+        #[approx(skip)]
+        timestamp: u64,
+        value: f32,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 1,
+            label: "xyz",
+            timestamp: 20,
+            value: 1.0000011_f32,
+        };
+        let max_abs_diff = SampleAbsDiffTolerance {
+            value: 10.0_f32 * f32::EPSILON,
+        };
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 10.0_f32 * f32::EPSILON);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= 10.0_f32 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_ne_exact() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 2,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let max_abs_diff = SampleAbsDiffTolerance { value: 1.0_f32 };
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= 1.0_f32);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff_all <= 1.0_f32);
+    }
+
+    #[test]
+    fn test_ne_with() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 1,
+            label: "abcd",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let max_abs_diff = SampleAbsDiffTolerance { value: 1.0_f32 };
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 2,
+            label: "xyz",
+            timestamp: 20,
+            value: 1.5_f32,
+        };
+        let abs_diff = SampleDebugAbsDiffTolerance {
+            id: FieldCmp::NotEqual,
+            label: FieldCmp::Equal,
+            value: 0.5_f32,
+        };
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_tolerance() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 2,
+            label: "xyz",
+            timestamp: 20,
+            value: 1.5_f32,
+        };
+        let max_abs_diff = SampleAbsDiffTolerance { value: 0.25_f32 };
+        let tolerance = SampleDebugAbsDiffTolerance {
+            id: (),
+            label: (),
+            value: 0.25_f32,
+        };
+
+        assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 2,
+            label: "xyz",
+            timestamp: 20,
+            value: 1.5_f32,
+        };
+        let tolerance = SampleDebugAbsDiffAllTolerance {
+            id: (),
+            label: (),
+            value: 0.25_f32,
+        };
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &0.25_f32), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &0.25_f32), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod relative_eq_attributes_tests {
+    use approx_cmp::{
+        AssertRelativeAllEq,
+        AssertRelativeEq,
+        FieldCmp,
+        RelativeAllEq,
+        RelativeEq,
+        assert_relative_eq,
+        assert_relative_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, RelativeEq, RelativeAllEq, AssertRelativeEq, AssertRelativeAllEq)]
+    struct Measurement(#[approx(skip)] u64, #[approx(exact)] char, f64, f64);
+
+    #[test]
+    fn test_eq() {
+        let lhs = Measurement(1, 'a', 1.0_f64, 2.0_f64);
+        let rhs = Measurement(2, 'a', 1.0_f64, 2.000000000000001_f64);
+        let max_abs_diff = MeasurementRelativeTolerance(0.0_f64, 0.0_f64);
+        let max_relative = MeasurementRelativeTolerance(0.0_f64, 2.0_f64 * f64::EPSILON);
+
+        assert_relative_eq!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+        assert_relative_eq!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Measurement(1, 'a', 1.0_f64, 2.0_f64);
+        let rhs = Measurement(1, 'b', 1.0_f64, 2.0_f64);
+
+        assert_relative_ne!(lhs, rhs, abs_diff_all <= 1.0_f64, relative_all <= 1.0_f64);
+        assert_relative_ne!(rhs, lhs, abs_diff_all <= 1.0_f64, relative_all <= 1.0_f64);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Measurement(1, 'a', 1.0_f64, 2.0_f64);
+        let rhs = Measurement(2, 'b', 1.5_f64, 2.0_f64);
+        let abs_diff = MeasurementDebugRelativeTolerance(FieldCmp::NotEqual, 0.5_f64, 0.0_f64);
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_relative_tolerance() {
+        let lhs = Measurement(1, 'a', 1.0_f64, 2.0_f64);
+        let rhs = Measurement(2, 'b', 1.5_f64, 2.0_f64);
+        let max_relative = MeasurementRelativeTolerance(0.5_f64, 0.5_f64);
+        let tolerance = MeasurementDebugRelativeTolerance((), 0.75_f64, 1.0_f64);
+
+        assert_eq!(lhs.debug_relative_tolerance(&rhs, &max_relative), tolerance);
+        assert_eq!(rhs.debug_relative_tolerance(&lhs, &max_relative), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod ulps_eq_attributes_tests {
+    use approx_cmp::{
+        AssertUlpsAllEq,
+        AssertUlpsEq,
+        FieldCmp,
+        UlpsAllEq,
+        UlpsEq,
+        assert_ulps_eq,
+        assert_ulps_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, UlpsEq, UlpsAllEq, AssertUlpsEq, AssertUlpsAllEq)]
+    struct Tagged<T, U> {
+        #[approx(exact)]
+        tag: U,
+        value: T,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Tagged { tag: 'a', value: 1.0_f32 };
+        let rhs = Tagged {
+            tag: 'a',
+            value: 1.0000001_f32,
+        };
+
+        assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 1_u32);
+        assert_ulps_eq!(rhs, lhs, abs_diff_all <= 0.0_f32, ulps_all <= 1_u32);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Tagged { tag: 'a', value: 1.0_f32 };
+        let rhs = Tagged { tag: 'b', value: 1.0_f32 };
+        let max_abs_diff = TaggedUlpsTolerance { value: 0.0_f32 };
+        let max_ulps = TaggedUlpsTolerance { value: 1_u32 };
+
+        assert_ulps_ne!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+        assert_ulps_ne!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    }
+
+    #[test]
+    fn test_debug_ulps_diff() {
+        let lhs = Tagged { tag: 'a', value: 1.0_f32 };
+        let rhs = Tagged {
+            tag: 'b',
+            value: 1.0000001_f32,
+        };
+        let ulps_diff = TaggedDebugUlpsTolerance {
+            tag: FieldCmp::NotEqual,
+            value: Some(1_u32),
+        };
+
+        assert_eq!(lhs.debug_ulps_diff(&rhs), ulps_diff);
+        assert_eq!(rhs.debug_ulps_diff(&lhs), ulps_diff);
+    }
+
+    #[test]
+    fn test_debug_ulps_all_tolerance() {
+        let lhs = Tagged { tag: 'a', value: 1.0_f32 };
+        let rhs = Tagged {
+            tag: 'b',
+            value: 1.0000001_f32,
+        };
+        let tolerance = TaggedDebugUlpsAllTolerance { tag: (), value: 4_u32 };
+
+        assert_eq!(lhs.debug_ulps_all_tolerance(&rhs, &4_u32), tolerance);
+        assert_eq!(rhs.debug_ulps_all_tolerance(&lhs, &4_u32), tolerance);
+    }
+}
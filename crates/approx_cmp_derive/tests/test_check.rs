@@ -0,0 +1,364 @@
+#[cfg(test)]
+mod check_struct_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        AssertRelativeAllEq,
+        AssertRelativeEq,
+        AssertUlpsAllEq,
+        AssertUlpsEq,
+        Element,
+        Path,
+        RelativeAllEq,
+        RelativeEq,
+        UlpsAllEq,
+        UlpsEq,
+        check_abs_diff_eq,
+        check_relative_eq,
+        check_ulps_eq,
+    };
+    use core::ops::ControlFlow;
+
+    #[derive(
+        Copy,
+        Clone,
+        Debug,
+        PartialEq,
+        AbsDiffEq,
+        AbsDiffAllEq,
+        AssertAbsDiffEq,
+        AssertAbsDiffAllEq,
+        RelativeEq,
+        RelativeAllEq,
+        AssertRelativeEq,
+        AssertRelativeAllEq,
+        UlpsEq,
+        UlpsAllEq,
+        AssertUlpsEq,
+        AssertUlpsAllEq,
+    )]
+    struct Segment {
+        start: [f64; 2],
+        end: [f64; 2],
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Pair(f64, f64);
+
+    #[test]
+    fn test_abs_diff_field_path() {
+        let lhs = Segment {
+            start: [0.0_f64, 0.0_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+        let rhs = Segment {
+            start: [0.0_f64, 0.0_f64],
+            end: [1.0_f64, 1.5_f64],
+        };
+        let max_abs_diff = SegmentAbsDiffTolerance {
+            start: [0.1_f64; 2],
+            end: [0.1_f64; 2],
+        };
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".end[1]");
+
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".end[1]");
+    }
+
+    #[test]
+    fn test_relative_field_path() {
+        let lhs = Segment {
+            start: [1.0_f64, 1.0_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+        let rhs = Segment {
+            start: [1.0_f64, 1.5_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+        let mismatch = check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 0.01_f64).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".start[1]");
+    }
+
+    #[test]
+    fn test_ulps_field_path() {
+        let lhs = Segment {
+            start: [1.0_f64, 1.0_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+        let rhs = Segment {
+            start: [1.0_f64, 1.0_f64],
+            end: [1.5_f64, 1.0_f64],
+        };
+        let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".end[0]");
+    }
+
+    #[test]
+    fn test_tuple_struct_field_path() {
+        let lhs = Pair(1.0_f64, 2.0_f64);
+        let rhs = Pair(1.0_f64, 2.5_f64);
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".1");
+    }
+
+    #[test]
+    #[should_panic(expected = "first failure at `.end[1]`")]
+    fn test_assert_abs_diff_eq_panic_message() {
+        let lhs = Segment {
+            start: [0.0_f64, 0.0_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+        let rhs = Segment {
+            start: [0.0_f64, 0.0_f64],
+            end: [1.0_f64, 1.5_f64],
+        };
+
+        approx_cmp::assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64);
+    }
+
+    #[test]
+    #[should_panic(expected = "first failure at `.start[0]`")]
+    fn test_assert_ulps_eq_panic_message() {
+        let lhs = Segment {
+            start: [0.0_f64, 0.0_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+        let rhs = Segment {
+            start: [0.5_f64, 0.0_f64],
+            end: [1.0_f64, 1.0_f64],
+        };
+
+        approx_cmp::assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64);
+    }
+
+    #[test]
+    fn test_visit_every_field() {
+        let lhs = Pair(1.0_f64, 2.0_f64);
+        let rhs = Pair(1.5_f64, 2.0_f64);
+        let mut visited = Vec::new();
+        let _ = lhs.visit_abs_diff(
+            &rhs,
+            &PairAbsDiffTolerance(0.1_f64, 0.1_f64),
+            &mut Path::new(),
+            &mut |path: &Path<'_>, element: &Element<'_>| {
+                visited.push((path.to_string(), element.passed));
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        assert_eq!(visited, vec![(String::from(".0"), false), (String::from(".1"), true)]);
+    }
+}
+
+#[cfg(test)]
+mod check_attributes_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        Element,
+        Path,
+        check_abs_diff_eq,
+    };
+    use core::ops::ControlFlow;
+
+    fn same_len(lhs: &&str, rhs: &&str) -> bool {
+        lhs.len() == rhs.len()
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    struct Sample {
+        #[approx(exact)]
+        id: u32,
+        #[approx(with = same_len)]
+        label: &'static str,
+        #[approx(skip)]
+        timestamp: u64,
+        value: f32,
+    }
+
+    #[test]
+    fn test_exact_field_path() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 2,
+            label: "abc",
+            timestamp: 20,
+            value: 1.0_f32,
+        };
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.0_f32).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".id");
+    }
+
+    #[test]
+    fn test_with_field_path() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 1,
+            label: "abcd",
+            timestamp: 20,
+            value: 1.0_f32,
+        };
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= SampleAbsDiffTolerance { value: 0.0_f32 }).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".label");
+    }
+
+    #[test]
+    #[should_panic(expected = "first failure at `.id`:
+        left: `1`,
+       right: `2`,
+       exact: `NotEqual`,")]
+    fn test_assert_abs_diff_eq_exact_field_panic_message() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 2,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+
+        approx_cmp::assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.0_f32);
+    }
+
+    #[test]
+    #[should_panic(expected = "first failure at `.label`:
+        left: `\"abc\"`,
+       right: `\"abcd\"`,
+        with: `NotEqual`,")]
+    fn test_assert_abs_diff_eq_with_field_panic_message() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 1,
+            label: "abcd",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+
+        approx_cmp::assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.0_f32);
+    }
+
+    #[test]
+    fn test_visit_skips_skipped_fields() {
+        let lhs = Sample {
+            id: 1,
+            label: "abc",
+            timestamp: 10,
+            value: 1.0_f32,
+        };
+        let rhs = Sample {
+            id: 1,
+            label: "xyz",
+            timestamp: 20,
+            value: 1.5_f32,
+        };
+        let mut visited = Vec::new();
+        let _ = lhs.visit_abs_diff_all(&rhs, &0.1_f32, &mut Path::new(), &mut |path: &Path<'_>, element: &Element<'_>| {
+            let label = element.diffs.first().map(|(label, _)| *label);
+            let values = element.left.zip(element.right).map(|(left, right)| format!("{left:?} {right:?}"));
+            visited.push((path.to_string(), element.passed, label, values));
+
+            ControlFlow::Continue(())
+        });
+
+        let expected = vec![
+            (String::from(".id"), true, Some("exact"), Some(String::from("1 1"))),
+            (String::from(".label"), true, Some("with"), Some(String::from("\"abc\" \"xyz\""))),
+            (String::from(".value"), false, Some("abs_diff"), Some(String::from("1.0 1.5"))),
+        ];
+
+        assert_eq!(visited, expected);
+    }
+}
+
+#[cfg(test)]
+mod check_enum_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        Element,
+        Path,
+        StructureMismatch,
+        VariantMismatch,
+        check_abs_diff_eq,
+    };
+    use core::ops::ControlFlow;
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    enum Shape {
+        Circle { r: f64 },
+        Rect { w: f64, h: f64 },
+    }
+
+    #[test]
+    fn test_variant_field_path() {
+        let lhs = Shape::Rect { w: 1.0_f64, h: 2.0_f64 };
+        let rhs = Shape::Rect { w: 1.0_f64, h: 2.5_f64 };
+        let max_abs_diff = ShapeAbsDiffTolerance::Rect { w: 0.1_f64, h: 0.1_f64 };
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff).unwrap_err();
+
+        assert_eq!(mismatch.path.to_string(), ".h");
+    }
+
+    #[test]
+    fn test_variant_mismatch() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Rect { w: 1.0_f64, h: 1.0_f64 };
+        let mut structure = None;
+        let _ = lhs.visit_abs_diff(
+            &rhs,
+            &ShapeAbsDiffTolerance::Circle { r: 0.1_f64 },
+            &mut Path::new(),
+            &mut |_path: &Path<'_>, element: &Element<'_>| {
+                structure = element.diffs.first().map(|(_, value)| format!("{:?}", value));
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        let expected = StructureMismatch::Variant(VariantMismatch {
+            left: "Circle",
+            right: "Rect",
+            tolerance: Some("Circle"),
+        });
+
+        assert_eq!(structure, Some(format!("{:?}", expected)));
+
+        let mismatch = check_abs_diff_eq!(lhs, rhs, abs_diff_all <= 0.1_f64).unwrap_err();
+
+        assert!(mismatch.path.is_root());
+    }
+}
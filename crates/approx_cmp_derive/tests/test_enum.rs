@@ -0,0 +1,330 @@
+#[cfg(test)]
+mod abs_diff_eq_enum_tests {
+    use approx_cmp::{
+        AbsDiffAllEq,
+        AbsDiffEq,
+        AssertAbsDiffAllEq,
+        AssertAbsDiffEq,
+        VariantMismatch,
+        assert_abs_diff_eq,
+        assert_abs_diff_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, AbsDiffEq, AbsDiffAllEq, AssertAbsDiffEq, AssertAbsDiffAllEq)]
+    enum Shape {
+        Circle { r: f64 },
+        Rect { w: f64, h: f64 },
+        Segment(f64),
+        Point,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Shape::Rect { w: 1.0_f64, h: 2.0_f64 };
+        let rhs = Shape::Rect {
+            w: 1.0_f64,
+            h: 2.000000000000001_f64,
+        };
+        let max_abs_diff = ShapeAbsDiffTolerance::Rect {
+            w: 0.0_f64,
+            h: 4.0_f64 * f64::EPSILON,
+        };
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_eq_unit_variant() {
+        assert_abs_diff_eq!(Shape::Point, Shape::Point, abs_diff <= ShapeAbsDiffTolerance::Point);
+        assert_abs_diff_eq!(Shape::Point, Shape::Point, abs_diff_all <= 0.0_f64);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Circle { r: 2.0_f64 };
+        let max_abs_diff = ShapeAbsDiffTolerance::Circle { r: 0.5_f64 };
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_ne_variant_mismatch() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Segment(1.0_f64);
+        let max_abs_diff = ShapeAbsDiffTolerance::Circle { r: 0.5_f64 };
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(lhs, rhs, abs_diff_all <= 0.5_f64);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff_all <= 0.5_f64);
+    }
+
+    #[test]
+    fn test_ne_tolerance_variant_mismatch() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Circle { r: 1.0_f64 };
+        let max_abs_diff = ShapeAbsDiffTolerance::Segment(0.5_f64);
+
+        assert_abs_diff_ne!(lhs, rhs, abs_diff <= max_abs_diff);
+        assert_abs_diff_ne!(rhs, lhs, abs_diff <= max_abs_diff);
+    }
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Shape::Segment(1.0_f64);
+        let rhs = Shape::Segment(1.0000000000000002_f64);
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= f64::EPSILON);
+        assert_abs_diff_eq!(rhs, lhs, abs_diff_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Shape::Rect { w: 1.0_f64, h: 2.0_f64 };
+        let rhs = Shape::Rect { w: 1.5_f64, h: 2.0_f64 };
+        let abs_diff = Ok(ShapeDebugAbsDiffTolerance::Rect { w: 0.5_f64, h: 0.0_f64 });
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_variant_mismatch() {
+        let lhs = Shape::Rect { w: 1.0_f64, h: 2.0_f64 };
+        let rhs = Shape::Point;
+
+        assert_eq!(
+            lhs.debug_abs_diff(&rhs),
+            Err(VariantMismatch {
+                left: "Rect",
+                right: "Point",
+                tolerance: None,
+            })
+        );
+        assert_eq!(
+            rhs.debug_abs_diff(&lhs),
+            Err(VariantMismatch {
+                left: "Point",
+                right: "Rect",
+                tolerance: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_debug_abs_diff_tolerance() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Circle { r: 1.5_f64 };
+        let max_abs_diff = ShapeAbsDiffTolerance::Circle { r: 0.25_f64 };
+        let tolerance = Ok(ShapeDebugAbsDiffTolerance::Circle { r: 0.25_f64 });
+
+        assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_tolerance_variant_mismatch() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Circle { r: 1.5_f64 };
+        let max_abs_diff = ShapeAbsDiffTolerance::Point;
+        let tolerance = Err(VariantMismatch {
+            left: "Circle",
+            right: "Circle",
+            tolerance: Some("Point"),
+        });
+
+        assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Shape::Rect { w: 1.0_f64, h: 2.0_f64 };
+        let rhs = Shape::Rect { w: 1.5_f64, h: 2.0_f64 };
+        let tolerance = Ok(ShapeDebugAbsDiffAllTolerance::Rect { w: 0.25_f64, h: 0.25_f64 });
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &0.25_f64), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &0.25_f64), tolerance);
+    }
+
+    #[test]
+    #[should_panic(expected = "VariantMismatch { left: \"Circle\", right: \"Rect\", tolerance: None }")]
+    fn test_assert_variant_mismatch() {
+        let lhs = Shape::Circle { r: 1.0_f64 };
+        let rhs = Shape::Rect { w: 1.0_f64, h: 1.0_f64 };
+
+        assert_abs_diff_eq!(lhs, rhs, abs_diff_all <= 1.0_f64);
+    }
+}
+
+#[cfg(test)]
+mod relative_eq_enum_tests {
+    use approx_cmp::{
+        AssertRelativeAllEq,
+        AssertRelativeEq,
+        FieldCmp,
+        RelativeAllEq,
+        RelativeEq,
+        VariantMismatch,
+        assert_relative_eq,
+        assert_relative_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, RelativeEq, RelativeAllEq, AssertRelativeEq, AssertRelativeAllEq)]
+    enum Event<T> {
+        Sample {
+            #[approx(exact)]
+            channel: u8,
+            value: T,
+        },
+        Gap(#[approx(skip)] u64),
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Event::Sample {
+            channel: 1,
+            value: 1.0_f32,
+        };
+        let rhs = Event::Sample {
+            channel: 1,
+            value: 1.0000001_f32,
+        };
+
+        assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= f32::EPSILON);
+        assert_relative_eq!(rhs, lhs, abs_diff_all <= 0.0_f32, relative_all <= f32::EPSILON);
+        assert_relative_eq!(
+            Event::<f32>::Gap(1),
+            Event::Gap(2),
+            abs_diff_all <= 0.0_f32,
+            relative_all <= 0.0_f32
+        );
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Event::Sample {
+            channel: 1,
+            value: 1.0_f32,
+        };
+        let rhs = Event::Sample {
+            channel: 2,
+            value: 1.0_f32,
+        };
+        let max_abs_diff = EventRelativeTolerance::Sample { value: 1.0_f32 };
+        let max_relative = EventRelativeTolerance::Sample { value: 1.0_f32 };
+
+        assert_relative_ne!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+        assert_relative_ne!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+        assert_relative_ne!(lhs, Event::Gap(0), abs_diff <= max_abs_diff, relative <= max_relative);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Event::Sample {
+            channel: 1,
+            value: 1.0_f32,
+        };
+        let rhs = Event::Sample {
+            channel: 2,
+            value: 1.5_f32,
+        };
+        let abs_diff = Ok(EventDebugRelativeTolerance::Sample {
+            channel: FieldCmp::NotEqual,
+            value: 0.5_f32,
+        });
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_relative_all_tolerance() {
+        let lhs = Event::Sample {
+            channel: 1,
+            value: 1.0_f32,
+        };
+        let rhs = Event::Gap(0);
+        let tolerance = Err(VariantMismatch {
+            left: "Sample",
+            right: "Gap",
+            tolerance: None,
+        });
+
+        assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &0.5_f32), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod ulps_eq_enum_tests {
+    use approx_cmp::{
+        AssertUlpsAllEq,
+        AssertUlpsEq,
+        UlpsAllEq,
+        UlpsEq,
+        VariantMismatch,
+        assert_ulps_eq,
+        assert_ulps_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, UlpsEq, UlpsAllEq, AssertUlpsEq, AssertUlpsAllEq)]
+    enum Reading {
+        Scalar(f32),
+        Vector([f32; 2]),
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Reading::Vector([1.0_f32, 2.0_f32]);
+        let rhs = Reading::Vector([1.0000001_f32, 2.0_f32]);
+        let max_abs_diff = ReadingUlpsTolerance::Vector([0.0_f32, 0.0_f32]);
+        let max_ulps = ReadingUlpsTolerance::Vector([1_u32, 0_u32]);
+
+        assert_ulps_eq!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+        assert_ulps_eq!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Reading::Scalar(1.0_f32);
+        let rhs = Reading::Vector([1.0_f32, 1.0_f32]);
+
+        assert_ulps_ne!(lhs, rhs, abs_diff_all <= 1.0_f32, ulps_all <= 4_u32);
+        assert_ulps_ne!(rhs, lhs, abs_diff_all <= 1.0_f32, ulps_all <= 4_u32);
+    }
+
+    #[test]
+    fn test_debug_ulps_diff() {
+        let lhs = Reading::Scalar(1.0_f32);
+        let rhs = Reading::Scalar(1.0000001_f32);
+
+        assert_eq!(lhs.debug_ulps_diff(&rhs), Ok(ReadingDebugUlpsTolerance::Scalar(Some(1_u32))));
+        assert_eq!(
+            lhs.debug_ulps_diff(&Reading::Vector([1.0_f32, 1.0_f32])),
+            Err(VariantMismatch {
+                left: "Scalar",
+                right: "Vector",
+                tolerance: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_debug_ulps_tolerance() {
+        let lhs = Reading::Scalar(1.0_f32);
+        let rhs = Reading::Scalar(1.0000001_f32);
+        let max_ulps = ReadingUlpsTolerance::Vector([1_u32, 1_u32]);
+
+        assert_eq!(
+            lhs.debug_ulps_tolerance(&rhs, &max_ulps),
+            Err(VariantMismatch {
+                left: "Scalar",
+                right: "Scalar",
+                tolerance: Some("Vector"),
+            })
+        );
+    }
+}
@@ -0,0 +1,277 @@
+#[cfg(test)]
+mod relative_eq_struct_tests {
+    use approx_cmp::{
+        AssertRelativeAllEq,
+        AssertRelativeEq,
+        RelativeAllEq,
+        RelativeEq,
+        assert_relative_eq,
+        assert_relative_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, RelativeEq, RelativeAllEq, AssertRelativeEq, AssertRelativeAllEq)]
+    struct Vector2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2RelativeTolerance { x: 0.0_f32, y: 0.0_f32 };
+        let max_relative = Vector2RelativeTolerance {
+            x: 10.0_f32 * f32::EPSILON,
+            y: 10.0_f32 * f32::EPSILON,
+        };
+
+        assert_relative_eq!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+        assert_relative_eq!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 { x: 1.0_f32, y: 4.0_f32 };
+        let max_abs_diff = Vector2RelativeTolerance { x: 0.0_f32, y: 0.0_f32 };
+        let max_relative = Vector2RelativeTolerance {
+            x: 10.0_f32 * f32::EPSILON,
+            y: 10.0_f32 * f32::EPSILON,
+        };
+
+        assert_relative_ne!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+        assert_relative_ne!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    }
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = 0.0_f32;
+        let max_relative = 10.0_f32 * f32::EPSILON;
+
+        assert_relative_eq!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+        assert_relative_eq!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    }
+
+    #[test]
+    fn test_all_ne() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 { x: 1.0_f32, y: 4.0_f32 };
+        let max_abs_diff = 0.0_f32;
+        let max_relative = 10.0_f32 * f32::EPSILON;
+
+        assert_relative_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+        assert_relative_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let abs_diff = Vector2DebugRelativeTolerance {
+            x: 0.0000010728836_f32,
+            y: 0.0000021457672_f32,
+        };
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_relative_diff() {
+        let lhs = Vector2 { x: 4.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 { x: 5.0_f32, y: 1.0_f32 };
+        let relative_diff = Vector2DebugRelativeTolerance { x: 0.2_f32, y: 0.5_f32 };
+
+        assert_eq!(lhs.debug_relative_diff(&rhs), relative_diff);
+        assert_eq!(rhs.debug_relative_diff(&lhs), relative_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2RelativeTolerance { x: 0.2_f32, y: 0.4_f32 };
+        let tolerance = Vector2DebugRelativeTolerance { x: 0.2_f32, y: 0.4_f32 };
+
+        assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_relative_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_relative = Vector2RelativeTolerance { x: 0.2_f32, y: 0.2_f32 };
+        let tolerance = Vector2DebugRelativeTolerance {
+            x: 0.20000021_f32,
+            y: 0.40000042_f32,
+        };
+
+        assert_eq!(lhs.debug_relative_tolerance(&rhs, &max_relative), tolerance);
+        assert_eq!(rhs.debug_relative_tolerance(&lhs, &max_relative), tolerance);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = 0.2_f32;
+        let tolerance = Vector2DebugRelativeAllTolerance { x: 0.2_f32, y: 0.2_f32 };
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_relative_all_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_relative = 0.2_f32;
+        let tolerance = Vector2DebugRelativeAllTolerance {
+            x: 0.20000021_f32,
+            y: 0.40000042_f32,
+        };
+
+        assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &max_relative), tolerance);
+        assert_eq!(rhs.debug_relative_all_tolerance(&lhs, &max_relative), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod relative_eq_tuple_struct_tests {
+    use approx_cmp::{
+        AssertRelativeAllEq,
+        AssertRelativeEq,
+        RelativeAllEq,
+        RelativeEq,
+        assert_relative_eq,
+        assert_relative_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, RelativeEq, RelativeAllEq, AssertRelativeEq, AssertRelativeAllEq)]
+    struct Segment([f64; 2], [f64; 2]);
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000001_f64]);
+        let max_abs_diff = 0.0_f64;
+        let max_relative = f64::EPSILON;
+
+        assert_relative_eq!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+        assert_relative_eq!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    }
+
+    #[test]
+    fn test_all_ne() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]);
+        let max_abs_diff = 0.0_f64;
+        let max_relative = f64::EPSILON;
+
+        assert_relative_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+        assert_relative_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]);
+        let abs_diff = SegmentDebugRelativeTolerance([0.0_f64, 0.0_f64], [0.0_f64, 0.5_f64]);
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_relative_all_tolerance() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]);
+        let max_relative = 0.5_f64;
+        let tolerance = SegmentDebugRelativeAllTolerance([0.5_f64, 1.0_f64], [1.5_f64, 2.25_f64]);
+
+        assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &max_relative), tolerance);
+        assert_eq!(rhs.debug_relative_all_tolerance(&lhs, &max_relative), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod relative_eq_generic_struct_tests {
+    use approx_cmp::{
+        AssertRelativeAllEq,
+        AssertRelativeEq,
+        RelativeAllEq,
+        RelativeEq,
+        assert_relative_eq,
+        assert_relative_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, RelativeEq, RelativeAllEq, AssertRelativeEq, AssertRelativeAllEq)]
+    struct Particle<T> {
+        position: [T; 3],
+        mass: T,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Particle {
+            position: [1.0_f32, 2.0_f32, 3.0_f32],
+            mass: 4.0_f32,
+        };
+        let rhs = Particle {
+            position: [1.0_f32, 2.0_f32, 3.0_f32],
+            mass: 4.0000005_f32,
+        };
+        let max_abs_diff = ParticleRelativeTolerance {
+            position: [0.0_f32, 0.0_f32, 0.0_f32],
+            mass: 0.0_f32,
+        };
+        let max_relative = ParticleRelativeTolerance {
+            position: [0.0_f32, 0.0_f32, 0.0_f32],
+            mass: f32::EPSILON,
+        };
+
+        assert_relative_eq!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+        assert_relative_eq!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Particle {
+            position: [1.0_f64, 2.0_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let rhs = Particle {
+            position: [1.0_f64, 2.5_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let max_abs_diff = 0.0_f64;
+        let max_relative = f64::EPSILON;
+
+        assert_relative_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+        assert_relative_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    }
+}
@@ -0,0 +1,263 @@
+#[cfg(test)]
+mod ulps_eq_struct_tests {
+    use approx_cmp::{
+        AssertUlpsAllEq,
+        AssertUlpsEq,
+        UlpsAllEq,
+        UlpsEq,
+        assert_ulps_eq,
+        assert_ulps_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, UlpsEq, UlpsAllEq, AssertUlpsEq, AssertUlpsAllEq)]
+    struct Vector2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2UlpsTolerance { x: 0.0_f32, y: 0.0_f32 };
+        let max_ulps = Vector2UlpsTolerance { x: 9_u32, y: 9_u32 };
+
+        assert_ulps_eq!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+        assert_ulps_eq!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2UlpsTolerance { x: 0.0_f32, y: 0.0_f32 };
+        let max_ulps = Vector2UlpsTolerance { x: 9_u32, y: 8_u32 };
+
+        assert_ulps_ne!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+        assert_ulps_ne!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    }
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = 0.0_f32;
+        let max_ulps = 9_u32;
+
+        assert_ulps_eq!(lhs, rhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+        assert_ulps_eq!(rhs, lhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+    }
+
+    #[test]
+    fn test_all_ne() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 { x: 1.0_f32, y: -2.0_f32 };
+        let max_abs_diff = 0.0_f32;
+        let max_ulps = 9_u32;
+
+        assert_ulps_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+        assert_ulps_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+    }
+
+    #[test]
+    fn test_debug_abs_diff() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let abs_diff = Vector2DebugUlpsTolerance {
+            x: 0.0000010728836_f32,
+            y: 0.0000021457672_f32,
+        };
+
+        assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+        assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    }
+
+    #[test]
+    fn test_debug_ulps_diff() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: -2.0_f32,
+        };
+        let ulps_diff = Vector2DebugUlpsTolerance {
+            x: Some(9_u32),
+            y: Some(2 * 2.0_f32.to_bits()),
+        };
+
+        assert_eq!(lhs.debug_ulps_diff(&rhs), ulps_diff);
+        assert_eq!(rhs.debug_ulps_diff(&lhs), ulps_diff);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = Vector2UlpsTolerance { x: 0.2_f32, y: 0.4_f32 };
+        let tolerance = Vector2DebugUlpsTolerance { x: 0.2_f32, y: 0.4_f32 };
+
+        assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_ulps_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_ulps = Vector2UlpsTolerance { x: 4_u32, y: 8_u32 };
+        let tolerance = Vector2DebugUlpsTolerance { x: 4_u32, y: 8_u32 };
+
+        assert_eq!(lhs.debug_ulps_tolerance(&rhs, &max_ulps), tolerance);
+        assert_eq!(rhs.debug_ulps_tolerance(&lhs, &max_ulps), tolerance);
+    }
+
+    #[test]
+    fn test_debug_abs_diff_all_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_abs_diff = 0.2_f32;
+        let tolerance = Vector2DebugUlpsAllTolerance { x: 0.2_f32, y: 0.2_f32 };
+
+        assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &max_abs_diff), tolerance);
+        assert_eq!(rhs.debug_abs_diff_all_tolerance(&lhs, &max_abs_diff), tolerance);
+    }
+
+    #[test]
+    fn test_debug_ulps_all_tolerance() {
+        let lhs = Vector2 { x: 1.0_f32, y: 2.0_f32 };
+        let rhs = Vector2 {
+            x: 1.0000011_f32,
+            y: 2.0000022_f32,
+        };
+        let max_ulps = 4_u32;
+        let tolerance = Vector2DebugUlpsAllTolerance { x: 4_u32, y: 4_u32 };
+
+        assert_eq!(lhs.debug_ulps_all_tolerance(&rhs, &max_ulps), tolerance);
+        assert_eq!(rhs.debug_ulps_all_tolerance(&lhs, &max_ulps), tolerance);
+    }
+}
+
+#[cfg(test)]
+mod ulps_eq_tuple_struct_tests {
+    use approx_cmp::{
+        AssertUlpsAllEq,
+        AssertUlpsEq,
+        UlpsAllEq,
+        UlpsEq,
+        assert_ulps_eq,
+        assert_ulps_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, UlpsEq, UlpsAllEq, AssertUlpsEq, AssertUlpsAllEq)]
+    struct Segment([f64; 2], [f64; 2]);
+
+    #[test]
+    fn test_all_eq() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000001_f64]);
+        let max_abs_diff = 0.0_f64;
+        let max_ulps = 1_u64;
+
+        assert_ulps_eq!(lhs, rhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+        assert_ulps_eq!(rhs, lhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+    }
+
+    #[test]
+    fn test_all_ne() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000002_f64]);
+        let max_abs_diff = 0.0_f64;
+        let max_ulps = 1_u64;
+
+        assert_ulps_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+        assert_ulps_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+    }
+
+    #[test]
+    fn test_debug_ulps_diff() {
+        let lhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]);
+        let rhs = Segment([1.0_f64, 2.0_f64], [3.0_f64, 4.000000000000002_f64]);
+        let ulps_diff = SegmentDebugUlpsTolerance([Some(0_u64), Some(0_u64)], [Some(0_u64), Some(2_u64)]);
+
+        assert_eq!(lhs.debug_ulps_diff(&rhs), ulps_diff);
+        assert_eq!(rhs.debug_ulps_diff(&lhs), ulps_diff);
+    }
+}
+
+#[cfg(test)]
+mod ulps_eq_generic_struct_tests {
+    use approx_cmp::{
+        AssertUlpsAllEq,
+        AssertUlpsEq,
+        UlpsAllEq,
+        UlpsEq,
+        assert_ulps_eq,
+        assert_ulps_ne,
+    };
+
+    #[derive(Copy, Clone, Debug, PartialEq, UlpsEq, UlpsAllEq, AssertUlpsEq, AssertUlpsAllEq)]
+    struct Particle<T> {
+        position: [T; 3],
+        mass: T,
+    }
+
+    #[test]
+    fn test_eq() {
+        let lhs = Particle {
+            position: [1.0_f32, 2.0_f32, 3.0_f32],
+            mass: 4.0_f32,
+        };
+        let rhs = Particle {
+            position: [1.0_f32, 2.0_f32, 3.0_f32],
+            mass: 4.0000005_f32,
+        };
+        let max_abs_diff = ParticleUlpsTolerance {
+            position: [0.0_f32, 0.0_f32, 0.0_f32],
+            mass: 0.0_f32,
+        };
+        let max_ulps = ParticleUlpsTolerance {
+            position: [0_u32, 0_u32, 0_u32],
+            mass: 1_u32,
+        };
+
+        assert_ulps_eq!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+        assert_ulps_eq!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    }
+
+    #[test]
+    fn test_ne() {
+        let lhs = Particle {
+            position: [1.0_f64, 2.0_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let rhs = Particle {
+            position: [1.0_f64, 2.5_f64, 3.0_f64],
+            mass: 4.0_f64,
+        };
+        let max_abs_diff = 0.0_f64;
+        let max_ulps = 4_u64;
+
+        assert_ulps_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+        assert_ulps_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+    }
+}
@@ -0,0 +1,275 @@
+use core::fmt;
+use core::ops;
+
+/// The unsigned integer type of the bit patterns of a [`Float`].
+pub trait FloatBits: Copy + Ord + fmt::Debug {
+    /// The number of bits of the bit patterns.
+    const BITS: u32;
+
+    /// Widen a bit pattern to a `u128`.
+    fn into_u128(self) -> u128;
+
+    /// Narrow a `u128` to a bit pattern, keeping its `Self::BITS` lowest bits.
+    fn from_u128(value: u128) -> Self;
+}
+
+macro_rules! impl_float_bits {
+    ($($U:ident),* $(,)?) => {$(
+        impl FloatBits for $U {
+            const BITS: u32 = $U::BITS;
+
+            #[inline]
+            fn into_u128(self) -> u128 {
+                self as u128
+            }
+
+            #[inline]
+            fn from_u128(value: u128) -> Self {
+                value as $U
+            }
+        }
+    )*};
+}
+
+impl_float_bits!(u8, u16, u32, u64, u128);
+
+/// A sign and magnitude encoded floating point number.
+///
+/// The comparison algorithms of the comparison crates are written against this
+/// trait, so a number type implementing it compares with the same semantics as
+/// [`f32`] and [`f64`]. The bit patterns of the implementing type must encode the
+/// sign of a number in their highest bit and its magnitude in the remaining
+/// bits, such that magnitudes increase with their bit patterns, as IEEE 754
+/// binary floating point numbers do. Its comparison operators must compare
+/// numbers by value, so that NaN is unequal to every number, including itself,
+/// and both zeros are equal.
+///
+/// # Example
+///
+/// A brain floating point number holds the highest sixteen bits of an [`f32`].
+///
+/// ```
+/// # use approx_cmp_float::Float;
+/// # use core::ops;
+/// #
+/// #[derive(Copy, Clone, Debug)]
+/// struct Bf16(u16);
+///
+/// impl Bf16 {
+///     fn from_f32(value: f32) -> Self {
+///         Self((value.to_bits() >> 16) as u16)
+///     }
+///
+///     fn to_f32(self) -> f32 {
+///         f32::from_bits((self.0 as u32) << 16)
+///     }
+/// }
+///
+/// impl ops::Sub for Bf16 {
+///     type Output = Self;
+///
+///     fn sub(self, other: Self) -> Self {
+///         Self::from_f32(self.to_f32() - other.to_f32())
+///     }
+/// }
+///
+/// // The comparison operators, and the `Add`, `Mul`, and `Div` implementations,
+/// // work on the `f32` values the same way.
+/// # impl PartialEq for Bf16 {
+/// #     fn eq(&self, other: &Self) -> bool {
+/// #         self.to_f32() == other.to_f32()
+/// #     }
+/// # }
+/// #
+/// # impl PartialOrd for Bf16 {
+/// #     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+/// #         self.to_f32().partial_cmp(&other.to_f32())
+/// #     }
+/// # }
+/// #
+/// # impl ops::Add for Bf16 {
+/// #     type Output = Self;
+/// #
+/// #     fn add(self, other: Self) -> Self {
+/// #         Self::from_f32(self.to_f32() + other.to_f32())
+/// #     }
+/// # }
+/// #
+/// # impl ops::Mul for Bf16 {
+/// #     type Output = Self;
+/// #
+/// #     fn mul(self, other: Self) -> Self {
+/// #         Self::from_f32(self.to_f32() * other.to_f32())
+/// #     }
+/// # }
+/// #
+/// # impl ops::Div for Bf16 {
+/// #     type Output = Self;
+/// #
+/// #     fn div(self, other: Self) -> Self {
+/// #         Self::from_f32(self.to_f32() / other.to_f32())
+/// #     }
+/// # }
+///
+/// impl Float for Bf16 {
+///     type Bits = u16;
+///
+///     const ZERO: Self = Self(0x0000);
+///     const ONE: Self = Self(0x3F80);
+///     const INFINITY: Self = Self(0x7F80);
+///     const MAX: Self = Self(0x7F7F);
+///     const MIN_POSITIVE: Self = Self(0x0080);
+///
+///     fn to_bits(self) -> Self::Bits {
+///         self.0
+///     }
+///
+///     fn from_bits(bits: Self::Bits) -> Self {
+///         Self(bits)
+///     }
+///
+///     fn abs(self) -> Self {
+///         Self(self.0 & 0x7FFF)
+///     }
+///
+///     fn signum(self) -> Self {
+///         Self::from_f32(self.to_f32().signum())
+///     }
+///
+///     fn is_nan(self) -> bool {
+///         self.to_f32().is_nan()
+///     }
+///
+///     fn is_infinite(self) -> bool {
+///         self.to_f32().is_infinite()
+///     }
+///
+///     fn max(self, other: Self) -> Self {
+///         Self::from_f32(f32::max(self.to_f32(), other.to_f32()))
+///     }
+/// }
+///
+/// let one = Bf16::from_f32(1.0_f32);
+///
+/// assert_eq!(Float::abs(Bf16::from_f32(-1.0_f32)), one);
+/// assert!(Float::is_infinite(Bf16::INFINITY));
+/// ```
+pub trait Float:
+    Copy + PartialOrd + fmt::Debug + ops::Add<Output = Self> + ops::Sub<Output = Self> + ops::Mul<Output = Self> + ops::Div<Output = Self>
+{
+    /// The unsigned integer type of the bit patterns of the numbers.
+    type Bits: FloatBits;
+
+    /// Positive zero.
+    const ZERO: Self;
+
+    /// One.
+    const ONE: Self;
+
+    /// Positive infinity.
+    const INFINITY: Self;
+
+    /// The largest finite number.
+    const MAX: Self;
+
+    /// The smallest positive normal number. The nonzero numbers of smaller
+    /// magnitude are the subnormal numbers.
+    const MIN_POSITIVE: Self;
+
+    /// The bit pattern of the number.
+    fn to_bits(self) -> Self::Bits;
+
+    /// The number with the bit pattern `bits`.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// The absolute value of the number.
+    fn abs(self) -> Self;
+
+    /// The sign of the number, as a number.
+    fn signum(self) -> Self;
+
+    /// Determine whether the number is NaN.
+    fn is_nan(self) -> bool;
+
+    /// Determine whether the number is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+
+    /// The larger of the number and `other`.
+    fn max(self, other: Self) -> Self;
+
+    /// Determine whether the number is subnormal, i.e. nonzero and of smaller
+    /// magnitude than [`Float::MIN_POSITIVE`].
+    #[inline]
+    fn is_subnormal(self) -> bool {
+        self != Self::ZERO && self.abs() < Self::MIN_POSITIVE
+    }
+
+    /// The number with a subnormal number replaced by the zero of the same sign,
+    /// as arithmetic with denormals flushed to zero produces it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp_float::Float;
+    /// #
+    /// assert_eq!(Float::flush_to_zero(1e-40_f32).to_bits(), 0.0_f32.to_bits());
+    /// assert_eq!(Float::flush_to_zero(-1e-40_f32).to_bits(), (-0.0_f32).to_bits());
+    /// assert_eq!(Float::flush_to_zero(f32::MIN_POSITIVE), f32::MIN_POSITIVE);
+    /// ```
+    #[inline]
+    fn flush_to_zero(self) -> Self {
+        if self.is_subnormal() { self.signum() * Self::ZERO } else { self }
+    }
+}
+
+macro_rules! impl_float {
+    ($T:ident, $U:ident) => {
+        impl Float for $T {
+            type Bits = $U;
+
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const INFINITY: Self = $T::INFINITY;
+            const MAX: Self = $T::MAX;
+            const MIN_POSITIVE: Self = $T::MIN_POSITIVE;
+
+            #[inline]
+            fn to_bits(self) -> Self::Bits {
+                $T::to_bits(self)
+            }
+
+            #[inline]
+            fn from_bits(bits: Self::Bits) -> Self {
+                $T::from_bits(bits)
+            }
+
+            #[inline]
+            fn abs(self) -> Self {
+                $T::abs(self)
+            }
+
+            #[inline]
+            fn signum(self) -> Self {
+                $T::signum(self)
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                $T::is_nan(self)
+            }
+
+            #[inline]
+            fn is_infinite(self) -> bool {
+                $T::is_infinite(self)
+            }
+
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                $T::max(self, other)
+            }
+        }
+    };
+}
+
+impl_float!(f32, u32);
+impl_float!(f64, u64);
@@ -0,0 +1,15 @@
+//! The floating point number abstraction shared by the approximate comparison
+//! crates of **approx_cmp**.
+//!
+//! The comparison algorithms of each comparison crate are written once against
+//! the [`Float`] trait, and the implementations of the comparison traits for
+//! [`f32`] and [`f64`] delegate to them. A number type implementing [`Float`],
+//! such as a half precision floating point number, reuses the same algorithms,
+//! and therefore compares with the same semantics as the primitive floating
+//! point numbers.
+#![deny(unsafe_op_in_unsafe_fn)]
+#![no_std]
+
+mod float;
+
+pub use float::*;
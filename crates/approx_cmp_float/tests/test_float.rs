@@ -0,0 +1,84 @@
+use approx_cmp_float::{
+    Float,
+    FloatBits,
+};
+
+const SPECIAL_F32: [f32; 10] = [
+    0.0_f32,
+    -0.0_f32,
+    f32::from_bits(1),
+    -f32::MIN_POSITIVE,
+    1.0_f32,
+    -2.5_f32,
+    f32::MAX,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+const SPECIAL_F64: [f64; 10] = [
+    0.0_f64,
+    -0.0_f64,
+    f64::from_bits(1),
+    -f64::MIN_POSITIVE,
+    1.0_f64,
+    -2.5_f64,
+    f64::MAX,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+#[test]
+fn test_float_bits_round_trip() {
+    assert_eq!(<u8 as FloatBits>::BITS, 8);
+    assert_eq!(<u16 as FloatBits>::BITS, 16);
+    assert_eq!(<u128 as FloatBits>::BITS, 128);
+    assert_eq!(u16::from_u128(0xABCD_u16.into_u128()), 0xABCD_u16);
+    assert_eq!(u64::from_u128(u64::MAX.into_u128()), u64::MAX);
+    assert_eq!(u8::from_u128(0x1FF_u128), 0xFF_u8);
+}
+
+#[test]
+fn test_float_constants_f32() {
+    assert_eq!(<f32 as Float>::ZERO.to_bits(), 0_u32);
+    assert_eq!(<f32 as Float>::INFINITY, f32::INFINITY);
+    assert_eq!(<f32 as Float>::MAX, f32::MAX);
+}
+
+#[test]
+fn test_float_constants_f64() {
+    assert_eq!(<f64 as Float>::ZERO.to_bits(), 0_u64);
+    assert_eq!(<f64 as Float>::INFINITY, f64::INFINITY);
+    assert_eq!(<f64 as Float>::MAX, f64::MAX);
+}
+
+#[test]
+fn test_float_matches_inherent_methods_f32() {
+    for value in SPECIAL_F32 {
+        assert_eq!(Float::to_bits(value), f32::to_bits(value));
+        assert_eq!(<f32 as Float>::from_bits(value.to_bits()).to_bits(), value.to_bits());
+        assert_eq!(Float::abs(value).to_bits(), f32::abs(value).to_bits());
+        assert_eq!(Float::signum(value).to_bits(), f32::signum(value).to_bits());
+        assert_eq!(Float::is_nan(value), f32::is_nan(value));
+        assert_eq!(Float::is_infinite(value), f32::is_infinite(value));
+        for other in SPECIAL_F32 {
+            assert_eq!(Float::max(value, other).to_bits(), f32::max(value, other).to_bits());
+        }
+    }
+}
+
+#[test]
+fn test_float_matches_inherent_methods_f64() {
+    for value in SPECIAL_F64 {
+        assert_eq!(Float::to_bits(value), f64::to_bits(value));
+        assert_eq!(<f64 as Float>::from_bits(value.to_bits()).to_bits(), value.to_bits());
+        assert_eq!(Float::abs(value).to_bits(), f64::abs(value).to_bits());
+        assert_eq!(Float::signum(value).to_bits(), f64::signum(value).to_bits());
+        assert_eq!(Float::is_nan(value), f64::is_nan(value));
+        assert_eq!(Float::is_infinite(value), f64::is_infinite(value));
+        for other in SPECIAL_F64 {
+            assert_eq!(Float::max(value, other).to_bits(), f64::max(value, other).to_bits());
+        }
+    }
+}
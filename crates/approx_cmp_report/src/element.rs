@@ -0,0 +1,190 @@
+use crate::path::Path;
+use crate::stats::ElementErrors;
+
+use core::fmt;
+use core::ops;
+
+/// A labeled debugging value of an element, e.g. the absolute difference
+/// `("abs_diff", &0.25_f64)`.
+pub type Entry<'e> = (&'static str, &'e dyn fmt::Debug);
+
+/// An element of a pair of compared values, as reported to a [`Visitor`].
+///
+/// An element is the smallest unit a comparison is broken into, e.g. a pair of
+/// floating point numbers, or a pair of values whose types do not report their
+/// elements individually. The differences and tolerances of an element are the
+/// debugging values the comparison computed for it, labeled by the name of the
+/// comparison that computed them.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug)]
+pub struct Element<'e> {
+    /// Whether the element passed the comparison.
+    pub passed: bool,
+    /// The left hand side of the element, if it can be displayed.
+    pub left: Option<&'e dyn fmt::Debug>,
+    /// The right hand side of the element, if it can be displayed.
+    pub right: Option<&'e dyn fmt::Debug>,
+    /// The differences between the left hand side and the right hand side.
+    pub diffs: &'e [Entry<'e>],
+    /// The tolerances the differences are compared against.
+    pub tolerances: &'e [Entry<'e>],
+    /// The numerical errors between the left hand side and the right hand side,
+    /// if the element is a pair of numbers.
+    pub errors: Option<ElementErrors>,
+}
+
+impl<'e> Element<'e> {
+    /// Construct an element from the outcome of its comparison and its
+    /// debugging values.
+    #[inline]
+    pub const fn new(passed: bool, diffs: &'e [Entry<'e>], tolerances: &'e [Entry<'e>]) -> Self {
+        Self {
+            passed,
+            left: None,
+            right: None,
+            diffs,
+            tolerances,
+            errors: None,
+        }
+    }
+
+    /// Attach the compared values to an element.
+    #[inline]
+    pub const fn with_values(self, left: &'e dyn fmt::Debug, right: &'e dyn fmt::Debug) -> Self {
+        Self {
+            left: Some(left),
+            right: Some(right),
+            ..self
+        }
+    }
+
+    /// Attach the numerical errors between the compared values to an element.
+    #[inline]
+    pub const fn with_errors(self, errors: ElementErrors) -> Self {
+        Self {
+            errors: Some(errors),
+            ..self
+        }
+    }
+}
+
+/// Receives the elements of a pair of compared values, one at a time.
+///
+/// The `visit_*` methods of the debugging traits report each element to a
+/// visitor, in the same order the comparison traits compare them. Returning
+/// [`ControlFlow::Break`](ops::ControlFlow::Break) stops the traversal.
+///
+/// Every closure taking a path and an element implements this trait.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::{
+/// #     Element,
+/// #     Path,
+/// #     Visitor,
+/// # };
+/// # use core::ops::ControlFlow;
+/// #
+/// let mut failed = 0;
+/// let mut visitor = |_path: &Path<'_>, element: &Element<'_>| {
+///     if !element.passed {
+///         failed += 1;
+///     }
+///
+///     ControlFlow::Continue(())
+/// };
+///
+/// let path = Path::new();
+/// let _ = visitor.visit(&path, &Element::new(false, &[], &[]));
+/// let _ = visitor.visit(&path, &Element::new(true, &[], &[]));
+///
+/// assert_eq!(failed, 1);
+/// ```
+pub trait Visitor<'a> {
+    /// Receive the element at `path`.
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()>;
+}
+
+impl<'a, F> Visitor<'a> for F
+where
+    F: FnMut(&Path<'a>, &Element<'_>) -> ops::ControlFlow<()>,
+{
+    #[inline]
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()> {
+        self(path, element)
+    }
+}
+
+/// A comparison between values of a sum type that do not share a variant, e.g.
+/// an `Option` and a `Result`, or an enum with derived comparison traits.
+///
+/// Values of a sum type are compared variant by variant, so two values of different
+/// variants, or values compared with a tolerance for a different variant, are
+/// never approximately equal. The derived debugging traits report this value in
+/// place of the per field debugging values in that case, so that failed
+/// assertions distinguish a variant mismatch from a failed field comparison.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VariantMismatch {
+    /// The variant of the left hand side of the comparison.
+    pub left: &'static str,
+    /// The variant of the right hand side of the comparison.
+    pub right: &'static str,
+    /// The variant of the tolerance of the comparison, if the comparison uses per
+    /// field tolerances.
+    pub tolerance: Option<&'static str>,
+}
+
+impl VariantMismatch {
+    /// The name of the variant of an optional value.
+    #[inline]
+    pub const fn option_name<T>(value: &Option<T>) -> &'static str {
+        match value {
+            Some(_) => "Some",
+            None => "None",
+        }
+    }
+
+    /// The name of the variant of a result.
+    #[inline]
+    pub const fn result_name<T, E>(value: &Result<T, E>) -> &'static str {
+        match value {
+            Ok(_) => "Ok",
+            Err(_) => "Err",
+        }
+    }
+}
+
+/// A difference in structure between two compared values that prevents their
+/// elements from being compared.
+///
+/// Visitors receive a failing element with a difference labeled `"structure"`
+/// holding this value in place of the elements that could not be compared.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StructureMismatch {
+    /// The compared values, or the compared values and their per element
+    /// tolerances, have different lengths.
+    Len {
+        /// The length of the left hand side.
+        left: usize,
+        /// The length of the right hand side.
+        right: usize,
+        /// The length of the tolerance, if the comparison uses per element
+        /// tolerances.
+        tolerance: Option<usize>,
+    },
+    /// A key of the left hand side is missing from the right hand side or from
+    /// the tolerance.
+    MissingKey,
+    /// The compared values, or the compared values and their tolerance, are
+    /// different variants of a sum type.
+    Variant(VariantMismatch),
+}
+
+impl StructureMismatch {
+    /// Report the mismatch to a visitor as a failing element at `path`.
+    #[inline]
+    pub fn report<'a>(self, path: &Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+        visitor.visit(path, &Element::new(false, &[("structure", &self)], &[]))
+    }
+}
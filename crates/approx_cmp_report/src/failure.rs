@@ -0,0 +1,123 @@
+use crate::element::{
+    Element,
+    Visitor,
+};
+use crate::path::Path;
+
+use core::fmt;
+use core::ops;
+
+/// The first failing element of a comparison, formatted for a panic message.
+///
+/// Displaying the value runs the traversal `visit` of the compared values, and
+/// writes the path of the first failing element followed by its values, its
+/// differences, and its tolerances, one per line. The traversal stops at the
+/// first failing element, so the cost of displaying the value does not depend
+/// on the number of elements following it.
+///
+/// The assertion macros of each comparison crate display this value at the top
+/// of the panic message of a failed equality assertion.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::{
+/// #     Element,
+/// #     FirstFailure,
+/// #     PathSegment,
+/// # };
+/// #
+/// let failure = FirstFailure::new(|path, visitor| {
+///     path.with_segment(PathSegment::Index(2), |path| {
+///         let element = Element::new(false, &[("abs_diff", &0.5_f64)], &[("abs_diff", &0.25_f64)]);
+///
+///         visitor.visit(path, &element.with_values(&1.0_f64, &1.5_f64))
+///     })
+/// });
+/// let expected = "\
+/// first failure at `[2]`:
+///         left: `1.0`,
+///        right: `1.5`,
+///     abs_diff: `0.5`,
+/// [abs_diff] t: `0.25`,";
+///
+/// assert_eq!(failure.to_string(), expected);
+/// ```
+#[derive(Copy, Clone)]
+pub struct FirstFailure<F> {
+    visit: F,
+}
+
+impl<F> FirstFailure<F> {
+    /// Construct the first failure of the comparison traversed by `visit`.
+    #[inline]
+    pub const fn new<'a>(visit: F) -> Self
+    where
+        F: Fn(&mut Path<'a>, &mut dyn Visitor<'a>) -> ops::ControlFlow<()>,
+    {
+        Self { visit }
+    }
+}
+
+impl<'a, F> fmt::Display for FirstFailure<F>
+where
+    F: Fn(&mut Path<'a>, &mut dyn Visitor<'a>) -> ops::ControlFlow<()>,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = FailureWriter { formatter, result: Ok(()) };
+        let flow = (self.visit)(&mut Path::new(), &mut writer);
+        writer.result?;
+
+        if flow.is_continue() {
+            formatter.write_str("first failure at `<unknown>`: no element reported a failure")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F> fmt::Debug for FirstFailure<F> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("FirstFailure").finish_non_exhaustive()
+    }
+}
+
+/// A visitor writing the first failing element it receives to a formatter.
+struct FailureWriter<'f, 'g> {
+    formatter: &'f mut fmt::Formatter<'g>,
+    result: fmt::Result,
+}
+
+impl FailureWriter<'_, '_> {
+    fn write(&mut self, path: &Path<'_>, element: &Element<'_>) -> fmt::Result {
+        write!(self.formatter, "first failure at `{}`:", path)?;
+        if let Some(left) = element.left {
+            write!(self.formatter, "\n{:>12}: `{:?}`,", "left", left)?;
+        }
+        if let Some(right) = element.right {
+            write!(self.formatter, "\n{:>12}: `{:?}`,", "right", right)?;
+        }
+        for (label, diff) in element.diffs {
+            write!(self.formatter, "\n{:>12}: `{:?}`,", label, diff)?;
+        }
+        for (label, tolerance) in element.tolerances {
+            // Right align the bracketed label the same way the assertion macros do.
+            let padding = usize::saturating_sub(10, label.len() + 2);
+            write!(self.formatter, "\n{:>padding$}[{}] t: `{:?}`,", "", label, tolerance)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Visitor<'a> for FailureWriter<'_, '_> {
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()> {
+        if element.passed {
+            return ops::ControlFlow::Continue(());
+        }
+
+        self.result = self.write(path, element);
+
+        ops::ControlFlow::Break(())
+    }
+}
@@ -0,0 +1,60 @@
+use core::fmt;
+
+/// The sides of a pair of compared numbers that a flush-to-zero comparison
+/// replaced with zero, because they were subnormal.
+///
+/// The flush-to-zero comparisons of the comparison crates report it for every
+/// element they flushed, labeled `flushed`, so that a failed assertion shows
+/// where the normalization was applied.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::Flushed;
+/// #
+/// assert_eq!(Flushed::new(true, false), Some(Flushed::Left));
+/// assert_eq!(Flushed::new(true, true).map(Flushed::count), Some(2));
+/// assert_eq!(Flushed::new(false, false), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Flushed {
+    /// The left hand side was flushed to zero.
+    Left,
+    /// The right hand side was flushed to zero.
+    Right,
+    /// Both sides were flushed to zero.
+    Both,
+}
+
+impl Flushed {
+    /// Construct the sides flushed to zero from whether each side was flushed, or
+    /// `None` when neither side was.
+    #[inline]
+    pub const fn new(left: bool, right: bool) -> Option<Self> {
+        match (left, right) {
+            (true, true) => Some(Self::Both),
+            (true, false) => Some(Self::Left),
+            (false, true) => Some(Self::Right),
+            (false, false) => None,
+        }
+    }
+
+    /// The number of sides flushed to zero.
+    #[inline]
+    pub const fn count(self) -> usize {
+        match self {
+            Self::Left | Self::Right => 1,
+            Self::Both => 2,
+        }
+    }
+}
+
+impl fmt::Debug for Flushed {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left => formatter.write_str("left"),
+            Self::Right => formatter.write_str("right"),
+            Self::Both => formatter.write_str("both"),
+        }
+    }
+}
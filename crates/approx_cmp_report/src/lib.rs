@@ -0,0 +1,31 @@
+//! Failure reporting types shared by the approximate comparison crates of
+//! **approx_cmp**.
+//!
+//! The debugging traits of each comparison crate visit the elements of the
+//! values they compare one at a time. Each element is reported to a [`Visitor`]
+//! along with its [`Path`] inside the compared values, so that a failed
+//! comparison can be traced to the elements that failed it. A failed check
+//! returns a [`Mismatch`] holding the compared values, their debugging values,
+//! and the path of the first failing element.
+#![deny(unsafe_op_in_unsafe_fn)]
+#![no_std]
+
+mod element;
+mod failure;
+mod flushed;
+mod mismatch;
+mod path;
+mod policy;
+mod search;
+mod stats;
+mod summary;
+
+pub use element::*;
+pub use failure::*;
+pub use flushed::*;
+pub use mismatch::*;
+pub use path::*;
+pub use policy::*;
+pub use search::*;
+pub use stats::*;
+pub use summary::*;
@@ -0,0 +1,96 @@
+use crate::element::{
+    Element,
+    Visitor,
+};
+use crate::path::Path;
+
+use core::fmt;
+use core::ops;
+
+/// The report of a failed comparison.
+///
+/// A mismatch holds the compared values, the debugging values computed for them
+/// by the debugging trait of the comparison, and the path of the first element
+/// that failed the comparison. The path of a failed inequality comparison is the
+/// path of the compared values themselves, since no single element fails it.
+///
+/// The layout of the debugging values depends on the comparison. For instance,
+/// an absolute difference comparison reports a single difference and a single
+/// tolerance, while a units in last place comparison reports a tuple of the
+/// absolute difference and the ulps difference, and a tuple of their tolerances.
+#[derive(Debug)]
+pub struct Mismatch<'a, A, B, D, T>
+where
+    A: ?Sized,
+    B: ?Sized,
+{
+    /// The left hand side of the comparison.
+    pub left: &'a A,
+    /// The right hand side of the comparison.
+    pub right: &'a B,
+    /// The differences between the left hand side and the right hand side.
+    pub diffs: D,
+    /// The tolerances of the comparison.
+    pub tolerances: T,
+    /// The path of the first element that failed the comparison.
+    pub path: Path<'a>,
+}
+
+impl<A, B, D, T> fmt::Display for Mismatch<'_, A, B, D, T>
+where
+    A: fmt::Debug + ?Sized,
+    B: fmt::Debug + ?Sized,
+    D: fmt::Debug,
+    T: fmt::Debug,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            r#"comparison failed at `{}`
+        left: `{:?}`,
+       right: `{:?}`,
+       diffs: `{:?}`,
+  tolerances: `{:?}`"#,
+            self.path, self.left, self.right, self.diffs, self.tolerances,
+        )
+    }
+}
+
+/// A visitor recording the path of the first failing element of a comparison.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct FirstMismatch<'a> {
+    path: Option<Path<'a>>,
+}
+
+impl<'a> FirstMismatch<'a> {
+    /// Construct a visitor that has not seen a failing element.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { path: None }
+    }
+
+    /// The path of the first failing element, if any element failed.
+    #[inline]
+    pub const fn path(&self) -> Option<&Path<'a>> {
+        self.path.as_ref()
+    }
+
+    /// Consume the visitor, returning the path of the first failing element.
+    #[inline]
+    pub const fn into_path(self) -> Option<Path<'a>> {
+        self.path
+    }
+}
+
+impl<'a> Visitor<'a> for FirstMismatch<'a> {
+    #[inline]
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()> {
+        if element.passed {
+            ops::ControlFlow::Continue(())
+        } else {
+            self.path = Some(*path);
+
+            ops::ControlFlow::Break(())
+        }
+    }
+}
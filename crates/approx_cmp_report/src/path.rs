@@ -0,0 +1,161 @@
+use core::fmt;
+
+/// A step from a compared value to one of its elements.
+#[derive(Copy, Clone, Debug)]
+pub enum PathSegment<'a> {
+    /// The element at an index of a sequence, e.g. an array, a slice, or a `Vec`.
+    Index(usize),
+    /// The value stored under a key of a map, e.g. a `HashMap` or a `BTreeMap`.
+    Key(&'a dyn fmt::Debug),
+    /// A field of a struct or a tuple.
+    Field(&'static str),
+}
+
+impl fmt::Display for PathSegment<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Index(index) => write!(formatter, "[{}]", index),
+            PathSegment::Key(key) => write!(formatter, "[{:?}]", key),
+            PathSegment::Field(field) => write!(formatter, ".{}", field),
+        }
+    }
+}
+
+/// The location of an element inside a pair of compared values, e.g. `[1532][2]`,
+/// `["key"].0`, or `.position.x`.
+///
+/// A path holds at most [`Path::CAPACITY`] segments. The segments of elements
+/// nested more deeply than that are dropped, and the path is displayed with a
+/// trailing `...` to show it has been truncated.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::{
+/// #     Path,
+/// #     PathSegment,
+/// # };
+/// #
+/// let key = "velocity";
+/// let mut path = Path::new();
+/// path.push(PathSegment::Key(&key));
+/// path.push(PathSegment::Index(2));
+///
+/// assert_eq!(format!("{}", path), r#"["velocity"][2]"#);
+///
+/// path.pop();
+/// path.push(PathSegment::Field("x"));
+///
+/// assert_eq!(format!("{}", path), r#"["velocity"].x"#);
+/// ```
+#[derive(Copy, Clone)]
+pub struct Path<'a> {
+    segments: [PathSegment<'a>; Path::CAPACITY],
+    depth: usize,
+}
+
+impl<'a> Path<'a> {
+    /// The maximum number of segments held by a path.
+    pub const CAPACITY: usize = 32;
+
+    /// Construct the path of the compared values themselves.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            segments: [PathSegment::Index(0); Path::CAPACITY],
+            depth: 0,
+        }
+    }
+
+    /// Descend into an element of the current location.
+    #[inline]
+    pub fn push(&mut self, segment: PathSegment<'a>) {
+        if self.depth < Self::CAPACITY {
+            self.segments[self.depth] = segment;
+        }
+
+        self.depth += 1;
+    }
+
+    /// Return to the location containing the current location.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the path is the path of the compared values themselves.
+    #[inline]
+    pub fn pop(&mut self) {
+        assert!(self.depth > 0, "cannot pop the root of a path");
+
+        self.depth -= 1;
+    }
+
+    /// Descend into an element of the current location while calling `f`, and
+    /// return to the current location afterwards.
+    #[inline]
+    pub fn with_segment<R, F>(&mut self, segment: PathSegment<'a>, f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        self.push(segment);
+        let result = f(self);
+        self.pop();
+
+        result
+    }
+
+    /// The segments of the path, from the outermost to the innermost.
+    #[inline]
+    pub fn segments(&self) -> &[PathSegment<'a>] {
+        &self.segments[..usize::min(self.depth, Self::CAPACITY)]
+    }
+
+    /// The number of segments of the path, including the segments dropped from
+    /// a truncated path.
+    #[inline]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Determine whether the path is the path of the compared values themselves.
+    #[inline]
+    pub const fn is_root(&self) -> bool {
+        self.depth == 0
+    }
+
+    /// Determine whether segments have been dropped from the path.
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.depth > Self::CAPACITY
+    }
+}
+
+impl Default for Path<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for Path<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_root() {
+            return formatter.write_str("<root>");
+        }
+
+        for segment in self.segments() {
+            fmt::Display::fmt(segment, formatter)?;
+        }
+
+        if self.is_truncated() {
+            formatter.write_str("...")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Path<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_tuple("Path").field(&format_args!("{}", self)).finish()
+    }
+}
@@ -0,0 +1,277 @@
+use crate::element::{
+    Element,
+    Entry,
+    Visitor,
+};
+use crate::path::Path;
+
+use core::fmt;
+use core::ops;
+
+/// The treatment of NaNs, infinities, and signed zeros by a comparison.
+///
+/// The comparison crates disagree on these special values, e.g. every ulps
+/// comparison rejects NaN, while a relative comparison accepts two equal
+/// infinities and an absolute difference comparison accepts an infinity within
+/// an infinite tolerance. A policy decides every pair of numbers involving a
+/// special value the same way in every comparison, and leaves every other pair
+/// to the comparison:
+///
+/// * A NaN is unequal to every number, and to a NaN unless `nan_eq` is set.
+/// * An infinity is unequal to every number other than an infinity of the same
+///   sign, and to that infinity unless `inf_eq` is set.
+/// * Zeros of different signs are unequal when `signed_zero_strict` is set.
+///
+/// The comparison macros of every comparison crate take a policy as a trailing
+/// `policy = ...` argument, e.g. `abs_diff_eq!(a, b, abs_diff <= t, policy = p)`,
+/// and apply it to the numbers reported by the `visit_*` methods of the
+/// debugging traits. The `*_eq_with_policy` methods of the debugging traits do
+/// the same, e.g. `a.abs_diff_eq_with_policy(&b, &t, &p)`. The failing elements of an assertion report the rule that
+/// rejected them, labeled `policy`.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::{
+/// #     FloatPolicy,
+/// #     PolicyDecision,
+/// #     PolicyRule,
+/// # };
+/// #
+/// let policy = FloatPolicy {
+///     nan_eq: true,
+///     ..FloatPolicy::new()
+/// };
+///
+/// assert_eq!(policy.decide(f64::NAN, f64::NAN), Some(PolicyDecision::Accepted(PolicyRule::NanEq)));
+/// assert_eq!(policy.decide(f64::NAN, 1.0_f64), Some(PolicyDecision::Rejected(PolicyRule::NanEq)));
+/// assert_eq!(policy.decide(-0.0_f64, 0.0_f64), None);
+/// assert_eq!(policy.decide(1.0_f64, 2.0_f64), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FloatPolicy {
+    /// Whether a NaN equals a NaN.
+    pub nan_eq: bool,
+    /// Whether zeros of different signs are unequal.
+    pub signed_zero_strict: bool,
+    /// Whether an infinity equals the infinity of the same sign.
+    pub inf_eq: bool,
+}
+
+impl FloatPolicy {
+    /// Construct the default policy, which rejects NaN, accepts equal
+    /// infinities, and accepts zeros of either sign.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            nan_eq: false,
+            signed_zero_strict: false,
+            inf_eq: true,
+        }
+    }
+
+    /// Decide a pair of numbers, widened to [`f64`].
+    ///
+    /// Returns `None` when neither number is a special value covered by the
+    /// policy, in which case the comparison decides the pair.
+    pub fn decide(&self, lhs: f64, rhs: f64) -> Option<PolicyDecision> {
+        if lhs.is_nan() || rhs.is_nan() {
+            if self.nan_eq && lhs.is_nan() && rhs.is_nan() {
+                return Some(PolicyDecision::Accepted(PolicyRule::NanEq));
+            }
+
+            return Some(PolicyDecision::Rejected(PolicyRule::NanEq));
+        }
+
+        if lhs.is_infinite() || rhs.is_infinite() {
+            if self.inf_eq && lhs == rhs {
+                return Some(PolicyDecision::Accepted(PolicyRule::InfEq));
+            }
+
+            return Some(PolicyDecision::Rejected(PolicyRule::InfEq));
+        }
+
+        if self.signed_zero_strict && lhs == 0.0 && rhs == 0.0 && lhs.is_sign_negative() != rhs.is_sign_negative() {
+            return Some(PolicyDecision::Rejected(PolicyRule::SignedZeroStrict));
+        }
+
+        None
+    }
+
+    /// Determine whether every element reported by the traversal `visit` passes
+    /// its comparison, with the pairs of numbers covered by the policy decided by
+    /// the policy.
+    ///
+    /// The traversal stops at the first failing element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp_report::{
+    /// #     Element,
+    /// #     ElementErrors,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = f64::NAN;
+    /// let rhs = f64::NAN;
+    /// let visit = |path: &mut _, visitor: &mut dyn approx_cmp_report::Visitor<'_>| {
+    ///     visitor.visit(path, &Element::new(false, &[], &[]).with_errors(ElementErrors::new(&lhs, &rhs)))
+    /// };
+    /// let policy = FloatPolicy {
+    ///     nan_eq: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(policy.passes(visit));
+    /// assert!(!FloatPolicy::new().passes(visit));
+    /// ```
+    pub fn passes<'a, F>(&self, visit: F) -> bool
+    where
+        F: FnOnce(&mut Path<'a>, &mut dyn Visitor<'a>) -> ops::ControlFlow<()>,
+    {
+        let mut passed = true;
+        let mut visitor = |_path: &Path<'a>, element: &Element<'_>| {
+            if element.passed {
+                ops::ControlFlow::Continue(())
+            } else {
+                passed = false;
+                ops::ControlFlow::Break(())
+            }
+        };
+        let _ = visit(&mut Path::new(), &mut PolicyVisitor::new(*self, &mut visitor));
+
+        passed
+    }
+}
+
+impl Default for FloatPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A rule of a [`FloatPolicy`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PolicyRule {
+    /// The rule for NaNs, set by [`FloatPolicy::nan_eq`].
+    NanEq,
+    /// The rule for zeros of different signs, set by
+    /// [`FloatPolicy::signed_zero_strict`].
+    SignedZeroStrict,
+    /// The rule for infinities, set by [`FloatPolicy::inf_eq`].
+    InfEq,
+}
+
+impl PolicyRule {
+    /// The name of the field of [`FloatPolicy`] that sets the rule.
+    #[inline]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::NanEq => "nan_eq",
+            Self::SignedZeroStrict => "signed_zero_strict",
+            Self::InfEq => "inf_eq",
+        }
+    }
+}
+
+/// The decision of a [`FloatPolicy`] on a pair of numbers, along with the rule
+/// that made it.
+///
+/// The decision is formatted with the name of its rule, e.g.
+/// `rejected by signed_zero_strict`, in the panic messages of failed assertions.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PolicyDecision {
+    /// The rule accepted the pair.
+    Accepted(PolicyRule),
+    /// The rule rejected the pair.
+    Rejected(PolicyRule),
+}
+
+impl PolicyDecision {
+    /// Determine whether the pair passes the comparison.
+    #[inline]
+    pub const fn passed(self) -> bool {
+        matches!(self, Self::Accepted(_))
+    }
+
+    /// The rule that made the decision.
+    #[inline]
+    pub const fn rule(self) -> PolicyRule {
+        match self {
+            Self::Accepted(rule) | Self::Rejected(rule) => rule,
+        }
+    }
+}
+
+impl fmt::Debug for PolicyDecision {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Accepted(rule) => write!(formatter, "accepted by {}", rule.name()),
+            Self::Rejected(rule) => write!(formatter, "rejected by {}", rule.name()),
+        }
+    }
+}
+
+// The largest number of differences of an element decided by a policy,
+// including the decision.
+const MAX_POLICY_DIFFS: usize = 8;
+
+/// A visitor applying a [`FloatPolicy`] to the elements it receives before
+/// passing them on to another visitor.
+///
+/// An element carrying the numbers it compares, as reported by the
+/// [`ElementErrors`](crate::ElementErrors) of the floating point numbers, is
+/// passed on with the outcome decided by the policy, and with the decision
+/// appended to its differences, labeled `policy`. Every other element is passed
+/// on unchanged.
+///
+/// An element decided by the policy keeps at most seven of its differences,
+/// which is more than any comparison reports.
+pub struct PolicyVisitor<'v, 'a> {
+    policy: FloatPolicy,
+    visitor: &'v mut dyn Visitor<'a>,
+}
+
+impl<'v, 'a> PolicyVisitor<'v, 'a> {
+    /// Construct a visitor applying `policy` to the elements passed on to
+    /// `visitor`.
+    #[inline]
+    pub fn new(policy: FloatPolicy, visitor: &'v mut dyn Visitor<'a>) -> Self {
+        Self { policy, visitor }
+    }
+}
+
+impl<'a> Visitor<'a> for PolicyVisitor<'_, 'a> {
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()> {
+        let decision = element
+            .errors
+            .and_then(|errors| errors.values)
+            .and_then(|(lhs, rhs)| self.policy.decide(lhs, rhs));
+        let Some(decision) = decision else {
+            return self.visitor.visit(path, element);
+        };
+
+        let mut diffs: [Entry<'_>; MAX_POLICY_DIFFS] = [("", &()); MAX_POLICY_DIFFS];
+        let len = usize::min(element.diffs.len(), MAX_POLICY_DIFFS - 1);
+        diffs[..len].copy_from_slice(&element.diffs[..len]);
+        diffs[len] = ("policy", &decision);
+        let decided = Element {
+            passed: decision.passed(),
+            diffs: &diffs[..=len],
+            ..*element
+        };
+
+        self.visitor.visit(path, &decided)
+    }
+}
+
+impl fmt::Debug for PolicyVisitor<'_, '_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("PolicyVisitor")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,89 @@
+/// The outcome of searching a slice for the elements equal to a value within a
+/// tolerance.
+///
+/// A tolerance can make several elements equal to the same value, e.g. the
+/// samples `0.349` and `0.351` of a time series both match `0.35` within
+/// `0.01`. A search reports this explicitly as [`Ambiguous`] instead of picking
+/// one of the matching elements, so that a caller never mistakes an arbitrary
+/// match for the match.
+///
+/// [`Ambiguous`]: SearchMatch::Ambiguous
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::SearchMatch;
+/// #
+/// assert_eq!(SearchMatch::from_indices([]), SearchMatch::NotFound);
+/// assert_eq!(SearchMatch::from_indices([3]), SearchMatch::Found(3));
+/// assert_eq!(
+///     SearchMatch::from_indices([3, 4, 6]),
+///     SearchMatch::Ambiguous { first: 3, last: 6, count: 3 }
+/// );
+/// assert_eq!(SearchMatch::from_indices([3, 4, 6]).found(), None);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SearchMatch {
+    /// No element is equal to the value.
+    NotFound,
+    /// Exactly one element is equal to the value, at this index.
+    Found(usize),
+    /// Several elements are equal to the value.
+    Ambiguous {
+        /// The index of the first matching element.
+        first: usize,
+        /// The index of the last matching element.
+        last: usize,
+        /// The number of matching elements.
+        count: usize,
+    },
+}
+
+impl SearchMatch {
+    /// Construct the outcome of a search from the indices of the matching
+    /// elements, in increasing order.
+    pub fn from_indices<I>(indices: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        indices.into_iter().fold(Self::NotFound, |outcome, index| match outcome {
+            Self::NotFound => Self::Found(index),
+            Self::Found(first) => Self::Ambiguous {
+                first,
+                last: index,
+                count: 2,
+            },
+            Self::Ambiguous { first, count, .. } => Self::Ambiguous {
+                first,
+                last: index,
+                count: count + 1,
+            },
+        })
+    }
+
+    /// The index of the matching element, or `None` when no element or several
+    /// elements match.
+    #[inline]
+    pub const fn found(self) -> Option<usize> {
+        match self {
+            Self::Found(index) => Some(index),
+            Self::NotFound | Self::Ambiguous { .. } => None,
+        }
+    }
+
+    /// Determine whether several elements match.
+    #[inline]
+    pub const fn is_ambiguous(self) -> bool {
+        matches!(self, Self::Ambiguous { .. })
+    }
+
+    /// The number of matching elements.
+    #[inline]
+    pub const fn count(self) -> usize {
+        match self {
+            Self::NotFound => 0,
+            Self::Found(_) => 1,
+            Self::Ambiguous { count, .. } => count,
+        }
+    }
+}
@@ -0,0 +1,402 @@
+use crate::element::{
+    Element,
+    Visitor,
+};
+use crate::path::Path;
+
+use core::fmt;
+use core::ops;
+
+/// The numerical errors between the two sides of a compared element.
+///
+/// The errors are measured independently of the comparison that visits the
+/// element, so that the absolute difference, the relative difference, and the
+/// ulps distance of an element are available to every comparison.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ElementErrors {
+    /// The absolute difference between the left hand side and the right hand side.
+    pub abs_diff: f64,
+    /// The absolute difference relative to the larger magnitude of the left hand
+    /// side and the right hand side.
+    pub relative: f64,
+    /// The number of representable values between the left hand side and the right
+    /// hand side, if they are floating point numbers other than NaN.
+    pub ulps: Option<u64>,
+    /// The left hand side and the right hand side widened to [`f64`], if they are
+    /// floating point numbers. The widening is exact, so NaNs, infinities, and
+    /// the signs of zeros are preserved.
+    pub values: Option<(f64, f64)>,
+}
+
+impl ElementErrors {
+    /// Measure the errors between the two sides of an element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp_report::ElementErrors;
+    /// #
+    /// let errors = ElementErrors::new(&1.0_f32, &1.5_f32);
+    ///
+    /// assert_eq!(errors.abs_diff, 0.5_f64);
+    /// assert_eq!(errors.relative, 1.0_f64 / 3.0_f64);
+    /// assert_eq!(errors.ulps, Some(1 << 22));
+    /// assert_eq!(errors.values, Some((1.0_f64, 1.5_f64)));
+    /// ```
+    #[inline]
+    pub fn new<T>(lhs: &T, rhs: &T) -> Self
+    where
+        T: ErrorValue,
+    {
+        T::errors(*lhs, *rhs)
+    }
+}
+
+/// A numeric type whose elements report their numerical errors to a visitor.
+pub trait ErrorValue: Copy {
+    /// Measure the errors between `lhs` and `rhs`.
+    fn errors(lhs: Self, rhs: Self) -> ElementErrors;
+}
+
+macro_rules! impl_error_value_float {
+    ($T:ident, $U:ident) => {
+        impl ErrorValue for $T {
+            fn errors(lhs: Self, rhs: Self) -> ElementErrors {
+                let abs_diff = f64::abs(lhs as f64 - rhs as f64);
+                let largest = f64::max(f64::abs(lhs as f64), f64::abs(rhs as f64));
+                let relative = if lhs == rhs {
+                    0.0
+                } else if lhs.is_infinite() || rhs.is_infinite() {
+                    f64::INFINITY
+                } else {
+                    abs_diff / largest
+                };
+                let ulps = if lhs == rhs {
+                    Some(0)
+                } else if lhs.is_nan() || rhs.is_nan() {
+                    None
+                } else {
+                    // Count the representable numbers between `lhs` and `rhs`, including
+                    // across zero, the same way the ulps comparisons do.
+                    const SIGN: $U = 1 << ($U::BITS - 1);
+                    let bits_lhs: $U = lhs.to_bits();
+                    let bits_rhs: $U = rhs.to_bits();
+                    let magnitude_lhs = bits_lhs & !SIGN;
+                    let magnitude_rhs = bits_rhs & !SIGN;
+                    let distance = if (bits_lhs ^ bits_rhs) & SIGN == 0 {
+                        $U::max(magnitude_lhs, magnitude_rhs) - $U::min(magnitude_lhs, magnitude_rhs)
+                    } else {
+                        magnitude_lhs + magnitude_rhs
+                    };
+
+                    Some(u64::from(distance))
+                };
+
+                ElementErrors {
+                    abs_diff,
+                    relative,
+                    ulps,
+                    values: Some((lhs as f64, rhs as f64)),
+                }
+            }
+        }
+    };
+}
+
+impl_error_value_float!(f32, u32);
+impl_error_value_float!(f64, u64);
+
+macro_rules! impl_error_value_int {
+    ($($T:ident),* $(,)?) => {$(
+        impl ErrorValue for $T {
+            fn errors(lhs: Self, rhs: Self) -> ElementErrors {
+                let abs_diff = $T::abs_diff(lhs, rhs) as f64;
+                let largest = f64::max(f64::abs(lhs as f64), f64::abs(rhs as f64));
+                let relative = if lhs == rhs { 0.0 } else { abs_diff / largest };
+
+                ElementErrors {
+                    abs_diff,
+                    relative,
+                    ulps: None,
+                    values: None,
+                }
+            }
+        }
+    )*};
+}
+
+impl_error_value_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The largest error of a comparison, along with the path of the element it
+/// occurred at.
+#[derive(Copy, Clone, Debug)]
+pub struct Extremum<'a, T> {
+    /// The value of the error.
+    pub value: T,
+    /// The path of the element with the largest error.
+    pub path: Path<'a>,
+}
+
+/// Aggregate error statistics of a comparison.
+///
+/// An error statistics value is a [`Visitor`] that reduces the errors of every
+/// element it receives into the largest absolute difference, relative difference,
+/// and ulps distance along with the paths of the elements they occurred at, and
+/// the mean and the root mean square of the absolute differences. Elements that
+/// do not carry their [`ElementErrors`], e.g. structural mismatches, are counted
+/// but not measured.
+///
+/// The comparison crates provide `error_stats` functions gathering the
+/// statistics of a comparison, and print them in the panic messages of failed
+/// equality assertions.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::{
+/// #     Element,
+/// #     ElementErrors,
+/// #     ErrorStats,
+/// #     PathSegment,
+/// # };
+/// # use core::ops::ControlFlow;
+/// #
+/// let lhs = [1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+/// let rhs = [1.0_f64, 2.5_f64, 3.0_f64, 3.0_f64];
+/// let stats = ErrorStats::from_visit(|path, visitor| {
+///     for (i, (a, b)) in lhs.iter().zip(rhs.iter()).enumerate() {
+///         path.with_segment(PathSegment::Index(i), |path| {
+///             let element = Element::new(a == b, &[], &[]).with_errors(ElementErrors::new(a, b));
+///
+///             visitor.visit(path, &element)
+///         })?;
+///     }
+///
+///     ControlFlow::Continue(())
+/// });
+///
+/// assert_eq!(stats.elements(), 4);
+/// assert_eq!(stats.failed(), 2);
+/// assert_eq!(stats.max_abs_diff().map(|max| max.value), Some(1.0_f64));
+/// assert_eq!(stats.max_abs_diff().map(|max| max.path.to_string()), Some(String::from("[3]")));
+/// assert_eq!(stats.max_relative().map(|max| max.path.to_string()), Some(String::from("[3]")));
+/// assert_eq!(stats.mean_abs_diff(), Some(0.375_f64));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ErrorStats<'a> {
+    elements: usize,
+    failed: usize,
+    measured: usize,
+    max_abs_diff: Option<Extremum<'a, f64>>,
+    max_relative: Option<Extremum<'a, f64>>,
+    max_ulps: Option<Extremum<'a, u64>>,
+    sum_abs_diff: f64,
+    sum_squared_abs_diff: f64,
+}
+
+impl<'a> ErrorStats<'a> {
+    /// Construct error statistics that have not received any elements.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            elements: 0,
+            failed: 0,
+            measured: 0,
+            max_abs_diff: None,
+            max_relative: None,
+            max_ulps: None,
+            sum_abs_diff: 0.0,
+            sum_squared_abs_diff: 0.0,
+        }
+    }
+
+    /// Gather the error statistics of the comparison traversed by `visit`.
+    pub fn from_visit<F>(visit: F) -> Self
+    where
+        F: FnOnce(&mut Path<'a>, &mut dyn Visitor<'a>) -> ops::ControlFlow<()>,
+    {
+        let mut stats = Self::new();
+        let _ = visit(&mut Path::new(), &mut stats);
+
+        stats
+    }
+
+    /// The number of elements received.
+    #[inline]
+    pub const fn elements(&self) -> usize {
+        self.elements
+    }
+
+    /// The number of failing elements received.
+    #[inline]
+    pub const fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// The number of elements received that carried their errors.
+    #[inline]
+    pub const fn measured(&self) -> usize {
+        self.measured
+    }
+
+    /// The largest absolute difference, if any element carried its errors.
+    #[inline]
+    pub const fn max_abs_diff(&self) -> Option<&Extremum<'a, f64>> {
+        self.max_abs_diff.as_ref()
+    }
+
+    /// The largest relative difference, if any element carried its errors.
+    #[inline]
+    pub const fn max_relative(&self) -> Option<&Extremum<'a, f64>> {
+        self.max_relative.as_ref()
+    }
+
+    /// The largest ulps distance, if any element carried a ulps distance.
+    #[inline]
+    pub const fn max_ulps(&self) -> Option<&Extremum<'a, u64>> {
+        self.max_ulps.as_ref()
+    }
+
+    /// The mean of the absolute differences, if any element carried its errors.
+    #[inline]
+    pub fn mean_abs_diff(&self) -> Option<f64> {
+        if self.measured == 0 {
+            return None;
+        }
+
+        Some(self.sum_abs_diff / self.measured as f64)
+    }
+
+    /// The root mean square of the absolute differences, if any element carried
+    /// its errors.
+    #[inline]
+    pub fn rms_abs_diff(&self) -> Option<f64> {
+        if self.measured == 0 {
+            return None;
+        }
+
+        Some(sqrt(self.sum_squared_abs_diff / self.measured as f64))
+    }
+}
+
+impl Default for ErrorStats<'_> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Visitor<'a> for ErrorStats<'a> {
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()> {
+        self.elements += 1;
+        if !element.passed {
+            self.failed += 1;
+        }
+
+        let Some(errors) = element.errors else {
+            return ops::ControlFlow::Continue(());
+        };
+
+        self.measured += 1;
+        self.sum_abs_diff += errors.abs_diff;
+        self.sum_squared_abs_diff += errors.abs_diff * errors.abs_diff;
+
+        if self.max_abs_diff.is_none_or(|max| exceeds(errors.abs_diff, max.value)) {
+            self.max_abs_diff = Some(Extremum {
+                value: errors.abs_diff,
+                path: *path,
+            });
+        }
+        if self.max_relative.is_none_or(|max| exceeds(errors.relative, max.value)) {
+            self.max_relative = Some(Extremum {
+                value: errors.relative,
+                path: *path,
+            });
+        }
+        if let Some(ulps) = errors.ulps
+            && self.max_ulps.is_none_or(|max| ulps > max.value)
+        {
+            self.max_ulps = Some(Extremum { value: ulps, path: *path });
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl fmt::Display for ErrorStats<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Some(max_abs_diff), Some(max_relative), Some(mean), Some(rms)) =
+            (self.max_abs_diff, self.max_relative, self.mean_abs_diff(), self.rms_abs_diff())
+        else {
+            return formatter.write_str("error statistics: no element reported its errors");
+        };
+
+        write!(formatter, "error statistics over {} elements:", self.measured)?;
+        write!(
+            formatter,
+            "\n{:>17}: `{:?}` at `{}`,",
+            "max abs_diff", max_abs_diff.value, max_abs_diff.path
+        )?;
+        write!(
+            formatter,
+            "\n{:>17}: `{:?}` at `{}`,",
+            "max relative", max_relative.value, max_relative.path
+        )?;
+        if let Some(max_ulps) = self.max_ulps {
+            write!(formatter, "\n{:>17}: `{:?}` at `{}`,", "max ulps", max_ulps.value, max_ulps.path)?;
+        }
+        write!(formatter, "\n{:>17}: `{:?}`,", "mean abs_diff", mean)?;
+        write!(formatter, "\n{:>17}: `{:?}`", "rms abs_diff", rms)
+    }
+}
+
+/// Whether `value` is a larger error than `max`. A NaN error is larger than any
+/// other error, so that it is never hidden by the statistics.
+#[inline]
+fn exceeds(value: f64, max: f64) -> bool {
+    (value.is_nan() && !max.is_nan()) || value > max
+}
+
+/// The correctly rounded square root of `value`, since `core` does not provide
+/// one.
+fn sqrt(value: f64) -> f64 {
+    if value.is_nan() || value < 0.0 {
+        return f64::NAN;
+    }
+    if value == 0.0 || value.is_infinite() {
+        return value;
+    }
+
+    // Write `value` as `mantissa * 2^exponent` with an even exponent, so that
+    // its square root is `sqrt(mantissa) * 2^(exponent / 2)`.
+    let bits = value.to_bits();
+    let biased_exponent = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1 << 52), biased_exponent - 1075)
+    };
+    if exponent % 2 != 0 {
+        mantissa <<= 1;
+        exponent -= 1;
+    }
+
+    // Shift the mantissa by an even amount so that its integer square root has
+    // 55 bits: the 53 bits of the result, a rounding bit, and a sticky bit.
+    let shift = (110 - (u64::BITS - mantissa.leading_zeros()) as i32) & !1;
+    let square = u128::from(mantissa) << shift;
+    let root = square.isqrt();
+    let remainder = square - root * root;
+    let dropped = root & 0b11;
+    let mut rounded = (root >> 2) as u64;
+    if dropped > 0b10 || (dropped == 0b10 && (remainder != 0 || rounded & 1 == 1)) {
+        rounded += 1;
+    }
+
+    // The result is a normal number, so scaling it by a power of two is exact.
+    let scale = (exponent - shift) / 2 + 2;
+
+    rounded as f64 * f64::from_bits(((scale + 1023) as u64) << 52)
+}
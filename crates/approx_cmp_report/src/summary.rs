@@ -0,0 +1,221 @@
+use crate::element::{
+    Element,
+    Visitor,
+};
+use crate::path::Path;
+use crate::stats::ErrorStats;
+
+use core::fmt;
+use core::ops;
+
+/// The failing elements of a comparison, formatted for a panic message.
+///
+/// Displaying the value runs the traversal `visit` of the compared values, and
+/// writes how many of the visited elements failed, followed by the path and
+/// the values of each failing element, one per line. At most
+/// [`FailureSummary::MAX_LISTED`] elements are listed, and the remaining
+/// failing elements are elided. The list is omitted when a single element
+/// failed, since [`FirstFailure`](crate::FirstFailure) already displays it.
+/// When more than one element carries its numerical errors, the summary ends
+/// with the [`ErrorStats`] of the comparison.
+///
+/// The assertion macros of each comparison crate display this value in place
+/// of the compared values of a failed equality assertion, unless the
+/// `full_dump` feature is enabled. See [`FullDump`].
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp_report::{
+/// #     Element,
+/// #     FailureSummary,
+/// #     PathSegment,
+/// # };
+/// # use core::ops::ControlFlow;
+/// #
+/// let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+/// let rhs = [1.5_f64, 2.0_f64, 3.5_f64];
+/// let summary = FailureSummary::new(|path, visitor| {
+///     for (i, (a, b)) in lhs.iter().zip(rhs.iter()).enumerate() {
+///         path.with_segment(PathSegment::Index(i), |path| {
+///             let diff = (a - b).abs();
+///             let diffs = [("abs_diff", &diff as _)];
+///             let element = Element::new(diff <= 0.25_f64, &diffs, &[("abs_diff", &0.25_f64)]);
+///
+///             visitor.visit(path, &element.with_values(a, b))
+///         })?;
+///     }
+///
+///     ControlFlow::Continue(())
+/// });
+/// let expected = "\
+/// failed elements: 2 of 3
+///          [0]: left: `1.0`, right: `1.5`, abs_diff: `0.5`, [abs_diff] t: `0.25`
+///          [2]: left: `3.0`, right: `3.5`, abs_diff: `0.5`, [abs_diff] t: `0.25`";
+///
+/// assert_eq!(summary.to_string(), expected);
+/// ```
+#[derive(Copy, Clone)]
+pub struct FailureSummary<F> {
+    visit: F,
+}
+
+impl<F> FailureSummary<F> {
+    /// The largest number of failing elements listed by the summary.
+    pub const MAX_LISTED: usize = 16;
+
+    /// Construct the summary of the failing elements of the comparison traversed
+    /// by `visit`.
+    #[inline]
+    pub const fn new<'a>(visit: F) -> Self
+    where
+        F: Fn(&mut Path<'a>, &mut dyn Visitor<'a>) -> ops::ControlFlow<()>,
+    {
+        Self { visit }
+    }
+}
+
+impl<'a, F> fmt::Display for FailureSummary<F>
+where
+    F: Fn(&mut Path<'a>, &mut dyn Visitor<'a>) -> ops::ControlFlow<()>,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stats = ErrorStats::from_visit(&self.visit);
+
+        write!(formatter, "failed elements: {} of {}", stats.failed(), stats.elements())?;
+        if stats.failed() > 1 {
+            let mut writer = FailureListWriter {
+                formatter,
+                listed: 0,
+                result: Ok(()),
+            };
+            let _ = (self.visit)(&mut Path::new(), &mut writer);
+            writer.result?;
+
+            let elided = stats.failed().saturating_sub(writer.listed);
+            if elided > 0 {
+                write!(formatter, "\n{:>12}  and {} more failed elements", "...", elided)?;
+            }
+        }
+        if stats.measured() > 1 {
+            write!(formatter, "\n{}", stats)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<F> fmt::Debug for FailureSummary<F> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("FailureSummary").finish_non_exhaustive()
+    }
+}
+
+/// The compared values of a failed assertion, displayed only when the
+/// `full_dump` feature is enabled.
+///
+/// The compared values of large containers produce very large panic messages,
+/// so the assertion macros of each comparison crate print them only on request.
+/// Enable the `full_dump` feature of any of the comparison crates to display
+/// them below the [`FailureSummary`] of a failed equality assertion.
+#[derive(Copy, Clone)]
+pub struct FullDump<'a> {
+    arguments: fmt::Arguments<'a>,
+}
+
+impl<'a> FullDump<'a> {
+    /// Whether the compared values are displayed.
+    pub const ENABLED: bool = cfg!(feature = "full_dump");
+
+    /// Construct a full dump of the formatted compared values.
+    #[inline]
+    pub const fn new(arguments: fmt::Arguments<'a>) -> Self {
+        Self { arguments }
+    }
+}
+
+impl fmt::Display for FullDump<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if Self::ENABLED {
+            formatter.write_fmt(self.arguments)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FullDump<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_struct("FullDump").field("enabled", &Self::ENABLED).finish()
+    }
+}
+
+/// A writer counting the characters written to it.
+struct LenCounter {
+    len: usize,
+}
+
+impl fmt::Write for LenCounter {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        self.len += string.chars().count();
+
+        Ok(())
+    }
+}
+
+/// A visitor writing one line per failing element it receives to a formatter.
+struct FailureListWriter<'f, 'g> {
+    formatter: &'f mut fmt::Formatter<'g>,
+    listed: usize,
+    result: fmt::Result,
+}
+
+impl FailureListWriter<'_, '_> {
+    fn write(&mut self, path: &Path<'_>, element: &Element<'_>) -> fmt::Result {
+        // Right align the path with the labels of the first failure.
+        let mut counter = LenCounter { len: 0 };
+        let _ = fmt::write(&mut counter, format_args!("{}", path));
+        let padding = usize::saturating_sub(12, counter.len);
+        write!(self.formatter, "\n{:>padding$}{}:", "", path)?;
+
+        let mut separator = " ";
+        if let Some(left) = element.left {
+            write!(self.formatter, "{}left: `{:?}`", separator, left)?;
+            separator = ", ";
+        }
+        if let Some(right) = element.right {
+            write!(self.formatter, "{}right: `{:?}`", separator, right)?;
+            separator = ", ";
+        }
+        for (label, diff) in element.diffs {
+            write!(self.formatter, "{}{}: `{:?}`", separator, label, diff)?;
+            separator = ", ";
+        }
+        for (label, tolerance) in element.tolerances {
+            write!(self.formatter, "{}[{}] t: `{:?}`", separator, label, tolerance)?;
+            separator = ", ";
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Visitor<'a> for FailureListWriter<'_, '_> {
+    fn visit(&mut self, path: &Path<'a>, element: &Element<'_>) -> ops::ControlFlow<()> {
+        if element.passed {
+            return ops::ControlFlow::Continue(());
+        }
+        if self.listed >= FailureSummary::<()>::MAX_LISTED {
+            return ops::ControlFlow::Break(());
+        }
+
+        self.result = self.write(path, element);
+        self.listed += 1;
+
+        if self.result.is_err() {
+            ops::ControlFlow::Break(())
+        } else {
+            ops::ControlFlow::Continue(())
+        }
+    }
+}
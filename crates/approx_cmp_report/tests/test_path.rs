@@ -0,0 +1,70 @@
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+};
+
+#[test]
+fn test_root() {
+    let path = Path::new();
+
+    assert!(path.is_root());
+    assert_eq!(path.depth(), 0);
+    assert_eq!(path.to_string(), "<root>");
+}
+
+#[test]
+fn test_display() {
+    let key = "alpha";
+    let mut path = Path::new();
+    path.push(PathSegment::Field("points"));
+    path.push(PathSegment::Index(3));
+    path.push(PathSegment::Key(&key));
+
+    assert_eq!(path.depth(), 3);
+    assert_eq!(path.to_string(), ".points[3][\"alpha\"]");
+}
+
+#[test]
+fn test_push_pop() {
+    let mut path = Path::new();
+    path.push(PathSegment::Index(1));
+    path.push(PathSegment::Index(2));
+    path.pop();
+
+    assert_eq!(path.to_string(), "[1]");
+}
+
+#[test]
+#[should_panic]
+fn test_pop_root() {
+    let mut path = Path::new();
+    path.pop();
+}
+
+#[test]
+fn test_with_segment() {
+    let mut path = Path::new();
+    let inner = path.with_segment(PathSegment::Field("x"), |path| path.to_string());
+
+    assert_eq!(inner, ".x");
+    assert!(path.is_root());
+}
+
+#[test]
+fn test_truncated() {
+    let mut path = Path::new();
+    for i in 0..(Path::CAPACITY + 2) {
+        path.push(PathSegment::Index(i));
+    }
+
+    assert!(path.is_truncated());
+    assert_eq!(path.depth(), Path::CAPACITY + 2);
+    assert_eq!(path.segments().len(), Path::CAPACITY);
+    assert!(path.to_string().ends_with("..."));
+
+    path.pop();
+    path.pop();
+
+    assert!(!path.is_truncated());
+    assert!(!path.to_string().ends_with("..."));
+}
@@ -0,0 +1,159 @@
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    ErrorStats,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+use core::ops::ControlFlow;
+
+fn visit_pairs<'a>(lhs: &[f64], rhs: &[f64], path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ControlFlow<()> {
+    for (i, (a, b)) in lhs.iter().zip(rhs.iter()).enumerate() {
+        path.with_segment(PathSegment::Index(i), |path| {
+            visitor.visit(path, &Element::new(a == b, &[], &[]).with_errors(ElementErrors::new(a, b)))
+        })?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+#[test]
+fn test_element_errors_float() {
+    let errors = ElementErrors::new(&2.0_f64, &1.0_f64);
+
+    assert_eq!(errors.abs_diff, 1.0_f64);
+    assert_eq!(errors.relative, 0.5_f64);
+    assert_eq!(errors.ulps, Some(1 << 52));
+}
+
+#[test]
+fn test_element_errors_float_equal() {
+    let errors = ElementErrors::new(&f32::INFINITY, &f32::INFINITY);
+
+    assert_eq!(errors.relative, 0.0_f64);
+    assert_eq!(errors.ulps, Some(0));
+}
+
+#[test]
+fn test_element_errors_float_opposite_signs() {
+    let errors = ElementErrors::new(&1.0_f32, &-1.0_f32);
+
+    assert_eq!(errors.abs_diff, 2.0_f64);
+    assert_eq!(errors.ulps, Some(2 * u64::from(1.0_f32.to_bits())));
+
+    let errors = ElementErrors::new(&f64::from_bits(1), &-f64::from_bits(1));
+
+    assert_eq!(errors.ulps, Some(2));
+
+    let errors = ElementErrors::new(&0.0_f64, &-0.0_f64);
+
+    assert_eq!(errors.ulps, Some(0));
+}
+
+#[test]
+fn test_element_errors_float_nan() {
+    let errors = ElementErrors::new(&f64::NAN, &1.0_f64);
+
+    assert!(errors.abs_diff.is_nan());
+    assert_eq!(errors.ulps, None);
+}
+
+#[test]
+fn test_element_errors_int() {
+    let errors = ElementErrors::new(&i8::MIN, &i8::MAX);
+
+    assert_eq!(errors.abs_diff, 255.0_f64);
+    assert_eq!(errors.relative, 255.0_f64 / 128.0_f64);
+    assert_eq!(errors.ulps, None);
+
+    let errors = ElementErrors::new(&u128::MAX, &u128::MAX);
+
+    assert_eq!(errors.abs_diff, 0.0_f64);
+    assert_eq!(errors.relative, 0.0_f64);
+}
+
+#[test]
+fn test_stats_empty() {
+    let stats = ErrorStats::new();
+
+    assert_eq!(stats.elements(), 0);
+    assert_eq!(stats.measured(), 0);
+    assert!(stats.max_abs_diff().is_none());
+    assert!(stats.mean_abs_diff().is_none());
+    assert!(stats.rms_abs_diff().is_none());
+    assert_eq!(stats.to_string(), "error statistics: no element reported its errors");
+}
+
+#[test]
+fn test_stats_maxima() {
+    let lhs = [1.0_f64, 100.0_f64, 0.5_f64, -3.0_f64];
+    let rhs = [1.0_f64, 102.0_f64, 1.0_f64, 3.0_f64];
+    let stats = ErrorStats::from_visit(|path, visitor| visit_pairs(&lhs, &rhs, path, visitor));
+    let max_abs_diff = stats.max_abs_diff().unwrap();
+    let max_relative = stats.max_relative().unwrap();
+    let max_ulps = stats.max_ulps().unwrap();
+
+    assert_eq!(stats.elements(), 4);
+    assert_eq!(stats.failed(), 3);
+    assert_eq!(stats.measured(), 4);
+    assert_eq!(max_abs_diff.value, 6.0_f64);
+    assert_eq!(max_abs_diff.path.to_string(), "[3]");
+    assert_eq!(max_relative.value, 2.0_f64);
+    assert_eq!(max_relative.path.to_string(), "[3]");
+    assert_eq!(max_ulps.value, 2 * 3.0_f64.to_bits());
+    assert_eq!(max_ulps.path.to_string(), "[3]");
+}
+
+#[test]
+fn test_stats_mean_rms() {
+    let lhs = [0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64];
+    let rhs = [3.0_f64, 4.0_f64, 0.0_f64, 0.0_f64];
+    let stats = ErrorStats::from_visit(|path, visitor| visit_pairs(&lhs, &rhs, path, visitor));
+
+    assert_eq!(stats.mean_abs_diff(), Some(1.75_f64));
+    assert_eq!(stats.rms_abs_diff(), Some(2.5_f64));
+}
+
+#[test]
+fn test_stats_nan_is_largest() {
+    let lhs = [1.0_f64, f64::NAN, 1.0_f64];
+    let rhs = [2.0_f64, 1.0_f64, 5.0_f64];
+    let stats = ErrorStats::from_visit(|path, visitor| visit_pairs(&lhs, &rhs, path, visitor));
+    let max_abs_diff = stats.max_abs_diff().unwrap();
+
+    assert!(max_abs_diff.value.is_nan());
+    assert_eq!(max_abs_diff.path.to_string(), "[1]");
+}
+
+#[test]
+fn test_stats_unmeasured_elements() {
+    let stats = ErrorStats::from_visit(|path, visitor| {
+        StructureMismatch::MissingKey.report(path, visitor)?;
+        visitor.visit(
+            path,
+            &Element::new(true, &[], &[]).with_errors(ElementErrors::new(&1.0_f32, &1.0_f32)),
+        )
+    });
+
+    assert_eq!(stats.elements(), 2);
+    assert_eq!(stats.failed(), 1);
+    assert_eq!(stats.measured(), 1);
+}
+
+#[test]
+fn test_stats_display() {
+    let lhs = [1.0_f64, 2.0_f64];
+    let rhs = [1.0_f64, 3.0_f64];
+    let stats = ErrorStats::from_visit(|path, visitor| visit_pairs(&lhs, &rhs, path, visitor));
+    let expected = "\
+error statistics over 2 elements:
+     max abs_diff: `1.0` at `[1]`,
+     max relative: `0.3333333333333333` at `[1]`,
+         max ulps: `2251799813685248` at `[1]`,
+    mean abs_diff: `0.5`,
+     rms abs_diff: `0.7071067811865476`";
+
+    assert_eq!(stats.to_string(), expected);
+}
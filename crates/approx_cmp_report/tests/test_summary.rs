@@ -0,0 +1,68 @@
+use approx_cmp_report::{
+    Element,
+    FailureSummary,
+    FullDump,
+    Path,
+    PathSegment,
+    Visitor,
+};
+use core::ops::ControlFlow;
+
+fn visit_indices<'a>(failing: &[bool], path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ControlFlow<()> {
+    for (i, failed) in failing.iter().enumerate() {
+        path.with_segment(PathSegment::Index(i), |path| {
+            visitor.visit(path, &Element::new(!failed, &[], &[]).with_values(&i, &i))
+        })?;
+    }
+
+    ControlFlow::Continue(())
+}
+
+#[test]
+fn test_summary_no_failures() {
+    let failing = [false; 4];
+    let summary = FailureSummary::new(|path, visitor| visit_indices(&failing, path, visitor));
+
+    assert_eq!(summary.to_string(), "failed elements: 0 of 4");
+}
+
+#[test]
+fn test_summary_single_failure() {
+    let failing = [false, true, false];
+    let summary = FailureSummary::new(|path, visitor| visit_indices(&failing, path, visitor));
+
+    assert_eq!(summary.to_string(), "failed elements: 1 of 3");
+}
+
+#[test]
+fn test_summary_lists_failures() {
+    let failing = [true, false, true, true];
+    let summary = FailureSummary::new(|path, visitor| visit_indices(&failing, path, visitor));
+    let expected = "\
+failed elements: 3 of 4
+         [0]: left: `0`, right: `0`
+         [2]: left: `2`, right: `2`
+         [3]: left: `3`, right: `3`";
+
+    assert_eq!(summary.to_string(), expected);
+}
+
+#[test]
+fn test_summary_elides_failures() {
+    let failing = [true; 20];
+    let summary = FailureSummary::new(|path, visitor| visit_indices(&failing, path, visitor)).to_string();
+    let lines: Vec<&str> = summary.lines().collect();
+
+    assert_eq!(lines.len(), FailureSummary::<()>::MAX_LISTED + 2);
+    assert_eq!(lines[0], "failed elements: 20 of 20");
+    assert_eq!(lines[16], "        [15]: left: `15`, right: `15`");
+    assert_eq!(lines[17], "         ...  and 4 more failed elements");
+}
+
+#[test]
+fn test_full_dump() {
+    let left = [1.0_f64; 2];
+    let expected = if FullDump::ENABLED { "left: `[1.0, 1.0]`" } else { "" };
+
+    assert_eq!(FullDump::new(format_args!("left: `{:?}`", left)).to_string(), expected);
+}
@@ -0,0 +1,75 @@
+use approx_cmp_float::Float;
+
+/// The magnitude that the relative difference of two floating point numbers is
+/// measured against.
+///
+/// The relative comparisons of [`RelativeEq`](crate::RelativeEq) measure the
+/// absolute difference of two numbers against the larger of their magnitudes,
+/// which makes the comparison symmetric. The comparisons of
+/// [`RelativeEqBy`](crate::RelativeEqBy) measure it against the magnitude chosen
+/// here instead. Comparing an actual value against an expected value with
+/// [`RelativeDenominator::Second`] makes the tolerance relative to the expected
+/// value alone, and [`RelativeDenominator::Min`] is the strictest of the choices.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::RelativeDenominator;
+/// #
+/// let lhs = -2.0_f64;
+/// let rhs = 8.0_f64;
+///
+/// assert_eq!(RelativeDenominator::Max.magnitude(lhs, rhs), 8.0_f64);
+/// assert_eq!(RelativeDenominator::Min.magnitude(lhs, rhs), 2.0_f64);
+/// assert_eq!(RelativeDenominator::First.magnitude(lhs, rhs), 2.0_f64);
+/// assert_eq!(RelativeDenominator::Second.magnitude(lhs, rhs), 8.0_f64);
+/// assert_eq!(RelativeDenominator::Mean.magnitude(lhs, rhs), 5.0_f64);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RelativeDenominator {
+    /// The larger of the magnitudes of the compared numbers, as in
+    /// [`RelativeEq`](crate::RelativeEq).
+    #[default]
+    Max,
+    /// The smaller of the magnitudes of the compared numbers.
+    Min,
+    /// The magnitude of the left hand side of the comparison.
+    First,
+    /// The magnitude of the right hand side of the comparison.
+    Second,
+    /// The mean of the magnitudes of the compared numbers.
+    Mean,
+}
+
+impl RelativeDenominator {
+    /// The magnitude of `lhs` and `rhs` that their relative difference is measured
+    /// against.
+    #[inline]
+    pub fn magnitude<T>(self, lhs: T, rhs: T) -> T
+    where
+        T: Float,
+    {
+        let lhs = lhs.abs();
+        let rhs = rhs.abs();
+
+        match self {
+            Self::Max => Float::max(lhs, rhs),
+            Self::Min => {
+                if lhs <= rhs {
+                    lhs
+                } else {
+                    rhs
+                }
+            }
+            Self::First => lhs,
+            Self::Second => rhs,
+            Self::Mean => {
+                // Halving each magnitude before adding them up keeps the mean of
+                // two large numbers finite.
+                let two = T::ONE + T::ONE;
+
+                lhs / two + rhs / two
+            }
+        }
+    }
+}
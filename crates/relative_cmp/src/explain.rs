@@ -0,0 +1,86 @@
+use crate::relative;
+use crate::traits::RelativeEq;
+use approx_cmp_float::Float;
+
+/// The branch of a relative comparison of two floating point numbers that
+/// decided its outcome.
+///
+/// The relative comparison of two floating point numbers passes or fails at the
+/// first branch that applies to them, in the order of the variants below. The
+/// absolute difference branch applies before the relative branch, so that two
+/// numbers near zero, whose relative difference is large even when they are very
+/// close together, compare equal with a suitable absolute difference tolerance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RelativeExplanation {
+    /// The compared numbers are equal, including infinities of the same sign, so
+    /// the comparison passes.
+    PassedEqual,
+    /// At least one of the compared numbers is infinite, and they are not equal,
+    /// so the comparison fails.
+    FailedInfinite,
+    /// At least one of the compared numbers is NaN, so the comparison fails.
+    FailedNan,
+    /// The absolute difference of the compared numbers is within the absolute
+    /// difference tolerance, so the comparison passes.
+    PassedAbsDiff,
+    /// The relative difference of the compared numbers is within the relative
+    /// tolerance, so the comparison passes.
+    PassedRelative,
+    /// The relative difference of the compared numbers exceeds the relative
+    /// tolerance, so the comparison fails.
+    FailedRelative,
+}
+
+impl RelativeExplanation {
+    /// Determine whether the explained comparison passed.
+    #[inline]
+    pub const fn passed(&self) -> bool {
+        matches!(self, Self::PassedEqual | Self::PassedAbsDiff | Self::PassedRelative)
+    }
+}
+
+/// Explain the outcome of a relative comparison of two floating point numbers.
+///
+/// Every [`Float`] type with a relative comparison using its own type as the
+/// tolerances implements this trait, with the explanations of
+/// [`relative::explain_relative`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     RelativeEq,
+/// #     RelativeExplain,
+/// #     RelativeExplanation,
+/// # };
+/// #
+/// let lhs = 1e-30_f64;
+/// let rhs = -1e-30_f64;
+///
+/// assert!(lhs.relative_ne(&rhs, &0.0_f64, &f64::EPSILON));
+/// assert_eq!(lhs.explain_relative(&rhs, &0.0_f64, &f64::EPSILON), RelativeExplanation::FailedRelative);
+/// assert_eq!(lhs.explain_relative(&rhs, &1e-29_f64, &f64::EPSILON), RelativeExplanation::PassedAbsDiff);
+/// assert_eq!(
+///     1.0_f64.explain_relative(&f64::INFINITY, &0.0_f64, &f64::EPSILON),
+///     RelativeExplanation::FailedInfinite
+/// );
+/// assert_eq!(
+///     100.0_f64.explain_relative(&101.0_f64, &0.0_f64, &0.01_f64),
+///     RelativeExplanation::PassedRelative
+/// );
+/// ```
+pub trait RelativeExplain: RelativeEq {
+    /// Explain the outcome of comparing `self` and `other` with
+    /// [`RelativeEq::relative_eq`], using the same tolerances.
+    fn explain_relative(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> RelativeExplanation;
+}
+
+impl<T> RelativeExplain for T
+where
+    T: Float + RelativeEq<Tolerance = T>,
+{
+    #[inline]
+    fn explain_relative(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> RelativeExplanation {
+        relative::explain_relative(*self, *other, *max_abs_diff, *max_relative)
+    }
+}
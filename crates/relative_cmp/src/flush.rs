@@ -0,0 +1,911 @@
+use crate::relative;
+use crate::traits::AssertRelativeEq;
+use approx_cmp_float::Float;
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Entry,
+    Flushed,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
+
+use core::ops;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::fmt;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::collections::{
+    BTreeMap,
+    LinkedList,
+    VecDeque,
+};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::vec::Vec;
+
+#[cfg(feature = "std")]
+use core::hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A data type for comparing results computed with denormals flushed to zero
+/// against results computed with subnormal numbers.
+///
+/// Arithmetic with denormals flushed to zero, e.g. the SIMD units of most
+/// processors in their fast modes, replaces every subnormal result with zero.
+/// The relative difference between a subnormal number and zero is one, however
+/// small the subnormal number is. A flush-to-zero comparison replaces the
+/// subnormal numbers on either side with the zero of the same sign, and then
+/// compares with the semantics of [`RelativeEq`](crate::RelativeEq).
+///
+/// Like [`RelativeAllEq`](crate::RelativeAllEq), the comparison uses a single
+/// pair of tolerances for every element of a container. The [`relative_flushed`] method
+/// counts the numbers the comparison flushes, and failed assertions report the
+/// elements it flushed, labeled `flushed`.
+///
+/// [`relative_flushed`]: RelativeEqFtz::relative_flushed
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     RelativeAllEq,
+/// #     RelativeEqFtz,
+/// # };
+/// #
+/// let reference = [1.0_f32, 1e-40_f32, -2e-39_f32];
+/// let flushed = [1.0_f32, 0.0_f32, -0.0_f32];
+///
+/// assert!(flushed.relative_all_ne(&reference, &0.0_f32, &1e-6_f32));
+/// assert!(flushed.relative_eq_ftz(&reference, &0.0_f32, &1e-6_f32));
+/// assert_eq!(flushed.relative_flushed(&reference), 2);
+/// ```
+pub trait RelativeEqFtz<Rhs: ?Sized = Self> {
+    /// The data type representing the maximum allowed absolute difference and
+    /// relative difference between two numbers after flushing.
+    type Tolerance: ?Sized;
+
+    /// Compare `self` and `other` for relative difference equality, with the
+    /// subnormal numbers of both flushed to zero.
+    ///
+    /// Returns `true` when every pair of elements, after flushing, is within
+    /// `max_abs_diff` or within `max_relative` of the larger magnitude.
+    fn relative_eq_ftz(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool;
+
+    /// Compare `self` and `other` for relative difference inequality, with the
+    /// subnormal numbers of both flushed to zero.
+    ///
+    /// Returns `true` when a pair of elements, after flushing, is neither within
+    /// `max_abs_diff` nor within `max_relative` of the larger magnitude.
+    #[inline]
+    fn relative_ne_ftz(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        !Self::relative_eq_ftz(self, other, max_abs_diff, max_relative)
+    }
+
+    /// Count the subnormal numbers of `self` and `other` that a flush-to-zero
+    /// comparison flushes, i.e. zero when the normalization changes nothing.
+    fn relative_flushed(&self, other: &Rhs) -> usize;
+}
+
+/// A trait for computing debugging information when a flush-to-zero relative
+/// comparison fails.
+///
+/// This trait is the debugging counterpart of [`RelativeEqFtz`], in the same way
+/// [`AssertRelativeAllEq`](crate::AssertRelativeAllEq) is the debugging counterpart of
+/// [`RelativeAllEq`](crate::RelativeAllEq).
+pub trait AssertRelativeEqFtz<Rhs: ?Sized = Self>: RelativeEqFtz<Rhs> {
+    /// Visit the elements of `self` and `other`, reporting the differences of
+    /// each pair of elements after flushing, the sides flushed to zero, and the
+    /// tolerances to `visitor`.
+    ///
+    /// The default implementation reports `self` and `other` as a single
+    /// element without values or tolerances.
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        visitor.visit(
+            path,
+            &Element::new(RelativeEqFtz::relative_eq_ftz(self, other, max_abs_diff, max_relative), &[], &[]),
+        )
+    }
+}
+
+// The flushed numbers are widened to the precision the tolerances are given in,
+// which is exact, so that mixed precision pairs compare like `RelativeEq` does.
+macro_rules! impl_relative_eq_ftz_float {
+    ($($T:ident, $Rhs:ident => $E:ident),* $(,)?) => {$(
+        impl RelativeEqFtz<$Rhs> for $T {
+            type Tolerance = $E;
+
+            #[inline]
+            fn relative_eq_ftz(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+                let lhs = $E::from(Float::flush_to_zero(*self));
+                let rhs = $E::from(Float::flush_to_zero(*other));
+
+                relative::relative_eq(lhs, rhs, *max_abs_diff, *max_relative)
+            }
+
+            #[inline]
+            fn relative_flushed(&self, other: &$Rhs) -> usize {
+                Flushed::new(Float::is_subnormal(*self), Float::is_subnormal(*other)).map_or(0, Flushed::count)
+            }
+        }
+
+        impl AssertRelativeEqFtz<$Rhs> for $T {
+            #[inline]
+            fn visit_relative_ftz<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let lhs = $E::from(Float::flush_to_zero(*self));
+                let rhs = $E::from(Float::flush_to_zero(*other));
+                let branch = relative::explain_relative(lhs, rhs, *max_abs_diff, *max_relative);
+                let abs_diff = AssertRelativeEq::debug_abs_diff(&lhs, &rhs);
+                let relative_diff = AssertRelativeEq::debug_relative_diff(&lhs, &rhs);
+                // The flushed sides are reported only when a side was flushed.
+                let (flushed, len) = match Flushed::new(Float::is_subnormal(*self), Float::is_subnormal(*other)) {
+                    Some(flushed) => (flushed, 4),
+                    None => (Flushed::Both, 3),
+                };
+                let diffs: [Entry<'_>; 4] = [
+                    ("abs_diff", &abs_diff),
+                    ("relative", &relative_diff),
+                    ("branch", &branch),
+                    ("flushed", &flushed),
+                ];
+
+                visitor.visit(
+                    path,
+                    &Element::new(branch.passed(), &diffs[..len], &[("abs_diff", max_abs_diff), ("relative", max_relative)])
+                        .with_values(self, other)
+                        .with_errors(ElementErrors::new(&lhs, &rhs)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_ftz_float!(f32, f32 => f32, f64, f64 => f64, f32, f64 => f64, f64, f32 => f64);
+
+impl<A, B> RelativeEqFtz<&B> for &A
+where
+    A: RelativeEqFtz<B> + ?Sized,
+    B: ?Sized,
+{
+    type Tolerance = A::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &&B, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        RelativeEqFtz::relative_eq_ftz(*self, *other, max_abs_diff, max_relative)
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &&B) -> usize {
+        RelativeEqFtz::relative_flushed(*self, *other)
+    }
+}
+
+impl<A, B> AssertRelativeEqFtz<&B> for &A
+where
+    A: AssertRelativeEqFtz<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqFtz::visit_relative_ftz(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> RelativeEqFtz<[B; N]> for [A; N]
+where
+    A: RelativeEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &[B; N], max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        RelativeEqFtz::relative_eq_ftz(&self[..], &other[..], max_abs_diff, max_relative)
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &[B; N]) -> usize {
+        RelativeEqFtz::relative_flushed(&self[..], &other[..])
+    }
+}
+
+impl<A, B, const N: usize> AssertRelativeEqFtz<[B; N]> for [A; N]
+where
+    A: AssertRelativeEqFtz<B>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqFtz::visit_relative_ftz(&self[..], &other[..], max_abs_diff, max_relative, path, visitor)
+    }
+}
+
+impl<A, B> RelativeEqFtz<[B]> for [A]
+where
+    A: RelativeEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &[B], max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative))
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &[B]) -> usize {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| RelativeEqFtz::relative_flushed(a, b))
+            .sum()
+    }
+}
+
+impl<A, B> AssertRelativeEqFtz<[B]> for [A]
+where
+    A: AssertRelativeEqFtz<B>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> RelativeEqFtz<Vec<B>> for Vec<A>
+where
+    A: RelativeEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &Vec<B>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        RelativeEqFtz::relative_eq_ftz(self.as_slice(), other.as_slice(), max_abs_diff, max_relative)
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &Vec<B>) -> usize {
+        RelativeEqFtz::relative_flushed(self.as_slice(), other.as_slice())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertRelativeEqFtz<Vec<B>> for Vec<A>
+where
+    A: AssertRelativeEqFtz<B>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqFtz::visit_relative_ftz(self.as_slice(), other.as_slice(), max_abs_diff, max_relative, path, visitor)
+    }
+}
+
+// Expand to the element type of a tuple once per index.
+macro_rules! ftz_tuple_element {
+    ($idx:tt, $T:ident) => {
+        $T
+    };
+}
+
+macro_rules! impl_relative_eq_ftz_tuple {
+    ($(($($idx:tt),+))+) => {$(
+        impl<A, B> RelativeEqFtz<($(ftz_tuple_element!($idx, B),)+)> for ($(ftz_tuple_element!($idx, A),)+)
+        where
+            A: RelativeEqFtz<B>,
+        {
+            type Tolerance = A::Tolerance;
+
+            #[inline]
+            fn relative_eq_ftz(&self, other: &($(ftz_tuple_element!($idx, B),)+), max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+                $(RelativeEqFtz::relative_eq_ftz(&self.$idx, &other.$idx, max_abs_diff, max_relative))&&+
+            }
+
+            #[inline]
+            fn relative_flushed(&self, other: &($(ftz_tuple_element!($idx, B),)+)) -> usize {
+                0 $(+ RelativeEqFtz::relative_flushed(&self.$idx, &other.$idx))+
+            }
+        }
+
+        impl<A, B> AssertRelativeEqFtz<($(ftz_tuple_element!($idx, B),)+)> for ($(ftz_tuple_element!($idx, A),)+)
+        where
+            A: AssertRelativeEqFtz<B>,
+        {
+            #[inline]
+            fn visit_relative_ftz<'a>(
+                &'a self,
+                other: &($(ftz_tuple_element!($idx, B),)+),
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                $(
+                    path.with_segment(PathSegment::Field(stringify!($idx)), |path| {
+                        AssertRelativeEqFtz::visit_relative_ftz(&self.$idx, &other.$idx, max_abs_diff, max_relative, path, visitor)
+                    })?;
+                )+
+
+                ops::ControlFlow::Continue(())
+            }
+        }
+    )+};
+}
+
+impl_relative_eq_ftz_tuple! {
+    (0)
+    (0, 1)
+    (0, 1, 2)
+    (0, 1, 2, 3)
+    (0, 1, 2, 3, 4)
+    (0, 1, 2, 3, 4, 5)
+    (0, 1, 2, 3, 4, 5, 6)
+    (0, 1, 2, 3, 4, 5, 6, 7)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+}
+
+// The tolerances of an `Option` or a `Result` select the variant they apply to,
+// like the tolerances of the `all` comparisons do.
+impl<A, B> RelativeEqFtz<Option<B>> for Option<A>
+where
+    A: RelativeEqFtz<B>,
+    A::Tolerance: Sized,
+{
+    type Tolerance = Option<A::Tolerance>;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &Option<B>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        if let (Some(a), Some(b), Some(max_abs_diff), Some(max_relative)) = (self, other, max_abs_diff, max_relative) {
+            RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &Option<B>) -> usize {
+        if let (Some(a), Some(b)) = (self, other) {
+            RelativeEqFtz::relative_flushed(a, b)
+        } else {
+            0
+        }
+    }
+}
+
+impl<A, B> AssertRelativeEqFtz<Option<B>> for Option<A>
+where
+    A: AssertRelativeEqFtz<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> RelativeEqFtz<Result<B, F>> for Result<A, E>
+where
+    A: RelativeEqFtz<B>,
+    E: RelativeEqFtz<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    type Tolerance = Result<A::Tolerance, E::Tolerance>;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &Result<B, F>) -> usize {
+        match (self, other) {
+            (Ok(a), Ok(b)) => RelativeEqFtz::relative_flushed(a, b),
+            (Err(a), Err(b)) => RelativeEqFtz::relative_flushed(a, b),
+            _ => 0,
+        }
+    }
+}
+
+impl<A, B, E, F> AssertRelativeEqFtz<Result<B, F>> for Result<A, E>
+where
+    A: AssertRelativeEqFtz<B>,
+    E: AssertRelativeEqFtz<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> RelativeEqFtz<VecDeque<B>> for VecDeque<A>
+where
+    A: RelativeEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &VecDeque<B>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative))
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &VecDeque<B>) -> usize {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| RelativeEqFtz::relative_flushed(a, b))
+            .sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertRelativeEqFtz<VecDeque<B>> for VecDeque<A>
+where
+    A: AssertRelativeEqFtz<B>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> RelativeEqFtz<LinkedList<B>> for LinkedList<A>
+where
+    A: RelativeEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &LinkedList<B>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative))
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &LinkedList<B>) -> usize {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| RelativeEqFtz::relative_flushed(a, b))
+            .sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertRelativeEqFtz<LinkedList<B>> for LinkedList<A>
+where
+    A: AssertRelativeEqFtz<B>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K, VA, VB> RelativeEqFtz<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord,
+    VA: RelativeEqFtz<VB>,
+{
+    type Tolerance = VA::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &BTreeMap<K, VB>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative)
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &BTreeMap<K, VB>) -> usize {
+        self.iter()
+            .filter_map(|(key, a)| other.get(key).map(|b| RelativeEqFtz::relative_flushed(a, b)))
+            .sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K, VA, VB> AssertRelativeEqFtz<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + fmt::Debug,
+    VA: AssertRelativeEqFtz<VB>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, VA, VB, S> RelativeEqFtz<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash,
+    S: hash::BuildHasher,
+    VA: RelativeEqFtz<VB>,
+{
+    type Tolerance = VA::Tolerance;
+
+    #[inline]
+    fn relative_eq_ftz(&self, other: &HashMap<K, VB, S>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    RelativeEqFtz::relative_eq_ftz(a, b, max_abs_diff, max_relative)
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn relative_flushed(&self, other: &HashMap<K, VB, S>) -> usize {
+        self.iter()
+            .filter_map(|(key, a)| other.get(key).map(|b| RelativeEqFtz::relative_flushed(a, b)))
+            .sum()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, VA, VB, S> AssertRelativeEqFtz<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash + fmt::Debug,
+    S: hash::BuildHasher,
+    VA: AssertRelativeEqFtz<VB>,
+{
+    #[inline]
+    fn visit_relative_ftz<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertRelativeEqFtz::visit_relative_ftz(a, b, max_abs_diff, max_relative, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+/// Compare two finite precision floating point expressions for relative
+/// difference equality, with the subnormal numbers of both flushed to zero.
+///
+/// For more details, see the documentation for [`RelativeEqFtz`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_eq_ftz;
+/// #
+/// let simd = vec![0.5_f32, 0.0_f32];
+/// let scalar = vec![0.5_f32, 3e-39_f32];
+///
+/// assert!(relative_eq_ftz!(simd, scalar, abs_diff <= 0.0_f32, relative <= 1e-6_f32));
+/// assert!(relative_eq_ftz!(simd, scalar, relative <= 1e-6_f32, abs_diff <= 0.0_f32));
+/// ```
+#[macro_export]
+macro_rules! relative_eq_ftz {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                $crate::RelativeEqFtz::relative_eq_ftz(left_val, right_val, tol_1_val, tol_2_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::relative_eq_ftz!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+}
+
+/// Compare two finite precision floating point expressions for relative
+/// difference inequality, with the subnormal numbers of both flushed to zero.
+///
+/// For more details, see the documentation for [`RelativeEqFtz`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_ne_ftz;
+/// #
+/// let simd = [0.5_f32, 0.0_f32];
+/// let scalar = [0.5_f32, 1e-30_f32];
+///
+/// assert!(relative_ne_ftz!(simd, scalar, abs_diff <= 0.0_f32, relative <= 1e-6_f32));
+/// ```
+#[macro_export]
+macro_rules! relative_ne_ftz {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                $crate::RelativeEqFtz::relative_ne_ftz(left_val, right_val, tol_1_val, tol_2_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::relative_ne_ftz!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference equal, with the subnormal numbers of both flushed to zero.
+///
+/// See the documentation for [`RelativeEqFtz`] for details about flush-to-zero
+/// comparisons. See the documentation for [`AssertRelativeEqFtz`] for details about
+/// the debugging context provided when an assertion fails.
+///
+/// A failed assertion prints the number of subnormal numbers flushed to zero,
+/// followed by the path and the values of its first failing element, the number
+/// of failing elements, and a list of the failing elements. The differences of
+/// each element are measured after flushing, and the elements that were
+/// flushed report the flushed sides, labeled `flushed`.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_eq_ftz;
+/// #
+/// let simd = [0.25_f64, -0.0_f64];
+/// let scalar = [0.25_f64, -1e-310_f64];
+///
+/// assert_relative_eq_ftz!(simd, scalar, abs_diff <= 0.0_f64, relative <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq_ftz {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeEqFtz::relative_eq_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!(
+                            "assertion failed: `relative_eq_ftz!(left, right, abs_diff <= t, relative <= t)`",
+                            "\nsubnormals flushed to zero: `{}`\n{}\n{}"
+                        ),
+                        $crate::RelativeEqFtz::relative_flushed(&*left_val, &*right_val),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertRelativeEqFtz::visit_relative_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertRelativeEqFtz::visit_relative_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeEqFtz::relative_eq_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!(
+                            "assertion failed: `relative_eq_ftz!(left, right, abs_diff <= t, relative <= t)`",
+                            "\nsubnormals flushed to zero: `{}`\n{}\n{}: {}"
+                        ),
+                        $crate::RelativeEqFtz::relative_flushed(&*left_val, &*right_val),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertRelativeEqFtz::visit_relative_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertRelativeEqFtz::visit_relative_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_relative_eq_ftz!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_relative_eq_ftz!($left, $right, abs_diff <= $tol_1, relative <= $tol_2, $($arg)+)
+    };
+}
@@ -1,7 +1,19 @@
+use crate::denominator::RelativeDenominator;
 use crate::traits::{
+    AssertAllClose,
     AssertRelativeAllEq,
+    AssertRelativeAllEqBy,
     AssertRelativeEq,
+    AssertRelativeEqBy,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::ops;
 use std::vec::Vec;
 
 impl<A, B> AssertRelativeEq<[B]> for [A]
@@ -11,6 +23,7 @@ where
     A::DebugTolerance: Sized,
 {
     type DebugAbsDiff = Option<Vec<A::DebugAbsDiff>>;
+    type DebugRelativeDiff = Option<Vec<A::DebugRelativeDiff>>;
     type DebugTolerance = Option<Vec<A::DebugTolerance>>;
 
     #[rustfmt::skip]
@@ -28,6 +41,15 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &[B]) -> Self::DebugRelativeDiff {
+        if self.len() == other.len() {
+            Some(self.iter().zip(other.iter()).map(|(a, b)| a.debug_relative_diff(b)).collect())
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &[B], max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if (self.len() == other.len()) && (self.len() == max_abs_diff.len()) {
@@ -57,6 +79,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<[B]> for [A]
@@ -94,4 +167,157 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<[B]> for [A]
+where
+    A: AssertAllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &[B],
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<[B]> for [A]
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<[B]> for [A]
+where
+    A: AssertRelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
@@ -1,9 +1,26 @@
+use crate::denominator::RelativeDenominator;
+use crate::norm::NormAccumulator;
 use crate::traits::{
+    AllClose,
+    AssertAllClose,
     AssertRelativeAllEq,
+    AssertRelativeAllEqBy,
     AssertRelativeEq,
+    AssertRelativeEqBy,
+    NormEq,
     RelativeAllEq,
+    RelativeAllEqBy,
     RelativeEq,
+    RelativeEqBy,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::ops;
 use std::boxed::Box;
 use std::collections::BTreeMap;
 use std::collections::LinkedList;
@@ -255,6 +272,7 @@ where
     B: ?Sized + Copy,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -262,6 +280,11 @@ where
         AssertRelativeEq::debug_abs_diff(&**self, &**other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &Box<B>) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(&**self, &**other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &Box<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(&**self, &**other, max_abs_diff)
@@ -271,6 +294,18 @@ where
     fn debug_relative_tolerance(&self, other: &Box<B>, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(&**self, &**other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(&**self, &**other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeEq<Rc<B>> for Rc<A>
@@ -279,6 +314,7 @@ where
     B: ?Sized + Copy,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -286,6 +322,11 @@ where
         AssertRelativeEq::debug_abs_diff(&**self, &**other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &Rc<B>) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(&**self, &**other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &Rc<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(&**self, &**other, max_abs_diff)
@@ -295,6 +336,18 @@ where
     fn debug_relative_tolerance(&self, other: &Rc<B>, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(&**self, &**other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(&**self, &**other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeEq<Arc<B>> for Arc<A>
@@ -303,6 +356,7 @@ where
     B: ?Sized + Copy,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -310,6 +364,11 @@ where
         AssertRelativeEq::debug_abs_diff(&**self, &**other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &Arc<B>) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(&**self, &**other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &Arc<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(&**self, &**other, max_abs_diff)
@@ -319,6 +378,18 @@ where
     fn debug_relative_tolerance(&self, other: &Arc<B>, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(&**self, &**other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(&**self, &**other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeEq<Vec<B>> for Vec<A>
@@ -329,6 +400,7 @@ where
     A::DebugTolerance: Sized,
 {
     type DebugAbsDiff = Option<Vec<A::DebugAbsDiff>>;
+    type DebugRelativeDiff = Option<Vec<A::DebugRelativeDiff>>;
     type DebugTolerance = Option<Vec<A::DebugTolerance>>;
 
     #[inline]
@@ -345,6 +417,20 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &Vec<B>) -> Self::DebugRelativeDiff {
+        if self.len() == other.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| AssertRelativeEq::debug_relative_diff(a, b))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &Vec<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if (self.len() == other.len()) && (self.len() == max_abs_diff.len()) {
@@ -374,6 +460,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeEq<VecDeque<B>> for VecDeque<A>
@@ -384,6 +521,7 @@ where
     A::DebugTolerance: Sized,
 {
     type DebugAbsDiff = Option<VecDeque<A::DebugAbsDiff>>;
+    type DebugRelativeDiff = Option<VecDeque<A::DebugRelativeDiff>>;
     type DebugTolerance = Option<VecDeque<A::DebugTolerance>>;
 
     #[inline]
@@ -400,6 +538,20 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &VecDeque<B>) -> Self::DebugRelativeDiff {
+        if self.len() == other.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| AssertRelativeEq::debug_relative_diff(a, b))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &VecDeque<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if (self.len() == other.len()) && (self.len() == max_abs_diff.len()) {
@@ -429,6 +581,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeEq<LinkedList<B>> for LinkedList<A>
@@ -439,6 +642,7 @@ where
     A::DebugTolerance: Sized,
 {
     type DebugAbsDiff = Option<LinkedList<A::DebugAbsDiff>>;
+    type DebugRelativeDiff = Option<LinkedList<A::DebugRelativeDiff>>;
     type DebugTolerance = Option<LinkedList<A::DebugTolerance>>;
 
     #[inline]
@@ -455,6 +659,20 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &LinkedList<B>) -> Self::DebugRelativeDiff {
+        if self.len() == other.len() {
+            Some(
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| AssertRelativeEq::debug_relative_diff(a, b))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &LinkedList<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if (self.len() == other.len()) && (self.len() == max_abs_diff.len()) {
@@ -484,6 +702,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<K, VA, VB> AssertRelativeEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
@@ -494,6 +763,7 @@ where
     VA::DebugTolerance: Sized,
 {
     type DebugAbsDiff = Option<BTreeMap<K, VA::DebugAbsDiff>>;
+    type DebugRelativeDiff = Option<BTreeMap<K, VA::DebugRelativeDiff>>;
     type DebugTolerance = Option<BTreeMap<K, VA::DebugTolerance>>;
 
     #[inline]
@@ -509,6 +779,19 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &BTreeMap<K, VB>) -> Self::DebugRelativeDiff {
+        if self.len() == other.len() {
+            let mut result = BTreeMap::new();
+            for (key, v) in self {
+                result.insert(key.clone(), v.debug_relative_diff(other.get(key)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &BTreeMap<K, VB>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if self.len() == other.len() && self.len() == max_abs_diff.len() {
@@ -534,6 +817,56 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| {
+                match (other.get(key), max_abs_diff.get(key), max_relative.get(key)) {
+                    (Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                        AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+                    }
+                    _ => StructureMismatch::MissingKey.report(path, visitor),
+                }
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<Box<B>> for Box<A>
@@ -552,6 +885,18 @@ where
     fn debug_relative_all_tolerance(&self, other: &Box<B>, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(&**self, &**other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(&**self, &**other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<Rc<B>> for Rc<A>
@@ -570,6 +915,18 @@ where
     fn debug_relative_all_tolerance(&self, other: &Rc<B>, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(&**self, &**other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(&**self, &**other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<Arc<B>> for Arc<A>
@@ -588,6 +945,18 @@ where
     fn debug_relative_all_tolerance(&self, other: &Arc<B>, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(&**self, &**other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(&**self, &**other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<Vec<B>> for Vec<A>
@@ -625,6 +994,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<VecDeque<B>> for VecDeque<A>
@@ -662,6 +1058,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<LinkedList<B>> for LinkedList<A>
@@ -699,6 +1122,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<K, VA, VB> AssertRelativeAllEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
@@ -734,4 +1184,1130 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AllClose<Box<B>> for Box<A>
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &Box<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(&**self, &**other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<Rc<B>> for Rc<A>
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &Rc<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(&**self, &**other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<Arc<B>> for Arc<A>
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &Arc<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(&**self, &**other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<Vec<B>> for Vec<A>
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &Vec<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| AllClose::all_close(a, b, rtol, atol, equal_nan))
+    }
+}
+
+impl<A, B> AllClose<VecDeque<B>> for VecDeque<A>
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &VecDeque<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| AllClose::all_close(a, b, rtol, atol, equal_nan))
+    }
+}
+
+impl<A, B> AllClose<LinkedList<B>> for LinkedList<A>
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &LinkedList<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| AllClose::all_close(a, b, rtol, atol, equal_nan))
+    }
+}
+
+impl<K, VA, VB> AllClose<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord,
+    VA: AllClose<VB>,
+{
+    type CloseTolerance = VA::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &BTreeMap<K, VB>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    AllClose::all_close(a, b, rtol, atol, equal_nan)
+                } else {
+                    false
+                }
+            })
+    }
+}
+
+impl<A, B> AssertAllClose<Box<B>> for Box<A>
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Box<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(&**self, &**other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B> AssertAllClose<Rc<B>> for Rc<A>
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Rc<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(&**self, &**other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B> AssertAllClose<Arc<B>> for Arc<A>
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Arc<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(&**self, &**other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B> AssertAllClose<Vec<B>> for Vec<A>
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Vec<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<VecDeque<B>> for VecDeque<A>
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<LinkedList<B>> for LinkedList<A>
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<K, VA, VB> AssertAllClose<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + Clone + fmt::Debug,
+    VA: AssertAllClose<VB>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> RelativeEqBy<Box<B>> for Box<A>
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &Box<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(&**self, &**other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<Rc<B>> for Rc<A>
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(&**self, &**other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<Arc<B>> for Arc<A>
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(&**self, &**other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<Vec<B>> for Vec<A>
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == max_abs_diff.len()
+            && self.len() == max_relative.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(max_abs_diff.iter())
+                .zip(max_relative.iter())
+                .all(|(((a, b), abs_tol), rel_tol)| RelativeEqBy::relative_eq_by(a, b, abs_tol, rel_tol, denominator))
+    }
+}
+
+impl<A, B> RelativeEqBy<VecDeque<B>> for VecDeque<A>
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == max_abs_diff.len()
+            && self.len() == max_relative.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(max_abs_diff.iter())
+                .zip(max_relative.iter())
+                .all(|(((a, b), abs_tol), rel_tol)| RelativeEqBy::relative_eq_by(a, b, abs_tol, rel_tol, denominator))
+    }
+}
+
+impl<A, B> RelativeEqBy<LinkedList<B>> for LinkedList<A>
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == max_abs_diff.len()
+            && self.len() == max_relative.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(max_abs_diff.iter())
+                .zip(max_relative.iter())
+                .all(|(((a, b), abs_tol), rel_tol)| RelativeEqBy::relative_eq_by(a, b, abs_tol, rel_tol, denominator))
+    }
+}
+
+impl<K, VA, VB> RelativeEqBy<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord,
+    VA: RelativeEqBy<VB>,
+    VA::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == max_abs_diff.len()
+            && self.len() == max_relative.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    if let (Some(abs_tol), Some(rel_tol)) = (max_abs_diff.get(key), max_relative.get(key)) {
+                        RelativeEqBy::relative_eq_by(a, b, abs_tol, rel_tol, denominator)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+}
+
+impl<A, B> RelativeAllEqBy<Box<B>> for Box<A>
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &Box<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(&**self, &**other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<Rc<B>> for Rc<A>
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(&**self, &**other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<Arc<B>> for Arc<A>
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(&**self, &**other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<Vec<B>> for Vec<A>
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator))
+    }
+}
+
+impl<A, B> RelativeAllEqBy<VecDeque<B>> for VecDeque<A>
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator))
+    }
+}
+
+impl<A, B> RelativeAllEqBy<LinkedList<B>> for LinkedList<A>
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator))
+    }
+}
+
+impl<K, VA, VB> RelativeAllEqBy<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord,
+    VA: RelativeAllEqBy<VB>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator)
+                } else {
+                    false
+                }
+            })
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<Box<B>> for Box<A>
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(&**self, &**other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<Rc<B>> for Rc<A>
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(&**self, &**other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<Arc<B>> for Arc<A>
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(&**self, &**other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<Vec<B>> for Vec<A>
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<VecDeque<B>> for VecDeque<A>
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<LinkedList<B>> for LinkedList<A>
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_relative)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_relative.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<K, VA, VB> AssertRelativeEqBy<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + fmt::Debug,
+    VA: AssertRelativeEqBy<VB>,
+    VA::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| {
+                match (other.get(key), max_abs_diff.get(key), max_relative.get(key)) {
+                    (Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                        AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+                    }
+                    _ => StructureMismatch::MissingKey.report(path, visitor),
+                }
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<Box<B>> for Box<A>
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(&**self, &**other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<Rc<B>> for Rc<A>
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(&**self, &**other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<Arc<B>> for Arc<A>
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(&**self, &**other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<Vec<B>> for Vec<A>
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<VecDeque<B>> for VecDeque<A>
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<LinkedList<B>> for LinkedList<A>
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<K, VA, VB> AssertRelativeAllEqBy<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + fmt::Debug,
+    VA: AssertRelativeAllEqBy<VB>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> NormEq<Vec<B>> for Vec<A>
+where
+    A: NormEq<B>,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &Vec<B>, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        NormEq::accumulate_norms(self.as_slice(), other.as_slice(), accumulator)
+    }
+}
+
+impl<A, B> NormEq<VecDeque<B>> for VecDeque<A>
+where
+    A: NormEq<B>,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &VecDeque<B>, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| NormEq::accumulate_norms(a, b, accumulator))
+    }
 }
@@ -1,7 +1,15 @@
+use crate::denominator::RelativeDenominator;
+use crate::norm::NormAccumulator;
+use crate::ordering::RelativeOrd;
 use crate::traits::{
+    AllClose,
+    NormEq,
     RelativeAllEq,
+    RelativeAllEqBy,
     RelativeEq,
+    RelativeEqBy,
 };
+use approx_cmp_report::SearchMatch;
 
 impl<A, B> RelativeEq<[B]> for [A]
 where
@@ -40,3 +48,195 @@ where
                 .all(|(a, b)| a.relative_all_eq(b, max_abs_diff, max_relative))
     }
 }
+
+impl<A, B> AllClose<[B]> for [A]
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn all_close(&self, other: &[B], rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.all_close(b, rtol, atol, equal_nan))
+    }
+}
+
+impl<A, B> RelativeEqBy<[B]> for [A]
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == max_abs_diff.len()
+            && self.len() == max_relative.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .zip(max_abs_diff.iter())
+                .zip(max_relative.iter())
+                .all(|(((a, b), abs_tol), rel_tol)| a.relative_eq_by(b, abs_tol, rel_tol, denominator))
+    }
+}
+
+impl<A, B> RelativeAllEqBy<[B]> for [A]
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn relative_all_eq_by(&self, other: &[B], max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance, denominator: RelativeDenominator) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.relative_all_eq_by(b, max_abs_diff, max_relative, denominator))
+    }
+}
+
+impl<A, B> NormEq<[B]> for [A]
+where
+    A: NormEq<B>,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[rustfmt::skip]
+    #[inline]
+    fn accumulate_norms(&self, other: &[B], accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| a.accumulate_norms(b, accumulator))
+    }
+}
+
+/// A data type for searching slices for the elements relative equal to a value.
+///
+/// The search compares every element with the value using the semantics of
+/// [`RelativeEq`], e.g. to find the sample of a time series at `t ≈ 0.35`. When
+/// several elements match, the search reports them as
+/// [`SearchMatch::Ambiguous`] instead of returning one of them.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     RelativeSearch,
+/// #     SearchMatch,
+/// # };
+/// #
+/// let times = [0.0_f64, 0.1_f64, 0.2_f64, 0.1_f64 + 0.2_f64, 0.4_f64];
+///
+/// assert_eq!(times.relative_binary_search(&0.3_f64, &0.0_f64, &0.03_f64), SearchMatch::Found(3));
+/// assert_eq!(times.relative_position(&0.3_f64, &0.0_f64, &0.03_f64), SearchMatch::Found(3));
+/// assert!(times.relative_contains(&0.3_f64, &0.0_f64, &0.03_f64));
+/// assert!(!times.relative_contains(&0.35_f64, &0.0_f64, &0.03_f64));
+///
+/// let samples = vec![0.3_f64, 0.349_f64, 0.351_f64, 0.4_f64];
+///
+/// assert_eq!(
+///     samples.relative_binary_search(&0.35_f64, &0.0_f64, &0.03_f64),
+///     SearchMatch::Ambiguous { first: 1, last: 2, count: 2 }
+/// );
+/// ```
+pub trait RelativeSearch<A> {
+    /// Search a sorted slice for the elements relative equal to `value` with a
+    /// binary search.
+    ///
+    /// The slice must be sorted in increasing order, so that the elements matching
+    /// `value` are contiguous. If the slice is not sorted, the outcome is
+    /// unspecified.
+    fn relative_binary_search<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as RelativeEq<B>>::Tolerance,
+        max_relative: &<A as RelativeEq<B>>::Tolerance,
+    ) -> SearchMatch
+    where
+        A: RelativeOrd<B>;
+
+    /// Search the slice for the elements relative equal to `value` with a linear
+    /// search.
+    ///
+    /// The slice does not need to be sorted.
+    fn relative_position<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as RelativeEq<B>>::Tolerance,
+        max_relative: &<A as RelativeEq<B>>::Tolerance,
+    ) -> SearchMatch
+    where
+        A: RelativeEq<B>;
+
+    /// Determine whether an element of the slice is relative equal to `value`.
+    fn relative_contains<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as RelativeEq<B>>::Tolerance,
+        max_relative: &<A as RelativeEq<B>>::Tolerance,
+    ) -> bool
+    where
+        A: RelativeEq<B>;
+}
+
+impl<A> RelativeSearch<A> for [A] {
+    #[inline]
+    fn relative_binary_search<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as RelativeEq<B>>::Tolerance,
+        max_relative: &<A as RelativeEq<B>>::Tolerance,
+    ) -> SearchMatch
+    where
+        A: RelativeOrd<B>,
+    {
+        let start = self.partition_point(|element| element.relative_lt(value, max_abs_diff, max_relative));
+        let end = start + self[start..].partition_point(|element| element.relative_le(value, max_abs_diff, max_relative));
+
+        SearchMatch::from_indices(start..end)
+    }
+
+    #[inline]
+    fn relative_position<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as RelativeEq<B>>::Tolerance,
+        max_relative: &<A as RelativeEq<B>>::Tolerance,
+    ) -> SearchMatch
+    where
+        A: RelativeEq<B>,
+    {
+        SearchMatch::from_indices(
+            self.iter()
+                .enumerate()
+                .filter(|&(_, element)| element.relative_eq(value, max_abs_diff, max_relative))
+                .map(|(i, _)| i),
+        )
+    }
+
+    #[inline]
+    fn relative_contains<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as RelativeEq<B>>::Tolerance,
+        max_relative: &<A as RelativeEq<B>>::Tolerance,
+    ) -> bool
+    where
+        A: RelativeEq<B>,
+    {
+        self.iter().any(|element| element.relative_eq(value, max_abs_diff, max_relative))
+    }
+}
@@ -1,10 +1,24 @@
 use crate::{
+    AllClose,
+    AssertAllClose,
     AssertRelativeAllEq,
+    AssertRelativeAllEqBy,
     AssertRelativeEq,
+    AssertRelativeEqBy,
     RelativeAllEq,
+    RelativeAllEqBy,
+    RelativeDenominator,
     RelativeEq,
+    RelativeEqBy,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    Visitor,
+};
+
 use core::fmt;
+use core::ops;
 
 impl RelativeEq for () {
     type Tolerance = ();
@@ -26,11 +40,15 @@ impl RelativeAllEq for () {
 
 impl AssertRelativeEq for () {
     type DebugAbsDiff = ();
+    type DebugRelativeDiff = ();
     type DebugTolerance = ();
 
     #[inline]
     fn debug_abs_diff(&self, _other: &()) -> Self::DebugAbsDiff {}
 
+    #[inline]
+    fn debug_relative_diff(&self, _other: &()) -> Self::DebugRelativeDiff {}
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, _other: &(), _max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {}
 
@@ -74,6 +92,7 @@ macro_rules! impl_relative_tuple {
             $($T::DebugTolerance: Sized,)+
         {
             type DebugAbsDiff = ($($T::DebugAbsDiff,)+);
+            type DebugRelativeDiff = ($($T::DebugRelativeDiff,)+);
             type DebugTolerance = ($($T::DebugTolerance,)+);
 
             #[inline]
@@ -81,6 +100,11 @@ macro_rules! impl_relative_tuple {
                 ($(self.$idx.debug_abs_diff(&other.$idx),)+)
             }
 
+            #[inline]
+            fn debug_relative_diff(&self, other: &Self) -> Self::DebugRelativeDiff {
+                ($(self.$idx.debug_relative_diff(&other.$idx),)+)
+            }
+
             #[inline]
             fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
                 ($(self.$idx.debug_abs_diff_tolerance(&other.$idx, &max_abs_diff.$idx),)+)
@@ -90,6 +114,60 @@ macro_rules! impl_relative_tuple {
             fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
                 ($(self.$idx.debug_relative_tolerance(&other.$idx, &max_relative.$idx),)+)
             }
+
+            #[inline]
+            fn visit_relative<'a>(
+                &'a self,
+                other: &Self,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                $(
+                    path.with_segment(PathSegment::Field(stringify!($idx)), |path| {
+                        AssertRelativeEq::visit_relative(&self.$idx, &other.$idx, &max_abs_diff.$idx, &max_relative.$idx, path, visitor)
+                    })?;
+                )+
+
+                ops::ControlFlow::Continue(())
+            }
+        }
+
+        impl<$($T:RelativeEqBy),+> RelativeEqBy for ($($T,)+)
+        where
+            last_type!($($T,)+): ?Sized,
+            $($T::Tolerance: Sized,)+
+        {
+            #[inline]
+            fn relative_eq_by(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance, denominator: RelativeDenominator) -> bool {
+                $(self.$idx.relative_eq_by(&other.$idx, &max_abs_diff.$idx, &max_relative.$idx, denominator))&&+
+            }
+        }
+
+        impl<$($T:AssertRelativeEqBy + fmt::Debug),+> AssertRelativeEqBy for ($($T,)+)
+        where
+            last_type!($($T,)+): ?Sized,
+            $($T::Tolerance: Sized,)+
+        {
+            #[inline]
+            fn visit_relative_by<'a>(
+                &'a self,
+                other: &Self,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                $(
+                    path.with_segment(PathSegment::Field(stringify!($idx)), |path| {
+                        AssertRelativeEqBy::visit_relative_by(&self.$idx, &other.$idx, &max_abs_diff.$idx, &max_relative.$idx, denominator, path, visitor)
+                    })?;
+                )+
+
+                ops::ControlFlow::Continue(())
+            }
         }
     )+};
 }
@@ -231,6 +309,22 @@ where
     fn debug_relative_all_tolerance(&self, other: &(B,), max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         (self.0.debug_relative_all_tolerance(&other.0, max_relative),)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B,),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B)> for (A, A)
@@ -266,6 +360,25 @@ where
             self.1.debug_relative_all_tolerance(&other.1, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B)> for (A, A, A)
@@ -305,6 +418,28 @@ where
             self.2.debug_relative_all_tolerance(&other.2, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B)> for (A, A, A, A)
@@ -352,6 +487,31 @@ where
             self.3.debug_relative_all_tolerance(&other.3, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B)> for (A, A, A, A, A)
@@ -403,6 +563,34 @@ where
             self.4.debug_relative_all_tolerance(&other.4, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
@@ -458,6 +646,37 @@ where
             self.5.debug_relative_all_tolerance(&other.5, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
@@ -517,6 +736,40 @@ where
             self.6.debug_relative_all_tolerance(&other.6, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.6, &other.6, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
@@ -585,6 +838,43 @@ where
             self.7.debug_relative_all_tolerance(&other.7, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.6, &other.6, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.7, &other.7, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
@@ -665,6 +955,46 @@ where
             self.8.debug_relative_all_tolerance(&other.8, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.6, &other.6, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.7, &other.7, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.8, &other.8, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
@@ -749,6 +1079,49 @@ where
             self.9.debug_relative_all_tolerance(&other.9, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.6, &other.6, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.7, &other.7, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.8, &other.8, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.9, &other.9, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
@@ -837,6 +1210,52 @@ where
             self.10.debug_relative_all_tolerance(&other.10, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.6, &other.6, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.7, &other.7, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.8, &other.8, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.9, &other.9, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.10, &other.10, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> RelativeAllEq<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
@@ -928,4 +1347,1513 @@ where
             self.11.debug_relative_all_tolerance(&other.11, max_relative),
         )
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.0, &other.0, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.1, &other.1, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.2, &other.2, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.3, &other.3, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.4, &other.4, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.5, &other.5, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.6, &other.6, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.7, &other.7, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.8, &other.8, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.9, &other.9, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.10, &other.10, max_abs_diff, max_relative, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("11"), |path| {
+            AssertRelativeAllEq::visit_relative_all(&self.11, &other.11, max_abs_diff, max_relative, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl AllClose for () {
+    type CloseTolerance = ();
+
+    #[inline]
+    fn all_close(&self, _other: &(), _rtol: &Self::CloseTolerance, _atol: &Self::CloseTolerance, _equal_nan: bool) -> bool {
+        true
+    }
+}
+
+impl<A, B> AllClose<(B,)> for (A,)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B,), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B)> for (A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B, B), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan) && self.1.all_close(&other.1, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B)> for (A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B, B, B), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B)> for (A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B, B, B, B), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B)> for (A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B, B, B, B, B), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B, B, B, B, B, B), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &(B, B, B, B, B, B, B), rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+            && self.6.all_close(&other.6, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(
+        &self,
+        other: &(B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+    ) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+            && self.6.all_close(&other.6, rtol, atol, equal_nan)
+            && self.7.all_close(&other.7, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+    ) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+            && self.6.all_close(&other.6, rtol, atol, equal_nan)
+            && self.7.all_close(&other.7, rtol, atol, equal_nan)
+            && self.8.all_close(&other.8, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+    ) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+            && self.6.all_close(&other.6, rtol, atol, equal_nan)
+            && self.7.all_close(&other.7, rtol, atol, equal_nan)
+            && self.8.all_close(&other.8, rtol, atol, equal_nan)
+            && self.9.all_close(&other.9, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+    ) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+            && self.6.all_close(&other.6, rtol, atol, equal_nan)
+            && self.7.all_close(&other.7, rtol, atol, equal_nan)
+            && self.8.all_close(&other.8, rtol, atol, equal_nan)
+            && self.9.all_close(&other.9, rtol, atol, equal_nan)
+            && self.10.all_close(&other.10, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+    ) -> bool {
+        self.0.all_close(&other.0, rtol, atol, equal_nan)
+            && self.1.all_close(&other.1, rtol, atol, equal_nan)
+            && self.2.all_close(&other.2, rtol, atol, equal_nan)
+            && self.3.all_close(&other.3, rtol, atol, equal_nan)
+            && self.4.all_close(&other.4, rtol, atol, equal_nan)
+            && self.5.all_close(&other.5, rtol, atol, equal_nan)
+            && self.6.all_close(&other.6, rtol, atol, equal_nan)
+            && self.7.all_close(&other.7, rtol, atol, equal_nan)
+            && self.8.all_close(&other.8, rtol, atol, equal_nan)
+            && self.9.all_close(&other.9, rtol, atol, equal_nan)
+            && self.10.all_close(&other.10, rtol, atol, equal_nan)
+    }
+}
+
+impl AssertAllClose for () {}
+
+impl<A, B> AssertAllClose<(B,)> for (A,)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B,),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B)> for (A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B)> for (A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B)> for (A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B)> for (A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAllClose::visit_all_close(&self.6, &other.6, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAllClose::visit_all_close(&self.6, &other.6, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAllClose::visit_all_close(&self.7, &other.7, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAllClose::visit_all_close(&self.6, &other.6, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAllClose::visit_all_close(&self.7, &other.7, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAllClose::visit_all_close(&self.8, &other.8, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAllClose::visit_all_close(&self.6, &other.6, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAllClose::visit_all_close(&self.7, &other.7, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAllClose::visit_all_close(&self.8, &other.8, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertAllClose::visit_all_close(&self.9, &other.9, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAllClose::visit_all_close(&self.6, &other.6, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAllClose::visit_all_close(&self.7, &other.7, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAllClose::visit_all_close(&self.8, &other.8, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertAllClose::visit_all_close(&self.9, &other.9, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertAllClose::visit_all_close(&self.10, &other.10, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertAllClose::visit_all_close(&self.0, &other.0, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertAllClose::visit_all_close(&self.1, &other.1, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertAllClose::visit_all_close(&self.2, &other.2, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertAllClose::visit_all_close(&self.3, &other.3, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertAllClose::visit_all_close(&self.4, &other.4, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertAllClose::visit_all_close(&self.5, &other.5, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertAllClose::visit_all_close(&self.6, &other.6, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertAllClose::visit_all_close(&self.7, &other.7, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertAllClose::visit_all_close(&self.8, &other.8, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertAllClose::visit_all_close(&self.9, &other.9, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertAllClose::visit_all_close(&self.10, &other.10, rtol, atol, equal_nan, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("11"), |path| {
+            AssertAllClose::visit_all_close(&self.11, &other.11, rtol, atol, equal_nan, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl RelativeEqBy for () {
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        _other: &(),
+        _max_abs_diff: &Self::Tolerance,
+        _max_relative: &Self::Tolerance,
+        _denominator: RelativeDenominator,
+    ) -> bool {
+        true
+    }
+}
+
+impl RelativeAllEqBy for () {
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        _other: &(),
+        _max_abs_diff: &Self::AllTolerance,
+        _max_relative: &Self::AllTolerance,
+        _denominator: RelativeDenominator,
+    ) -> bool {
+        true
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B,)> for (A,)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B,),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B)> for (A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B)> for (A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B)> for (A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B)> for (A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+            && self.6.relative_all_eq_by(&other.6, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+            && self.6.relative_all_eq_by(&other.6, max_abs_diff, max_relative, denominator)
+            && self.7.relative_all_eq_by(&other.7, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+            && self.6.relative_all_eq_by(&other.6, max_abs_diff, max_relative, denominator)
+            && self.7.relative_all_eq_by(&other.7, max_abs_diff, max_relative, denominator)
+            && self.8.relative_all_eq_by(&other.8, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+            && self.6.relative_all_eq_by(&other.6, max_abs_diff, max_relative, denominator)
+            && self.7.relative_all_eq_by(&other.7, max_abs_diff, max_relative, denominator)
+            && self.8.relative_all_eq_by(&other.8, max_abs_diff, max_relative, denominator)
+            && self.9.relative_all_eq_by(&other.9, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+            && self.6.relative_all_eq_by(&other.6, max_abs_diff, max_relative, denominator)
+            && self.7.relative_all_eq_by(&other.7, max_abs_diff, max_relative, denominator)
+            && self.8.relative_all_eq_by(&other.8, max_abs_diff, max_relative, denominator)
+            && self.9.relative_all_eq_by(&other.9, max_abs_diff, max_relative, denominator)
+            && self.10.relative_all_eq_by(&other.10, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.0.relative_all_eq_by(&other.0, max_abs_diff, max_relative, denominator)
+            && self.1.relative_all_eq_by(&other.1, max_abs_diff, max_relative, denominator)
+            && self.2.relative_all_eq_by(&other.2, max_abs_diff, max_relative, denominator)
+            && self.3.relative_all_eq_by(&other.3, max_abs_diff, max_relative, denominator)
+            && self.4.relative_all_eq_by(&other.4, max_abs_diff, max_relative, denominator)
+            && self.5.relative_all_eq_by(&other.5, max_abs_diff, max_relative, denominator)
+            && self.6.relative_all_eq_by(&other.6, max_abs_diff, max_relative, denominator)
+            && self.7.relative_all_eq_by(&other.7, max_abs_diff, max_relative, denominator)
+            && self.8.relative_all_eq_by(&other.8, max_abs_diff, max_relative, denominator)
+            && self.9.relative_all_eq_by(&other.9, max_abs_diff, max_relative, denominator)
+            && self.10.relative_all_eq_by(&other.10, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl AssertRelativeEqBy for () {}
+
+impl AssertRelativeAllEqBy for () {}
+
+impl<A, B> AssertRelativeAllEqBy<(B,)> for (A,)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B,),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B)> for (A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B)> for (A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B)> for (A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B)> for (A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.6, &other.6, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.6, &other.6, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.7, &other.7, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.6, &other.6, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.7, &other.7, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.8, &other.8, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.6, &other.6, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.7, &other.7, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.8, &other.8, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.9, &other.9, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.6, &other.6, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.7, &other.7, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.8, &other.8, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.9, &other.9, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.10, &other.10, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.0, &other.0, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.1, &other.1, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.2, &other.2, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.3, &other.3, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.4, &other.4, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.5, &other.5, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.6, &other.6, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.7, &other.7, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.8, &other.8, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.9, &other.9, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.10, &other.10, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("11"), |path| {
+            AssertRelativeAllEqBy::visit_relative_all_by(&self.11, &other.11, max_abs_diff, max_relative, denominator, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
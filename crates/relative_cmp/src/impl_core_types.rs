@@ -1,12 +1,33 @@
+use crate::denominator::RelativeDenominator;
+use crate::explain::RelativeExplain;
+use crate::norm::NormAccumulator;
+use crate::relative;
 use crate::traits::{
+    AllClose,
+    AssertAllClose,
     AssertRelativeAllEq,
+    AssertRelativeAllEqBy,
     AssertRelativeEq,
+    AssertRelativeEqBy,
+    NormEq,
     RelativeAllEq,
+    RelativeAllEqBy,
     RelativeEq,
+    RelativeEqBy,
+};
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
 };
 
 use core::cell;
 use core::mem;
+use core::ops;
 
 #[inline(always)]
 fn uninit_array<T, const N: usize>() -> [mem::MaybeUninit<T>; N] {
@@ -25,41 +46,59 @@ macro_rules! impl_relative_eq_float {
 
             #[inline]
             fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
-                // If `self` and `other` are finite and bitwise identical, They are relatively
-                // equal. If `self` and `other` are infinite and bitwise identical, they are
-                // the same kind of infinity, and therefore also equal.
+                relative::relative_eq(*self, *other, *max_abs_diff, *max_relative)
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_float!(f32, f64);
+
+macro_rules! impl_relative_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl RelativeEq<$Rhs> for $T {
+            type Tolerance = f64;
+
+            #[inline]
+            fn relative_eq(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+                // Every `f32` widens to an `f64` exactly, so the absolute and relative
+                // differences are measured in `f64` precision.
+                relative::relative_eq(f64::from(*self), f64::from(*other), *max_abs_diff, *max_relative)
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_relative_eq_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeEq for $T {
+            type Tolerance = f64;
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+                // Equal integers are relatively equal with any tolerance.
                 if self == other {
                     return true;
                 }
 
-                // If `self` and `other` are finite, this clause does not apply. If one
-                // of `self` and `other` is finite, and the other one is infinite, they
-                // are not equal.
-                if $T::is_infinite(*self) || $T::is_infinite(*other) {
-                    return false;
-                }
-
-                // Now check whether `self` and `other` are really close together.
-                // This is necessary when `self` and `other` are near zero.
-                let abs_diff = $T::abs(self - other);
+                // The distance between any two integers fits in the unsigned integer type
+                // of the same width, so it never overflows.
+                let abs_diff = $T::abs_diff(*self, *other) as f64;
                 if abs_diff <= *max_abs_diff {
                     return true;
                 }
 
-                // Finally, if the other cases have failed, we check their relative
-                // absolute difference against the largest absolute value of `other` and
-                // `self`.
-                let abs_self = $T::abs(*self);
-                let abs_other = $T::abs(*other);
-                let largest = $T::max(abs_self, abs_other);
+                let largest = f64::max(f64::abs(*self as f64), f64::abs(*other as f64));
 
-                return abs_diff <= largest * max_relative
+                abs_diff <= largest * max_relative
             }
         }
     )*};
 }
 
-impl_relative_eq_float!(f32, f64);
+impl_relative_eq_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
 impl<A, B> RelativeEq<&B> for &A
 where
@@ -175,6 +214,25 @@ where
     }
 }
 
+impl<A, B, E, F> RelativeEq<Result<B, F>> for Result<A, E>
+where
+    A: RelativeEq<B>,
+    E: RelativeEq<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    type Tolerance = Result<A::Tolerance, E::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => RelativeEq::relative_eq(a, b, max_abs_diff, max_relative),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => RelativeEq::relative_eq(a, b, max_abs_diff, max_relative),
+            _ => false,
+        }
+    }
+}
+
 impl<A, B> RelativeEq<cell::OnceCell<B>> for cell::OnceCell<A>
 where
     A: RelativeEq<B>,
@@ -207,6 +265,36 @@ macro_rules! impl_relative_all_eq_float {
 
 impl_relative_all_eq_float!(f32, f64);
 
+macro_rules! impl_relative_all_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl RelativeAllEq<$Rhs> for $T {
+            type AllTolerance = f64;
+
+            #[inline]
+            fn relative_all_eq(&self, other: &$Rhs, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+                RelativeEq::relative_eq(self, other, max_abs_diff, max_relative)
+            }
+        }
+    )*}
+}
+
+impl_relative_all_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_relative_all_eq_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeAllEq for $T {
+            type AllTolerance = f64;
+
+            #[inline]
+            fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+                self.relative_eq(other, max_abs_diff, max_relative)
+            }
+        }
+    )*}
+}
+
+impl_relative_all_eq_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 impl<A, B> RelativeAllEq<&B> for &A
 where
     A: RelativeAllEq<B> + ?Sized,
@@ -315,6 +403,25 @@ where
     }
 }
 
+impl<A, B, E, F> RelativeAllEq<Result<B, F>> for Result<A, E>
+where
+    A: RelativeAllEq<B>,
+    E: RelativeAllEq<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+{
+    type AllTolerance = Result<A::AllTolerance, E::AllTolerance>;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Result<B, F>, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => RelativeAllEq::relative_all_eq(a, b, max_abs_diff, max_relative),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => RelativeAllEq::relative_all_eq(a, b, max_abs_diff, max_relative),
+            _ => false,
+        }
+    }
+}
+
 impl<A, B> RelativeAllEq<cell::OnceCell<B>> for cell::OnceCell<A>
 where
     A: RelativeAllEq<B>,
@@ -336,6 +443,7 @@ macro_rules! impl_assert_relative_eq_float {
     ($($T:ident),* $(,)?) => {$(
         impl AssertRelativeEq for $T {
             type DebugAbsDiff = $T;
+            type DebugRelativeDiff = $T;
             type DebugTolerance = Self::Tolerance;
 
             #[inline]
@@ -343,6 +451,11 @@ macro_rules! impl_assert_relative_eq_float {
                 $T::abs(self - other)
             }
 
+            #[inline]
+            fn debug_relative_diff(&self, other: &Self) -> Self::DebugRelativeDiff {
+                relative::relative_diff(*self, *other)
+            }
+
             #[inline]
             fn debug_abs_diff_tolerance(&self, _other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
                 *max_abs_diff
@@ -352,18 +465,166 @@ macro_rules! impl_assert_relative_eq_float {
             fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
                 Self::max(Self::abs(*self), Self::abs(*other)) * max_relative
             }
+
+            #[inline]
+            fn visit_relative<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        RelativeEq::relative_eq(self, other, max_abs_diff, max_relative),
+                        &[
+                            ("abs_diff", &AssertRelativeEq::debug_abs_diff(self, other)),
+                            ("relative", &AssertRelativeEq::debug_relative_diff(self, other)),
+                            ("branch", &RelativeExplain::explain_relative(self, other, max_abs_diff, max_relative)),
+                        ],
+                        &[("abs_diff", &AssertRelativeEq::debug_abs_diff_tolerance(self, other, max_abs_diff)), ("relative", &AssertRelativeEq::debug_relative_tolerance(self, other, max_relative))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     )*};
 }
 
 impl_assert_relative_eq_float!(f32, f64);
 
+macro_rules! impl_assert_relative_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertRelativeEq<$Rhs> for $T {
+            type DebugAbsDiff = f64;
+            type DebugRelativeDiff = f64;
+            type DebugTolerance = f64;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$Rhs) -> Self::DebugAbsDiff {
+                f64::abs(f64::from(*self) - f64::from(*other))
+            }
+
+            #[inline]
+            fn debug_relative_diff(&self, other: &$Rhs) -> Self::DebugRelativeDiff {
+                relative::relative_diff(f64::from(*self), f64::from(*other))
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, _other: &$Rhs, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                *max_abs_diff
+            }
+
+            #[inline]
+            fn debug_relative_tolerance(&self, other: &$Rhs, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+                f64::max(f64::abs(f64::from(*self)), f64::abs(f64::from(*other))) * max_relative
+            }
+
+            #[inline]
+            fn visit_relative<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = relative::explain_relative(f64::from(*self), f64::from(*other), *max_abs_diff, *max_relative);
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &AssertRelativeEq::debug_abs_diff(self, other)),
+                            ("relative", &AssertRelativeEq::debug_relative_diff(self, other)),
+                            ("branch", &explanation),
+                        ],
+                        &[("abs_diff", &AssertRelativeEq::debug_abs_diff_tolerance(self, other, max_abs_diff)), ("relative", &AssertRelativeEq::debug_relative_tolerance(self, other, max_relative))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_relative_eq_integer {
+    ($($T:ident => $U:ident),* $(,)?) => {$(
+        impl AssertRelativeEq for $T {
+            type DebugAbsDiff = $U;
+            type DebugRelativeDiff = f64;
+            type DebugTolerance = Self::Tolerance;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $T::abs_diff(*self, *other)
+            }
+
+            #[inline]
+            fn debug_relative_diff(&self, other: &Self) -> Self::DebugRelativeDiff {
+                if self == other {
+                    return 0.0;
+                }
+
+                let abs_diff = $T::abs_diff(*self, *other) as f64;
+                let largest = f64::max(f64::abs(*self as f64), f64::abs(*other as f64));
+
+                abs_diff / largest
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, _other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                *max_abs_diff
+            }
+
+            #[inline]
+            fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+                f64::max(f64::abs(*self as f64), f64::abs(*other as f64)) * max_relative
+            }
+
+            #[inline]
+            fn visit_relative<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        RelativeEq::relative_eq(self, other, max_abs_diff, max_relative),
+                        &[
+                            ("abs_diff", &AssertRelativeEq::debug_abs_diff(self, other)),
+                            ("relative", &AssertRelativeEq::debug_relative_diff(self, other)),
+                        ],
+                        &[("abs_diff", &AssertRelativeEq::debug_abs_diff_tolerance(self, other, max_abs_diff)), ("relative", &AssertRelativeEq::debug_relative_tolerance(self, other, max_relative))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_eq_integer!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize, i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
 impl<A, B> AssertRelativeEq<&B> for &A
 where
     A: AssertRelativeEq<B> + ?Sized,
     B: ?Sized,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -371,6 +632,11 @@ where
         AssertRelativeEq::debug_abs_diff(*self, *other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &&B) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(*self, *other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &&B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
@@ -380,6 +646,18 @@ where
     fn debug_relative_tolerance(&self, other: &&B, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeEq<&mut B> for &A
@@ -388,6 +666,7 @@ where
     B: ?Sized,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -395,6 +674,11 @@ where
         AssertRelativeEq::debug_abs_diff(*self, *other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &&mut B) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(*self, *other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &&mut B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
@@ -404,6 +688,18 @@ where
     fn debug_relative_tolerance(&self, other: &&mut B, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeEq<&B> for &mut A
@@ -412,6 +708,7 @@ where
     B: ?Sized,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -419,6 +716,11 @@ where
         AssertRelativeEq::debug_abs_diff(*self, *other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &&B) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(*self, *other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &&B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
@@ -428,6 +730,18 @@ where
     fn debug_relative_tolerance(&self, other: &&B, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeEq<&mut B> for &mut A
@@ -436,6 +750,7 @@ where
     B: ?Sized,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -443,6 +758,11 @@ where
         AssertRelativeEq::debug_abs_diff(*self, *other)
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &&mut B) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(*self, *other)
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &&mut B, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(*self, *other, max_abs_diff)
@@ -452,6 +772,18 @@ where
     fn debug_relative_tolerance(&self, other: &&mut B, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_relative_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEq::visit_relative(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B, const N: usize> AssertRelativeEq<[B; N]> for [A; N]
@@ -461,6 +793,7 @@ where
     A::DebugTolerance: Sized,
 {
     type DebugAbsDiff = [A::DebugAbsDiff; N];
+    type DebugRelativeDiff = [A::DebugRelativeDiff; N];
     type DebugTolerance = [A::DebugTolerance; N];
 
     #[inline]
@@ -473,6 +806,16 @@ where
         unsafe { array_assume_init(result) }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &[B; N]) -> Self::DebugRelativeDiff {
+        let mut result: [mem::MaybeUninit<A::DebugRelativeDiff>; N] = uninit_array();
+        for i in 0..N {
+            result[i] = mem::MaybeUninit::new(self[i].debug_relative_diff(&other[i]));
+        }
+
+        unsafe { array_assume_init(result) }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &[B; N], max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         let mut result: [mem::MaybeUninit<A::DebugTolerance>; N] = uninit_array();
@@ -492,6 +835,24 @@ where
 
         unsafe { array_assume_init(result) }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEq::visit_relative(&self[i], &other[i], &max_abs_diff[i], &max_relative[i], path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeEq<cell::Cell<B>> for cell::Cell<A>
@@ -500,6 +861,7 @@ where
     B: Copy,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -507,6 +869,11 @@ where
         AssertRelativeEq::debug_abs_diff(&self.get(), &other.get())
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &cell::Cell<B>) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(&self.get(), &other.get())
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &cell::Cell<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(&self.get(), &other.get(), max_abs_diff)
@@ -524,6 +891,7 @@ where
     B: ?Sized + Copy,
 {
     type DebugAbsDiff = A::DebugAbsDiff;
+    type DebugRelativeDiff = A::DebugRelativeDiff;
     type DebugTolerance = A::DebugTolerance;
 
     #[inline]
@@ -531,6 +899,11 @@ where
         AssertRelativeEq::debug_abs_diff(&*self.borrow(), &*other.borrow())
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &cell::RefCell<B>) -> Self::DebugRelativeDiff {
+        AssertRelativeEq::debug_relative_diff(&*self.borrow(), &*other.borrow())
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &cell::RefCell<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         AssertRelativeEq::debug_abs_diff_tolerance(&*self.borrow(), &*other.borrow(), max_abs_diff)
@@ -548,6 +921,7 @@ where
     A::Tolerance: Sized,
 {
     type DebugAbsDiff = Option<A::DebugAbsDiff>;
+    type DebugRelativeDiff = Option<A::DebugRelativeDiff>;
     type DebugTolerance = Option<A::DebugTolerance>;
 
     #[inline]
@@ -558,6 +932,14 @@ where
         Some(AssertRelativeEq::debug_abs_diff(ref_self, ref_other))
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &Option<B>) -> Self::DebugRelativeDiff {
+        let ref_self = self.as_ref()?;
+        let ref_other = other.as_ref()?;
+
+        Some(AssertRelativeEq::debug_relative_diff(ref_self, ref_other))
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &Option<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         let ref_self = self.as_ref()?;
@@ -575,6 +957,101 @@ where
 
         Some(AssertRelativeEq::debug_relative_tolerance(ref_self, ref_other, ref_max_relative))
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertRelativeEq<Result<B, F>> for Result<A, E>
+where
+    A: AssertRelativeEq<B>,
+    E: AssertRelativeEq<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    type DebugAbsDiff = Option<Result<A::DebugAbsDiff, E::DebugAbsDiff>>;
+    type DebugRelativeDiff = Option<Result<A::DebugRelativeDiff, E::DebugRelativeDiff>>;
+    type DebugTolerance = Option<Result<A::DebugTolerance, E::DebugTolerance>>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Result<B, F>) -> Self::DebugAbsDiff {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Some(Ok(AssertRelativeEq::debug_abs_diff(a, b))),
+            (Err(a), Err(b)) => Some(Err(AssertRelativeEq::debug_abs_diff(a, b))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_relative_diff(&self, other: &Result<B, F>) -> Self::DebugRelativeDiff {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Some(Ok(AssertRelativeEq::debug_relative_diff(a, b))),
+            (Err(a), Err(b)) => Some(Err(AssertRelativeEq::debug_relative_diff(a, b))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => Some(Ok(AssertRelativeEq::debug_abs_diff_tolerance(a, b, max_abs_diff))),
+            (Err(a), Err(b), Err(max_abs_diff)) => Some(Err(AssertRelativeEq::debug_abs_diff_tolerance(a, b, max_abs_diff))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Result<B, F>, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        match (self, other, max_relative) {
+            (Ok(a), Ok(b), Ok(max_relative)) => Some(Ok(AssertRelativeEq::debug_relative_tolerance(a, b, max_relative))),
+            (Err(a), Err(b), Err(max_relative)) => Some(Err(AssertRelativeEq::debug_relative_tolerance(a, b, max_relative))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<A, B> AssertRelativeEq<cell::OnceCell<B>> for cell::OnceCell<A>
@@ -583,6 +1060,7 @@ where
     A::Tolerance: Sized,
 {
     type DebugAbsDiff = Option<A::DebugAbsDiff>;
+    type DebugRelativeDiff = Option<A::DebugRelativeDiff>;
     type DebugTolerance = Option<A::DebugTolerance>;
 
     #[inline]
@@ -594,6 +1072,15 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &cell::OnceCell<B>) -> Self::DebugRelativeDiff {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            Some(AssertRelativeEq::debug_relative_diff(a, b))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &cell::OnceCell<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if let (Some(a), Some(b)) = (self.get(), other.get()) {
@@ -611,6 +1098,26 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 macro_rules! impl_assert_relative_all_eq_float {
@@ -627,12 +1134,128 @@ macro_rules! impl_assert_relative_all_eq_float {
             fn debug_relative_all_tolerance(&self, other: &$T, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
                 self.debug_relative_tolerance(other, max_relative)
             }
+
+            #[inline]
+            fn visit_relative_all<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                max_relative: &Self::AllTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        RelativeAllEq::relative_all_eq(self, other, max_abs_diff, max_relative),
+                        &[
+                            ("abs_diff", &AssertRelativeEq::debug_abs_diff(self, other)),
+                            ("relative", &AssertRelativeEq::debug_relative_diff(self, other)),
+                            ("branch", &RelativeExplain::explain_relative(self, other, max_abs_diff, max_relative)),
+                        ],
+                        &[("abs_diff_all", &AssertRelativeAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff)), ("relative_all", &AssertRelativeAllEq::debug_relative_all_tolerance(self, other, max_relative))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     )*};
 }
 
 impl_assert_relative_all_eq_float!(f32, f64);
 
+macro_rules! impl_assert_relative_all_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertRelativeAllEq<$Rhs> for $T {
+            type AllDebugTolerance = f64;
+
+            #[inline]
+            fn debug_abs_diff_all_tolerance(&self, other: &$Rhs, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                AssertRelativeEq::debug_abs_diff_tolerance(self, other, max_abs_diff)
+            }
+
+            #[inline]
+            fn debug_relative_all_tolerance(&self, other: &$Rhs, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                AssertRelativeEq::debug_relative_tolerance(self, other, max_relative)
+            }
+
+            #[inline]
+            fn visit_relative_all<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::AllTolerance,
+                max_relative: &Self::AllTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = relative::explain_relative(f64::from(*self), f64::from(*other), *max_abs_diff, *max_relative);
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &AssertRelativeEq::debug_abs_diff(self, other)),
+                            ("relative", &AssertRelativeEq::debug_relative_diff(self, other)),
+                            ("branch", &explanation),
+                        ],
+                        &[("abs_diff_all", &AssertRelativeAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff)), ("relative_all", &AssertRelativeAllEq::debug_relative_all_tolerance(self, other, max_relative))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_all_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_relative_all_eq_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertRelativeAllEq for $T {
+            type AllDebugTolerance = Self::AllTolerance;
+
+            #[inline]
+            fn debug_abs_diff_all_tolerance(&self, other: &$T, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                self.debug_abs_diff_tolerance(other, max_abs_diff)
+            }
+
+            #[inline]
+            fn debug_relative_all_tolerance(&self, other: &$T, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                self.debug_relative_tolerance(other, max_relative)
+            }
+
+            #[inline]
+            fn visit_relative_all<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                max_relative: &Self::AllTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        RelativeAllEq::relative_all_eq(self, other, max_abs_diff, max_relative),
+                        &[
+                            ("abs_diff", &AssertRelativeEq::debug_abs_diff(self, other)),
+                            ("relative", &AssertRelativeEq::debug_relative_diff(self, other)),
+                        ],
+                        &[("abs_diff_all", &AssertRelativeAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff)), ("relative_all", &AssertRelativeAllEq::debug_relative_all_tolerance(self, other, max_relative))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_all_eq_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 impl<A, B> AssertRelativeAllEq<&B> for &A
 where
     A: AssertRelativeAllEq<B> + ?Sized,
@@ -649,6 +1272,18 @@ where
     fn debug_relative_all_tolerance(&self, other: &&B, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<&mut B> for &A
@@ -667,7 +1302,19 @@ where
     fn debug_relative_all_tolerance(&self, other: &&mut B, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(*self, *other, max_relative)
     }
-}
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
+}
 
 impl<A, B> AssertRelativeAllEq<&B> for &mut A
 where
@@ -685,6 +1332,18 @@ where
     fn debug_relative_all_tolerance(&self, other: &&B, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<&mut B> for &mut A
@@ -703,6 +1362,18 @@ where
     fn debug_relative_all_tolerance(&self, other: &&mut B, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
         AssertRelativeAllEq::debug_relative_all_tolerance(*self, *other, max_relative)
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEq::visit_relative_all(*self, *other, max_abs_diff, max_relative, path, visitor)
+    }
 }
 
 impl<A, B, const N: usize> AssertRelativeAllEq<[B; N]> for [A; N]
@@ -730,6 +1401,24 @@ where
 
         unsafe { array_assume_init(result) }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEq::visit_relative_all(&self[i], &other[i], max_abs_diff, max_relative, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<cell::Cell<B>> for cell::Cell<A>
@@ -800,6 +1489,81 @@ where
             ref_max_relative,
         ))
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertRelativeAllEq<Result<B, F>> for Result<A, E>
+where
+    A: AssertRelativeAllEq<B>,
+    E: AssertRelativeAllEq<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+{
+    type AllDebugTolerance = Option<Result<A::AllDebugTolerance, E::AllDebugTolerance>>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Result<B, F>, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => Some(Ok(AssertRelativeAllEq::debug_abs_diff_all_tolerance(a, b, max_abs_diff))),
+            (Err(a), Err(b), Err(max_abs_diff)) => Some(Err(AssertRelativeAllEq::debug_abs_diff_all_tolerance(a, b, max_abs_diff))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Result<B, F>, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        match (self, other, max_relative) {
+            (Ok(a), Ok(b), Ok(max_relative)) => Some(Ok(AssertRelativeAllEq::debug_relative_all_tolerance(a, b, max_relative))),
+            (Err(a), Err(b), Err(max_relative)) => Some(Err(AssertRelativeAllEq::debug_relative_all_tolerance(a, b, max_relative))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<cell::OnceCell<B>> for cell::OnceCell<A>
@@ -826,4 +1590,1692 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+macro_rules! impl_all_close_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl AllClose for $T {
+            type CloseTolerance = $T;
+
+            #[inline]
+            fn all_close(&self, other: &$T, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+                relative::is_close(*self, *other, *rtol, *atol, equal_nan)
+            }
+        }
+    )*}
+}
+
+impl_all_close_float!(f32, f64);
+
+macro_rules! impl_all_close_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AllClose<$Rhs> for $T {
+            type CloseTolerance = f64;
+
+            #[inline]
+            fn all_close(&self, other: &$Rhs, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+                relative::is_close(f64::from(*self), f64::from(*other), *rtol, *atol, equal_nan)
+            }
+        }
+    )*}
+}
+
+impl_all_close_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_all_close_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl AllClose for $T {
+            type CloseTolerance = f64;
+
+            #[inline]
+            fn all_close(&self, other: &$T, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, _equal_nan: bool) -> bool {
+                // Integers compare as `f64` numbers, the same way NumPy converts them.
+                relative::is_close(*self as f64, *other as f64, *rtol, *atol, false)
+            }
+        }
+    )*}
+}
+
+impl_all_close_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<A, B> AllClose<&B> for &A
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &&B, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(*self, *other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<&mut B> for &A
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &&mut B, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(*self, *other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<&B> for &mut A
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &&B, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(*self, *other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<&mut B> for &mut A
+where
+    A: AllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &&mut B, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(*self, *other, rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B, const N: usize> AllClose<[B; N]> for [A; N]
+where
+    A: AllClose<B>,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &[B; N], rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.iter().zip(other.iter()).all(|(a, b)| a.all_close(b, rtol, atol, equal_nan))
+    }
+}
+
+impl<A, B> AllClose<cell::Cell<B>> for cell::Cell<A>
+where
+    A: AllClose<B> + Copy,
+    B: Copy,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &cell::Cell<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(&self.get(), &other.get(), rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<cell::RefCell<B>> for cell::RefCell<A>
+where
+    A: AllClose<B> + ?Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &cell::RefCell<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        AllClose::all_close(&*self.borrow(), &*other.borrow(), rtol, atol, equal_nan)
+    }
+}
+
+impl<A, B> AllClose<Option<B>> for Option<A>
+where
+    A: AllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    type CloseTolerance = Option<A::CloseTolerance>;
+
+    #[inline]
+    fn all_close(&self, other: &Option<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        if let (Some(a), Some(b), Some(rtol), Some(atol)) = (self, other, rtol, atol) {
+            a.all_close(b, rtol, atol, equal_nan)
+        } else {
+            false
+        }
+    }
+}
+
+impl<A, B, E, F> AllClose<Result<B, F>> for Result<A, E>
+where
+    A: AllClose<B>,
+    E: AllClose<F>,
+    A::CloseTolerance: Sized,
+    E::CloseTolerance: Sized,
+{
+    type CloseTolerance = Result<A::CloseTolerance, E::CloseTolerance>;
+
+    #[inline]
+    fn all_close(&self, other: &Result<B, F>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        match (self, other, rtol, atol) {
+            (Ok(a), Ok(b), Ok(rtol), Ok(atol)) => AllClose::all_close(a, b, rtol, atol, equal_nan),
+            (Err(a), Err(b), Err(rtol), Err(atol)) => AllClose::all_close(a, b, rtol, atol, equal_nan),
+            _ => false,
+        }
+    }
+}
+
+impl<A, B> AllClose<cell::OnceCell<B>> for cell::OnceCell<A>
+where
+    A: AllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &cell::OnceCell<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            a.all_close(b, rtol, atol, equal_nan)
+        } else {
+            false
+        }
+    }
+}
+
+macro_rules! impl_assert_all_close_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertAllClose for $T {
+            #[inline]
+            fn visit_all_close<'a>(
+                &'a self,
+                other: &$T,
+                rtol: &Self::CloseTolerance,
+                atol: &Self::CloseTolerance,
+                equal_nan: bool,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AllClose::all_close(self, other, rtol, atol, equal_nan),
+                        &[("abs_diff", &$T::abs(*self - *other))],
+                        &[("close", &relative::close_tolerance(*other, *rtol, *atol))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_all_close_float!(f32, f64);
+
+macro_rules! impl_assert_all_close_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertAllClose<$Rhs> for $T {
+            #[inline]
+            fn visit_all_close<'a>(
+                &'a self,
+                other: &$Rhs,
+                rtol: &Self::CloseTolerance,
+                atol: &Self::CloseTolerance,
+                equal_nan: bool,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AllClose::all_close(self, other, rtol, atol, equal_nan),
+                        &[("abs_diff", &f64::abs(f64::from(*self) - f64::from(*other)))],
+                        &[("close", &relative::close_tolerance(f64::from(*other), *rtol, *atol))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_all_close_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_all_close_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertAllClose for $T {
+            #[inline]
+            fn visit_all_close<'a>(
+                &'a self,
+                other: &$T,
+                rtol: &Self::CloseTolerance,
+                atol: &Self::CloseTolerance,
+                equal_nan: bool,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        AllClose::all_close(self, other, rtol, atol, equal_nan),
+                        &[("abs_diff", &f64::abs(*self as f64 - *other as f64))],
+                        &[("close", &relative::close_tolerance(*other as f64, *rtol, *atol))],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_all_close_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<A, B> AssertAllClose<&B> for &A
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &&B,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(*self, *other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B> AssertAllClose<&mut B> for &A
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &&mut B,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(*self, *other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B> AssertAllClose<&B> for &mut A
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &&B,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(*self, *other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B> AssertAllClose<&mut B> for &mut A
+where
+    A: AssertAllClose<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &&mut B,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertAllClose::visit_all_close(*self, *other, rtol, atol, equal_nan, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> AssertAllClose<[B; N]> for [A; N]
+where
+    A: AssertAllClose<B>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &[B; N],
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertAllClose::visit_all_close(&self[i], &other[i], rtol, atol, equal_nan, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<cell::Cell<B>> for cell::Cell<A>
+where
+    A: AssertAllClose<B> + Copy,
+    B: Copy,
+{
+}
+
+impl<A, B> AssertAllClose<cell::RefCell<B>> for cell::RefCell<A> where A: AssertAllClose<B> + ?Sized {}
+
+impl<A, B> AssertAllClose<Option<B>> for Option<A>
+where
+    A: AssertAllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Option<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, rtol, atol) {
+            (Some(a), Some(b), Some(rtol), Some(atol)) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(rtol)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertAllClose<Result<B, F>> for Result<A, E>
+where
+    A: AssertAllClose<B>,
+    E: AssertAllClose<F>,
+    A::CloseTolerance: Sized,
+    E::CloseTolerance: Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, rtol, atol) {
+            (Ok(a), Ok(b), Ok(rtol), Ok(atol)) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+            (Err(a), Err(b), Err(rtol), Err(atol)) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(rtol)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B> AssertAllClose<cell::OnceCell<B>> for cell::OnceCell<A>
+where
+    A: AssertAllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+macro_rules! impl_relative_eq_by_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeEqBy for $T {
+            #[inline]
+            fn relative_eq_by(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance, denominator: RelativeDenominator) -> bool {
+                relative::relative_eq_by(*self, *other, *max_abs_diff, *max_relative, denominator)
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_by_float!(f32, f64);
+
+macro_rules! impl_relative_eq_by_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl RelativeEqBy<$Rhs> for $T {
+            #[inline]
+            fn relative_eq_by(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance, denominator: RelativeDenominator) -> bool {
+                relative::relative_eq_by(f64::from(*self), f64::from(*other), *max_abs_diff, *max_relative, denominator)
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_by_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_relative_eq_by_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeEqBy for $T {
+            #[inline]
+            fn relative_eq_by(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance, denominator: RelativeDenominator) -> bool {
+                if self == other {
+                    return true;
+                }
+
+                let abs_diff = $T::abs_diff(*self, *other) as f64;
+                if abs_diff <= *max_abs_diff {
+                    return true;
+                }
+
+                abs_diff <= denominator.magnitude(*self as f64, *other as f64) * max_relative
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_by_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_relative_all_eq_by_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeAllEqBy for $T {
+            #[inline]
+            fn relative_all_eq_by(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance, denominator: RelativeDenominator) -> bool {
+                self.relative_eq_by(other, max_abs_diff, max_relative, denominator)
+            }
+        }
+    )*}
+}
+
+impl_relative_all_eq_by_float!(f32, f64);
+
+macro_rules! impl_relative_all_eq_by_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl RelativeAllEqBy<$Rhs> for $T {
+            #[inline]
+            fn relative_all_eq_by(&self, other: &$Rhs, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance, denominator: RelativeDenominator) -> bool {
+                RelativeEqBy::relative_eq_by(self, other, max_abs_diff, max_relative, denominator)
+            }
+        }
+    )*}
+}
+
+impl_relative_all_eq_by_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_relative_all_eq_by_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeAllEqBy for $T {
+            #[inline]
+            fn relative_all_eq_by(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance, denominator: RelativeDenominator) -> bool {
+                self.relative_eq_by(other, max_abs_diff, max_relative, denominator)
+            }
+        }
+    )*}
+}
+
+impl_relative_all_eq_by_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<A, B> RelativeEqBy<&B> for &A
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<&mut B> for &A
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<&B> for &mut A
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<&mut B> for &mut A
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B, const N: usize> RelativeEqBy<[B; N]> for [A; N]
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        for i in 0..N {
+            if !self[i].relative_eq_by(&other[i], &max_abs_diff[i], &max_relative[i], denominator) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl<A, B> RelativeEqBy<cell::Cell<B>> for cell::Cell<A>
+where
+    A: RelativeEqBy<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &cell::Cell<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(&self.get(), &other.get(), max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<cell::RefCell<B>> for cell::RefCell<A>
+where
+    A: RelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &cell::RefCell<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeEqBy::relative_eq_by(&*self.borrow(), &*other.borrow(), max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeEqBy<Option<B>> for Option<A>
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        if let (Some(a), Some(b), Some(abs_tol), Some(rel_tol)) = (self, other, max_abs_diff, max_relative) {
+            RelativeEqBy::relative_eq_by(a, b, abs_tol, rel_tol, denominator)
+        } else {
+            false
+        }
+    }
+}
+
+impl<A, B, E, F> RelativeEqBy<Result<B, F>> for Result<A, E>
+where
+    A: RelativeEqBy<B>,
+    E: RelativeEqBy<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                RelativeEqBy::relative_eq_by(a, b, max_abs_diff, max_relative, denominator)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                RelativeEqBy::relative_eq_by(a, b, max_abs_diff, max_relative, denominator)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<A, B> RelativeEqBy<cell::OnceCell<B>> for cell::OnceCell<A>
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            RelativeEqBy::relative_eq_by(a, b, max_abs_diff, max_relative, denominator)
+        } else {
+            false
+        }
+    }
+}
+
+impl<A, B> RelativeAllEqBy<&B> for &A
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<&mut B> for &A
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<&B> for &mut A
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<&mut B> for &mut A
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(*self, *other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B, const N: usize> RelativeAllEqBy<[B; N]> for [A; N]
+where
+    A: RelativeAllEqBy<B>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &[B; N],
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.relative_all_eq_by(b, max_abs_diff, max_relative, denominator))
+    }
+}
+
+impl<A, B> RelativeAllEqBy<cell::Cell<B>> for cell::Cell<A>
+where
+    A: RelativeAllEqBy<B> + Copy,
+    B: Copy,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &cell::Cell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(&self.get(), &other.get(), max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<cell::RefCell<B>> for cell::RefCell<A>
+where
+    A: RelativeAllEqBy<B> + ?Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &cell::RefCell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        RelativeAllEqBy::relative_all_eq_by(&*self.borrow(), &*other.borrow(), max_abs_diff, max_relative, denominator)
+    }
+}
+
+impl<A, B> RelativeAllEqBy<Option<B>> for Option<A>
+where
+    A: RelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &Option<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        if let (Some(a), Some(b), Some(abs_tol), Some(rel_tol)) = (self, other, max_abs_diff, max_relative) {
+            a.relative_all_eq_by(b, abs_tol, rel_tol, denominator)
+        } else {
+            false
+        }
+    }
+}
+
+impl<A, B, E, F> RelativeAllEqBy<Result<B, F>> for Result<A, E>
+where
+    A: RelativeAllEqBy<B>,
+    E: RelativeAllEqBy<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<A, B> RelativeAllEqBy<cell::OnceCell<B>> for cell::OnceCell<A>
+where
+    A: RelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            a.relative_all_eq_by(b, max_abs_diff, max_relative, denominator)
+        } else {
+            false
+        }
+    }
+}
+
+macro_rules! impl_assert_relative_eq_by_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertRelativeEqBy for $T {
+            #[inline]
+            fn visit_relative_by<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = relative::explain_relative_by(*self, *other, *max_abs_diff, *max_relative, denominator);
+                let relative_tolerance = denominator.magnitude(*self, *other) * max_relative;
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &$T::abs(self - other)),
+                            ("relative", &relative::relative_diff_by(*self, *other, denominator)),
+                            ("branch", &explanation),
+                        ],
+                        &[("abs_diff", max_abs_diff), ("relative", &relative_tolerance)],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_eq_by_float!(f32, f64);
+
+macro_rules! impl_assert_relative_eq_by_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertRelativeEqBy<$Rhs> for $T {
+            #[inline]
+            fn visit_relative_by<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = relative::explain_relative_by(f64::from(*self), f64::from(*other), *max_abs_diff, *max_relative, denominator);
+                let relative_tolerance = denominator.magnitude(f64::from(*self), f64::from(*other)) * max_relative;
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &f64::abs(f64::from(*self) - f64::from(*other))),
+                            ("relative", &relative::relative_diff_by(f64::from(*self), f64::from(*other), denominator)),
+                            ("branch", &explanation),
+                        ],
+                        &[("abs_diff", max_abs_diff), ("relative", &relative_tolerance)],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_eq_by_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_relative_eq_by_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertRelativeEqBy for $T {
+            #[inline]
+            fn visit_relative_by<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                max_relative: &Self::Tolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let magnitude = denominator.magnitude(*self as f64, *other as f64);
+                let abs_diff = $T::abs_diff(*self, *other);
+                let relative_diff = if self == other { 0.0 } else { abs_diff as f64 / magnitude };
+                let relative_tolerance = magnitude * max_relative;
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        RelativeEqBy::relative_eq_by(self, other, max_abs_diff, max_relative, denominator),
+                        &[
+                            ("abs_diff", &abs_diff),
+                            ("relative", &relative_diff),
+                        ],
+                        &[("abs_diff", max_abs_diff), ("relative", &relative_tolerance)],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_eq_by_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_assert_relative_all_eq_by_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertRelativeAllEqBy for $T {
+            #[inline]
+            fn visit_relative_all_by<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                max_relative: &Self::AllTolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = relative::explain_relative_by(*self, *other, *max_abs_diff, *max_relative, denominator);
+                let relative_tolerance = denominator.magnitude(*self, *other) * max_relative;
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &$T::abs(self - other)),
+                            ("relative", &relative::relative_diff_by(*self, *other, denominator)),
+                            ("branch", &explanation),
+                        ],
+                        &[("abs_diff_all", max_abs_diff), ("relative_all", &relative_tolerance)],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_all_eq_by_float!(f32, f64);
+
+macro_rules! impl_assert_relative_all_eq_by_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertRelativeAllEqBy<$Rhs> for $T {
+            #[inline]
+            fn visit_relative_all_by<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::AllTolerance,
+                max_relative: &Self::AllTolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = relative::explain_relative_by(f64::from(*self), f64::from(*other), *max_abs_diff, *max_relative, denominator);
+                let relative_tolerance = denominator.magnitude(f64::from(*self), f64::from(*other)) * max_relative;
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &f64::abs(f64::from(*self) - f64::from(*other))),
+                            ("relative", &relative::relative_diff_by(f64::from(*self), f64::from(*other), denominator)),
+                            ("branch", &explanation),
+                        ],
+                        &[("abs_diff_all", max_abs_diff), ("relative_all", &relative_tolerance)],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_all_eq_by_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_relative_all_eq_by_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertRelativeAllEqBy for $T {
+            #[inline]
+            fn visit_relative_all_by<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                max_relative: &Self::AllTolerance,
+                denominator: RelativeDenominator,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let magnitude = denominator.magnitude(*self as f64, *other as f64);
+                let abs_diff = $T::abs_diff(*self, *other);
+                let relative_diff = if self == other { 0.0 } else { abs_diff as f64 / magnitude };
+                let relative_tolerance = magnitude * max_relative;
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        RelativeAllEqBy::relative_all_eq_by(self, other, max_abs_diff, max_relative, denominator),
+                        &[
+                            ("abs_diff", &abs_diff),
+                            ("relative", &relative_diff),
+                        ],
+                        &[("abs_diff_all", max_abs_diff), ("relative_all", &relative_tolerance)],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_relative_all_eq_by_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<A, B> AssertRelativeEqBy<&B> for &A
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<&mut B> for &A
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<&B> for &mut A
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<&mut B> for &mut A
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeEqBy::visit_relative_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> AssertRelativeEqBy<[B; N]> for [A; N]
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeEqBy::visit_relative_by(&self[i], &other[i], &max_abs_diff[i], &max_relative[i], denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<cell::Cell<B>> for cell::Cell<A>
+where
+    A: AssertRelativeEqBy<B> + Copy,
+    B: Copy,
+{
+}
+
+impl<A, B> AssertRelativeEqBy<cell::RefCell<B>> for cell::RefCell<A>
+where
+    A: AssertRelativeEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+}
+
+impl<A, B> AssertRelativeEqBy<Option<B>> for Option<A>
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertRelativeEqBy<Result<B, F>> for Result<A, E>
+where
+    A: AssertRelativeEqBy<B>,
+    E: AssertRelativeEqBy<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<cell::OnceCell<B>> for cell::OnceCell<A>
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<&B> for &A
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<&mut B> for &A
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<&B> for &mut A
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<&mut B> for &mut A
+where
+    A: AssertRelativeAllEqBy<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertRelativeAllEqBy::visit_relative_all_by(*self, *other, max_abs_diff, max_relative, denominator, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> AssertRelativeAllEqBy<[B; N]> for [A; N]
+where
+    A: AssertRelativeAllEqBy<B>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertRelativeAllEqBy::visit_relative_all_by(&self[i], &other[i], max_abs_diff, max_relative, denominator, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<cell::Cell<B>> for cell::Cell<A>
+where
+    A: AssertRelativeAllEqBy<B> + Copy,
+    B: Copy,
+{
+}
+
+impl<A, B> AssertRelativeAllEqBy<cell::RefCell<B>> for cell::RefCell<A> where A: AssertRelativeAllEqBy<B> + ?Sized {}
+
+impl<A, B> AssertRelativeAllEqBy<Option<B>> for Option<A>
+where
+    A: AssertRelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertRelativeAllEqBy<Result<B, F>> for Result<A, E>
+where
+    A: AssertRelativeAllEqBy<B>,
+    E: AssertRelativeAllEqBy<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_relative) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_relative)) => {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_relative)) => {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<cell::OnceCell<B>> for cell::OnceCell<A>
+where
+    A: AssertRelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+macro_rules! impl_norm_eq_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl NormEq for $T {
+            type NormTolerance = $T;
+
+            #[inline]
+            fn accumulate_norms(&self, other: &$T, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+                // Equal infinities differ by zero rather than by NaN.
+                let diff = if self == other { 0.0 } else { $T::abs(self - other) };
+                accumulator.push(diff, *other);
+
+                true
+            }
+        }
+    )*}
+}
+
+impl_norm_eq_float!(f32, f64);
+
+macro_rules! impl_norm_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl NormEq<$Rhs> for $T {
+            type NormTolerance = f64;
+
+            #[inline]
+            fn accumulate_norms(&self, other: &$Rhs, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+                f64::from(*self).accumulate_norms(&f64::from(*other), accumulator)
+            }
+        }
+    )*}
+}
+
+impl_norm_eq_mixed_float!(f32 => f64, f64 => f32);
+
+impl<A, B> NormEq<&B> for &A
+where
+    A: NormEq<B> + ?Sized,
+    B: ?Sized,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &&B, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        NormEq::accumulate_norms(*self, *other, accumulator)
+    }
+}
+
+impl<A, B> NormEq<&mut B> for &A
+where
+    A: NormEq<B> + ?Sized,
+    B: ?Sized,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &&mut B, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        NormEq::accumulate_norms(*self, *other, accumulator)
+    }
+}
+
+impl<A, B> NormEq<&B> for &mut A
+where
+    A: NormEq<B> + ?Sized,
+    B: ?Sized,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &&B, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        NormEq::accumulate_norms(*self, *other, accumulator)
+    }
+}
+
+impl<A, B> NormEq<&mut B> for &mut A
+where
+    A: NormEq<B> + ?Sized,
+    B: ?Sized,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &&mut B, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        NormEq::accumulate_norms(*self, *other, accumulator)
+    }
+}
+
+impl<A, B, const N: usize> NormEq<[B; N]> for [A; N]
+where
+    A: NormEq<B>,
+{
+    type NormTolerance = A::NormTolerance;
+
+    #[inline]
+    fn accumulate_norms(&self, other: &[B; N], accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool {
+        self.iter().zip(other.iter()).all(|(a, b)| a.accumulate_norms(b, accumulator))
+    }
 }
@@ -1,12 +1,28 @@
+use crate::denominator::RelativeDenominator;
 use crate::traits::{
+    AllClose,
+    AssertAllClose,
     AssertRelativeAllEq,
+    AssertRelativeAllEqBy,
     AssertRelativeEq,
+    AssertRelativeEqBy,
     RelativeAllEq,
+    RelativeAllEqBy,
     RelativeEq,
+    RelativeEqBy,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
+
 use std::collections::HashMap;
 use std::fmt;
 use std::hash;
+use std::ops;
 use std::sync;
 
 impl<K, VA, VB, S> RelativeEq<HashMap<K, VB, S>> for HashMap<K, VA, S>
@@ -101,6 +117,7 @@ where
     VA::DebugTolerance: Sized,
 {
     type DebugAbsDiff = Option<HashMap<K, VA::DebugAbsDiff, S>>;
+    type DebugRelativeDiff = Option<HashMap<K, VA::DebugRelativeDiff, S>>;
     type DebugTolerance = Option<HashMap<K, VA::DebugTolerance, S>>;
 
     #[inline]
@@ -116,6 +133,19 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &HashMap<K, VB, S>) -> Self::DebugRelativeDiff {
+        if self.len() == other.len() {
+            let mut result = HashMap::with_hasher(self.hasher().clone());
+            for (key, val) in self {
+                result.insert(key.clone(), val.debug_relative_diff(other.get(key)?));
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &HashMap<K, VB, S>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if (self.len() == other.len()) && (self.len() == max_abs_diff.len()) {
@@ -141,6 +171,56 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| {
+                match (other.get(key), max_abs_diff.get(key), max_relative.get(key)) {
+                    (Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                        AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor)
+                    }
+                    _ => StructureMismatch::MissingKey.report(path, visitor),
+                }
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeEq<sync::OnceLock<B>> for sync::OnceLock<A>
@@ -149,6 +229,7 @@ where
     A::Tolerance: Sized,
 {
     type DebugAbsDiff = Option<A::DebugAbsDiff>;
+    type DebugRelativeDiff = Option<A::DebugRelativeDiff>;
     type DebugTolerance = Option<A::DebugTolerance>;
 
     #[inline]
@@ -160,6 +241,15 @@ where
         }
     }
 
+    #[inline]
+    fn debug_relative_diff(&self, other: &sync::OnceLock<B>) -> Self::DebugRelativeDiff {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            Some(AssertRelativeEq::debug_relative_diff(a, b))
+        } else {
+            None
+        }
+    }
+
     #[inline]
     fn debug_abs_diff_tolerance(&self, other: &sync::OnceLock<B>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
         if let (Some(a), Some(b)) = (self.get(), other.get()) {
@@ -177,6 +267,26 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertRelativeEq::visit_relative(a, b, max_abs_diff, max_relative, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<K, VA, VB, S> AssertRelativeAllEq<HashMap<K, VB, S>> for HashMap<K, VA, S>
@@ -213,6 +323,34 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertRelativeAllEq<sync::OnceLock<B>> for sync::OnceLock<A>
@@ -239,4 +377,375 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertRelativeAllEq::visit_relative_all(a, b, max_abs_diff, max_relative, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<K, VA, VB, S> AllClose<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash,
+    S: hash::BuildHasher,
+    VA: AllClose<VB>,
+{
+    type CloseTolerance = VA::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &HashMap<K, VB, S>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    AllClose::all_close(a, b, rtol, atol, equal_nan)
+                } else {
+                    false
+                }
+            })
+    }
+}
+
+impl<A, B> AllClose<sync::OnceLock<B>> for sync::OnceLock<A>
+where
+    A: AllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    type CloseTolerance = A::CloseTolerance;
+
+    #[inline]
+    fn all_close(&self, other: &sync::OnceLock<B>, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            a.all_close(b, rtol, atol, equal_nan)
+        } else {
+            false
+        }
+    }
+}
+
+impl<K, VA, VB, S> AssertAllClose<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash + Clone + fmt::Debug,
+    S: hash::BuildHasher + Clone,
+    VA: AssertAllClose<VB>,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertAllClose<sync::OnceLock<B>> for sync::OnceLock<A>
+where
+    A: AssertAllClose<B>,
+    A::CloseTolerance: Sized,
+{
+    #[inline]
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertAllClose::visit_all_close(a, b, rtol, atol, equal_nan, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<K, VA, VB, S> RelativeEqBy<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash,
+    S: hash::BuildHasher,
+    VA: RelativeEqBy<VB>,
+    VA::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.len() == max_abs_diff.len()
+            && self.len() == max_relative.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    if let (Some(abs_tol), Some(rel_tol)) = (max_abs_diff.get(key), max_relative.get(key)) {
+                        RelativeEqBy::relative_eq_by(a, b, abs_tol, rel_tol, denominator)
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            })
+    }
+}
+
+impl<A, B> RelativeEqBy<sync::OnceLock<B>> for sync::OnceLock<A>
+where
+    A: RelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn relative_eq_by(
+        &self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            RelativeEqBy::relative_eq_by(a, b, max_abs_diff, max_relative, denominator)
+        } else {
+            false
+        }
+    }
+}
+
+impl<K, VA, VB, S> RelativeAllEqBy<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash,
+    S: hash::BuildHasher,
+    VA: RelativeAllEqBy<VB>,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    RelativeAllEqBy::relative_all_eq_by(a, b, max_abs_diff, max_relative, denominator)
+                } else {
+                    false
+                }
+            })
+    }
+}
+
+impl<A, B> RelativeAllEqBy<sync::OnceLock<B>> for sync::OnceLock<A>
+where
+    A: RelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn relative_all_eq_by(
+        &self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        if let (Some(a), Some(b)) = (self.get(), other.get()) {
+            a.relative_all_eq_by(b, max_abs_diff, max_relative, denominator)
+        } else {
+            false
+        }
+    }
+}
+
+impl<K, VA, VB, S> AssertRelativeEqBy<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash + fmt::Debug,
+    S: hash::BuildHasher,
+    VA: AssertRelativeEqBy<VB>,
+    VA::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_relative.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_relative.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| {
+                match (other.get(key), max_abs_diff.get(key), max_relative.get(key)) {
+                    (Some(b), Some(max_abs_diff), Some(max_relative)) => {
+                        AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+                    }
+                    _ => StructureMismatch::MissingKey.report(path, visitor),
+                }
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeEqBy<sync::OnceLock<B>> for sync::OnceLock<A>
+where
+    A: AssertRelativeEqBy<B>,
+    A::Tolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertRelativeEqBy::visit_relative_by(a, b, max_abs_diff, max_relative, denominator, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<K, VA, VB, S> AssertRelativeAllEqBy<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash + fmt::Debug,
+    S: hash::BuildHasher,
+    VA: AssertRelativeAllEqBy<VB>,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> AssertRelativeAllEqBy<sync::OnceLock<B>> for sync::OnceLock<A>
+where
+    A: AssertRelativeAllEqBy<B>,
+    A::AllTolerance: Sized,
+{
+    #[inline]
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => {
+                AssertRelativeAllEqBy::visit_relative_all_by(a, b, max_abs_diff, max_relative, denominator, path, visitor)
+            }
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
@@ -9,11 +9,18 @@ extern crate alloc as std;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod denominator;
+mod explain;
+mod flush;
 mod impl_core_slice;
 mod impl_core_tuple;
 mod impl_core_types;
+mod norm;
+mod ordering;
 mod traits;
 
+pub mod relative;
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod impl_alloc_types;
 
@@ -23,11 +30,19 @@ mod impl_alloc_slice;
 #[cfg(feature = "std")]
 mod impl_std_types;
 
+pub use denominator::*;
+pub use explain::*;
+pub use flush::*;
 pub use impl_core_slice::*;
 pub use impl_core_tuple::*;
 pub use impl_core_types::*;
+pub use norm::*;
+pub use ordering::*;
 pub use traits::*;
 
+pub use approx_cmp_float::*;
+pub use approx_cmp_report::*;
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 pub use impl_alloc_types::*;
 
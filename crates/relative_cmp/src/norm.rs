@@ -0,0 +1,311 @@
+use approx_cmp_float::Float;
+
+use core::fmt;
+
+/// The vector norm that a norm comparison measures the compared values with.
+///
+/// The comparisons of [`NormEq`](crate::NormEq) flatten the compared values into
+/// vectors of numbers, and compare the norm of their difference against the
+/// norm of the right hand side, e.g. `||left - right||_2 <= max_relative * ||right||_2`.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     Norm,
+/// #     NormEq,
+/// # };
+/// #
+/// let left = [3.0_f64, -4.0_f64];
+/// let right = [0.0_f64, 0.0_f64];
+///
+/// assert_eq!(left.norms(&right, Norm::L1).map(|norms| norms.diff), Some(7.0_f64));
+/// assert_eq!(left.norms(&right, Norm::L2).map(|norms| norms.diff), Some(5.0_f64));
+/// assert_eq!(left.norms(&right, Norm::LInf).map(|norms| norms.diff), Some(4.0_f64));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Norm {
+    /// The sum of the magnitudes of the numbers.
+    L1,
+    /// The Euclidean norm, the square root of the sum of the squares of the
+    /// numbers.
+    #[default]
+    L2,
+    /// The largest of the magnitudes of the numbers.
+    LInf,
+}
+
+/// The norms of a norm comparison.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Norms<T> {
+    /// The norm of the difference of the compared values, `||left - right||`.
+    pub diff: T,
+    /// The norm of the right hand side of the comparison, `||right||`.
+    pub reference: T,
+}
+
+impl<T> Norms<T>
+where
+    T: Float,
+{
+    /// Determine whether the norm of the difference is within `max_abs_diff`, or
+    /// within `max_relative` times the norm of the right hand side.
+    ///
+    /// An infinite or NaN norm of the difference fails the comparison, even
+    /// against an infinite tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::Norms;
+    /// #
+    /// let norms = Norms { diff: 0.5_f64, reference: 10.0_f64 };
+    ///
+    /// assert!(norms.is_within(0.5_f64, 0.0_f64));
+    /// assert!(norms.is_within(0.0_f64, 0.05_f64));
+    /// assert!(!norms.is_within(0.25_f64, 0.025_f64));
+    /// ```
+    #[inline]
+    pub fn is_within(&self, max_abs_diff: T, max_relative: T) -> bool {
+        if self.diff.is_infinite() {
+            return false;
+        }
+
+        self.diff <= max_abs_diff || self.diff <= max_relative * self.reference
+    }
+}
+
+/// The running norms of the element-wise differences of two values, and of the
+/// elements of the right hand side.
+///
+/// Implementations of [`NormEq`](crate::NormEq) for containers pass the
+/// accumulator to each of their elements in turn, and the numbers push their
+/// differences into it.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     Norm,
+/// #     NormAccumulator,
+/// #     Norms,
+/// # };
+/// #
+/// let mut accumulator = NormAccumulator::new(Norm::L2);
+/// accumulator.push(3.0_f64, 1.0_f64);
+/// accumulator.push(4.0_f64, 0.0_f64);
+///
+/// assert_eq!(accumulator.finish(), Norms { diff: 5.0_f64, reference: 1.0_f64 });
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct NormAccumulator<T> {
+    norm: Norm,
+    diff: NormSum<T>,
+    reference: NormSum<T>,
+}
+
+impl<T> NormAccumulator<T>
+where
+    T: Float,
+{
+    /// Construct an accumulator of the norm `norm` of no numbers.
+    #[inline]
+    pub const fn new(norm: Norm) -> Self {
+        Self {
+            norm,
+            diff: NormSum::new(),
+            reference: NormSum::new(),
+        }
+    }
+
+    /// The norm that the accumulator computes.
+    #[inline]
+    pub const fn norm(&self) -> Norm {
+        self.norm
+    }
+
+    /// Add the magnitude `diff` of the difference of a pair of compared numbers,
+    /// and the magnitude `reference` of the right hand side of the pair.
+    #[inline]
+    pub fn push(&mut self, diff: T, reference: T) {
+        self.diff.push(self.norm, diff.abs());
+        self.reference.push(self.norm, reference.abs());
+    }
+
+    /// The norms of the numbers pushed so far.
+    #[inline]
+    pub fn finish(&self) -> Norms<T> {
+        Norms {
+            diff: self.diff.value(self.norm),
+            reference: self.reference.value(self.norm),
+        }
+    }
+}
+
+/// The running norm of a sequence of magnitudes.
+#[derive(Copy, Clone, Debug)]
+struct NormSum<T> {
+    // The Euclidean norm is `scale * sqrt(sum)`, with `scale` the largest
+    // magnitude so far, so that squaring large magnitudes does not overflow.
+    scale: T,
+    sum: T,
+    // A NaN or an infinity decides the norm on its own, so it is set aside
+    // rather than folded into the sum. A NaN takes precedence over an infinity.
+    special: Option<T>,
+}
+
+impl<T> NormSum<T>
+where
+    T: Float,
+{
+    const fn new() -> Self {
+        Self {
+            scale: T::ZERO,
+            sum: T::ZERO,
+            special: None,
+        }
+    }
+
+    fn push(&mut self, norm: Norm, magnitude: T) {
+        if magnitude.is_nan() {
+            self.special = Some(magnitude);
+            return;
+        }
+
+        if magnitude.is_infinite() {
+            if self.special.is_none() {
+                self.special = Some(magnitude);
+            }
+            return;
+        }
+
+        match norm {
+            Norm::L1 => self.sum = self.sum + magnitude,
+            Norm::L2 => {
+                if magnitude == T::ZERO {
+                    return;
+                }
+
+                if self.scale < magnitude {
+                    let ratio = self.scale / magnitude;
+                    self.sum = T::ONE + self.sum * ratio * ratio;
+                    self.scale = magnitude;
+                } else {
+                    let ratio = magnitude / self.scale;
+                    self.sum = self.sum + ratio * ratio;
+                }
+            }
+            Norm::LInf => self.sum = Float::max(self.sum, magnitude),
+        }
+    }
+
+    fn value(&self, norm: Norm) -> T {
+        if let Some(special) = self.special {
+            return special;
+        }
+
+        match norm {
+            Norm::L1 | Norm::LInf => self.sum,
+            Norm::L2 => {
+                if self.scale == T::ZERO {
+                    T::ZERO
+                } else {
+                    self.scale * sqrt_at_least_one(self.sum)
+                }
+            }
+        }
+    }
+}
+
+/// The square root of a number no smaller than one.
+///
+/// The comparison crates do not depend on the standard library, so the square
+/// root is computed with Newton's method. Starting from `value` itself, the
+/// iterates decrease towards the square root, and the iteration stops as soon
+/// as they stop decreasing.
+fn sqrt_at_least_one<T>(value: T) -> T
+where
+    T: Float,
+{
+    let two = T::ONE + T::ONE;
+    let mut root = value;
+    loop {
+        let next = (root + value / root) / two;
+        if next >= root {
+            return root;
+        }
+
+        root = next;
+    }
+}
+
+/// The norms of a failed norm comparison, formatted for a panic message.
+///
+/// The assertion macros of [`NormEq`](crate::NormEq) display this value below
+/// the first line of the panic message. It writes the norm, the norm of the
+/// difference of the compared values, the norm of the right hand side, and the
+/// tolerances, with the relative tolerance scaled by the norm of the right hand
+/// side.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     Norm,
+/// #     NormReport,
+/// #     Norms,
+/// # };
+/// #
+/// let norms = Norms { diff: 0.5_f64, reference: 10.0_f64 };
+/// let report = NormReport::new(Norm::L2, Some(norms), 0.0_f64, 0.01_f64);
+/// let expected = "\
+/// \x20       norm: `L2`,
+/// \x20  diff_norm: `0.5`,
+/// \x20 right_norm: `10.0`,
+/// [abs_diff] t: `0.0`,
+/// [relative] t: `0.1`,";
+///
+/// assert_eq!(report.to_string(), expected);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct NormReport<T> {
+    norm: Norm,
+    norms: Option<Norms<T>>,
+    max_abs_diff: T,
+    max_relative: T,
+}
+
+impl<T> NormReport<T>
+where
+    T: Float,
+{
+    /// Construct the report of comparing two values whose norms are `norms`, or
+    /// `None` when the values have different shapes.
+    #[inline]
+    pub const fn new(norm: Norm, norms: Option<Norms<T>>, max_abs_diff: T, max_relative: T) -> Self {
+        Self {
+            norm,
+            norms,
+            max_abs_diff,
+            max_relative,
+        }
+    }
+}
+
+impl<T> fmt::Display for NormReport<T>
+where
+    T: Float,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{:>12}: `{:?}`,", "norm", self.norm)?;
+        match self.norms {
+            Some(norms) => {
+                write!(formatter, "\n{:>12}: `{:?}`,", "diff_norm", norms.diff)?;
+                write!(formatter, "\n{:>12}: `{:?}`,", "right_norm", norms.reference)?;
+                write!(formatter, "\n[abs_diff] t: `{:?}`,", self.max_abs_diff)?;
+                write!(formatter, "\n[relative] t: `{:?}`,", self.max_relative * norms.reference)
+            }
+            None => formatter.write_str("\nthe compared values have different lengths"),
+        }
+    }
+}
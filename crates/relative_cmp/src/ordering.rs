@@ -0,0 +1,542 @@
+use crate::traits::RelativeEq;
+
+use core::cmp::Ordering;
+
+/// A data type for ordering values up to the tolerances of a relative
+/// comparison.
+///
+/// A tolerance-aware ordering treats two values as equal when they are relative
+/// equal, and otherwise orders them with [`PartialOrd`]. It checks bounds that
+/// hold up to rounding, e.g. that the energy of a simulation never increases,
+/// where an energy exceeding its previous value by a rounding error still
+/// satisfies the bound.
+///
+/// * [`relative_partial_cmp`] returns `Some(Ordering::Equal)` for relative
+///   equal values.
+/// * [`relative_lt`] and [`relative_gt`] hold for strictly ordered values that
+///   are not relative equal.
+/// * [`relative_le`] and [`relative_ge`] hold for strictly ordered values, and
+///   for relative equal values.
+///
+/// NaN is unordered, so it is neither less than, greater than, nor equal to any
+/// number. The integer and floating point primitives implement this trait, as do
+/// `f32` and `f64` against each other. Containers do not, since their elements
+/// compared under tolerance do not order them.
+///
+/// [`relative_partial_cmp`]: RelativeOrd::relative_partial_cmp
+/// [`relative_lt`]: RelativeOrd::relative_lt
+/// [`relative_gt`]: RelativeOrd::relative_gt
+/// [`relative_le`]: RelativeOrd::relative_le
+/// [`relative_ge`]: RelativeOrd::relative_ge
+///
+/// # Example
+///
+/// ```
+/// # use core::cmp::Ordering;
+/// # use relative_cmp::RelativeOrd;
+/// #
+/// let previous = 0.3_f64;
+/// let energy = 0.1_f64 + 0.2_f64;
+///
+/// assert!(energy > previous);
+/// assert!(energy.relative_le(&previous, &0.0_f64, &1e-12_f64));
+/// assert!(!energy.relative_lt(&previous, &0.0_f64, &1e-12_f64));
+/// assert_eq!(energy.relative_partial_cmp(&previous, &0.0_f64, &1e-12_f64), Some(Ordering::Equal));
+/// assert_eq!(energy.relative_partial_cmp(&f64::NAN, &0.0_f64, &1e-12_f64), None);
+/// ```
+///
+/// The first elements of these vectors are equal up to the tolerances, which
+/// does not decide whether the vectors are ordered, so they are not comparable.
+///
+/// ```compile_fail
+/// # use relative_cmp::relative_lt;
+/// #
+/// relative_lt!(vec![1.0_f64, 5.0_f64], vec![1.0000001_f64, 0.0_f64], abs_diff <= vec![0.0_f64, 0.0_f64], relative <= vec![1e-3_f64, 1e-3_f64]);
+/// ```
+pub trait RelativeOrd<Rhs = Self>: RelativeEq<Rhs> {
+    /// Order `self` and `other`, treating relative equal values as equal.
+    ///
+    /// Returns `None` when the values are not relative equal and unordered.
+    fn relative_partial_cmp(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> Option<Ordering>;
+
+    /// Determine whether `self` is less than `other` and not relative equal to it.
+    #[inline]
+    fn relative_lt(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        matches!(
+            Self::relative_partial_cmp(self, other, max_abs_diff, max_relative),
+            Some(Ordering::Less)
+        )
+    }
+
+    /// Determine whether `self` is less than `other` or relative equal to it.
+    #[inline]
+    fn relative_le(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        matches!(
+            Self::relative_partial_cmp(self, other, max_abs_diff, max_relative),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// Determine whether `self` is greater than `other` and not relative equal to
+    /// it.
+    #[inline]
+    fn relative_gt(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        matches!(
+            Self::relative_partial_cmp(self, other, max_abs_diff, max_relative),
+            Some(Ordering::Greater)
+        )
+    }
+
+    /// Determine whether `self` is greater than `other` or relative equal to it.
+    #[inline]
+    fn relative_ge(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        matches!(
+            Self::relative_partial_cmp(self, other, max_abs_diff, max_relative),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+}
+
+macro_rules! impl_relative_ord {
+    ($($T:ident),* $(,)?) => {$(
+        impl RelativeOrd for $T {
+            #[inline]
+            fn relative_partial_cmp(&self, other: &$T, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> Option<Ordering> {
+                if RelativeEq::relative_eq(self, other, max_abs_diff, max_relative) {
+                    Some(Ordering::Equal)
+                } else {
+                    PartialOrd::partial_cmp(self, other)
+                }
+            }
+        }
+    )*};
+}
+
+impl_relative_ord!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_relative_ord_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl RelativeOrd<$Rhs> for $T {
+            #[inline]
+            fn relative_partial_cmp(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> Option<Ordering> {
+                if RelativeEq::relative_eq(self, other, max_abs_diff, max_relative) {
+                    Some(Ordering::Equal)
+                } else {
+                    // Every `f32` widens to an `f64` exactly, so the order is decided in
+                    // `f64` precision.
+                    PartialOrd::partial_cmp(&f64::from(*self), &f64::from(*other))
+                }
+            }
+        }
+    )*};
+}
+
+impl_relative_ord_mixed_float!(f32 => f64, f64 => f32);
+
+/// Determine whether a finite precision floating point expression is less than
+/// another, treating relative equal values as equal.
+///
+/// For more details, see the documentation for [`RelativeOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_lt;
+/// #
+/// assert!(relative_lt!(0.25_f64, 0.5_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// assert!(!relative_lt!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! relative_lt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeOrd::relative_lt(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::relative_lt!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+}
+
+/// Determine whether a finite precision floating point expression is less than
+/// or equal to another, treating relative equal values as equal.
+///
+/// For more details, see the documentation for [`RelativeOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_le;
+/// #
+/// assert!(relative_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// assert!(!relative_le!(0.5_f64, 0.25_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! relative_le {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeOrd::relative_le(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::relative_le!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+}
+
+/// Determine whether a finite precision floating point expression is greater
+/// than another, treating relative equal values as equal.
+///
+/// For more details, see the documentation for [`RelativeOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_gt;
+/// #
+/// assert!(relative_gt!(0.5_f64, 0.25_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// assert!(!relative_gt!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! relative_gt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeOrd::relative_gt(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::relative_gt!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+}
+
+/// Determine whether a finite precision floating point expression is greater
+/// than or equal to another, treating relative equal values as equal.
+///
+/// For more details, see the documentation for [`RelativeOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_ge;
+/// #
+/// assert!(relative_ge!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// assert!(!relative_ge!(0.25_f64, 0.5_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64));
+/// ```
+#[macro_export]
+macro_rules! relative_ge {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeOrd::relative_ge(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::relative_ge!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+}
+
+/// Assert that a finite precision floating point expression is less than
+/// another, treating relative equal values as equal.
+///
+/// See the documentation for [`RelativeOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_lt;
+/// #
+/// assert_relative_lt!(0.25_f64, 0.5_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_lt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_lt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_lt!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_lt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_lt!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_relative_lt!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_relative_lt!($left, $right, abs_diff <= $tol_1, relative <= $tol_2, $($arg)+)
+    };
+}
+
+/// Assert that a finite precision floating point expression is less than or
+/// equal to another, treating relative equal values as equal.
+///
+/// See the documentation for [`RelativeOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_le;
+/// #
+/// assert_relative_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_le {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_le(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_le!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_le(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_le!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_relative_le!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_relative_le!($left, $right, abs_diff <= $tol_1, relative <= $tol_2, $($arg)+)
+    };
+}
+
+/// Assert that a finite precision floating point expression is greater than
+/// another, treating relative equal values as equal.
+///
+/// See the documentation for [`RelativeOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_gt;
+/// #
+/// assert_relative_gt!(0.5_f64, 0.25_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_gt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_gt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_gt!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_gt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_gt!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_relative_gt!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_relative_gt!($left, $right, abs_diff <= $tol_1, relative <= $tol_2, $($arg)+)
+    };
+}
+
+/// Assert that a finite precision floating point expression is greater than or
+/// equal to another, treating relative equal values as equal.
+///
+/// See the documentation for [`RelativeOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_ge;
+/// #
+/// assert_relative_ge!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 0.0_f64, relative <= 1e-12_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_ge {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_ge(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_ge!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::RelativeOrd::relative_ge(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_ge!(left, right, abs_diff <= t, relative <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+[abs_diff] t: `{:?}`,
+[relative] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertRelativeEq::debug_relative_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_relative_ge!($left, $right, abs_diff <= $tol_1, relative <= $tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_relative_ge!($left, $right, abs_diff <= $tol_1, relative <= $tol_2, $($arg)+)
+    };
+}
@@ -0,0 +1,289 @@
+//! Relative comparisons of floating point numbers.
+//!
+//! The functions in this module compare floating point numbers the same way the
+//! relative comparisons of this crate compare [`f32`] and [`f64`]. They are
+//! generic over [`Float`], so a number type implementing it, such as a half
+//! precision or fixed point number type, compares with exactly the same
+//! semantics as the primitive floating point types.
+//!
+//! # Example
+//!
+//! ```
+//! # use relative_cmp::relative;
+//! #
+//! assert!(relative::relative_eq(100.0_f64, 101.0_f64, 0.0_f64, 0.01_f64));
+//! assert!(!relative::relative_eq(100.0_f64, 102.0_f64, 0.0_f64, 0.01_f64));
+//! assert_eq!(relative::relative_diff(100.0_f64, 80.0_f64), 0.2_f64);
+//! ```
+use crate::denominator::RelativeDenominator;
+use crate::explain::RelativeExplanation;
+use approx_cmp_float::Float;
+
+/// Compare two floating point numbers with an absolute difference tolerance,
+/// followed by a relative tolerance.
+///
+/// This is the comparison of [`RelativeEq::relative_eq`](crate::RelativeEq::relative_eq)
+/// for [`f32`] and [`f64`], available to every number type implementing
+/// [`Float`]. Equal numbers, including infinities of the same sign, are
+/// relatively equal. Otherwise, the numbers are relatively equal when they are
+/// finite, and their absolute difference is within `max_abs_diff`, or within
+/// `max_relative` times the larger of their magnitudes. NaN is not relatively
+/// equal to any number.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative;
+/// #
+/// assert!(relative::relative_eq(f32::INFINITY, f32::INFINITY, 0.0_f32, f32::EPSILON));
+/// assert!(!relative::relative_eq(f32::MAX, f32::INFINITY, 0.0_f32, f32::EPSILON));
+/// assert!(relative::relative_eq(1e-30_f32, -1e-30_f32, 1e-29_f32, f32::EPSILON));
+/// ```
+#[inline]
+pub fn relative_eq<T>(lhs: T, rhs: T, max_abs_diff: T, max_relative: T) -> bool
+where
+    T: Float,
+{
+    explain_relative(lhs, rhs, max_abs_diff, max_relative).passed()
+}
+
+/// Compare two floating point numbers with [`relative_eq`], after flushing their
+/// subnormal numbers to the zeros of the same signs.
+///
+/// This is the comparison of [`RelativeEqFtz`](crate::RelativeEqFtz), available
+/// to every number type implementing [`Float`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative;
+/// #
+/// assert!(!relative::relative_eq(0.0_f64, 1e-310_f64, 0.0_f64, 1e-12_f64));
+/// assert!(relative::relative_eq_ftz(0.0_f64, 1e-310_f64, 0.0_f64, 1e-12_f64));
+/// ```
+#[inline]
+pub fn relative_eq_ftz<T>(lhs: T, rhs: T, max_abs_diff: T, max_relative: T) -> bool
+where
+    T: Float,
+{
+    relative_eq(lhs.flush_to_zero(), rhs.flush_to_zero(), max_abs_diff, max_relative)
+}
+
+/// Explain the outcome of comparing two floating point numbers with
+/// [`relative_eq`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative,
+/// #     RelativeExplanation,
+/// # };
+/// #
+/// assert_eq!(
+///     relative::explain_relative(100.0_f32, 101.0_f32, 0.0_f32, 0.01_f32),
+///     RelativeExplanation::PassedRelative
+/// );
+/// assert_eq!(
+///     relative::explain_relative(f32::NAN, 1.0_f32, 0.0_f32, 0.01_f32),
+///     RelativeExplanation::FailedNan
+/// );
+/// ```
+pub fn explain_relative<T>(lhs: T, rhs: T, max_abs_diff: T, max_relative: T) -> RelativeExplanation
+where
+    T: Float,
+{
+    explain_relative_by(lhs, rhs, max_abs_diff, max_relative, RelativeDenominator::Max)
+}
+
+/// Compare two floating point numbers with an absolute difference tolerance,
+/// followed by a relative tolerance measured against the magnitude chosen by
+/// `denominator`.
+///
+/// This is the comparison of
+/// [`RelativeEqBy::relative_eq_by`](crate::RelativeEqBy::relative_eq_by) for
+/// [`f32`] and [`f64`]. It is the comparison of [`relative_eq`], except that the
+/// absolute difference of the numbers must be within `max_relative` times the
+/// magnitude of `denominator`, in place of the larger of their magnitudes.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// assert!(relative::relative_eq_by(100.0_f64, 101.0_f64, 0.0_f64, 0.00995_f64, RelativeDenominator::Second));
+/// assert!(!relative::relative_eq_by(100.0_f64, 101.0_f64, 0.0_f64, 0.00995_f64, RelativeDenominator::First));
+/// assert!(relative::relative_eq_by(101.0_f64, 100.0_f64, 0.0_f64, 0.00995_f64, RelativeDenominator::First));
+/// ```
+#[inline]
+pub fn relative_eq_by<T>(lhs: T, rhs: T, max_abs_diff: T, max_relative: T, denominator: RelativeDenominator) -> bool
+where
+    T: Float,
+{
+    explain_relative_by(lhs, rhs, max_abs_diff, max_relative, denominator).passed()
+}
+
+/// Explain the outcome of comparing two floating point numbers with
+/// [`relative_eq_by`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative,
+/// #     RelativeDenominator,
+/// #     RelativeExplanation,
+/// # };
+/// #
+/// assert_eq!(
+///     relative::explain_relative_by(100.0_f32, 101.0_f32, 0.0_f32, 0.00995_f32, RelativeDenominator::Min),
+///     RelativeExplanation::FailedRelative
+/// );
+/// ```
+pub fn explain_relative_by<T>(lhs: T, rhs: T, max_abs_diff: T, max_relative: T, denominator: RelativeDenominator) -> RelativeExplanation
+where
+    T: Float,
+{
+    // If `lhs` and `rhs` are finite and bitwise identical, They are relatively
+    // equal. If `lhs` and `rhs` are infinite and bitwise identical, they are
+    // the same kind of infinity, and therefore also equal.
+    if lhs == rhs {
+        return RelativeExplanation::PassedEqual;
+    }
+
+    // If `lhs` and `rhs` are finite, this clause does not apply. If one
+    // of `lhs` and `rhs` is finite, and the other one is infinite, they
+    // are not equal.
+    if lhs.is_infinite() || rhs.is_infinite() {
+        return RelativeExplanation::FailedInfinite;
+    }
+
+    // A NaN fails every remaining branch, so it is reported before the
+    // comparisons that it fails silently.
+    if lhs.is_nan() || rhs.is_nan() {
+        return RelativeExplanation::FailedNan;
+    }
+
+    // Now check whether `lhs` and `rhs` are really close together.
+    // This is necessary when `lhs` and `rhs` are near zero.
+    let abs_diff = (lhs - rhs).abs();
+    if abs_diff <= max_abs_diff {
+        return RelativeExplanation::PassedAbsDiff;
+    }
+
+    // Finally, if the other cases have failed, we check their relative
+    // absolute difference against the magnitude chosen by `denominator`.
+    if abs_diff <= denominator.magnitude(lhs, rhs) * max_relative {
+        RelativeExplanation::PassedRelative
+    } else {
+        RelativeExplanation::FailedRelative
+    }
+}
+
+/// The relative difference of two floating point numbers.
+///
+/// This is the absolute difference of the numbers divided by the larger of their
+/// magnitudes. Equal numbers, including infinities of the same sign, have no
+/// relative difference, and any other infinite number is infinitely far from
+/// every number.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative;
+/// #
+/// assert_eq!(relative::relative_diff(2.0_f64, 1.0_f64), 0.5_f64);
+/// assert_eq!(relative::relative_diff(f64::INFINITY, f64::INFINITY), 0.0_f64);
+/// assert_eq!(relative::relative_diff(f64::INFINITY, 1.0_f64), f64::INFINITY);
+/// ```
+#[inline]
+pub fn relative_diff<T>(lhs: T, rhs: T) -> T
+where
+    T: Float,
+{
+    relative_diff_by(lhs, rhs, RelativeDenominator::Max)
+}
+
+/// The relative difference of two floating point numbers, measured against the
+/// magnitude chosen by `denominator`.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// assert_eq!(relative::relative_diff_by(2.0_f64, 1.0_f64, RelativeDenominator::First), 0.5_f64);
+/// assert_eq!(relative::relative_diff_by(2.0_f64, 1.0_f64, RelativeDenominator::Second), 1.0_f64);
+/// assert_eq!(relative::relative_diff_by(3.0_f64, 1.0_f64, RelativeDenominator::Mean), 1.0_f64);
+/// ```
+#[inline]
+pub fn relative_diff_by<T>(lhs: T, rhs: T, denominator: RelativeDenominator) -> T
+where
+    T: Float,
+{
+    if lhs == rhs {
+        return T::ZERO;
+    }
+    if lhs.is_infinite() || rhs.is_infinite() {
+        return T::INFINITY;
+    }
+
+    let abs_diff = (lhs - rhs).abs();
+
+    abs_diff / denominator.magnitude(lhs, rhs)
+}
+
+/// Compare two floating point numbers the way NumPy's `isclose` does.
+///
+/// The numbers are close when the absolute difference of `lhs` and `rhs` is
+/// within `atol + rtol * abs(rhs)`, and `rhs` is finite, or when the numbers are
+/// equal, including infinities of the same sign. Unlike [`relative_eq`], the
+/// comparison is asymmetric, since the relative tolerance scales with the
+/// magnitude of `rhs` alone, and the tolerances add up in place of applying one
+/// after the other. Two NaNs are close when `equal_nan` is `true`, and NaN is not
+/// close to any other number.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative;
+/// #
+/// assert!(relative::is_close(1.0_f64, 1.1_f64, 0.1_f64, 0.0_f64, false));
+/// assert!(!relative::is_close(1.1_f64, 1.0_f64, 0.1_f64, 0.0_f64, false));
+/// assert!(relative::is_close(f64::NAN, f64::NAN, 0.0_f64, 0.0_f64, true));
+/// assert!(!relative::is_close(f64::NAN, f64::NAN, 0.0_f64, 0.0_f64, false));
+/// ```
+#[inline]
+pub fn is_close<T>(lhs: T, rhs: T, rtol: T, atol: T, equal_nan: bool) -> bool
+where
+    T: Float,
+{
+    let finite = !rhs.is_nan() && !rhs.is_infinite();
+    let within = (lhs - rhs).abs() <= close_tolerance(rhs, rtol, atol);
+
+    (within && finite) || lhs == rhs || (equal_nan && lhs.is_nan() && rhs.is_nan())
+}
+
+/// The tolerance `atol + rtol * abs(rhs)` that [`is_close`] compares the absolute
+/// difference of two floating point numbers against.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative;
+/// #
+/// assert_eq!(relative::close_tolerance(-4.0_f64, 0.5_f64, 1.0_f64), 3.0_f64);
+/// ```
+#[inline]
+pub fn close_tolerance<T>(rhs: T, rtol: T, atol: T) -> T
+where
+    T: Float,
+{
+    atol + rtol * rhs.abs()
+}
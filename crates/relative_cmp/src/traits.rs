@@ -1,4 +1,23 @@
+use crate::denominator::RelativeDenominator;
+use crate::norm::{
+    Norm,
+    NormAccumulator,
+    Norms,
+};
+use approx_cmp_float::Float;
+use approx_cmp_report::{
+    Element,
+    ErrorStats,
+    FirstMismatch,
+    FloatPolicy,
+    Mismatch,
+    Path,
+    PolicyVisitor,
+    Visitor,
+};
+
 use core::fmt;
+use core::ops;
 
 /// Compare two sequences of finite precision floating point numbers using
 /// per entry relative difference tolerances.
@@ -90,6 +109,54 @@ use core::fmt;
 /// // Using the [`relative_ne`] macro.
 /// assert!(relative_ne!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative2));
 /// ```
+///
+/// # Examples (Integer Comparisons)
+///
+/// The integer types compare with [`f64`] tolerances, against the distance
+/// between the integers, which never overflows.
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative_eq,
+/// #     relative_ne,
+/// #     RelativeEq,
+/// # };
+/// #
+/// let lhs = 100_000_i32;
+/// let rhs = 100_090_i32;
+///
+/// assert!(relative_eq!(lhs, rhs, abs_diff <= 0.0_f64, relative <= 0.001_f64));
+/// assert!(relative_ne!(lhs, rhs, abs_diff <= 0.0_f64, relative <= 0.0001_f64));
+/// assert!(relative_eq!(i32::MIN, i32::MAX, abs_diff <= 0.0_f64, relative <= 2.0_f64));
+/// ```
+///
+/// # Examples (Mixed Precision Comparisons)
+///
+/// An [`f32`] compares with an [`f64`] in either order. The [`f32`] widens to an
+/// [`f64`] exactly, and the absolute and relative differences are measured in
+/// [`f64`] precision, so the tolerances are [`f64`] numbers.
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative_eq,
+/// #     relative_ne,
+/// #     RelativeEq,
+/// # };
+/// #
+/// let result = 0.1_f32;
+/// let reference = 0.1_f64;
+///
+/// // `0.1_f32` is `0.100000001490116...` as an `f64`.
+/// assert!(result.relative_eq(&reference, &0.0_f64, &2e-8_f64));
+/// assert!(result.relative_ne(&reference, &0.0_f64, &1e-8_f64));
+/// assert!(relative_eq!(reference, result, abs_diff <= 0.0_f64, relative <= 2e-8_f64));
+///
+/// // Containers of `f32` compare with containers of `f64` element by element.
+/// let results = vec![0.1_f32, 0.2_f32, 0.3_f32];
+/// let references = vec![0.1_f64, 0.2_f64, 0.3_f64];
+///
+/// assert!(relative_eq!(results, references, abs_diff_all <= 0.0_f64, relative_all <= f32::EPSILON as f64));
+/// ```
 pub trait RelativeEq<Rhs = Self>
 where
     Rhs: ?Sized,
@@ -401,6 +468,10 @@ where
     /// is used to display results via [`fmt::Debug`].
     type DebugAbsDiff: fmt::Debug + Sized;
 
+    /// The relative difference between two values in a debugging context. This
+    /// is used to display results via [`fmt::Debug`].
+    type DebugRelativeDiff: fmt::Debug + Sized;
+
     /// The value of the tolerance used for comparing two values in a debugging
     /// context. This is used to display results via [`fmt::Debug`].
     type DebugTolerance: fmt::Debug;
@@ -421,6 +492,27 @@ where
     /// ```
     fn debug_abs_diff(&self, other: &Rhs) -> Self::DebugAbsDiff;
 
+    /// Compute the relative difference between two values in a debugging context.
+    ///
+    /// The relative difference between two numbers is their absolute difference
+    /// divided by the larger of their absolute values. It is the smallest
+    /// relative tolerance for which the two numbers compare relatively equal
+    /// without the help of the absolute difference tolerance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::AssertRelativeEq;
+    /// #
+    /// let lhs = (4.0_f64, 50.0_f32);
+    /// let rhs = (5.0_f64, 50.0_f32);
+    /// let expected = (0.2_f64, 0.0_f32);
+    /// let result = lhs.debug_relative_diff(&rhs);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    fn debug_relative_diff(&self, other: &Rhs) -> Self::DebugRelativeDiff;
+
     /// Compute the maximum allowed absolute difference between two values for a
     /// debugging context.
     ///
@@ -456,6 +548,115 @@ where
     /// assert_eq!(result, expected);
     /// ```
     fn debug_relative_tolerance(&self, other: &Rhs, max_relative: &Self::Tolerance) -> Self::DebugTolerance;
+
+    /// Report every element compared by [`RelativeEq::relative_eq`] to a visitor,
+    /// along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, so that a failed
+    /// comparison can be traced to the elements that failed it. The traversal
+    /// stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertRelativeEq,
+    /// #     Element,
+    /// #     Path,
+    /// # };
+    /// # use core::ops::ControlFlow;
+    /// #
+    /// let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    /// let rhs = [1.0_f64, 2.5_f64, 3.0_f64];
+    /// let max_abs_diff = [0.0_f64; 3];
+    /// let max_relative = [0.01_f64; 3];
+    /// let mut failed = Vec::new();
+    /// let _ = lhs.visit_relative(
+    ///     &rhs,
+    ///     &max_abs_diff,
+    ///     &max_relative,
+    ///     &mut Path::new(),
+    ///     &mut |path: &Path<'_>, element: &Element<'_>| {
+    ///         if !element.passed {
+    ///             failed.push(path.to_string());
+    ///         }
+    ///
+    ///         ControlFlow::Continue(())
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(failed, vec!["[1]"]);
+    /// ```
+    fn visit_relative<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        let abs_diff = self.debug_abs_diff(other);
+        let relative_diff = self.debug_relative_diff(other);
+        let abs_diff_tolerance = self.debug_abs_diff_tolerance(other, max_abs_diff);
+        let relative_tolerance = self.debug_relative_tolerance(other, max_relative);
+        visitor.visit(
+            path,
+            &Element::new(
+                self.relative_eq(other, max_abs_diff, max_relative),
+                &[("abs_diff", &abs_diff), ("relative", &relative_diff)],
+                &[("abs_diff", &abs_diff_tolerance), ("relative", &relative_tolerance)],
+            ),
+        )
+    }
+
+    /// Compare two values for relative difference equality, with the pairs of numbers
+    /// involving NaNs, infinities, or signed zeros decided by `policy`.
+    ///
+    /// The elements reported by [`visit_relative`] decide the comparison, so the
+    /// policy reaches every number compared inside a container.
+    ///
+    /// [`visit_relative`]: AssertRelativeEq::visit_relative
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertRelativeEq,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = [1.0_f64, f64::NAN];
+    /// let rhs = [1.0_f64, f64::NAN];
+    /// let nan_eq = FloatPolicy {
+    ///     nan_eq: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(lhs.relative_eq_with_policy(&rhs, &[0.0_f64; 2], &[1e-9_f64; 2], &nan_eq));
+    /// assert!(!lhs.relative_eq_with_policy(&rhs, &[0.0_f64; 2], &[1e-9_f64; 2], &FloatPolicy::new()));
+    /// ```
+    fn relative_eq_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        policy.passes(|path, visitor| self.visit_relative(other, max_abs_diff, max_relative, path, visitor))
+    }
+
+    /// Compare two values for relative difference inequality, with the pairs of numbers
+    /// involving NaNs, infinities, or signed zeros decided by `policy`.
+    fn relative_ne_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        !Self::relative_eq_with_policy(self, other, max_abs_diff, max_relative, policy)
+    }
 }
 
 /// Provides a debugging context for when a relative difference comparison using
@@ -507,238 +708,2033 @@ where
     /// assert_eq!(result, expected);
     /// ```
     fn debug_relative_all_tolerance(&self, other: &Rhs, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance;
-}
-
-#[doc(hidden)]
-pub struct RelativeCmp {}
-
-impl RelativeCmp {
-    #[must_use]
-    #[inline]
-    pub fn eq<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance) -> bool
-    where
-        A: RelativeEq<B> + ?Sized,
-        B: ?Sized,
-    {
-        A::relative_eq(lhs, rhs, max_abs_diff, max_relative)
-    }
-
-    #[must_use]
-    #[inline]
-    pub fn ne<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance) -> bool
-    where
-        A: RelativeEq<B> + ?Sized,
-        B: ?Sized,
-    {
-        A::relative_ne(lhs, rhs, max_abs_diff, max_relative)
-    }
-
-    #[must_use]
-    #[inline]
-    pub fn all_eq<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::AllTolerance, max_relative: &A::AllTolerance) -> bool
-    where
-        A: RelativeAllEq<B> + ?Sized,
-        B: ?Sized,
-    {
-        A::relative_all_eq(lhs, rhs, max_abs_diff, max_relative)
-    }
-
-    #[must_use]
-    #[inline]
-    pub fn all_ne<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::AllTolerance, max_relative: &A::AllTolerance) -> bool
-    where
-        A: RelativeAllEq<B> + ?Sized,
-        B: ?Sized,
-    {
-        A::relative_all_ne(lhs, rhs, max_abs_diff, max_relative)
-    }
-}
-
-#[doc(hidden)]
-pub struct RelativeCmpOpTol {}
-
-impl RelativeCmpOpTol {
-    #[inline]
-    pub fn abs_diff<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance) -> A::DebugTolerance
-    where
-        A: RelativeEq<B> + AssertRelativeEq<B>,
-    {
-        A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff)
-    }
 
-    #[inline]
-    pub fn abs_diff_all<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::AllTolerance) -> A::AllDebugTolerance
-    where
-        A: RelativeAllEq<B> + AssertRelativeAllEq<B>,
-    {
-        A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff)
+    /// Report every element compared by [`RelativeAllEq::relative_all_eq`] to a
+    /// visitor, along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, so that a failed
+    /// comparison can be traced to the elements that failed it. The traversal
+    /// stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertRelativeAllEq,
+    /// #     FirstMismatch,
+    /// #     Path,
+    /// # };
+    /// #
+    /// let lhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    /// let rhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    /// let mut first = FirstMismatch::new();
+    /// let _ = lhs.visit_relative_all(&rhs, &0.0_f64, &0.01_f64, &mut Path::new(), &mut first);
+    ///
+    /// assert_eq!(first.path().map(|path| path.to_string()), Some(String::from("[1][1]")));
+    /// ```
+    fn visit_relative_all<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        let abs_diff_tolerance = self.debug_abs_diff_all_tolerance(other, max_abs_diff);
+        let relative_tolerance = self.debug_relative_all_tolerance(other, max_relative);
+        visitor.visit(
+            path,
+            &Element::new(
+                self.relative_all_eq(other, max_abs_diff, max_relative),
+                &[],
+                &[("abs_diff_all", &abs_diff_tolerance), ("relative_all", &relative_tolerance)],
+            ),
+        )
     }
 
-    #[inline]
-    pub fn relative<A, B>(lhs: &A, rhs: &B, max_relative: &A::Tolerance) -> A::DebugTolerance
-    where
-        A: RelativeEq<B> + AssertRelativeEq<B>,
-    {
-        A::debug_relative_tolerance(lhs, rhs, max_relative)
+    /// Compare two values for relative difference equality using uniform tolerances, with
+    /// the pairs of numbers involving NaNs, infinities, or signed zeros decided by
+    /// `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertRelativeAllEq,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = [-0.0_f32, 1.0_f32];
+    /// let rhs = [0.0_f32, 1.0_f32];
+    /// let signed_zero_strict = FloatPolicy {
+    ///     signed_zero_strict: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(lhs.relative_all_eq_with_policy(&rhs, &0.0_f32, &1e-6_f32, &FloatPolicy::new()));
+    /// assert!(!lhs.relative_all_eq_with_policy(&rhs, &0.0_f32, &1e-6_f32, &signed_zero_strict));
+    /// ```
+    fn relative_all_eq_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        policy.passes(|path, visitor| self.visit_relative_all(other, max_abs_diff, max_relative, path, visitor))
     }
 
-    #[inline]
-    pub fn relative_all<A, B>(lhs: &A, rhs: &B, max_relative: &A::AllTolerance) -> A::AllDebugTolerance
-    where
-        A: RelativeAllEq<B> + AssertRelativeAllEq<B>,
-    {
-        A::debug_relative_all_tolerance(lhs, rhs, max_relative)
+    /// Compare two values for relative difference inequality using uniform tolerances, with
+    /// the pairs of numbers involving NaNs, infinities, or signed zeros decided by
+    /// `policy`.
+    fn relative_all_ne_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        !Self::relative_all_eq_with_policy(self, other, max_abs_diff, max_relative, policy)
     }
 }
 
-/// Compare two finite precision floating point expression for relative
-/// difference equality.
+/// Compare two sequences of finite precision floating point numbers using per
+/// entry relative difference tolerances, measured against a chosen magnitude.
 ///
-/// For more details, see the documentation for [`RelativeEq`] and [`RelativeAllEq`].
+/// Types implement this trait to utilize the [`relative_eq_by`] and
+/// [`relative_ne_by`] macros.
+///
+/// More precisely, let `A` be a finite set of values, let `T` be a floating
+/// point data type, let `u :: A -> T` and `v :: A -> T` be sequences of finite
+/// precision floating point numbers, and let `max_relative :: A -> T` be a
+/// sequence of finite precision floating point numbers such that
+/// ```text
+/// forall a :: A. max_relative[a] >= 0
+/// ```
+/// Let `d` be one of the [`RelativeDenominator`] choices, with `d(x, y)` its
+/// magnitude of `x` and `y`. We say that `u` is **relative equal** to `v` with
+/// tolerance `max_relative` measured against `d` provided that
+/// ```text
+/// forall a :: A. abs(u[a] - v[a]) <= d(u[a], v[a]) * max_relative[a]
+/// ```
+/// The comparison is the one of [`RelativeEq`] otherwise, with the same
+/// tolerance types, and [`RelativeDenominator::Max`] compares exactly like
+/// [`RelativeEq::relative_eq`]. Choosing [`RelativeDenominator::First`] or
+/// [`RelativeDenominator::Second`] makes the comparison asymmetric.
 ///
 /// # Example
 ///
 /// ```
-/// # use relative_cmp::relative_eq;
+/// # use relative_cmp::{
+/// #     relative_eq_by,
+/// #     RelativeDenominator,
+/// #     RelativeEqBy,
+/// # };
 /// #
-/// let lhs = 98.0005_f32;
-/// let rhs = 98.0001_f32;
+/// let actual = 99.0_f64;
+/// let expected = 100.0_f64;
 ///
-/// assert!(relative_eq!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 6e-6_f32));
-/// assert!(relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32));
+/// // The tolerance is relative to the expected value alone.
+/// assert!(actual.relative_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+/// assert!(actual.relative_ne_by(&expected, &0.0_f64, &0.0099_f64, RelativeDenominator::Second));
+///
+/// // The smaller magnitude is the strictest choice.
+/// assert!(actual.relative_ne_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Min));
+///
+/// assert!(relative_eq_by!(
+///     actual,
+///     expected,
+///     abs_diff <= 0.0_f64,
+///     relative <= 0.01_f64,
+///     denominator = RelativeDenominator::Second,
+/// ));
 /// ```
-#[macro_export]
-macro_rules! relative_eq {
-    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::eq(left_val, right_val, tol_1_val, tol_2_val),
-        }
-    }};
-    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::eq(left_val, right_val, tol_1_val, tol_2_val),
-        }
-    }};
-    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_eq(left_val, right_val, tol_1_val, tol_2_val),
-        }
-    }};
-    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_eq(left_val, right_val, tol_1_val, tol_2_val),
-        }
-    }};
+pub trait RelativeEqBy<Rhs = Self>: RelativeEq<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// Compare two sequences of finite precision floating point numbers for
+    /// relative equality, with the relative difference measured against the
+    /// magnitude chosen by `denominator`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     RelativeDenominator,
+    /// #     RelativeEqBy,
+    /// # };
+    /// #
+    /// let lhs = [1.0_f32, 4.0_f32];
+    /// let rhs = [1.0_f32, 5.0_f32];
+    /// let max_abs_diff = [0.0_f32; 2];
+    /// let max_relative = [0.0_f32, 0.2_f32];
+    ///
+    /// assert!(lhs.relative_eq_by(&rhs, &max_abs_diff, &max_relative, RelativeDenominator::Max));
+    /// assert!(!lhs.relative_eq_by(&rhs, &max_abs_diff, &max_relative, RelativeDenominator::First));
+    /// ```
+    fn relative_eq_by(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool;
+
+    /// Compare two sequences of finite precision floating point numbers for
+    /// relative inequality, with the relative difference measured against the
+    /// magnitude chosen by `denominator`.
+    ///
+    /// An implementation of [`RelativeEqBy::relative_ne_by`] should be the
+    /// negation of [`RelativeEqBy::relative_eq_by`], and should not be
+    /// implemented directly in general.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     RelativeDenominator,
+    /// #     RelativeEqBy,
+    /// # };
+    /// #
+    /// let lhs = 4.0_f32;
+    /// let rhs = 5.0_f32;
+    ///
+    /// assert!(lhs.relative_ne_by(&rhs, &0.0_f32, &0.2_f32, RelativeDenominator::First));
+    /// ```
+    fn relative_ne_by(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        !Self::relative_eq_by(self, other, max_abs_diff, max_relative, denominator)
+    }
 }
 
-/// Compare two finite precision floating point expression for relative
-/// difference inequality.
+/// Compare two sequences of finite precision floating point numbers using a
+/// uniform relative difference tolerance, measured against a chosen magnitude.
 ///
-/// For more details, see the documentation for [`RelativeEq`] and [`RelativeAllEq`].
+/// Types implement this trait to utilize the [`relative_eq_by`] and
+/// [`relative_ne_by`] macros with `all` parameters.
+///
+/// The comparison is the one of [`RelativeEqBy`], with the single uniform
+/// tolerance of [`RelativeAllEq`] for every entry of two values.
 ///
 /// # Example
 ///
 /// ```
-/// # use relative_cmp::relative_ne;
+/// # use relative_cmp::{
+/// #     relative_eq_by,
+/// #     RelativeAllEqBy,
+/// #     RelativeDenominator,
+/// # };
 /// #
-/// let lhs = 98.0005_f32;
-/// let rhs = 98.0001_f32;
+/// let actual = vec![1.005_f64, 2.01_f64, 2.985_f64];
+/// let expected = vec![1.0_f64, 2.0_f64, 3.0_f64];
 ///
-/// assert!(relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32));
-/// assert!(relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 2e-6_f32));
+/// assert!(actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+/// assert!(actual.relative_all_ne_by(&expected, &0.0_f64, &0.004_f64, RelativeDenominator::Second));
+///
+/// assert!(relative_eq_by!(
+///     actual,
+///     expected,
+///     abs_diff_all <= 0.0_f64,
+///     relative_all <= 0.01_f64,
+///     denominator = RelativeDenominator::Second,
+/// ));
 /// ```
-#[macro_export]
-macro_rules! relative_ne {
-    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::ne(left_val, right_val, tol_1_val, tol_2_val),
-        }
-    }};
-    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::ne(left_val, right_val, tol_1_val, tol_2_val),
-        }
-    }};
-    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_ne(left_val, right_val, tol_1_val, tol_2_val),
-        }
+pub trait RelativeAllEqBy<Rhs = Self>: RelativeAllEq<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// Compare two sequences of floating point numbers for relative equality
+    /// using a single uniform tolerance value, with the relative difference
+    /// measured against the magnitude chosen by `denominator`.
+    ///
+    /// An implementation of [`RelativeAllEqBy::relative_all_eq_by`] must use the
+    /// same algorithm as [`RelativeEqBy::relative_eq_by`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     RelativeAllEqBy,
+    /// #     RelativeDenominator,
+    /// # };
+    /// #
+    /// let lhs = [2.0_f32, 4.0_f32];
+    /// let rhs = [2.0_f32, 6.0_f32];
+    ///
+    /// assert!(lhs.relative_all_eq_by(&rhs, &0.0_f32, &0.4_f32, RelativeDenominator::Mean));
+    /// assert!(!lhs.relative_all_eq_by(&rhs, &0.0_f32, &0.4_f32, RelativeDenominator::Min));
+    /// ```
+    fn relative_all_eq_by(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool;
+
+    /// Compare two sequences of floating point numbers for relative inequality
+    /// using a single uniform tolerance value, with the relative difference
+    /// measured against the magnitude chosen by `denominator`.
+    ///
+    /// An implementation of [`RelativeAllEqBy::relative_all_ne_by`] should be the
+    /// negation of [`RelativeAllEqBy::relative_all_eq_by`], and should not be
+    /// implemented directly in general.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     RelativeAllEqBy,
+    /// #     RelativeDenominator,
+    /// # };
+    /// #
+    /// let lhs = [2.0_f32, 4.0_f32];
+    /// let rhs = [2.0_f32, 6.0_f32];
+    ///
+    /// assert!(lhs.relative_all_ne_by(&rhs, &0.0_f32, &0.4_f32, RelativeDenominator::First));
+    /// ```
+    fn relative_all_ne_by(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool {
+        !Self::relative_all_eq_by(self, other, max_abs_diff, max_relative, denominator)
+    }
+}
+
+/// Provide a debugging context for when a relative difference comparison
+/// measured against a chosen magnitude fails.
+///
+/// Types implement this trait to use the [`assert_relative_eq_by`] and
+/// [`assert_relative_ne_by`] macros.
+pub trait AssertRelativeEqBy<Rhs = Self>: RelativeEqBy<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// Report every element compared by [`RelativeEqBy::relative_eq_by`] to a
+    /// visitor, along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, and the floating point
+    /// numbers report their absolute and relative differences, with the relative
+    /// difference and the relative tolerance measured against the magnitude
+    /// chosen by `denominator`. The traversal stops as soon as the visitor returns
+    /// [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertRelativeEqBy,
+    /// #     FirstMismatch,
+    /// #     Path,
+    /// #     RelativeDenominator,
+    /// # };
+    /// #
+    /// let lhs = [1.0_f64, 4.0_f64];
+    /// let rhs = [1.0_f64, 5.0_f64];
+    /// let mut first = FirstMismatch::new();
+    /// let _ = lhs.visit_relative_by(&rhs, &[0.0_f64; 2], &[0.2_f64; 2], RelativeDenominator::First, &mut Path::new(), &mut first);
+    ///
+    /// assert_eq!(first.path().map(|path| path.to_string()), Some(String::from("[1]")));
+    /// ```
+    fn visit_relative_by<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_relative: &Self::Tolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        visitor.visit(
+            path,
+            &Element::new(self.relative_eq_by(other, max_abs_diff, max_relative, denominator), &[], &[]),
+        )
+    }
+}
+
+/// Provide a debugging context for when a relative difference comparison
+/// measured against a chosen magnitude using an `all` comparison fails.
+///
+/// Types implement this trait to use the [`assert_relative_eq_by`] and
+/// [`assert_relative_ne_by`] macros with `all` parameters.
+pub trait AssertRelativeAllEqBy<Rhs = Self>: RelativeAllEqBy<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// Report every element compared by [`RelativeAllEqBy::relative_all_eq_by`]
+    /// to a visitor, along with its path inside the compared values relative to
+    /// `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn. The traversal stops as
+    /// soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertRelativeAllEqBy,
+    /// #     FirstMismatch,
+    /// #     Path,
+    /// #     RelativeDenominator,
+    /// # };
+    /// #
+    /// let lhs = vec![[2.0_f64, 4.0_f64], [2.0_f64, 4.0_f64]];
+    /// let rhs = vec![[2.0_f64, 6.0_f64], [2.0_f64, 4.0_f64]];
+    /// let mut first = FirstMismatch::new();
+    /// let _ = lhs.visit_relative_all_by(&rhs, &0.0_f64, &0.4_f64, RelativeDenominator::Min, &mut Path::new(), &mut first);
+    ///
+    /// assert_eq!(first.path().map(|path| path.to_string()), Some(String::from("[0][1]")));
+    /// ```
+    fn visit_relative_all_by<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_relative: &Self::AllTolerance,
+        denominator: RelativeDenominator,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        visitor.visit(
+            path,
+            &Element::new(self.relative_all_eq_by(other, max_abs_diff, max_relative, denominator), &[], &[]),
+        )
+    }
+}
+
+/// Compare two values with the semantics of NumPy's `isclose` and `allclose`.
+///
+/// Types implement this trait to utilize the [`all_close`] and
+/// [`assert_all_close`] macros.
+///
+/// More precisely, let `A` be a finite set of values, let `T` be a floating
+/// point data type, and let `u :: A -> T` and `v :: A -> T` be sequences of
+/// finite precision floating point numbers. Let `rtol :: T` and `atol :: T` be
+/// finite precision floating point numbers such that `rtol >= 0` and
+/// `atol >= 0`. We say that `u` is **all close** to `v` with tolerances `rtol`
+/// and `atol` provided that
+/// ```text
+/// forall a :: A. abs(u[a] - v[a]) <= atol + rtol * abs(v[a])
+/// ```
+/// where `v[a]` is finite, or `u[a] == v[a]`. This is the rule of
+/// `numpy.allclose(u, v, rtol, atol)`, so tolerances ported from NumPy keep
+/// their meaning. Unlike [`RelativeEq`], the rule is asymmetric, since the
+/// relative tolerance scales with the magnitude of `v[a]` alone, and the
+/// absolute and relative tolerances add up in place of applying one after the
+/// other. Two NaNs are close when `equal_nan` is `true`, and NaN is not close to
+/// any other number.
+///
+/// The trait implementations for [`f32`] and [`f64`] compute in the precision of
+/// the compared numbers. The integer primitives, and mixed precision comparisons
+/// between [`f32`] and [`f64`], compute in [`f64`] with [`f64`] tolerances, the
+/// same way NumPy converts them.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     all_close,
+/// #     AllClose,
+/// # };
+/// #
+/// let lhs = [1.0_f64, 100.0_f64, f64::NAN];
+/// let rhs = [1.0_f64 + 1e-9_f64, 100.0_f64 + 1e-4_f64, f64::NAN];
+///
+/// assert!(lhs.all_close(&rhs, &1e-5_f64, &1e-8_f64, true));
+/// assert!(!lhs.all_close(&rhs, &1e-5_f64, &1e-8_f64, false));
+///
+/// assert!(all_close!(lhs, rhs, rtol = 1e-5_f64, atol = 1e-8_f64, equal_nan = true));
+/// ```
+pub trait AllClose<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// The data type of the relative tolerance and the absolute tolerance shared
+    /// by every entry of two values.
+    type CloseTolerance: ?Sized;
+
+    /// Compare two sequences of floating point numbers with the semantics of
+    /// NumPy's `allclose`.
+    ///
+    /// Returns a boolean indicating whether every entry of `self` is within the
+    /// absolute tolerance `atol` plus the relative tolerance `rtol` times the
+    /// magnitude of the corresponding entry of `other`. Pairs of NaNs are close
+    /// when `equal_nan` is `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::AllClose;
+    /// #
+    /// let lhs = 100.0_f32;
+    /// let rhs = 101.0_f32;
+    ///
+    /// // The relative tolerance scales with the magnitude of `other`.
+    /// assert!(lhs.all_close(&rhs, &0.00995_f32, &0.0_f32, false));
+    /// assert!(!rhs.all_close(&lhs, &0.00995_f32, &0.0_f32, false));
+    /// assert!(rhs.all_close(&lhs, &0.00995_f32, &0.01_f32, false));
+    /// ```
+    fn all_close(&self, other: &Rhs, rtol: &Self::CloseTolerance, atol: &Self::CloseTolerance, equal_nan: bool) -> bool;
+}
+
+/// Provide a debugging context for when an [`AllClose`] comparison fails.
+///
+/// Types implement this trait to use the [`assert_all_close`] macro.
+pub trait AssertAllClose<Rhs = Self>: AllClose<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// Report every element compared by [`AllClose::all_close`] to a visitor,
+    /// along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, and the floating point
+    /// numbers report their absolute difference along with the tolerance
+    /// `atol + rtol * abs(other)` it was compared against, labeled `close`. The
+    /// traversal stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     AssertAllClose,
+    /// #     FirstMismatch,
+    /// #     Path,
+    /// # };
+    /// #
+    /// let lhs = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    /// let rhs = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    /// let mut first = FirstMismatch::new();
+    /// let _ = lhs.visit_all_close(&rhs, &0.1_f64, &0.0_f64, false, &mut Path::new(), &mut first);
+    ///
+    /// assert_eq!(first.path().map(|path| path.to_string()), Some(String::from("[1][1]")));
+    /// ```
+    fn visit_all_close<'a>(
+        &'a self,
+        other: &Rhs,
+        rtol: &Self::CloseTolerance,
+        atol: &Self::CloseTolerance,
+        equal_nan: bool,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        visitor.visit(path, &Element::new(self.all_close(other, rtol, atol, equal_nan), &[], &[]))
+    }
+}
+
+/// Compare two sequences of floating point numbers by the norm of their
+/// difference.
+///
+/// Types implement this trait to utilize the [`norm_eq`] and [`assert_norm_eq`]
+/// macros.
+///
+/// More precisely, let `u` and `v` be vectors of finite precision floating
+/// point numbers of the same length, let `||.||` be one of the norms selected by
+/// [`Norm`], and let `max_abs_diff :: T` and `max_relative :: T` be finite
+/// precision floating point numbers such that `max_abs_diff >= 0` and
+/// `max_relative >= 0`. We say that `u` is **norm equal** to `v` with
+/// tolerances `max_abs_diff` and `max_relative` provided that
+/// ```text
+/// ||u - v|| <= max_abs_diff || ||u - v|| <= max_relative * ||v||
+/// ```
+/// Unlike the element-wise comparisons of [`RelativeEq`], a single tolerance
+/// bounds the error of the values as a whole, so individual elements may be off
+/// by more than the tolerance as long as the overall error is small. This is
+/// the usual acceptance test for the results of iterative solvers. As with
+/// [`AllClose`], the rule is asymmetric, since the relative tolerance scales
+/// with the norm of `v` alone.
+///
+/// Nested containers, such as arrays of arrays or a `Vec` of arrays, flatten
+/// into a single vector. Equal numbers, including infinities of the same sign,
+/// differ by zero. Any other infinity, or a NaN, in either value fails the
+/// comparison.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     Norm,
+/// #     NormEq,
+/// # };
+/// #
+/// let solution = [1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+/// let expected = [1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64 + 1e-3_f64];
+///
+/// // The last element is off by far more than an element-wise comparison allows.
+/// assert!(!solution.norm_eq(&expected, &0.0_f64, &1e-6_f64, Norm::LInf));
+///
+/// // But the error is small compared to the solution as a whole.
+/// assert!(solution.norm_eq(&expected, &0.0_f64, &1e-3_f64, Norm::L2));
+/// ```
+pub trait NormEq<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// The data type of the norms and of the tolerances of the comparison.
+    type NormTolerance: Float;
+
+    /// Add the element-wise differences of `self` and `other`, and the elements of
+    /// `other`, to `accumulator`.
+    ///
+    /// Returns `false` when `self` and `other` have different lengths.
+    fn accumulate_norms(&self, other: &Rhs, accumulator: &mut NormAccumulator<Self::NormTolerance>) -> bool;
+
+    /// Compute the norm `norm` of the difference of `self` and `other`, and of
+    /// `other`.
+    ///
+    /// Returns `None` when `self` and `other` have different lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     Norm,
+    /// #     NormEq,
+    /// #     Norms,
+    /// # };
+    /// #
+    /// let lhs = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    /// let rhs = vec![[1.0_f64, 2.0_f64], [3.0_f64, 6.0_f64]];
+    ///
+    /// assert_eq!(lhs.norms(&rhs, Norm::L1), Some(Norms { diff: 2.0_f64, reference: 12.0_f64 }));
+    /// assert_eq!(lhs[..1].norms(&rhs[..], Norm::L1), None);
+    /// ```
+    #[inline]
+    fn norms(&self, other: &Rhs, norm: Norm) -> Option<Norms<Self::NormTolerance>> {
+        let mut accumulator = NormAccumulator::new(norm);
+        if self.accumulate_norms(other, &mut accumulator) {
+            Some(accumulator.finish())
+        } else {
+            None
+        }
+    }
+
+    /// Compare two sequences of floating point numbers by the norm of their
+    /// difference.
+    ///
+    /// Returns a boolean indicating whether `self` and `other` have the same
+    /// length, and the norm of their difference is within `max_abs_diff`, or
+    /// within `max_relative` times the norm of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     Norm,
+    /// #     NormEq,
+    /// # };
+    /// #
+    /// let lhs = vec![3.0_f32, 4.0_f32];
+    /// let rhs = vec![3.0_f32, 4.5_f32];
+    ///
+    /// assert!(lhs.norm_eq(&rhs, &0.5_f32, &0.0_f32, Norm::L2));
+    /// assert!(!lhs.norm_eq(&rhs, &0.0_f32, &0.05_f32, Norm::L2));
+    /// assert!(lhs.norm_eq(&rhs, &0.0_f32, &0.1_f32, Norm::L2));
+    /// ```
+    #[inline]
+    fn norm_eq(&self, other: &Rhs, max_abs_diff: &Self::NormTolerance, max_relative: &Self::NormTolerance, norm: Norm) -> bool {
+        self.norms(other, norm)
+            .is_some_and(|norms| norms.is_within(*max_abs_diff, *max_relative))
+    }
+
+    /// Compare two sequences of floating point numbers by the norm of their
+    /// difference.
+    ///
+    /// Returns a boolean indicating whether `self` and `other` have different
+    /// lengths, or the norm of their difference exceeds both `max_abs_diff` and
+    /// `max_relative` times the norm of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use relative_cmp::{
+    /// #     Norm,
+    /// #     NormEq,
+    /// # };
+    /// #
+    /// let lhs = [3.0_f32, 4.0_f32];
+    /// let rhs = [3.0_f32, 4.5_f32];
+    ///
+    /// assert!(lhs.norm_ne(&rhs, &0.25_f32, &0.0_f32, Norm::LInf));
+    /// assert!(!lhs.norm_ne(&rhs, &0.5_f32, &0.0_f32, Norm::LInf));
+    /// ```
+    #[inline]
+    fn norm_ne(&self, other: &Rhs, max_abs_diff: &Self::NormTolerance, max_relative: &Self::NormTolerance, norm: Norm) -> bool {
+        !self.norm_eq(other, max_abs_diff, max_relative, norm)
+    }
+}
+
+/// The failed relative difference comparison returned by [`check_relative_eq`] and
+/// [`check_relative_ne`].
+///
+/// The debugging values are the ones computed by [`AssertRelativeEq`].
+pub type RelativeMismatch<'a, A, B> = Mismatch<
+    'a,
+    A,
+    B,
+    <A as AssertRelativeEq<B>>::DebugAbsDiff,
+    (
+        <A as AssertRelativeEq<B>>::DebugTolerance,
+        <A as AssertRelativeEq<B>>::DebugTolerance,
+    ),
+>;
+
+/// The failed relative difference comparison returned by [`check_relative_eq`] and
+/// [`check_relative_ne`] with `all` parameters.
+///
+/// The debugging values are the ones computed by [`AssertRelativeAllEq`].
+pub type RelativeAllMismatch<'a, A, B> = Mismatch<
+    'a,
+    A,
+    B,
+    <A as AssertRelativeEq<B>>::DebugAbsDiff,
+    (
+        <A as AssertRelativeAllEq<B>>::AllDebugTolerance,
+        <A as AssertRelativeAllEq<B>>::AllDebugTolerance,
+    ),
+>;
+
+#[doc(hidden)]
+pub struct RelativeCmp {}
+
+// The mismatches hold their paths inline, so that they can be returned without
+// allocating.
+#[allow(clippy::result_large_err)]
+impl RelativeCmp {
+    #[must_use]
+    #[inline]
+    pub fn eq<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance) -> bool
+    where
+        A: RelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_eq(lhs, rhs, max_abs_diff, max_relative)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn ne<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance) -> bool
+    where
+        A: RelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_ne(lhs, rhs, max_abs_diff, max_relative)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn all_eq<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::AllTolerance, max_relative: &A::AllTolerance) -> bool
+    where
+        A: RelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_all_eq(lhs, rhs, max_abs_diff, max_relative)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn all_ne<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::AllTolerance, max_relative: &A::AllTolerance) -> bool
+    where
+        A: RelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_all_ne(lhs, rhs, max_abs_diff, max_relative)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn eq_by<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance, denominator: RelativeDenominator) -> bool
+    where
+        A: RelativeEqBy<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_eq_by(lhs, rhs, max_abs_diff, max_relative, denominator)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn ne_by<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance, denominator: RelativeDenominator) -> bool
+    where
+        A: RelativeEqBy<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_ne_by(lhs, rhs, max_abs_diff, max_relative, denominator)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn all_eq_by<A, B>(
+        lhs: &A,
+        rhs: &B,
+        max_abs_diff: &A::AllTolerance,
+        max_relative: &A::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool
+    where
+        A: RelativeAllEqBy<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_all_eq_by(lhs, rhs, max_abs_diff, max_relative, denominator)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn all_ne_by<A, B>(
+        lhs: &A,
+        rhs: &B,
+        max_abs_diff: &A::AllTolerance,
+        max_relative: &A::AllTolerance,
+        denominator: RelativeDenominator,
+    ) -> bool
+    where
+        A: RelativeAllEqBy<B> + ?Sized,
+        B: ?Sized,
+    {
+        A::relative_all_ne_by(lhs, rhs, max_abs_diff, max_relative, denominator)
+    }
+
+    #[inline]
+    pub fn check_eq<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_relative: &A::Tolerance,
+    ) -> Result<(), RelativeMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::relative_eq(lhs, rhs, max_abs_diff, max_relative) {
+            return Ok(());
+        }
+
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_relative(lhs, rhs, max_abs_diff, max_relative, &mut Path::new(), &mut first);
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_tolerance(lhs, rhs, max_relative),
+            ),
+            path: first.into_path().unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn check_ne<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_relative: &A::Tolerance,
+    ) -> Result<(), RelativeMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::relative_ne(lhs, rhs, max_abs_diff, max_relative) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_tolerance(lhs, rhs, max_relative),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_eq<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_relative: &A::AllTolerance,
+    ) -> Result<(), RelativeAllMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + AssertRelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::relative_all_eq(lhs, rhs, max_abs_diff, max_relative) {
+            return Ok(());
+        }
+
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_relative_all(lhs, rhs, max_abs_diff, max_relative, &mut Path::new(), &mut first);
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_all_tolerance(lhs, rhs, max_relative),
+            ),
+            path: first.into_path().unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_ne<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_relative: &A::AllTolerance,
+    ) -> Result<(), RelativeAllMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + AssertRelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::relative_all_ne(lhs, rhs, max_abs_diff, max_relative) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_all_tolerance(lhs, rhs, max_relative),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_eq_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_relative: &A::Tolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), RelativeMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_relative(
+            lhs,
+            rhs,
+            max_abs_diff,
+            max_relative,
+            &mut Path::new(),
+            &mut PolicyVisitor::new(*policy, &mut first),
+        );
+        let Some(path) = first.into_path() else {
+            return Ok(());
+        };
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_tolerance(lhs, rhs, max_relative),
+            ),
+            path,
+        })
+    }
+
+    #[inline]
+    pub fn check_ne_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_relative: &A::Tolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), RelativeMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::relative_ne_with_policy(lhs, rhs, max_abs_diff, max_relative, policy) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_tolerance(lhs, rhs, max_relative),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_eq_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_relative: &A::AllTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), RelativeAllMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + AssertRelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_relative_all(
+            lhs,
+            rhs,
+            max_abs_diff,
+            max_relative,
+            &mut Path::new(),
+            &mut PolicyVisitor::new(*policy, &mut first),
+        );
+        let Some(path) = first.into_path() else {
+            return Ok(());
+        };
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_all_tolerance(lhs, rhs, max_relative),
+            ),
+            path,
+        })
+    }
+
+    #[inline]
+    pub fn check_all_ne_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_relative: &A::AllTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), RelativeAllMismatch<'a, A, B>>
+    where
+        A: AssertRelativeEq<B> + AssertRelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::relative_all_ne_with_policy(lhs, rhs, max_abs_diff, max_relative, policy) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: A::debug_abs_diff(lhs, rhs),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_relative_all_tolerance(lhs, rhs, max_relative),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn error_stats<'a, A, B>(lhs: &'a A, rhs: &B, max_abs_diff: &A::Tolerance, max_relative: &A::Tolerance) -> ErrorStats<'a>
+    where
+        A: AssertRelativeEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        ErrorStats::from_visit(|path, visitor| A::visit_relative(lhs, rhs, max_abs_diff, max_relative, path, visitor))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn error_stats_all<'a, A, B>(lhs: &'a A, rhs: &B, max_abs_diff: &A::AllTolerance, max_relative: &A::AllTolerance) -> ErrorStats<'a>
+    where
+        A: AssertRelativeAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        ErrorStats::from_visit(|path, visitor| A::visit_relative_all(lhs, rhs, max_abs_diff, max_relative, path, visitor))
+    }
+}
+
+#[doc(hidden)]
+pub struct RelativeCmpOpTol {}
+
+impl RelativeCmpOpTol {
+    #[inline]
+    pub fn abs_diff<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::Tolerance) -> A::DebugTolerance
+    where
+        A: RelativeEq<B> + AssertRelativeEq<B>,
+    {
+        A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff)
+    }
+
+    #[inline]
+    pub fn abs_diff_all<A, B>(lhs: &A, rhs: &B, max_abs_diff: &A::AllTolerance) -> A::AllDebugTolerance
+    where
+        A: RelativeAllEq<B> + AssertRelativeAllEq<B>,
+    {
+        A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff)
+    }
+
+    #[inline]
+    pub fn relative<A, B>(lhs: &A, rhs: &B, max_relative: &A::Tolerance) -> A::DebugTolerance
+    where
+        A: RelativeEq<B> + AssertRelativeEq<B>,
+    {
+        A::debug_relative_tolerance(lhs, rhs, max_relative)
+    }
+
+    #[inline]
+    pub fn relative_all<A, B>(lhs: &A, rhs: &B, max_relative: &A::AllTolerance) -> A::AllDebugTolerance
+    where
+        A: RelativeAllEq<B> + AssertRelativeAllEq<B>,
+    {
+        A::debug_relative_all_tolerance(lhs, rhs, max_relative)
+    }
+}
+
+/// Compare two finite precision floating point expression for relative
+/// difference equality.
+///
+/// For more details, see the documentation for [`RelativeEq`] and [`RelativeAllEq`].
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     FloatPolicy,
+/// #     relative_eq,
+/// # };
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// assert!(relative_eq!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 6e-6_f32));
+/// assert!(relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32));
+///
+/// let nan_eq = FloatPolicy {
+///     nan_eq: true,
+///     ..FloatPolicy::new()
+/// };
+///
+/// assert!(!relative_eq!(f32::NAN, f32::NAN, abs_diff <= 0.0_f32, relative <= 6e-6_f32));
+/// assert!(relative_eq!(f32::NAN, f32::NAN, abs_diff <= 0.0_f32, relative <= 6e-6_f32, policy = nan_eq));
+/// ```
+#[macro_export]
+macro_rules! relative_eq {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::eq(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::eq(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_eq(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_eq(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => $crate::FloatPolicy::passes(policy_val, |path, visitor| {
+                $crate::__relative_visit!(left_val, right_val, $eq1 <= tol_1_val, $eq2 <= tol_2_val, path, visitor)
+            }),
+        }
+    }};
+}
+
+/// Compare two finite precision floating point expression for relative
+/// difference inequality.
+///
+/// For more details, see the documentation for [`RelativeEq`] and [`RelativeAllEq`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::relative_ne;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// assert!(relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32));
+/// assert!(relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 2e-6_f32));
+/// ```
+#[macro_export]
+macro_rules! relative_ne {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::ne(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::ne(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_ne(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_ne(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => !$crate::FloatPolicy::passes(policy_val, |path, visitor| {
+                $crate::__relative_visit!(left_val, right_val, $eq1 <= tol_1_val, $eq2 <= tol_2_val, path, visitor)
+            }),
+        }
+    }};
+}
+
+/// Check whether two finite precision floating point expressions are relative
+/// difference equal, without panicking.
+///
+/// The macro returns `Ok(())` when the comparison succeeds, and a [`Mismatch`]
+/// otherwise. The mismatch holds the compared values, their absolute difference,
+/// their tolerances, and the path of the first element that failed the comparison.
+/// The mismatch borrows the compared values, so they must outlive it.
+///
+/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
+/// about relative difference comparisons. See the documentation for
+/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// debugging values reported when a comparison fails.
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::check_relative_eq;
+/// #
+/// let lhs = [98.0005_f32, 99.0_f32, 100.0_f32];
+/// let rhs = [98.0001_f32, 99.0_f32, 100.5_f32];
+///
+/// assert!(check_relative_eq!(lhs, rhs, abs_diff <= [0.0_f32; 3], relative <= [6e-6_f32; 3]).is_err());
+/// assert!(check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 0.01_f32).is_ok());
+///
+/// let mismatch = check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32).unwrap_err();
+///
+/// assert_eq!(mismatch.path.to_string(), "[2]");
+/// assert_eq!(mismatch.diffs[2], 0.5_f32);
+/// ```
+#[macro_export]
+macro_rules! check_relative_eq {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {
+        $crate::RelativeCmp::check_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::RelativeCmp::check_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+}
+
+/// Check whether two finite precision floating point expressions are relative
+/// difference unequal, without panicking.
+///
+/// The macro returns `Ok(())` when the comparison succeeds, and a [`Mismatch`]
+/// otherwise. Since no single element fails an inequality comparison, the path
+/// of the mismatch is the path of the compared values themselves.
+///
+/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
+/// about relative difference comparisons. See the documentation for
+/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// debugging values reported when a comparison fails.
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::check_relative_ne;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// assert!(check_relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32).is_ok());
+/// assert!(check_relative_ne!(lhs, rhs, relative_all <= 6e-6_f32, abs_diff_all <= 0.0_f32).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_relative_ne {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr $(,)?) => {
+        $crate::RelativeCmp::check_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::RelativeCmp::check_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::RelativeCmp::check_all_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+}
+
+// Visit the elements of two values with tolerances given in either order, for
+// the panic messages of the assertion macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __relative_visit {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeEq::visit_relative($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeEq::visit_relative($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeAllEq::visit_relative_all($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeAllEq::visit_relative_all($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference equal.
+///
+/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
+/// about relative difference comparisons. See the documentation for
+/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// debugging context provided when an assertion fails.
+///
+/// A failed assertion prints the path and the values of its first failing
+/// element, followed by the number of failing elements and a list of the
+/// failing elements, so that the panic message stays short for large
+/// containers. Enable the `full_dump` feature to print the compared values in
+/// full as well.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_eq;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// assert_relative_eq!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 6e-6_f32);
+/// assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::relative_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+{}
+{}{}"#),
+                        &*policy_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::relative_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+{}
+{}{}: {}"#),
+                        &*policy_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::relative_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+{}
+{}{}"#),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::relative_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+{}
+{}{}: {}"#),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__relative_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference unequal.
+///
+/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
+/// about relative difference comparisons. See the documentation for
+/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// debugging context provided when an assertion fails.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::assert_relative_ne;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// assert_relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32);
+/// assert_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 2e-6_f32);
+/// ```
+#[macro_export]
+macro_rules! assert_relative_ne {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::relative_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#),
+                        &*policy_val,
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        concat!("[", stringify!($eq2), "]"),
+                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
     }};
-    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {{
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::relative_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`: {}"#),
+                        &*policy_val,
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        concat!("[", stringify!($eq2), "]"),
+                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr $(,)?) => {{
         match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => $crate::RelativeCmp::all_ne(left_val, right_val, tol_1_val, tol_2_val),
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::relative_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        concat!("[", stringify!($eq2), "]"),
+                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::relative_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+    relative: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertRelativeEq::debug_relative_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        concat!("[", stringify!($eq2), "]"),
+                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference equal.
+///
+/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
+/// about relative difference comparisons. See the documentation for
+/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// debugging context provided when an assertion fails.
+///
+/// This macro is only enabled in debug builds like [`debug_assert_eq`] in the
+/// standard library.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::debug_assert_relative_eq;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// debug_assert_relative_eq!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 6e-6_f32);
+/// debug_assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_relative_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_relative_eq!($($arg)*); })
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference equal.
+///
+/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
+/// about relative difference comparisons. See the documentation for
+/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// debugging context provided when an assertion fails.
+///
+/// This macro is only enabled in debug builds like [`debug_assert_ne`] in the
+/// standard library.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::debug_assert_relative_ne;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// debug_assert_relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32);
+/// debug_assert_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 2e-6_f32);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_relative_ne {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_relative_ne!($($arg)*); })
+}
+
+/// Compare two finite precision floating point expressions for relative
+/// difference equality, with the relative difference measured against the
+/// magnitude chosen by a [`RelativeDenominator`].
+///
+/// The tolerances are given in either order, followed by the denominator. For
+/// more details, see the documentation for [`RelativeEqBy`] and
+/// [`RelativeAllEqBy`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative_eq_by,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// let actual = 7.9_f32;
+/// let expected = 8.0_f32;
+///
+/// assert!(relative_eq_by!(actual, expected, abs_diff <= 0.0_f32, relative <= 0.0125_f32, denominator = RelativeDenominator::Second));
+/// assert!(relative_eq_by!(actual, expected, relative_all <= 0.0125_f32, abs_diff_all <= 0.0_f32, denominator = RelativeDenominator::Second));
+/// ```
+#[macro_export]
+macro_rules! relative_eq_by {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::eq_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::eq_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::all_eq_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::all_eq_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+}
+
+/// Compare two finite precision floating point expressions for relative
+/// difference inequality, with the relative difference measured against the
+/// magnitude chosen by a [`RelativeDenominator`].
+///
+/// The tolerances are given in either order, followed by the denominator. For
+/// more details, see the documentation for [`RelativeEqBy`] and
+/// [`RelativeAllEqBy`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     relative_ne_by,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// let actual = 7.9_f32;
+/// let expected = 8.0_f32;
+///
+/// assert!(relative_ne_by!(actual, expected, abs_diff <= 0.0_f32, relative <= 0.0125_f32, denominator = RelativeDenominator::First));
+/// assert!(relative_ne_by!(actual, expected, abs_diff_all <= 0.0_f32, relative_all <= 0.0125_f32, denominator = RelativeDenominator::Min));
+/// ```
+#[macro_export]
+macro_rules! relative_ne_by {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::ne_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::ne_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::all_ne_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                $crate::RelativeCmp::all_ne_by(left_val, right_val, tol_1_val, tol_2_val, denominator_val)
+            }
+        }
+    }};
+}
+
+// Visit the elements of two values with tolerances given in either order, for
+// the panic messages of the assertion macros with a chosen denominator.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __relative_visit_by {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, $denominator:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeEqBy::visit_relative_by($left, $right, $tol_1, $tol_2, $denominator, $path, $visitor)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, $denominator:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeEqBy::visit_relative_by($left, $right, $tol_1, $tol_2, $denominator, $path, $visitor)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, relative_all <= $tol_2:expr, $denominator:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeAllEqBy::visit_relative_all_by($left, $right, $tol_1, $tol_2, $denominator, $path, $visitor)
+    };
+    ($left:expr, $right:expr, relative_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, $denominator:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertRelativeAllEqBy::visit_relative_all_by($left, $right, $tol_1, $tol_2, $denominator, $path, $visitor)
+    };
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference equal, with the relative difference measured against the
+/// magnitude chosen by a [`RelativeDenominator`].
+///
+/// See the documentation for [`RelativeEqBy`] and [`RelativeAllEqBy`] for
+/// details about the comparisons. See the documentation for
+/// [`AssertRelativeEqBy`] and [`AssertRelativeAllEqBy`] for details about the
+/// debugging context provided when an assertion fails.
+///
+/// A failed assertion prints the denominator, and the path and the values of
+/// its first failing element, followed by the number of failing elements and a
+/// list of the failing elements. The relative difference and the relative
+/// tolerance of each failing element are measured against the chosen
+/// magnitude.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     assert_relative_eq_by,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// let actual = [7.9_f32, 16.0_f32];
+/// let expected = [8.0_f32, 16.0_f32];
+///
+/// assert_relative_eq_by!(actual, expected, abs_diff_all <= 0.0_f32, relative_all <= 0.0125_f32, denominator = RelativeDenominator::Second);
+/// assert_relative_eq_by!(
+///     actual[0],
+///     expected[0],
+///     abs_diff <= 0.0_f32,
+///     relative <= 0.0127_f32,
+///     denominator = RelativeDenominator::First,
+///     "relative to the actual value",
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_relative_eq_by {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                if !$crate::relative_eq_by!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, denominator = denominator_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_eq_by!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, denominator = {:?})`", r#"
+{}
+{}{}"#),
+                        denominator_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__relative_visit_by!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, denominator_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__relative_visit_by!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, denominator_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            concat!("[", stringify!($eq1), "]"),
+                            &*tol_1_val,
+                            concat!("[", stringify!($eq2), "]"),
+                            &*tol_2_val,
+                        )),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, denominator = $denominator:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                if !$crate::relative_eq_by!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, denominator = denominator_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `relative_eq_by!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, denominator = {:?})`", r#"
+{}
+{}{}: {}"#),
+                        denominator_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__relative_visit_by!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, denominator_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__relative_visit_by!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, denominator_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            concat!("[", stringify!($eq1), "]"),
+                            &*tol_1_val,
+                            concat!("[", stringify!($eq2), "]"),
+                            &*tol_2_val,
+                        )),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
         }
     }};
 }
 
 /// Assert that two finite precision floating point expressions are relative
-/// difference equal.
+/// difference unequal, with the relative difference measured against the
+/// magnitude chosen by a [`RelativeDenominator`].
 ///
-/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
-/// about relative difference comparisons. See the documentation for
-/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
-/// debugging context provided when an assertion fails.
+/// See the documentation for [`RelativeEqBy`] and [`RelativeAllEqBy`] for
+/// details about the comparisons.
 ///
 /// # Example
 ///
 /// ```
-/// # use relative_cmp::assert_relative_eq;
+/// # use relative_cmp::{
+/// #     assert_relative_ne_by,
+/// #     RelativeDenominator,
+/// # };
 /// #
-/// let lhs = 98.0005_f32;
-/// let rhs = 98.0001_f32;
+/// let actual = 7.9_f32;
+/// let expected = 8.0_f32;
 ///
-/// assert_relative_eq!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 6e-6_f32);
-/// assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32);
+/// assert_relative_ne_by!(actual, expected, abs_diff <= 0.0_f32, relative <= 0.0125_f32, denominator = RelativeDenominator::First);
+/// assert_relative_ne_by!(actual, expected, abs_diff_all <= 0.0_f32, relative_all <= 0.0125_f32, denominator = RelativeDenominator::Min);
 /// ```
 #[macro_export]
-macro_rules! assert_relative_eq {
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::relative_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+macro_rules! assert_relative_ne_by {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, denominator = $denominator:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                if !$crate::relative_ne_by!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, denominator = denominator_val) {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down. See the documentation for `core::assert_eq`.
                     panic!(concat!(
-"assertion failed: `relative_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+"assertion failed: `relative_ne_by!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, denominator = {:?})`", r#"
         left: `{:?}`,
        right: `{:?}`,
-    abs_diff: `{:?}`,
 {:>10} t: `{:?}`,
 {:>10} t: `{:?}`"#),
+                        denominator_val,
                         &*left_val,
                         &*right_val,
-                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
                         concat!("[", stringify!($eq1), "]"),
-                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        &*tol_1_val,
                         concat!("[", stringify!($eq2), "]"),
-                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        &*tol_2_val,
                     )
                 }
             }
         }
     }};
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::relative_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, denominator = $denominator:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $denominator) {
+            (left_val, right_val, tol_1_val, tol_2_val, denominator_val) => {
+                if !$crate::relative_ne_by!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, denominator = denominator_val) {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down. See the documentation for `core::assert_eq`.
                     panic!(concat!(
-"assertion failed: `relative_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+"assertion failed: `relative_ne_by!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, denominator = {:?})`", r#"
         left: `{:?}`,
        right: `{:?}`,
-    abs_diff: `{:?}`,
 {:>10} t: `{:?}`,
 {:>10} t: `{:?}`: {}"#),
+                        denominator_val,
                         &*left_val,
                         &*right_val,
-                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
                         concat!("[", stringify!($eq1), "]"),
-                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        &*tol_1_val,
                         concat!("[", stringify!($eq2), "]"),
-                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        &*tol_2_val,
                         format_args!($($arg)+),
                     )
                 }
@@ -748,73 +2744,216 @@ macro_rules! assert_relative_eq {
 }
 
 /// Assert that two finite precision floating point expressions are relative
-/// difference unequal.
+/// difference equal, with the relative difference measured against the
+/// magnitude chosen by a [`RelativeDenominator`].
 ///
-/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
-/// about relative difference comparisons. See the documentation for
-/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
+/// This macro is only enabled in debug builds like [`debug_assert_eq`] in the
+/// standard library. See the documentation for [`assert_relative_eq_by`] for
+/// details.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     debug_assert_relative_eq_by,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// debug_assert_relative_eq_by!(7.9_f32, 8.0_f32, abs_diff <= 0.0_f32, relative <= 0.0125_f32, denominator = RelativeDenominator::Second);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_relative_eq_by {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_relative_eq_by!($($arg)*); })
+}
+
+/// Assert that two finite precision floating point expressions are relative
+/// difference unequal, with the relative difference measured against the
+/// magnitude chosen by a [`RelativeDenominator`].
+///
+/// This macro is only enabled in debug builds like [`debug_assert_ne`] in the
+/// standard library. See the documentation for [`assert_relative_ne_by`] for
+/// details.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     debug_assert_relative_ne_by,
+/// #     RelativeDenominator,
+/// # };
+/// #
+/// debug_assert_relative_ne_by!(7.9_f32, 8.0_f32, abs_diff <= 0.0_f32, relative <= 0.0125_f32, denominator = RelativeDenominator::First);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_relative_ne_by {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_relative_ne_by!($($arg)*); })
+}
+
+/// Compare two finite precision floating point expressions with the semantics
+/// of NumPy's `allclose`.
+///
+/// The tolerances are given by name, in either order, followed by an optional
+/// `equal_nan`, which defaults to `false` like it does in NumPy.
+///
+/// For more details, see the documentation for [`AllClose`].
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::all_close;
+/// #
+/// let lhs = [1.0_f64, 100.0_f64, f64::NAN];
+/// let rhs = [1.0_f64 + 1e-9_f64, 100.0_f64 + 1e-4_f64, f64::NAN];
+///
+/// assert!(all_close!(lhs[..2], rhs[..2], rtol = 1e-5_f64, atol = 1e-8_f64));
+/// assert!(all_close!(lhs, rhs, atol = 1e-8_f64, rtol = 1e-5_f64, equal_nan = true));
+/// assert!(!all_close!(lhs, rhs, rtol = 1e-5_f64, atol = 1e-8_f64, equal_nan = false));
+/// ```
+#[macro_export]
+macro_rules! all_close {
+    ($left:expr, $right:expr, rtol = $rtol:expr, atol = $atol:expr $(, equal_nan = $equal_nan:expr)? $(,)?) => {{
+        match (&$left, &$right, &$rtol, &$atol) {
+            (left_val, right_val, rtol_val, atol_val) => {
+                $crate::AllClose::all_close(left_val, right_val, rtol_val, atol_val, $crate::__equal_nan!($($equal_nan)?))
+            }
+        }
+    }};
+    ($left:expr, $right:expr, atol = $atol:expr, rtol = $rtol:expr $(, equal_nan = $equal_nan:expr)? $(,)?) => {{
+        match (&$left, &$right, &$rtol, &$atol) {
+            (left_val, right_val, rtol_val, atol_val) => {
+                $crate::AllClose::all_close(left_val, right_val, rtol_val, atol_val, $crate::__equal_nan!($($equal_nan)?))
+            }
+        }
+    }};
+}
+
+// The `equal_nan` argument of the `all_close` macros, defaulting to `false`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __equal_nan {
+    () => {
+        false
+    };
+    ($equal_nan:expr) => {
+        $equal_nan
+    };
+}
+
+/// Assert that two finite precision floating point expressions are close with
+/// the semantics of NumPy's `allclose`.
+///
+/// The tolerances are given by name, in either order, followed by an optional
+/// `equal_nan`, which defaults to `false` like it does in NumPy, and an optional
+/// panic message. See the documentation for [`AllClose`] for details about the
+/// comparison. See the documentation for [`AssertAllClose`] for details about the
 /// debugging context provided when an assertion fails.
 ///
+/// A failed assertion prints the path and the values of its first failing
+/// element, followed by the number of failing elements and a list of the
+/// failing elements. Each failing element reports its absolute difference, and
+/// the tolerance `atol + rtol * abs(right)` it exceeds, labeled `close`.
+///
 /// # Example
 ///
 /// ```
-/// # use relative_cmp::assert_relative_ne;
+/// # use relative_cmp::assert_all_close;
 /// #
-/// let lhs = 98.0005_f32;
-/// let rhs = 98.0001_f32;
+/// let lhs = vec![1.0_f32, 2.0_f32, 3.0_f32];
+/// let rhs = vec![1.000001_f32, 2.000001_f32, 3.000001_f32];
 ///
-/// assert_relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32);
-/// assert_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 2e-6_f32);
+/// assert_all_close!(lhs, rhs, rtol = 1e-5_f32, atol = 1e-8_f32);
+/// assert_all_close!(lhs, rhs, atol = 1e-4_f32, rtol = 0.0_f32, "with an absolute tolerance only");
+/// assert_all_close!([f32::NAN], [f32::NAN], rtol = 0.0_f32, atol = 0.0_f32, equal_nan = true);
 /// ```
 #[macro_export]
-macro_rules! assert_relative_ne {
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr $(,)?) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::relative_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+macro_rules! assert_all_close {
+    ($left:expr, $right:expr, rtol = $rtol:expr, atol = $atol:expr, equal_nan = $equal_nan:expr $(,)?) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, $equal_nan)
+    };
+    ($left:expr, $right:expr, atol = $atol:expr, rtol = $rtol:expr, equal_nan = $equal_nan:expr $(,)?) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, $equal_nan)
+    };
+    ($left:expr, $right:expr, rtol = $rtol:expr, atol = $atol:expr, equal_nan = $equal_nan:expr, $($arg:tt)+) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, $equal_nan, $($arg)+)
+    };
+    ($left:expr, $right:expr, atol = $atol:expr, rtol = $rtol:expr, equal_nan = $equal_nan:expr, $($arg:tt)+) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, $equal_nan, $($arg)+)
+    };
+    ($left:expr, $right:expr, rtol = $rtol:expr, atol = $atol:expr $(,)?) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, false)
+    };
+    ($left:expr, $right:expr, atol = $atol:expr, rtol = $rtol:expr $(,)?) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, false)
+    };
+    ($left:expr, $right:expr, rtol = $rtol:expr, atol = $atol:expr, $($arg:tt)+) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, false, $($arg)+)
+    };
+    ($left:expr, $right:expr, atol = $atol:expr, rtol = $rtol:expr, $($arg:tt)+) => {
+        $crate::__assert_all_close!($left, $right, $rtol, $atol, false, $($arg)+)
+    };
+}
+
+// The body of the `assert_all_close` macro, with the tolerances in a fixed order.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_all_close {
+    ($left:expr, $right:expr, $rtol:expr, $atol:expr, $equal_nan:expr $(,)?) => {{
+        match (&$left, &$right, &$rtol, &$atol, $equal_nan) {
+            (left_val, right_val, rtol_val, atol_val, equal_nan_val) => {
+                if !$crate::AllClose::all_close(&*left_val, &*right_val, &*rtol_val, &*atol_val, equal_nan_val) {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down. See the documentation for `core::assert_eq`.
-                    panic!(concat!(
-"assertion failed: `relative_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+                    panic!(
+                        "assertion failed: `all_close!(left, right, rtol = t, atol = t, equal_nan = {})`\n{}\n{}{}",
+                        equal_nan_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertAllClose::visit_all_close(&*left_val, &*right_val, &*rtol_val, &*atol_val, equal_nan_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertAllClose::visit_all_close(&*left_val, &*right_val, &*rtol_val, &*atol_val, equal_nan_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
         left: `{:?}`,
        right: `{:?}`,
-    abs_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+    [rtol] t: `{:?}`,
+    [atol] t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            &*rtol_val,
+                            &*atol_val,
+                        )),
                     )
                 }
             }
         }
     }};
-    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, $($arg:tt)+) => {{
-        match (&$left, &$right, &$tol_1, &$tol_2) {
-            (left_val, right_val, tol_1_val, tol_2_val) => {
-                if !$crate::relative_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val) {
+    ($left:expr, $right:expr, $rtol:expr, $atol:expr, $equal_nan:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$rtol, &$atol, $equal_nan) {
+            (left_val, right_val, rtol_val, atol_val, equal_nan_val) => {
+                if !$crate::AllClose::all_close(&*left_val, &*right_val, &*rtol_val, &*atol_val, equal_nan_val) {
                     // The reborrows below are intentional. Without them, the stack slot for the
                     // borrow is initialized even before the values are compared, leading to a
                     // noticeable slow down. See the documentation for `core::assert_eq`.
-                    panic!(concat!(
-"assertion failed: `relative_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+                    panic!(
+                        "assertion failed: `all_close!(left, right, rtol = t, atol = t, equal_nan = {})`\n{}\n{}{}: {}",
+                        equal_nan_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertAllClose::visit_all_close(&*left_val, &*right_val, &*rtol_val, &*atol_val, equal_nan_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertAllClose::visit_all_close(&*left_val, &*right_val, &*rtol_val, &*atol_val, equal_nan_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
         left: `{:?}`,
        right: `{:?}`,
-    abs_diff: `{:?}`,
-{:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertRelativeEq::debug_abs_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::RelativeCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::RelativeCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+    [rtol] t: `{:?}`,
+    [atol] t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            &*rtol_val,
+                            &*atol_val,
+                        )),
                         format_args!($($arg)+),
                     )
                 }
@@ -823,56 +2962,259 @@ macro_rules! assert_relative_ne {
     }};
 }
 
-/// Assert that two finite precision floating point expressions are relative
-/// difference equal.
+/// Assert that two finite precision floating point expressions are close with
+/// the semantics of NumPy's `allclose`.
 ///
-/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
-/// about relative difference comparisons. See the documentation for
-/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
-/// debugging context provided when an assertion fails.
+/// See the documentation for [`AllClose`] for details about the comparison. See
+/// the documentation for [`AssertAllClose`] for details about the debugging
+/// context provided when an assertion fails.
 ///
-/// This macro is only enabled in debug builds like [`debug_assert_eq`] in the
+/// This macro is only enable in debug builds like [`debug_assert_eq`] in the
 /// standard library.
 ///
 /// # Example
 ///
 /// ```
-/// # use relative_cmp::debug_assert_relative_eq;
+/// # use relative_cmp::debug_assert_all_close;
 /// #
-/// let lhs = 98.0005_f32;
-/// let rhs = 98.0001_f32;
+/// let lhs = [1.0_f64, 2.0_f64];
+/// let rhs = [1.0_f64 + 1e-9_f64, 2.0_f64];
 ///
-/// debug_assert_relative_eq!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 6e-6_f32);
-/// debug_assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 6e-6_f32);
+/// debug_assert_all_close!(lhs, rhs, rtol = 1e-5_f64, atol = 1e-8_f64);
 /// ```
 #[macro_export]
-macro_rules! debug_assert_relative_eq {
-    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_relative_eq!($($arg)*); })
+macro_rules! debug_assert_all_close {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_all_close!($($arg)*); })
 }
 
-/// Assert that two finite precision floating point expressions are relative
-/// difference equal.
+/// Compare two finite precision floating point expressions by the norm of
+/// their difference.
 ///
-/// See the documentation for [`RelativeEq`] and [`RelativeAllEq`] for details
-/// about relative difference comparisons. See the documentation for
-/// [`AssertRelativeEq`] and [`AssertRelativeAllEq`] for details about the
-/// debugging context provided when an assertion fails.
+/// The tolerances are given in either order, followed by the norm. For more
+/// details, see the documentation for [`NormEq`].
 ///
-/// This macro is only enabled in debug builds like [`debug_assert_ne`] in the
-/// standard library.
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     norm_eq,
+/// #     Norm,
+/// # };
+/// #
+/// let solution = vec![1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+/// let expected = vec![1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64 + 1e-3_f64];
+///
+/// assert!(norm_eq!(solution, expected, abs_diff <= 0.0_f64, relative <= 1e-3_f64, norm = Norm::L2));
+/// assert!(norm_eq!(solution, expected, relative <= 1e-3_f64, abs_diff <= 0.0_f64, norm = Norm::L2));
+/// assert!(!norm_eq!(solution, expected, abs_diff <= 1e-6_f64, relative <= 0.0_f64, norm = Norm::LInf));
+/// ```
+#[macro_export]
+macro_rules! norm_eq {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, norm = $norm:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $norm) {
+            (left_val, right_val, tol_1_val, tol_2_val, norm_val) => {
+                $crate::NormEq::norm_eq(left_val, right_val, tol_1_val, tol_2_val, norm_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, norm = $norm:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $norm) {
+            (left_val, right_val, tol_1_val, tol_2_val, norm_val) => {
+                $crate::NormEq::norm_eq(left_val, right_val, tol_1_val, tol_2_val, norm_val)
+            }
+        }
+    }};
+}
+
+/// Compare two finite precision floating point expressions by the norm of
+/// their difference for inequality.
+///
+/// The tolerances are given in either order, followed by the norm. For more
+/// details, see the documentation for [`NormEq`].
 ///
 /// # Example
 ///
 /// ```
-/// # use relative_cmp::debug_assert_relative_ne;
+/// # use relative_cmp::{
+/// #     norm_ne,
+/// #     Norm,
+/// # };
 /// #
-/// let lhs = 98.0005_f32;
-/// let rhs = 98.0001_f32;
+/// let lhs = [1.0_f64, 2.0_f64];
+/// let rhs = [1.5_f64, 2.5_f64];
 ///
-/// debug_assert_relative_ne!(lhs, rhs, abs_diff <= 0.0_f32, relative <= 2e-6_f32);
-/// debug_assert_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f32, relative_all <= 2e-6_f32);
+/// assert!(norm_ne!(lhs, rhs, abs_diff <= 0.5_f64, relative <= 0.0_f64, norm = Norm::L1));
+/// assert!(!norm_ne!(lhs, rhs, relative <= 0.0_f64, abs_diff <= 0.5_f64, norm = Norm::LInf));
 /// ```
 #[macro_export]
-macro_rules! debug_assert_relative_ne {
-    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_relative_ne!($($arg)*); })
+macro_rules! norm_ne {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, norm = $norm:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $norm) {
+            (left_val, right_val, tol_1_val, tol_2_val, norm_val) => {
+                $crate::NormEq::norm_ne(left_val, right_val, tol_1_val, tol_2_val, norm_val)
+            }
+        }
+    }};
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, norm = $norm:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $norm) {
+            (left_val, right_val, tol_1_val, tol_2_val, norm_val) => {
+                $crate::NormEq::norm_ne(left_val, right_val, tol_1_val, tol_2_val, norm_val)
+            }
+        }
+    }};
+}
+
+/// Assert that two finite precision floating point expressions are equal by
+/// the norm of their difference.
+///
+/// The tolerances are given in either order, followed by the norm and an
+/// optional panic message. See the documentation for [`NormEq`] for details
+/// about the comparison.
+///
+/// A failed assertion prints the norm, the norm of the difference of the
+/// compared values, the norm of the right hand side, and the tolerances, with
+/// the relative tolerance scaled by the norm of the right hand side, as
+/// formatted by [`NormReport`](crate::NormReport).
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     assert_norm_eq,
+/// #     Norm,
+/// # };
+/// #
+/// let solution = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64 + 1e-9_f64]];
+/// let expected = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+///
+/// assert_norm_eq!(solution, expected, abs_diff <= 0.0_f64, relative <= 1e-9_f64, norm = Norm::L2);
+/// assert_norm_eq!(solution, expected, relative <= 0.0_f64, abs_diff <= 1e-8_f64, norm = Norm::LInf, "message {}", 1);
+/// ```
+#[macro_export]
+macro_rules! assert_norm_eq {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, norm = $norm:expr $(,)?) => {
+        $crate::__assert_norm!(norm_eq, $left, $right, $tol_1, $tol_2, $norm)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, norm = $norm:expr $(,)?) => {
+        $crate::__assert_norm!(norm_eq, $left, $right, $tol_1, $tol_2, $norm)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, norm = $norm:expr, $($arg:tt)+) => {
+        $crate::__assert_norm!(norm_eq, $left, $right, $tol_1, $tol_2, $norm, $($arg)+)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, norm = $norm:expr, $($arg:tt)+) => {
+        $crate::__assert_norm!(norm_eq, $left, $right, $tol_1, $tol_2, $norm, $($arg)+)
+    };
+}
+
+/// Assert that two finite precision floating point expressions are unequal by
+/// the norm of their difference.
+///
+/// The tolerances are given in either order, followed by the norm and an
+/// optional panic message. See the documentation for [`NormEq`] for details
+/// about the comparison.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     assert_norm_ne,
+/// #     Norm,
+/// # };
+/// #
+/// let lhs = [1.0_f64, 2.0_f64];
+/// let rhs = [1.5_f64, 2.5_f64];
+///
+/// assert_norm_ne!(lhs, rhs, abs_diff <= 0.5_f64, relative <= 0.0_f64, norm = Norm::L1);
+/// assert_norm_ne!(lhs, rhs, relative <= 0.1_f64, abs_diff <= 0.0_f64, norm = Norm::L2, "message {}", 1);
+/// ```
+#[macro_export]
+macro_rules! assert_norm_ne {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, norm = $norm:expr $(,)?) => {
+        $crate::__assert_norm!(norm_ne, $left, $right, $tol_1, $tol_2, $norm)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, norm = $norm:expr $(,)?) => {
+        $crate::__assert_norm!(norm_ne, $left, $right, $tol_1, $tol_2, $norm)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, relative <= $tol_2:expr, norm = $norm:expr, $($arg:tt)+) => {
+        $crate::__assert_norm!(norm_ne, $left, $right, $tol_1, $tol_2, $norm, $($arg)+)
+    };
+    ($left:expr, $right:expr, relative <= $tol_2:expr, abs_diff <= $tol_1:expr, norm = $norm:expr, $($arg:tt)+) => {
+        $crate::__assert_norm!(norm_ne, $left, $right, $tol_1, $tol_2, $norm, $($arg)+)
+    };
+}
+
+// The body of the `assert_norm_eq` and `assert_norm_ne` macros, with the
+// tolerances in a fixed order.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_norm {
+    ($cmp:ident, $left:expr, $right:expr, $tol_1:expr, $tol_2:expr, $norm:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $norm) {
+            (left_val, right_val, tol_1_val, tol_2_val, norm_val) => {
+                if !$crate::NormEq::$cmp(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, norm_val) {
+                    panic!(
+                        concat!("assertion failed: `", stringify!($cmp), "!(left, right, abs_diff <= t, relative <= t, norm = {:?})`\n{}"),
+                        norm_val,
+                        $crate::NormReport::new(norm_val, $crate::NormEq::norms(&*left_val, &*right_val, norm_val), *tol_1_val, *tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($cmp:ident, $left:expr, $right:expr, $tol_1:expr, $tol_2:expr, $norm:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, $norm) {
+            (left_val, right_val, tol_1_val, tol_2_val, norm_val) => {
+                if !$crate::NormEq::$cmp(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, norm_val) {
+                    panic!(
+                        concat!("assertion failed: `", stringify!($cmp), "!(left, right, abs_diff <= t, relative <= t, norm = {:?})`\n{}: {}"),
+                        norm_val,
+                        $crate::NormReport::new(norm_val, $crate::NormEq::norms(&*left_val, &*right_val, norm_val), *tol_1_val, *tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
+
+/// Assert that two finite precision floating point expressions are equal by
+/// the norm of their difference.
+///
+/// This macro is only enabled in debug builds like [`debug_assert_eq`] in the
+/// standard library. See the documentation for [`assert_norm_eq`] for details.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     debug_assert_norm_eq,
+/// #     Norm,
+/// # };
+/// #
+/// debug_assert_norm_eq!([3.0_f64, 4.0_f64], [3.0_f64, 4.0_f64], abs_diff <= 0.0_f64, relative <= 0.0_f64, norm = Norm::L2);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_norm_eq {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_norm_eq!($($arg)*); })
+}
+
+/// Assert that two finite precision floating point expressions are unequal by
+/// the norm of their difference.
+///
+/// This macro is only enabled in debug builds like [`debug_assert_ne`] in the
+/// standard library. See the documentation for [`assert_norm_ne`] for details.
+///
+/// # Example
+///
+/// ```
+/// # use relative_cmp::{
+/// #     debug_assert_norm_ne,
+/// #     Norm,
+/// # };
+/// #
+/// debug_assert_norm_ne!([3.0_f64, 4.0_f64], [3.0_f64, 5.0_f64], abs_diff <= 0.5_f64, relative <= 0.0_f64, norm = Norm::L2);
+/// ```
+#[macro_export]
+macro_rules! debug_assert_norm_ne {
+    ($($arg:tt)*) => (if cfg!(debug_assertions) { $crate::assert_norm_ne!($($arg)*); })
 }
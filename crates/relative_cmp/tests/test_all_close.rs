@@ -0,0 +1,205 @@
+use relative_cmp::{
+    AllClose,
+    AssertAllClose,
+    FirstFailure,
+    all_close,
+    assert_all_close,
+    relative,
+};
+use std::cell;
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    LinkedList,
+    VecDeque,
+};
+use std::rc::Rc;
+use std::sync::Arc;
+
+// The default tolerances of `numpy.isclose` and `numpy.allclose`.
+const RTOL: f64 = 1e-5;
+const ATOL: f64 = 1e-8;
+
+#[test]
+fn test_numpy_defaults() {
+    assert!(1e10_f64.all_close(&1.00001e10_f64, &RTOL, &ATOL, false));
+    assert!(!1e-8_f64.all_close(&1e-7_f64, &RTOL, &ATOL, false));
+    assert!(1e-10_f64.all_close(&1e-20_f64, &RTOL, &ATOL, false));
+    assert!(!1e-100_f64.all_close(&1e-101_f64, &RTOL, &0.0_f64, false));
+    assert!(!1e10_f64.all_close(&1.0001e10_f64, &RTOL, &ATOL, false));
+}
+
+#[test]
+fn test_additive_tolerances() {
+    // Neither tolerance passes the pair on its own, but their sum does.
+    let lhs = 1.0_f64;
+    let rhs = 1.0_f64 + 1.5e-5_f64;
+
+    assert!(!lhs.all_close(&rhs, &1e-5_f64, &0.0_f64, false));
+    assert!(!lhs.all_close(&rhs, &0.0_f64, &1e-5_f64, false));
+    assert!(lhs.all_close(&rhs, &1e-5_f64, &1e-5_f64, false));
+}
+
+#[test]
+fn test_asymmetric() {
+    let lhs = 100.0_f64;
+    let rhs = 101.0_f64;
+    let rtol = 0.00995_f64;
+
+    assert!(lhs.all_close(&rhs, &rtol, &0.0_f64, false));
+    assert!(!rhs.all_close(&lhs, &rtol, &0.0_f64, false));
+}
+
+#[test]
+fn test_infinities() {
+    assert!(f64::INFINITY.all_close(&f64::INFINITY, &RTOL, &ATOL, false));
+    assert!(f64::NEG_INFINITY.all_close(&f64::NEG_INFINITY, &RTOL, &ATOL, false));
+    assert!(!f64::INFINITY.all_close(&f64::NEG_INFINITY, &RTOL, &ATOL, false));
+    assert!(!1.0_f64.all_close(&f64::INFINITY, &RTOL, &ATOL, false));
+    assert!(!f64::INFINITY.all_close(&1.0_f64, &RTOL, &ATOL, false));
+
+    // NumPy requires only the right hand side to be finite.
+    assert!(f64::INFINITY.all_close(&1.0_f64, &0.0_f64, &f64::INFINITY, false));
+    assert!(!1.0_f64.all_close(&f64::INFINITY, &0.0_f64, &f64::INFINITY, false));
+}
+
+#[test]
+fn test_nan() {
+    assert!(!f64::NAN.all_close(&f64::NAN, &RTOL, &ATOL, false));
+    assert!(f64::NAN.all_close(&f64::NAN, &RTOL, &ATOL, true));
+    assert!(!f64::NAN.all_close(&1.0_f64, &RTOL, &ATOL, true));
+    assert!(!1.0_f64.all_close(&f64::NAN, &f64::INFINITY, &f64::INFINITY, true));
+    assert!(f32::NAN.all_close(&f32::NAN, &0.0_f32, &0.0_f32, true));
+}
+
+#[test]
+fn test_f32() {
+    let lhs = 1.0_f32;
+    let rhs = 1.0_f32 + 8.0_f32 * f32::EPSILON;
+
+    assert!(lhs.all_close(&rhs, &1e-5_f32, &1e-8_f32, false));
+    assert!(!lhs.all_close(&rhs, &1e-7_f32, &1e-8_f32, false));
+    assert!(relative::is_close(lhs, rhs, 1e-5_f32, 1e-8_f32, false));
+}
+
+#[test]
+fn test_mixed_precision() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+
+    assert!(lhs.all_close(&rhs, &RTOL, &ATOL, false));
+    assert!(rhs.all_close(&lhs, &RTOL, &ATOL, false));
+    assert!(!lhs.all_close(&rhs, &0.0_f64, &0.0_f64, false));
+    assert!(all_close!(vec![lhs; 3], vec![rhs; 3], rtol = RTOL, atol = ATOL));
+}
+
+#[test]
+fn test_integers() {
+    assert!(1_000_000_001_i64.all_close(&1_000_000_000_i64, &1e-9_f64, &0.0_f64, false));
+    assert!(!1_000_000_002_i64.all_close(&1_000_000_000_i64, &1e-9_f64, &0.0_f64, false));
+    assert!(3_u8.all_close(&5_u8, &0.0_f64, &2.0_f64, false));
+    assert!(!5_u8.all_close(&3_u8, &0.0_f64, &1.0_f64, true));
+    assert!(i32::MIN.all_close(&i32::MIN, &0.0_f64, &0.0_f64, false));
+}
+
+#[test]
+fn test_containers() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = [1.0_f64, 2.0_f64 + 1e-6_f64, 3.0_f64];
+
+    assert!(lhs.all_close(&rhs, &RTOL, &ATOL, false));
+    assert!(!lhs.all_close(&rhs, &0.0_f64, &ATOL, false));
+    assert!(lhs[..].all_close(&rhs[..], &RTOL, &ATOL, false));
+    assert!(!lhs[..].all_close(&rhs[..2], &RTOL, &ATOL, false));
+    assert!(lhs.to_vec().all_close(&rhs.to_vec(), &RTOL, &ATOL, false));
+    assert!(VecDeque::from(lhs).all_close(&VecDeque::from(rhs), &RTOL, &ATOL, false));
+    assert!(LinkedList::from(lhs).all_close(&LinkedList::from(rhs), &RTOL, &ATOL, false));
+    assert!(Box::new(lhs).all_close(&Box::new(rhs), &RTOL, &ATOL, false));
+    assert!(Rc::new(lhs).all_close(&Rc::new(rhs), &RTOL, &ATOL, false));
+    assert!(Arc::new(lhs).all_close(&Arc::new(rhs), &RTOL, &ATOL, false));
+    assert!(cell::Cell::new(lhs).all_close(&cell::Cell::new(rhs), &RTOL, &ATOL, false));
+    assert!(cell::RefCell::new(lhs).all_close(&cell::RefCell::new(rhs), &RTOL, &ATOL, false));
+    assert!((lhs[0], lhs[1], lhs[2]).all_close(&(rhs[0], rhs[1], rhs[2]), &RTOL, &ATOL, false));
+    assert!(().all_close(&(), &(), &(), false));
+}
+
+#[test]
+fn test_sum_types() {
+    let lhs = Some(1.0_f64);
+    let rhs = Some(1.0_f64 + 1e-9_f64);
+
+    assert!(lhs.all_close(&rhs, &Some(RTOL), &Some(ATOL), false));
+    assert!(!lhs.all_close(&None::<f64>, &Some(RTOL), &Some(ATOL), false));
+    assert!(Ok::<f64, f64>(1.0_f64).all_close(&Ok::<f64, f64>(1.0_f64), &Ok(RTOL), &Ok(ATOL), false));
+    assert!(!Ok::<f64, f64>(1.0_f64).all_close(&Err::<f64, f64>(1.0_f64), &Ok(RTOL), &Ok(ATOL), false));
+
+    let lhs = cell::OnceCell::from(f64::NAN);
+    let rhs = cell::OnceCell::from(f64::NAN);
+
+    assert!(lhs.all_close(&rhs, &RTOL, &ATOL, true));
+    assert!(!lhs.all_close(&rhs, &RTOL, &ATOL, false));
+}
+
+#[test]
+fn test_maps() {
+    let lhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64)]);
+    let rhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64 + 1e-6_f64)]);
+
+    assert!(lhs.all_close(&rhs, &RTOL, &ATOL, false));
+    assert!(!lhs.all_close(&rhs, &0.0_f64, &0.0_f64, false));
+
+    let lhs = HashMap::from([("a", 1.0_f64), ("b", f64::NAN)]);
+    let rhs = HashMap::from([("a", 1.0_f64), ("b", f64::NAN)]);
+
+    assert!(lhs.all_close(&rhs, &RTOL, &ATOL, true));
+    assert!(!lhs.all_close(&rhs, &RTOL, &ATOL, false));
+}
+
+#[test]
+fn test_all_close_macro() {
+    let lhs = vec![1.0_f64, f64::NAN];
+    let rhs = vec![1.0_f64 + 1e-9_f64, f64::NAN];
+
+    assert!(all_close!(lhs, rhs, rtol = RTOL, atol = ATOL, equal_nan = true));
+    assert!(all_close!(lhs, rhs, atol = ATOL, rtol = RTOL, equal_nan = true,));
+    assert!(!all_close!(lhs, rhs, rtol = RTOL, atol = ATOL));
+    assert!(!all_close!(lhs, rhs, atol = ATOL, rtol = RTOL));
+}
+
+#[test]
+fn test_assert_all_close_macro() {
+    let lhs = vec![1.0_f64, f64::NAN];
+    let rhs = vec![1.0_f64 + 1e-9_f64, f64::NAN];
+
+    assert_all_close!(lhs, rhs, rtol = RTOL, atol = ATOL, equal_nan = true);
+    assert_all_close!(lhs, rhs, atol = ATOL, rtol = RTOL, equal_nan = true, "message {}", 1);
+    assert_all_close!(lhs[..1], rhs[..1], rtol = RTOL, atol = ATOL);
+    assert_all_close!(lhs[..1], rhs[..1], atol = ATOL, rtol = RTOL, "message {}", 2);
+}
+
+#[test]
+fn test_visit_all_close() {
+    let lhs = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    let rhs = vec![[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    let first = FirstFailure::new(|path, visitor| lhs.visit_all_close(&rhs, &0.1_f64, &0.0_f64, false, path, visitor));
+    let expected = "\
+first failure at `[1][1]`:
+        left: `4.5`,
+       right: `4.0`,
+    abs_diff: `0.5`,
+   [close] t: `0.4`,";
+
+    assert_eq!(first.to_string(), expected);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `all_close!(left, right, rtol = t, atol = t, equal_nan = false)`\nfirst failure at `[1]`")]
+fn test_assert_all_close_panic_message() {
+    assert_all_close!(vec![1.0_f64, f64::NAN], vec![1.0_f64, f64::NAN], rtol = RTOL, atol = ATOL);
+}
+
+#[test]
+#[should_panic(expected = ": custom message")]
+fn test_assert_all_close_panic_custom_message() {
+    assert_all_close!([1.0_f32], [2.0_f32], rtol = 0.1_f32, atol = 0.1_f32, "custom message");
+}
@@ -0,0 +1,97 @@
+use core::ops::ControlFlow;
+use relative_cmp::{
+    AssertRelativeEq,
+    Element,
+    Path,
+    check_relative_eq,
+    check_relative_ne,
+};
+
+#[test]
+fn test_check_eq_ok() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = [1.0_f64, 2.0_f64, 3.0000000000000004_f64];
+
+    assert!(check_relative_eq!(lhs, rhs, abs_diff <= [0.0_f64; 3], relative <= [f64::EPSILON; 3]).is_ok());
+    assert!(check_relative_eq!(lhs, rhs, relative <= [f64::EPSILON; 3], abs_diff <= [0.0_f64; 3]).is_ok());
+    assert!(check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= f64::EPSILON).is_ok());
+    assert!(check_relative_eq!(lhs, rhs, relative_all <= f64::EPSILON, abs_diff_all <= 0.0_f64).is_ok());
+}
+
+#[test]
+fn test_check_eq_scalar() {
+    let lhs = 1.0_f32;
+    let rhs = 1.5_f32;
+    let mismatch = check_relative_eq!(lhs, rhs, abs_diff <= 0.25_f32, relative <= 0.1_f32).unwrap_err();
+
+    assert_eq!(mismatch.left, &lhs);
+    assert_eq!(mismatch.right, &rhs);
+    assert_eq!(mismatch.diffs, 0.5_f32);
+    assert_eq!(mismatch.tolerances, (0.25_f32, 0.15_f32));
+    assert!(mismatch.path.is_root());
+}
+
+#[test]
+fn test_check_eq_array_path() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = [1.0_f64, 2.0_f64, 3.5_f64, 4.5_f64];
+    let mismatch = check_relative_eq!(lhs, rhs, abs_diff <= [0.0_f64; 4], relative <= [0.01_f64; 4]).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+    assert_eq!(mismatch.diffs, [0.0_f64, 0.0_f64, 0.5_f64, 0.5_f64]);
+}
+
+#[test]
+fn test_check_all_eq_nested_path() {
+    let lhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    let rhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    let mismatch = check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 0.01_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1][1]");
+}
+
+#[test]
+fn test_check_eq_slice_path() {
+    let lhs: &[f64] = &[1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs: &[f64] = &[1.0_f64, 2.5_f64, 3.0_f64];
+    let mismatch = check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 0.01_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+}
+
+#[test]
+fn test_check_ne() {
+    let lhs = [1.0_f64, 2.0_f64];
+    let rhs = [1.0_f64, 2.5_f64];
+
+    assert!(check_relative_ne!(lhs, rhs, abs_diff <= [0.0_f64; 2], relative <= [0.01_f64; 2]).is_ok());
+    assert!(check_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 0.01_f64).is_ok());
+
+    let mismatch = check_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 0.5_f64).unwrap_err();
+
+    assert!(mismatch.path.is_root());
+}
+
+#[test]
+fn test_visit_every_element() {
+    let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = [1.5_f64, 2.0_f64, 3.5_f64];
+    let mut visited = Vec::new();
+    let _ = lhs.visit_relative(&rhs, &[0.0_f64; 3], &[0.01_f64; 3], &mut Path::new(), &mut |path: &Path<'_>,
+                                                                                            element: &Element<
+        '_,
+    >| {
+        let labels = element.tolerances.iter().map(|(label, _)| *label).collect::<Vec<_>>();
+        visited.push((path.to_string(), element.passed, labels));
+
+        ControlFlow::Continue(())
+    });
+
+    let expected = vec![
+        (String::from("[0]"), false, vec!["abs_diff", "relative"]),
+        (String::from("[1]"), true, vec!["abs_diff", "relative"]),
+        (String::from("[2]"), false, vec!["abs_diff", "relative"]),
+    ];
+
+    assert_eq!(visited, expected);
+}
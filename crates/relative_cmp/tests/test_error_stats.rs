@@ -0,0 +1,50 @@
+use relative_cmp::{
+    RelativeCmp,
+    assert_relative_eq,
+};
+use std::collections::HashMap;
+
+#[test]
+fn test_error_stats_vec() {
+    let lhs = vec![1.0_f64, 200.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = vec![1.0_f64, 202.0_f64, 3.0_f64, 5.0_f64];
+    let stats = RelativeCmp::error_stats_all(&lhs, &rhs, &0.0_f64, &0.001_f64);
+    let max_abs_diff = stats.max_abs_diff().unwrap();
+    let max_relative = stats.max_relative().unwrap();
+
+    assert_eq!(stats.elements(), 4);
+    assert_eq!(stats.failed(), 2);
+    assert_eq!(max_abs_diff.value, 2.0_f64);
+    assert_eq!(max_abs_diff.path.to_string(), "[1]");
+    assert_eq!(max_relative.value, 0.2_f64);
+    assert_eq!(max_relative.path.to_string(), "[3]");
+    assert_eq!(stats.mean_abs_diff(), Some(0.75_f64));
+}
+
+#[test]
+fn test_error_stats_array() {
+    let lhs = [1.0_f32, 2.0_f32];
+    let rhs = [1.0_f32, 2.0_f32];
+    let stats = RelativeCmp::error_stats(&lhs, &rhs, &[0.0_f32; 2], &[f32::EPSILON; 2]);
+
+    assert_eq!(stats.failed(), 0);
+    assert_eq!(stats.max_relative().map(|max| max.value), Some(0.0_f64));
+}
+
+#[test]
+fn test_error_stats_map() {
+    let lhs = HashMap::from([("a", 1.0_f64), ("b", 2.0_f64)]);
+    let rhs = HashMap::from([("a", 1.5_f64), ("b", 2.0_f64)]);
+    let stats = RelativeCmp::error_stats_all(&lhs, &rhs, &0.0_f64, &0.001_f64);
+
+    assert_eq!(stats.max_relative().unwrap().path.to_string(), "[\"a\"]");
+}
+
+#[test]
+#[should_panic(expected = "error statistics over 4 elements:\n     max abs_diff: `2.0` at `[1]`,\n     max relative: `0.2` at `[3]`,")]
+fn test_assert_eq_panic_message_stats() {
+    let lhs = vec![1.0_f64, 200.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = vec![1.0_f64, 202.0_f64, 3.0_f64, 5.0_f64];
+
+    assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 0.001_f64);
+}
@@ -0,0 +1,112 @@
+use relative_cmp::{
+    RelativeEq,
+    RelativeExplain,
+    RelativeExplanation,
+    assert_relative_eq,
+};
+
+const VALUES_F32: [f32; 14] = [
+    0.0_f32,
+    -0.0_f32,
+    1e-30_f32,
+    -1e-30_f32,
+    1.0_f32,
+    1.000_000_1_f32,
+    -1.0_f32,
+    1e30_f32,
+    f32::MIN_POSITIVE,
+    f32::MAX,
+    f32::MIN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+const VALUES_F64: [f64; 14] = [
+    0.0_f64,
+    -0.0_f64,
+    1e-300_f64,
+    -1e-300_f64,
+    1.0_f64,
+    1.000_000_000_000_000_2_f64,
+    -1.0_f64,
+    1e300_f64,
+    f64::MIN_POSITIVE,
+    f64::MAX,
+    f64::MIN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+#[test]
+fn test_explain_relative_agrees_with_relative_eq_f32() {
+    for lhs in VALUES_F32 {
+        for rhs in VALUES_F32 {
+            for max_abs_diff in [0.0_f32, f32::EPSILON, 1e-29_f32] {
+                for max_relative in [0.0_f32, f32::EPSILON, 1.0_f32] {
+                    let explanation = lhs.explain_relative(&rhs, &max_abs_diff, &max_relative);
+
+                    assert_eq!(
+                        explanation.passed(),
+                        lhs.relative_eq(&rhs, &max_abs_diff, &max_relative),
+                        "lhs = {lhs:?}, rhs = {rhs:?}, explanation = {explanation:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_relative_agrees_with_relative_eq_f64() {
+    for lhs in VALUES_F64 {
+        for rhs in VALUES_F64 {
+            for max_abs_diff in [0.0_f64, f64::EPSILON, 1e-299_f64] {
+                for max_relative in [0.0_f64, f64::EPSILON, 1.0_f64] {
+                    let explanation = lhs.explain_relative(&rhs, &max_abs_diff, &max_relative);
+
+                    assert_eq!(
+                        explanation.passed(),
+                        lhs.relative_eq(&rhs, &max_abs_diff, &max_relative),
+                        "lhs = {lhs:?}, rhs = {rhs:?}, explanation = {explanation:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_relative_branches() {
+    assert_eq!(
+        f64::INFINITY.explain_relative(&f64::INFINITY, &0.0_f64, &0.0_f64),
+        RelativeExplanation::PassedEqual
+    );
+    assert_eq!(
+        f64::INFINITY.explain_relative(&f64::MAX, &0.0_f64, &1.0_f64),
+        RelativeExplanation::FailedInfinite
+    );
+    assert_eq!(
+        f64::NAN.explain_relative(&1.0_f64, &1.0_f64, &1.0_f64),
+        RelativeExplanation::FailedNan
+    );
+    assert_eq!(
+        1e-30_f64.explain_relative(&-1e-30_f64, &1e-29_f64, &0.0_f64),
+        RelativeExplanation::PassedAbsDiff
+    );
+    assert_eq!(
+        100.0_f64.explain_relative(&101.0_f64, &0.0_f64, &0.01_f64),
+        RelativeExplanation::PassedRelative
+    );
+    assert_eq!(
+        1e-30_f64.explain_relative(&-1e-30_f64, &0.0_f64, &0.01_f64),
+        RelativeExplanation::FailedRelative
+    );
+}
+
+#[test]
+#[should_panic(expected = "      branch: `FailedInfinite`,")]
+fn test_assert_eq_panic_message_infinite() {
+    assert_relative_eq!(f64::INFINITY, f64::MAX, abs_diff <= 0.0_f64, relative <= 1.0_f64);
+}
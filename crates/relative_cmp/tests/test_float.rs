@@ -0,0 +1,191 @@
+use core::cmp;
+use core::ops;
+use relative_cmp::{
+    Float,
+    RelativeEq,
+    RelativeExplain,
+    RelativeExplanation,
+    relative,
+};
+
+/// A brain floating point number, holding the highest sixteen bits of an `f32`.
+#[derive(Copy, Clone, Debug)]
+struct Bf16(u16);
+
+impl Bf16 {
+    fn from_f32(value: f32) -> Self {
+        Self((value.to_bits() >> 16) as u16)
+    }
+
+    fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+}
+
+impl PartialEq for Bf16 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for Bf16 {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl ops::Add for Bf16 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() + other.to_f32())
+    }
+}
+
+impl ops::Sub for Bf16 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() - other.to_f32())
+    }
+}
+
+impl ops::Mul for Bf16 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() * other.to_f32())
+    }
+}
+
+impl ops::Div for Bf16 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() / other.to_f32())
+    }
+}
+
+impl Float for Bf16 {
+    type Bits = u16;
+
+    const ZERO: Self = Self(0x0000);
+    const ONE: Self = Self(0x3F80);
+    const INFINITY: Self = Self(0x7F80);
+    const MAX: Self = Self(0x7F7F);
+    const MIN_POSITIVE: Self = Self(0x0080);
+
+    fn to_bits(self) -> Self::Bits {
+        self.0
+    }
+
+    fn from_bits(bits: Self::Bits) -> Self {
+        Self(bits)
+    }
+
+    fn abs(self) -> Self {
+        Self(self.0 & 0x7FFF)
+    }
+
+    fn signum(self) -> Self {
+        Self::from_f32(self.to_f32().signum())
+    }
+
+    fn is_nan(self) -> bool {
+        self.to_f32().is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.to_f32().is_infinite()
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::from_f32(f32::max(self.to_f32(), other.to_f32()))
+    }
+}
+
+/// Values whose `f32` representation is exactly representable as a `Bf16`.
+const SPECIAL_F32: [f32; 14] = [
+    0.0_f32,
+    -0.0_f32,
+    f32::from_bits(0x0001_0000),
+    -f32::from_bits(0x0001_0000),
+    f32::MIN_POSITIVE,
+    1.0_f32,
+    -1.0_f32,
+    1.0078125_f32,
+    3.0_f32,
+    -3.0_f32,
+    f32::from_bits(0x7F7F_0000),
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+impl RelativeEq for Bf16 {
+    type Tolerance = Bf16;
+
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        relative::relative_eq(*self, *other, *max_abs_diff, *max_relative)
+    }
+}
+
+#[test]
+fn test_relative_eq() {
+    let hundred = Bf16::from_f32(100.0_f32);
+    let hundred_one = Bf16::from_f32(101.0_f32);
+    let hundred_four = Bf16::from_f32(104.0_f32);
+    let max_relative = Bf16::from_f32(0.015625_f32);
+
+    assert!(hundred.relative_eq(&hundred_one, &Bf16::ZERO, &max_relative));
+    assert!(hundred.relative_ne(&hundred_four, &Bf16::ZERO, &max_relative));
+    assert!(hundred.relative_eq(&hundred_four, &Bf16::from_f32(4.0_f32), &Bf16::ZERO));
+}
+
+#[test]
+fn test_relative_diff() {
+    let one = Bf16::from_f32(1.0_f32);
+    let two = Bf16::from_f32(2.0_f32);
+
+    assert_eq!(relative::relative_diff(two, one).to_f32(), 0.5_f32);
+    assert_eq!(relative::relative_diff(Bf16::INFINITY, Bf16::INFINITY).to_f32(), 0.0_f32);
+    assert_eq!(relative::relative_diff(Bf16::INFINITY, one).to_f32(), f32::INFINITY);
+}
+
+#[test]
+fn test_relative_eq_special_values_agree_with_f32() {
+    // With tolerances of zero and infinity, rounding of the `Bf16` arithmetic
+    // does not affect the outcome, so it agrees with the `f32` comparison.
+    for lhs in SPECIAL_F32 {
+        for rhs in SPECIAL_F32 {
+            for (max_abs_diff, max_relative) in [(0.0_f32, 0.0_f32), (f32::INFINITY, 0.0_f32), (0.0_f32, f32::INFINITY)] {
+                let result =
+                    Bf16::from_f32(lhs).relative_eq(&Bf16::from_f32(rhs), &Bf16::from_f32(max_abs_diff), &Bf16::from_f32(max_relative));
+                let expected = lhs.relative_eq(&rhs, &max_abs_diff, &max_relative);
+
+                assert_eq!(
+                    result,
+                    expected,
+                    "lhs = {lhs:?}, rhs = {rhs:?}, tolerances = {:?}",
+                    (max_abs_diff, max_relative)
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_relative() {
+    let one = Bf16::from_f32(1.0_f32);
+    let nan = Bf16::from_f32(f32::NAN);
+
+    assert_eq!(
+        one.explain_relative(&one, &Bf16::ZERO, &Bf16::ZERO),
+        RelativeExplanation::PassedEqual
+    );
+    assert_eq!(
+        one.explain_relative(&Bf16::INFINITY, &Bf16::MAX, &Bf16::MAX),
+        RelativeExplanation::FailedInfinite
+    );
+    assert_eq!(one.explain_relative(&nan, &Bf16::MAX, &Bf16::MAX), RelativeExplanation::FailedNan);
+}
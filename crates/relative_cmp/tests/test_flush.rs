@@ -0,0 +1,271 @@
+use relative_cmp::{
+    RelativeEqFtz,
+    assert_relative_eq_ftz,
+    relative,
+    relative_eq,
+    relative_eq_ftz,
+    relative_ne_ftz,
+};
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    LinkedList,
+    VecDeque,
+};
+
+#[test]
+fn test_subnormals_flushed_to_zero() {
+    let subnormal = f32::MIN_POSITIVE / 2.0_f32;
+
+    assert!(!relative_eq!(0.0_f32, subnormal, abs_diff <= 0.0_f32, relative <= f32::EPSILON));
+    assert!(relative_eq_ftz!(0.0_f32, subnormal, abs_diff <= 0.0_f32, relative <= f32::EPSILON));
+    assert!(relative_eq_ftz!(
+        -0.0_f32,
+        -subnormal,
+        relative <= f32::EPSILON,
+        abs_diff <= 0.0_f32
+    ));
+    assert!(relative_eq_ftz!(
+        subnormal,
+        -subnormal,
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    ));
+    assert!(relative_ne_ftz!(
+        f32::MIN_POSITIVE,
+        subnormal,
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    ));
+    assert!(relative::relative_eq_ftz(0.0_f64, 1e-310_f64, 0.0_f64, f64::EPSILON));
+}
+
+#[test]
+fn test_normal_numbers_unchanged() {
+    assert!(relative_eq_ftz!(
+        1.0_f64,
+        1.0_f64 + f64::EPSILON,
+        abs_diff <= 0.0_f64,
+        relative <= f64::EPSILON
+    ));
+    assert!(relative_ne_ftz!(1.0_f64, 1.1_f64, abs_diff <= 0.0_f64, relative <= f64::EPSILON));
+    assert!(relative_ne_ftz!(
+        f64::NAN,
+        f64::NAN,
+        abs_diff <= f64::INFINITY,
+        relative <= f64::INFINITY
+    ));
+    assert_eq!(1.0_f64.relative_flushed(&1.0_f64), 0);
+}
+
+#[test]
+fn test_mixed_precision() {
+    // A subnormal number of one precision may be normal in the other.
+    let subnormal = f32::MIN_POSITIVE / 2.0_f32;
+
+    assert!(relative_eq_ftz!(subnormal, 0.0_f64, abs_diff <= 0.0_f64, relative <= f64::EPSILON));
+    assert!(relative_ne_ftz!(
+        0.0_f64,
+        f64::from(subnormal),
+        abs_diff <= 0.0_f64,
+        relative <= f64::EPSILON
+    ));
+    assert_eq!(subnormal.relative_flushed(&f64::from(subnormal)), 1);
+}
+
+#[test]
+fn test_containers() {
+    let reference = [1.0_f32, 1e-40_f32, -1e-41_f32];
+    let flushed = [1.0_f32, 0.0_f32, -0.0_f32];
+
+    assert!(relative_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, relative <= f32::EPSILON));
+    assert!(relative_eq_ftz!(
+        &flushed[..],
+        &reference[..],
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    ));
+    assert!(relative_eq_ftz!(
+        flushed.to_vec(),
+        reference.to_vec(),
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    ));
+    assert!(relative_ne_ftz!(
+        &flushed[..2],
+        &reference[..],
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    ));
+    assert_eq!(flushed.relative_flushed(&reference), 2);
+    assert_eq!(reference.relative_flushed(&reference), 4);
+}
+
+#[test]
+fn test_tuples() {
+    let reference = (1.0_f32, 1e-40_f32, -1e-41_f32);
+    let flushed = (1.0_f32, 0.0_f32, -0.0_f32);
+
+    assert!(relative_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, relative <= 0.0_f32));
+    assert!(relative_ne_ftz!(
+        (1.0_f32, 2.0_f32),
+        (1.0_f32, 1e-40_f32),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    ));
+    assert_eq!(flushed.relative_flushed(&reference), 2);
+}
+
+#[test]
+fn test_option_result() {
+    let subnormal = f32::MIN_POSITIVE / 2.0_f32;
+
+    assert!(relative_eq_ftz!(
+        Some(0.0_f32),
+        Some(subnormal),
+        abs_diff <= Some(0.0_f32),
+        relative <= Some(0.0_f32)
+    ));
+    assert!(relative_ne_ftz!(
+        Some(0.0_f32),
+        None::<f32>,
+        abs_diff <= Some(0.0_f32),
+        relative <= Some(0.0_f32)
+    ));
+    assert!(relative_ne_ftz!(
+        Some(0.0_f32),
+        Some(subnormal),
+        abs_diff <= None,
+        relative <= Some(0.0_f32)
+    ));
+    assert_eq!(Some(0.0_f32).relative_flushed(&Some(subnormal)), 1);
+
+    let ok: Result<f32, f32> = Ok(subnormal);
+    let err: Result<f32, f32> = Err(subnormal);
+
+    assert!(relative_eq_ftz!(
+        ok,
+        Ok::<f32, f32>(0.0_f32),
+        abs_diff <= Ok(0.0_f32),
+        relative <= Ok(0.0_f32)
+    ));
+    assert!(relative_eq_ftz!(
+        err,
+        Err::<f32, f32>(0.0_f32),
+        abs_diff <= Err(0.0_f32),
+        relative <= Err(0.0_f32)
+    ));
+    assert!(relative_ne_ftz!(
+        ok,
+        err,
+        abs_diff <= Ok::<f32, f32>(0.0_f32),
+        relative <= Ok::<f32, f32>(0.0_f32)
+    ));
+    assert_eq!(err.relative_flushed(&Err::<f32, f32>(0.0_f32)), 1);
+}
+
+#[test]
+fn test_sequences() {
+    let reference = [1.0_f32, 1e-40_f32, -1e-41_f32];
+    let flushed = [1.0_f32, 0.0_f32, -0.0_f32];
+
+    assert!(relative_eq_ftz!(
+        VecDeque::from(flushed),
+        VecDeque::from(reference),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    ));
+    assert!(relative_eq_ftz!(
+        LinkedList::from(flushed),
+        LinkedList::from(reference),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    ));
+    assert!(relative_ne_ftz!(
+        LinkedList::from(flushed),
+        LinkedList::from([1.0_f32]),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    ));
+    assert_eq!(VecDeque::from(flushed).relative_flushed(&VecDeque::from(reference)), 2);
+}
+
+#[test]
+fn test_maps() {
+    let reference = BTreeMap::from([("x", 1.0_f32), ("y", 1e-40_f32)]);
+    let flushed = BTreeMap::from([("x", 1.0_f32), ("y", 0.0_f32)]);
+
+    assert!(relative_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, relative <= 0.0_f32));
+    assert!(relative_ne_ftz!(
+        flushed,
+        BTreeMap::from([("x", 1.0_f32), ("z", 0.0_f32)]),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    ));
+    assert_eq!(flushed.relative_flushed(&reference), 1);
+
+    let reference = HashMap::from([("x", 1.0_f32), ("y", 1e-40_f32)]);
+    let flushed = HashMap::from([("x", 1.0_f32), ("y", 0.0_f32)]);
+
+    assert!(relative_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, relative <= 0.0_f32));
+    assert!(relative_ne_ftz!(
+        flushed,
+        HashMap::from([("x", 1.0_f32)]),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    ));
+    assert_eq!(flushed.relative_flushed(&reference), 1);
+}
+
+#[test]
+fn test_assert_relative_eq_ftz() {
+    assert_relative_eq_ftz!(
+        [0.0_f32, 1.0_f32],
+        [1e-40_f32, 1.0_f32],
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    );
+    assert_relative_eq_ftz!(0.0_f64, 1e-310_f64, relative <= f64::EPSILON, abs_diff <= 0.0_f64, "message {}", 1);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `relative_eq_ftz!(left, right, abs_diff <= t, relative <= t)`
+subnormals flushed to zero: `1`
+first failure at `[1]`:")]
+fn test_assert_relative_eq_ftz_panic_message() {
+    assert_relative_eq_ftz!(
+        [0.0_f32, 1.0_f32],
+        [1e-40_f32, 2.0_f32],
+        abs_diff <= 0.0_f32,
+        relative <= f32::EPSILON
+    );
+}
+
+#[test]
+#[should_panic(expected = "flushed: `right`,")]
+fn test_assert_relative_eq_ftz_panic_flushed_element() {
+    assert_relative_eq_ftz!(f32::MIN_POSITIVE, 1e-40_f32, abs_diff <= 0.0_f32, relative <= f32::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[\"y\"]`:")]
+fn test_assert_relative_eq_ftz_panic_map_key() {
+    assert_relative_eq_ftz!(
+        BTreeMap::from([("x", 1.0_f32), ("y", 2.0_f32)]),
+        BTreeMap::from([("x", 1.0_f32), ("y", 1e-40_f32)]),
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32
+    );
+}
+
+#[test]
+#[should_panic(expected = "custom message")]
+fn test_assert_relative_eq_ftz_panic_custom_message() {
+    assert_relative_eq_ftz!(
+        vec![1.0_f64],
+        vec![1.0_f64, 2.0_f64],
+        abs_diff <= 0.0_f64,
+        relative <= f64::EPSILON,
+        "custom message"
+    );
+}
@@ -576,7 +576,7 @@ fn test_ne_empty4() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_abs_diff = HashMap::from([
         ("0", 1.0_f32 * f32::EPSILON),
         ("1", 0.5_f32 * f32::EPSILON),
@@ -618,7 +618,7 @@ fn test_ne_empty5() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_abs_diff = HashMap::from([
         ("0", 1.0_f32 * f32::EPSILON),
         ("1", 0.5_f32 * f32::EPSILON),
@@ -650,7 +650,7 @@ fn test_ne_empty6() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_abs_diff = HashMap::new();
     let max_relative = HashMap::from([
         ("0", 1.0_f32 * f32::EPSILON),
@@ -682,7 +682,7 @@ fn test_ne_empty7() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_abs_diff = HashMap::new();
     let max_relative = HashMap::new();
 
@@ -959,7 +959,7 @@ fn test_all_ne_empty1() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_abs_diff = 2.0_f32 * f32::EPSILON;
     let max_relative = 1.0_f32 * f32::EPSILON;
 
@@ -1002,7 +1002,7 @@ fn test_debug_abs_diff_empty1() {
         ("7", 4.00_f32),
         ("8", 5.00_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -1011,7 +1011,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = HashMap::new();
+    let lhs = HashMap::<&str, f32>::new();
     let rhs = HashMap::from([
         ("0", 0.0000000_f32),
         ("1", 1.0000000_f32),
@@ -1037,7 +1037,7 @@ fn test_debug_abs_diff_tolerance_empty() {
         ("2", 4.50_f32),
         ("3", 5.75_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_abs_diff = HashMap::from([
         ("0", 0.10_f32),
         ("1", 0.20_f32),
@@ -1068,7 +1068,7 @@ fn test_debug_abs_diff_all_tolerance_empty() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_relative_tolerance_empty() {
-    let lhs = HashMap::new();
+    let lhs = HashMap::<&str, f32>::new();
     let rhs = HashMap::from([
         ("0", 2.50_f32),
         ("1", 3.00_f32),
@@ -1095,7 +1095,7 @@ fn test_debug_relative_all_tolerance_empty() {
         ("2", 4.50_f32),
         ("3", 5.75_f32),
     ]);
-    let rhs = HashMap::new();
+    let rhs = HashMap::<&str, f32>::new();
     let max_relative = 0.20_f32;
 
     assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &max_relative), None);
@@ -0,0 +1,90 @@
+use relative_cmp::{
+    AssertRelativeEq,
+    RelativeAllEq,
+    RelativeEq,
+    assert_relative_eq,
+    assert_relative_ne,
+    relative_eq,
+    relative_ne,
+};
+
+#[test]
+fn test_eq_f32_f64() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+
+    assert!(lhs.relative_eq(&rhs, &0.0_f64, &2e-8_f64));
+    assert!(rhs.relative_eq(&lhs, &0.0_f64, &2e-8_f64));
+    assert!(lhs.relative_all_eq(&rhs, &0.0_f64, &2e-8_f64));
+    assert!(relative_eq!(lhs, rhs, abs_diff <= 0.0_f64, relative <= 2e-8_f64));
+    assert_relative_eq!(rhs, lhs, abs_diff_all <= 0.0_f64, relative_all <= 2e-8_f64);
+}
+
+#[test]
+fn test_ne_f32_f64() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+
+    assert!(lhs.relative_ne(&rhs, &0.0_f64, &1e-8_f64));
+    assert!(rhs.relative_ne(&lhs, &0.0_f64, &1e-8_f64));
+    assert!(lhs.relative_all_ne(&rhs, &0.0_f64, &1e-8_f64));
+    assert!(relative_ne!(lhs, rhs, abs_diff <= 0.0_f64, relative <= 1e-8_f64));
+    assert_relative_ne!(rhs, lhs, abs_diff_all <= 0.0_f64, relative_all <= 1e-8_f64);
+}
+
+#[test]
+fn test_eq_exactly_representable() {
+    for value in [0.0_f32, -0.0_f32, 1.0_f32, -2.5_f32, f32::MAX, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(value.relative_eq(&f64::from(value), &0.0_f64, &0.0_f64));
+        assert!(f64::from(value).relative_eq(&value, &0.0_f64, &0.0_f64));
+    }
+}
+
+#[test]
+fn test_ne_special_values() {
+    assert!(f32::NAN.relative_ne(&f64::NAN, &f64::INFINITY, &f64::INFINITY));
+    assert!(f32::MAX.relative_ne(&f64::INFINITY, &f64::INFINITY, &f64::INFINITY));
+}
+
+#[test]
+fn test_debug_diffs_measured_in_f64() {
+    let lhs = 0.1_f32;
+    let rhs = 0.1_f64;
+    let abs_diff = f64::from(lhs) - rhs;
+
+    assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+    assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    assert_eq!(lhs.debug_relative_diff(&rhs), abs_diff / f64::from(lhs));
+    assert_eq!(lhs.debug_relative_tolerance(&rhs, &0.5_f64), f64::from(lhs) * 0.5_f64);
+}
+
+#[test]
+fn test_eq_containers() {
+    let lhs = vec![0.1_f32, 0.2_f32, 0.3_f32];
+    let rhs = vec![0.1_f64, 0.2_f64, 0.3_f64];
+
+    assert!(relative_eq!(
+        lhs,
+        rhs,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= f64::from(f32::EPSILON)
+    ));
+    assert!(relative_eq!(
+        rhs,
+        lhs,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= f64::from(f32::EPSILON)
+    ));
+    assert!(relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= f64::EPSILON));
+}
+
+#[test]
+#[should_panic(
+    expected = "first failure at `[1]`:\n        left: `0.2`,\n       right: `0.2`,\n    abs_diff: `2.980232227667301e-9`,\n    relative: `1.4901160916291903e-8`,\n      branch: `FailedRelative`,"
+)]
+fn test_assert_eq_panic_message() {
+    let lhs = vec![0.5_f32, 0.2_f32];
+    let rhs = vec![0.5_f64, 0.2_f64];
+
+    assert_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= f64::EPSILON);
+}
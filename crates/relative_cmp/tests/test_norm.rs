@@ -0,0 +1,311 @@
+use relative_cmp::{
+    Norm,
+    NormAccumulator,
+    NormEq,
+    NormReport,
+    Norms,
+    assert_norm_eq,
+    assert_norm_ne,
+    norm_eq,
+    norm_ne,
+};
+use std::collections::VecDeque;
+
+#[test]
+fn test_norms_of_each_norm() {
+    let lhs = [1.0_f64, -2.0_f64, 3.0_f64];
+    let rhs = [2.0_f64, 0.0_f64, 1.0_f64];
+
+    assert_eq!(
+        lhs.norms(&rhs, Norm::L1),
+        Some(Norms {
+            diff: 5.0_f64,
+            reference: 3.0_f64
+        })
+    );
+    assert_eq!(
+        lhs.norms(&rhs, Norm::L2),
+        Some(Norms {
+            diff: 3.0_f64,
+            reference: f64::sqrt(5.0_f64)
+        })
+    );
+    assert_eq!(
+        lhs.norms(&rhs, Norm::LInf),
+        Some(Norms {
+            diff: 2.0_f64,
+            reference: 2.0_f64
+        })
+    );
+    assert_eq!(Norm::default(), Norm::L2);
+}
+
+#[test]
+fn test_l2_matches_sqrt() {
+    let lhs: Vec<f64> = (0..100).map(|i| f64::from(i) * 0.37_f64).collect();
+    let rhs: Vec<f64> = (0..100).map(|i| f64::from(i) * 0.35_f64 + 1.0_f64).collect();
+    let expected = lhs.iter().zip(rhs.iter()).map(|(a, b)| (a - b) * (a - b)).sum::<f64>().sqrt();
+    let norms = lhs.norms(&rhs, Norm::L2).unwrap();
+
+    assert!(f64::abs(norms.diff - expected) <= 4.0_f64 * f64::EPSILON * expected);
+}
+
+#[test]
+fn test_l2_does_not_overflow() {
+    let lhs = [1e300_f64, 1e300_f64];
+    let rhs = [-1e300_f64, -1e300_f64];
+    let norms = lhs.norms(&rhs, Norm::L2).unwrap();
+
+    assert!(f64::abs(norms.diff - 2e300_f64 * f64::sqrt(2.0_f64)) <= 1e285_f64);
+    assert!(f64::abs(norms.reference - 1e300_f64 * f64::sqrt(2.0_f64)) <= 1e285_f64);
+}
+
+#[test]
+fn test_l2_does_not_underflow() {
+    let lhs = [3e-200_f64, 4e-200_f64];
+    let rhs = [0.0_f64, 0.0_f64];
+    let norms = lhs.norms(&rhs, Norm::L2).unwrap();
+
+    assert!(f64::abs(norms.diff - 5e-200_f64) <= 1e-214_f64);
+}
+
+#[test]
+fn test_solver_tolerance() {
+    // One component is off by far more than an element-wise tolerance, but the
+    // error of the solution as a whole is small.
+    let mut solution = vec![1.0_f64; 1000];
+    let expected = vec![1.0_f64; 1000];
+    solution[500] = 1.0_f64 + 1e-4_f64;
+
+    assert!(solution.norm_eq(&expected, &0.0_f64, &1e-5_f64, Norm::L2));
+    assert!(!solution.norm_eq(&expected, &0.0_f64, &1e-5_f64, Norm::LInf));
+    assert!(solution.norm_eq(&expected, &1e-4_f64, &0.0_f64, Norm::LInf));
+    assert!(!solution.norm_eq(&expected, &0.0_f64, &1e-8_f64, Norm::L1));
+}
+
+#[test]
+fn test_relative_to_right_hand_side() {
+    let lhs = [0.0_f64, 0.0_f64];
+    let rhs = [3.0_f64, 4.0_f64];
+
+    assert!(lhs.norm_eq(&rhs, &0.0_f64, &1.0_f64, Norm::L2));
+    assert!(!rhs.norm_eq(&lhs, &0.0_f64, &1.0_f64, Norm::L2));
+}
+
+#[test]
+fn test_empty() {
+    let empty: [f64; 0] = [];
+
+    assert_eq!(
+        empty.norms(&empty, Norm::L2),
+        Some(Norms {
+            diff: 0.0_f64,
+            reference: 0.0_f64
+        })
+    );
+    assert!(empty.norm_eq(&empty, &0.0_f64, &0.0_f64, Norm::L1));
+}
+
+#[test]
+fn test_length_mismatch() {
+    let lhs = vec![1.0_f64, 2.0_f64];
+    let rhs = vec![1.0_f64, 2.0_f64, 3.0_f64];
+
+    assert_eq!(lhs.norms(&rhs, Norm::L2), None);
+    assert!(!lhs.norm_eq(&rhs, &f64::MAX, &f64::MAX, Norm::L2));
+    assert!(lhs.norm_ne(&rhs, &f64::MAX, &f64::MAX, Norm::L2));
+}
+
+#[test]
+fn test_special_values() {
+    let lhs = [1.0_f64, f64::INFINITY];
+    let rhs = [1.0_f64, f64::INFINITY];
+
+    assert_eq!(
+        lhs.norms(&rhs, Norm::L2),
+        Some(Norms {
+            diff: 0.0_f64,
+            reference: f64::INFINITY
+        })
+    );
+    assert!(lhs.norm_eq(&rhs, &0.0_f64, &0.0_f64, Norm::L2));
+
+    let rhs = [1.0_f64, f64::NEG_INFINITY];
+
+    assert!(!lhs.norm_eq(&rhs, &f64::MAX, &f64::MAX, Norm::L1));
+
+    let lhs = [1.0_f64, f64::NAN, f64::INFINITY];
+    let rhs = [1.0_f64, 2.0_f64, 3.0_f64];
+
+    for norm in [Norm::L1, Norm::L2, Norm::LInf] {
+        assert!(lhs.norms(&rhs, norm).unwrap().diff.is_nan());
+        assert!(!lhs.norm_eq(&rhs, &f64::INFINITY, &f64::INFINITY, norm));
+    }
+}
+
+#[test]
+fn test_nested_containers() {
+    let lhs = [[1.0_f32, 2.0_f32], [3.0_f32, 4.0_f32]];
+    let rhs = [[1.0_f32, 2.0_f32], [3.0_f32, 5.0_f32]];
+
+    assert_eq!(
+        lhs.norms(&rhs, Norm::L1),
+        Some(Norms {
+            diff: 1.0_f32,
+            reference: 11.0_f32
+        })
+    );
+    assert!(lhs.norm_eq(&rhs, &0.0_f32, &0.2_f32, Norm::L2));
+    assert!(lhs.to_vec().norm_eq(&rhs.to_vec(), &0.0_f32, &0.2_f32, Norm::L2));
+    assert!(lhs[..].norm_eq(&rhs[..], &0.0_f32, &0.2_f32, Norm::L2));
+    assert!(VecDeque::from(lhs.to_vec()).norm_eq(&VecDeque::from(rhs.to_vec()), &0.0_f32, &0.2_f32, Norm::L2));
+    assert!(!vec![lhs.to_vec()].norm_eq(&vec![rhs[..1].to_vec()], &f32::MAX, &f32::MAX, Norm::L2));
+}
+
+#[test]
+fn test_mixed_precision() {
+    let lhs = [0.1_f32, 0.2_f32];
+    let rhs = [0.1_f64, 0.2_f64];
+
+    assert!(lhs.norm_eq(&rhs, &0.0_f64, &1e-7_f64, Norm::L2));
+    assert!(!lhs.norm_eq(&rhs, &0.0_f64, &1e-9_f64, Norm::L2));
+    assert!(rhs.to_vec().norm_eq(&lhs.to_vec(), &0.0_f64, &1e-7_f64, Norm::L2));
+}
+
+#[test]
+fn test_references() {
+    let lhs = vec![1.0_f64, 2.0_f64];
+    let rhs = vec![1.0_f64, 2.5_f64];
+    let lhs_slice: &[f64] = &lhs;
+    let rhs_slice: &[f64] = &rhs;
+
+    assert!(NormEq::norm_eq(&lhs_slice, &rhs_slice, &0.5_f64, &0.0_f64, Norm::L1));
+    assert!(norm_eq!(
+        lhs_slice,
+        rhs_slice,
+        abs_diff <= 0.5_f64,
+        relative <= 0.0_f64,
+        norm = Norm::L1
+    ));
+}
+
+#[test]
+fn test_accumulator() {
+    let mut accumulator = NormAccumulator::new(Norm::LInf);
+    accumulator.push(-3.0_f64, 1.0_f64);
+    accumulator.push(2.0_f64, -4.0_f64);
+
+    assert_eq!(accumulator.norm(), Norm::LInf);
+    assert_eq!(
+        accumulator.finish(),
+        Norms {
+            diff: 3.0_f64,
+            reference: 4.0_f64
+        }
+    );
+}
+
+#[test]
+fn test_norm_eq_macro() {
+    let lhs = vec![1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = vec![1.0_f64, 2.0_f64, 3.01_f64];
+
+    assert!(norm_eq!(lhs, rhs, abs_diff <= 0.0_f64, relative <= 0.01_f64, norm = Norm::L2));
+    assert!(norm_eq!(lhs, rhs, relative <= 0.01_f64, abs_diff <= 0.0_f64, norm = Norm::L2));
+    assert!(!norm_eq!(lhs, rhs, abs_diff <= 0.001_f64, relative <= 0.0_f64, norm = Norm::LInf));
+    assert!(norm_ne!(lhs, rhs, abs_diff <= 0.001_f64, relative <= 0.0_f64, norm = Norm::LInf));
+    assert!(!norm_ne!(lhs, rhs, relative <= 0.01_f64, abs_diff <= 0.0_f64, norm = Norm::L1));
+}
+
+#[test]
+fn test_assert_norm_eq_macro() {
+    let lhs = vec![1.0_f64, 2.0_f64, 3.0_f64];
+    let rhs = vec![1.0_f64, 2.0_f64, 3.01_f64];
+
+    assert_norm_eq!(lhs, rhs, abs_diff <= 0.0_f64, relative <= 0.01_f64, norm = Norm::L2);
+    assert_norm_eq!(
+        lhs,
+        rhs,
+        relative <= 0.0_f64,
+        abs_diff <= 0.011_f64,
+        norm = Norm::LInf,
+        "message {}",
+        1
+    );
+    assert_norm_ne!(lhs, rhs, abs_diff <= 0.001_f64, relative <= 0.0_f64, norm = Norm::L1);
+    assert_norm_ne!(
+        lhs,
+        rhs,
+        relative <= 0.0001_f64,
+        abs_diff <= 0.0_f64,
+        norm = Norm::L2,
+        "message {}",
+        2
+    );
+}
+
+#[test]
+fn test_norm_report() {
+    let report = NormReport::new(
+        Norm::L1,
+        Some(Norms {
+            diff: 1.5_f64,
+            reference: 20.0_f64,
+        }),
+        0.5_f64,
+        0.05_f64,
+    );
+    let expected = "        norm: `L1`,
+   diff_norm: `1.5`,
+  right_norm: `20.0`,
+[abs_diff] t: `0.5`,
+[relative] t: `1.0`,";
+
+    assert_eq!(report.to_string(), expected);
+
+    let report = NormReport::new(Norm::L2, None, 0.5_f64, 0.05_f64);
+    let expected = "        norm: `L2`,
+the compared values have different lengths";
+
+    assert_eq!(report.to_string(), expected);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `norm_eq!(left, right, abs_diff <= t, relative <= t, norm = L2)`
+        norm: `L2`,
+   diff_norm: `5.0`,
+  right_norm: `0.0`,")]
+fn test_assert_norm_eq_panic_message() {
+    assert_norm_eq!(
+        [3.0_f64, 4.0_f64],
+        [0.0_f64, 0.0_f64],
+        abs_diff <= 1.0_f64,
+        relative <= 1.0_f64,
+        norm = Norm::L2
+    );
+}
+
+#[test]
+#[should_panic(expected = "the compared values have different lengths: custom message")]
+fn test_assert_norm_eq_panic_custom_message() {
+    assert_norm_eq!(
+        vec![1.0_f32],
+        vec![1.0_f32, 2.0_f32],
+        abs_diff <= 1.0_f32,
+        relative <= 1.0_f32,
+        norm = Norm::L1,
+        "custom message"
+    );
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `norm_ne!(left, right, abs_diff <= t, relative <= t, norm = LInf)`")]
+fn test_assert_norm_ne_panic_message() {
+    assert_norm_ne!(
+        [3.0_f64, 4.0_f64],
+        [3.0_f64, 4.0_f64],
+        abs_diff <= 0.0_f64,
+        relative <= 0.0_f64,
+        norm = Norm::LInf
+    );
+}
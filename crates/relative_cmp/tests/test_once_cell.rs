@@ -268,7 +268,7 @@ fn test_ne_none3() {
 fn test_debug_abs_diff_tolerance_none() {
     let lhs = cell::OnceCell::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
     let rhs = cell::OnceCell::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
-    let empty_cell = cell::OnceCell::new();
+    let empty_cell = cell::OnceCell::<[f32; 4]>::new();
     let max_abs_diff = [0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32];
     let tolerance = max_abs_diff;
 
@@ -281,7 +281,7 @@ fn test_debug_abs_diff_tolerance_none() {
 fn test_debug_abs_diff_all_tolerance_none() {
     let lhs = cell::OnceCell::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
     let rhs = cell::OnceCell::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
-    let empty_cell = cell::OnceCell::new();
+    let empty_cell = cell::OnceCell::<[f32; 4]>::new();
     let max_abs_diff = 0.20_f32;
     let tolerance = [max_abs_diff; 4];
 
@@ -294,7 +294,7 @@ fn test_debug_abs_diff_all_tolerance_none() {
 fn test_debug_relative_tolerance_none() {
     let lhs = cell::OnceCell::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
     let rhs = cell::OnceCell::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
-    let empty_cell = cell::OnceCell::new();
+    let empty_cell = cell::OnceCell::<[f32; 4]>::new();
     let max_relative = [0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32];
     let tolerance = [0.25_f32, 0.65000004_f32, 1.35_f32, 2.40_f32];
 
@@ -307,7 +307,7 @@ fn test_debug_relative_tolerance_none() {
 fn test_debug_relative_all_tolerance_none() {
     let lhs = cell::OnceCell::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
     let rhs = cell::OnceCell::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
-    let empty_cell = cell::OnceCell::new();
+    let empty_cell = cell::OnceCell::<[f32; 4]>::new();
     let max_relative = 0.20_f32;
     let tolerance = [0.50_f32, 0.65000004_f32, 0.90000004_f32, 1.20_f32];
 
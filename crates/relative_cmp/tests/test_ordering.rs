@@ -0,0 +1,101 @@
+use core::cmp::Ordering;
+use relative_cmp::{
+    RelativeOrd,
+    assert_relative_ge,
+    assert_relative_gt,
+    assert_relative_le,
+    assert_relative_lt,
+    relative_ge,
+    relative_gt,
+    relative_le,
+    relative_lt,
+};
+
+#[test]
+fn test_relative_partial_cmp() {
+    assert_eq!(
+        100.0_f64.relative_partial_cmp(&101.0_f64, &0.0_f64, &0.01_f64),
+        Some(Ordering::Equal)
+    );
+    assert_eq!(
+        100.0_f64.relative_partial_cmp(&102.0_f64, &0.0_f64, &0.01_f64),
+        Some(Ordering::Less)
+    );
+    assert_eq!(
+        102.0_f64.relative_partial_cmp(&100.0_f64, &0.0_f64, &0.01_f64),
+        Some(Ordering::Greater)
+    );
+    assert_eq!(f64::NAN.relative_partial_cmp(&f64::NAN, &f64::INFINITY, &f64::INFINITY), None);
+}
+
+#[test]
+fn test_relative_lt_le() {
+    assert!(relative_lt!(100.0_f32, 102.0_f32, abs_diff <= 0.0_f32, relative <= 0.01_f32));
+    assert!(!relative_lt!(100.0_f32, 101.0_f32, abs_diff <= 0.0_f32, relative <= 0.01_f32));
+    assert!(relative_le!(101.0_f32, 100.0_f32, abs_diff <= 0.0_f32, relative <= 0.01_f32));
+    assert!(relative_le!(101.0_f32, 100.0_f32, relative <= 0.01_f32, abs_diff <= 0.0_f32));
+    assert!(!relative_le!(102.0_f32, 100.0_f32, abs_diff <= 0.0_f32, relative <= 0.01_f32));
+    assert!(!relative_le!(
+        f32::NAN,
+        1.0_f32,
+        abs_diff <= f32::INFINITY,
+        relative <= f32::INFINITY
+    ));
+}
+
+#[test]
+fn test_relative_gt_ge() {
+    assert!(relative_gt!(102.0_f64, 100.0_f64, abs_diff <= 0.0_f64, relative <= 0.01_f64));
+    assert!(!relative_gt!(101.0_f64, 100.0_f64, relative <= 0.01_f64, abs_diff <= 0.0_f64));
+    assert!(relative_ge!(100.0_f64, 101.0_f64, abs_diff <= 0.0_f64, relative <= 0.01_f64));
+    assert!(!relative_ge!(100.0_f64, 102.0_f64, abs_diff <= 0.0_f64, relative <= 0.01_f64));
+}
+
+#[test]
+fn test_relative_ordering_mixed_precision() {
+    assert_eq!(0.1_f32.relative_partial_cmp(&0.1_f64, &0.0_f64, &1e-7_f64), Some(Ordering::Equal));
+    assert_eq!(0.1_f32.relative_partial_cmp(&0.1_f64, &0.0_f64, &0.0_f64), Some(Ordering::Greater));
+    assert!(relative_lt!(0.1_f64, 0.1_f32, abs_diff <= 0.0_f64, relative <= 0.0_f64));
+    assert!(relative_le!(0.1_f64, 0.1_f32, abs_diff <= 0.0_f64, relative <= 1e-7_f64));
+    assert!(!relative_lt!(0.1_f64, 0.1_f32, abs_diff <= 0.0_f64, relative <= 1e-7_f64));
+}
+
+#[test]
+fn test_assert_relative_ordering() {
+    assert_relative_lt!(1.0_f64, 2.0_f64, abs_diff <= 0.0_f64, relative <= f64::EPSILON);
+    assert_relative_le!(
+        0.1_f64 + 0.2_f64,
+        0.3_f64,
+        abs_diff <= 0.0_f64,
+        relative <= f64::EPSILON,
+        "message {}",
+        1
+    );
+    assert_relative_gt!(2.0_f64, 1.0_f64, relative <= f64::EPSILON, abs_diff <= 0.0_f64);
+    assert_relative_ge!(
+        0.3_f64,
+        0.1_f64 + 0.2_f64,
+        relative <= f64::EPSILON,
+        abs_diff <= 0.0_f64,
+        "message {}",
+        2
+    );
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `relative_le!(left, right, abs_diff <= t, relative <= t)`
+        left: `2.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+    relative: `0.5`,
+[abs_diff] t: `0.0`,
+[relative] t: `0.02`")]
+fn test_assert_relative_le_panic_message() {
+    assert_relative_le!(2.0_f64, 1.0_f64, abs_diff <= 0.0_f64, relative <= 0.01_f64);
+}
+
+#[test]
+#[should_panic(expected = "custom message")]
+fn test_assert_relative_gt_panic_custom_message() {
+    assert_relative_gt!(101.0_f64, 100.0_f64, relative <= 0.01_f64, abs_diff <= 0.0_f64, "custom message");
+}
@@ -0,0 +1,214 @@
+use relative_cmp::{
+    AssertRelativeAllEq,
+    AssertRelativeEq,
+    FloatPolicy,
+    assert_relative_eq,
+    assert_relative_ne,
+    check_relative_eq,
+    check_relative_ne,
+    relative_eq,
+    relative_ne,
+};
+
+#[test]
+fn test_nan_eq() {
+    let lhs = vec![1.0_f64, f64::NAN];
+    let rhs = vec![1.0_f64, f64::NAN];
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(!relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 1e-6_f64));
+    assert!(relative_eq!(
+        lhs,
+        rhs,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 1e-6_f64,
+        policy = policy
+    ));
+    assert!(relative_eq!(
+        lhs,
+        rhs,
+        relative_all <= 1e-6_f64,
+        abs_diff_all <= 0.0_f64,
+        policy = policy
+    ));
+    assert!(!relative_eq!(
+        lhs,
+        rhs,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 1e-6_f64,
+        policy = FloatPolicy::new()
+    ));
+}
+
+#[test]
+fn test_inf_eq() {
+    let strict = FloatPolicy {
+        inf_eq: false,
+        ..FloatPolicy::new()
+    };
+
+    assert!(relative_eq!(f32::INFINITY, f32::INFINITY, abs_diff <= 0.0_f32, relative <= 0.0_f32));
+    assert!(!relative_eq!(
+        f32::INFINITY,
+        f32::INFINITY,
+        abs_diff <= 0.0_f32,
+        relative <= 0.0_f32,
+        policy = strict
+    ));
+    assert!(relative_ne!(
+        f32::INFINITY,
+        f32::MAX,
+        abs_diff <= f32::INFINITY,
+        relative <= f32::INFINITY,
+        policy = FloatPolicy::new()
+    ));
+}
+
+#[test]
+fn test_signed_zero_strict() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(relative_eq!(-0.0_f64, 0.0_f64, abs_diff <= 0.0_f64, relative <= 0.0_f64));
+    assert!(!relative_eq!(
+        -0.0_f64,
+        0.0_f64,
+        abs_diff <= 0.0_f64,
+        relative <= 0.0_f64,
+        policy = policy
+    ));
+    assert!(relative_ne!(
+        (1.0_f64, -0.0_f64),
+        (1.0_f64, 0.0_f64),
+        abs_diff <= (0.0_f64, 0.0_f64),
+        relative <= (0.0_f64, 0.0_f64),
+        policy = policy
+    ));
+}
+
+#[test]
+fn test_relative_eq_with_policy() {
+    let lhs = [1.0_f64, f64::NAN, -0.0_f64];
+    let rhs = [1.0_f64, f64::NAN, 0.0_f64];
+    let nan_eq = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+    let strict = FloatPolicy {
+        signed_zero_strict: true,
+        ..nan_eq
+    };
+
+    assert!(lhs.relative_eq_with_policy(&rhs, &[0.0_f64; 3], &[1e-6_f64; 3], &nan_eq));
+    assert!(!lhs.relative_ne_with_policy(&rhs, &[0.0_f64; 3], &[1e-6_f64; 3], &nan_eq));
+    assert!(!lhs.relative_eq_with_policy(&rhs, &[0.0_f64; 3], &[1e-6_f64; 3], &FloatPolicy::new()));
+    assert!(lhs.relative_all_eq_with_policy(&rhs, &0.0_f64, &1e-6_f64, &nan_eq));
+    assert!(lhs.relative_all_ne_with_policy(&rhs, &0.0_f64, &1e-6_f64, &strict));
+}
+
+#[test]
+fn test_check_relative_with_policy() {
+    let lhs = [1.0_f64, f64::NAN, -0.0_f64];
+    let rhs = [1.0_f64, f64::NAN, 0.0_f64];
+    let nan_eq = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+    let strict = FloatPolicy {
+        signed_zero_strict: true,
+        ..nan_eq
+    };
+
+    assert!(check_relative_eq!(lhs, rhs, abs_diff <= [0.0_f64; 3], relative <= [1e-6_f64; 3], policy = nan_eq).is_ok());
+    assert!(check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 1e-6_f64, policy = nan_eq).is_ok());
+    assert!(check_relative_eq!(lhs, rhs, relative_all <= 1e-6_f64, abs_diff_all <= 0.0_f64, policy = nan_eq).is_ok());
+    assert!(check_relative_ne!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 1e-6_f64, policy = strict).is_ok());
+    assert!(check_relative_ne!(lhs, rhs, abs_diff <= [0.0_f64; 3], relative <= [1e-6_f64; 3], policy = nan_eq).is_err());
+
+    let mismatch = check_relative_eq!(
+        lhs,
+        rhs,
+        abs_diff <= [0.0_f64; 3],
+        relative <= [1e-6_f64; 3],
+        policy = FloatPolicy::new()
+    )
+    .unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+
+    let mismatch = check_relative_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, relative_all <= 1e-6_f64, policy = strict).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+}
+
+#[test]
+fn test_assert_with_policy() {
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_relative_eq!(f64::NAN, f64::NAN, abs_diff <= 0.0_f64, relative <= 0.0_f64, policy = policy);
+    assert_relative_eq!(
+        [f64::NAN],
+        [f64::NAN],
+        relative_all <= 0.0_f64,
+        abs_diff_all <= 0.0_f64,
+        policy = policy,
+        "message {}",
+        1
+    );
+    assert_relative_ne!(
+        f64::NAN,
+        f64::NAN,
+        abs_diff <= 0.0_f64,
+        relative <= 0.0_f64,
+        policy = FloatPolicy::new()
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `relative_eq!(left, right, abs_diff_all <= t, relative_all <= t, policy = p)`
+      policy: `FloatPolicy { nan_eq: false, signed_zero_strict: false, inf_eq: false }`,
+first failure at `[0]`:
+        left: `inf`,
+       right: `inf`,"
+)]
+fn test_assert_relative_eq_policy_panic_message() {
+    let policy = FloatPolicy {
+        inf_eq: false,
+        ..FloatPolicy::new()
+    };
+
+    assert_relative_eq!(
+        [f64::INFINITY],
+        [f64::INFINITY],
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 0.0_f64,
+        policy = policy
+    );
+}
+
+#[test]
+#[should_panic(expected = "policy: `rejected by inf_eq`,")]
+fn test_assert_relative_eq_policy_rule() {
+    let policy = FloatPolicy {
+        inf_eq: false,
+        ..FloatPolicy::new()
+    };
+
+    assert_relative_eq!(
+        f64::INFINITY,
+        f64::INFINITY,
+        abs_diff <= 0.0_f64,
+        relative <= 0.0_f64,
+        policy = policy,
+        "custom message"
+    );
+}
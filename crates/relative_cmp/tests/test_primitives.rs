@@ -1580,3 +1580,91 @@ impl_relative_eq_float_exact_exhaustive_tests!(
     (relative_eq_f64_i8_exact_exhaustive_tests, f64, i8),
     (relative_eq_f64_i16_exact_exhaustive_tests, f64, i16)
 );
+
+macro_rules! impl_relative_eq_integer_tests {
+    ($(($module_name:ident, $T:ty, $U:ty)),*) => {$(
+        #[cfg(test)]
+        mod $module_name {
+            use relative_cmp::{
+                AssertRelativeEq,
+                RelativeAllEq,
+                RelativeEq,
+                assert_relative_eq,
+                assert_relative_ne,
+                relative_eq,
+                relative_ne,
+            };
+
+            fn check_relative_eq(a: $T, b: $T, max_abs_diff: f64, max_relative: f64) {
+                assert!(a.relative_eq(&b, &max_abs_diff, &max_relative));
+                assert!(relative_eq!(a, b, abs_diff <= max_abs_diff, relative <= max_relative));
+                assert_relative_eq!(a, b, abs_diff <= max_abs_diff, relative <= max_relative);
+
+                assert!(a.relative_all_eq(&b, &max_abs_diff, &max_relative));
+                assert!(relative_eq!(a, b, abs_diff_all <= max_abs_diff, relative_all <= max_relative));
+                assert_relative_eq!(a, b, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+            }
+
+            fn check_relative_ne(a: $T, b: $T, max_abs_diff: f64, max_relative: f64) {
+                assert!(a.relative_ne(&b, &max_abs_diff, &max_relative));
+                assert!(relative_ne!(a, b, abs_diff <= max_abs_diff, relative <= max_relative));
+                assert_relative_ne!(a, b, abs_diff <= max_abs_diff, relative <= max_relative);
+
+                assert!(a.relative_all_ne(&b, &max_abs_diff, &max_relative));
+                assert!(relative_ne!(a, b, abs_diff_all <= max_abs_diff, relative_all <= max_relative));
+                assert_relative_ne!(a, b, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+            }
+
+            #[test]
+            fn test_eq_self() {
+                for value in [<$T>::MIN, 0, 1, <$T>::MAX] {
+                    check_relative_eq(value, value, 0.0_f64, 0.0_f64);
+                }
+            }
+
+            #[test]
+            fn test_eq_relative() {
+                check_relative_eq(100, 101, 0.0_f64, 0.01_f64);
+                check_relative_eq(101, 100, 0.0_f64, 0.01_f64);
+                check_relative_ne(100, 102, 0.0_f64, 0.01_f64);
+                check_relative_ne(102, 100, 0.0_f64, 0.01_f64);
+            }
+
+            #[test]
+            fn test_eq_abs_diff() {
+                check_relative_eq(0, 2, 2.0_f64, 0.0_f64);
+                check_relative_ne(0, 2, 1.0_f64, 0.5_f64);
+            }
+
+            #[test]
+            fn test_eq_full_range() {
+                check_relative_eq(<$T>::MIN, <$T>::MAX, f64::INFINITY, 0.0_f64);
+                check_relative_ne(<$T>::MIN, <$T>::MAX, 0.0_f64, 0.5_f64);
+            }
+
+            #[test]
+            fn test_debug_diffs() {
+                assert_eq!(<$T>::MIN.debug_abs_diff(&<$T>::MAX), <$U>::MAX);
+                assert_eq!((100 as $T).debug_abs_diff(&80), 20);
+                assert_eq!((100 as $T).debug_relative_diff(&80), 0.2_f64);
+                assert_eq!(<$T>::MAX.debug_relative_diff(&<$T>::MAX), 0.0_f64);
+                assert_eq!((100 as $T).debug_relative_tolerance(&80, &0.5_f64), 50.0_f64);
+            }
+        }
+    )*};
+}
+
+impl_relative_eq_integer_tests!(
+    (relative_eq_u8_tests, u8, u8),
+    (relative_eq_u16_tests, u16, u16),
+    (relative_eq_u32_tests, u32, u32),
+    (relative_eq_u64_tests, u64, u64),
+    (relative_eq_u128_tests, u128, u128),
+    (relative_eq_usize_tests, usize, usize),
+    (relative_eq_i8_tests, i8, u8),
+    (relative_eq_i16_tests, i16, u16),
+    (relative_eq_i32_tests, i32, u32),
+    (relative_eq_i64_tests, i64, u64),
+    (relative_eq_i128_tests, i128, u128),
+    (relative_eq_isize_tests, isize, usize)
+);
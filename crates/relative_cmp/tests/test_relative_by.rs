@@ -0,0 +1,322 @@
+use relative_cmp::{
+    AssertRelativeEqBy,
+    FirstFailure,
+    RelativeAllEqBy,
+    RelativeDenominator,
+    RelativeEqBy,
+    assert_relative_eq_by,
+    assert_relative_ne_by,
+    relative,
+    relative_eq_by,
+    relative_ne_by,
+};
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    VecDeque,
+};
+
+#[test]
+fn test_denominator_magnitude() {
+    let lhs = 3.0_f32;
+    let rhs = -5.0_f32;
+
+    assert_eq!(RelativeDenominator::Max.magnitude(lhs, rhs), 5.0_f32);
+    assert_eq!(RelativeDenominator::Min.magnitude(lhs, rhs), 3.0_f32);
+    assert_eq!(RelativeDenominator::First.magnitude(lhs, rhs), 3.0_f32);
+    assert_eq!(RelativeDenominator::Second.magnitude(lhs, rhs), 5.0_f32);
+    assert_eq!(RelativeDenominator::Mean.magnitude(lhs, rhs), 4.0_f32);
+    assert_eq!(RelativeDenominator::default(), RelativeDenominator::Max);
+}
+
+#[test]
+fn test_denominator_mean_does_not_overflow() {
+    assert_eq!(RelativeDenominator::Mean.magnitude(f64::MAX, f64::MAX), f64::MAX);
+}
+
+#[test]
+fn test_max_matches_relative_eq() {
+    let pairs = [(1.0_f64, 1.1_f64), (-2.0_f64, 2.0_f64), (100.0_f64, 99.0_f64), (0.0_f64, 1e-10_f64)];
+    for (lhs, rhs) in pairs {
+        for max_relative in [0.0_f64, 0.01_f64, 0.1_f64, 1.0_f64] {
+            assert_eq!(
+                relative::relative_eq_by(lhs, rhs, 0.0_f64, max_relative, RelativeDenominator::Max),
+                relative::relative_eq(lhs, rhs, 0.0_f64, max_relative),
+            );
+        }
+    }
+}
+
+#[test]
+fn test_first_second_asymmetric() {
+    let lhs = 100.0_f64;
+    let rhs = 101.0_f64;
+    let max_relative = 0.00995_f64;
+
+    assert!(!lhs.relative_eq_by(&rhs, &0.0_f64, &max_relative, RelativeDenominator::First));
+    assert!(lhs.relative_eq_by(&rhs, &0.0_f64, &max_relative, RelativeDenominator::Second));
+    assert!(rhs.relative_eq_by(&lhs, &0.0_f64, &max_relative, RelativeDenominator::First));
+    assert!(!rhs.relative_eq_by(&lhs, &0.0_f64, &max_relative, RelativeDenominator::Second));
+}
+
+#[test]
+fn test_min_is_strictest() {
+    let lhs = 100.0_f64;
+    let rhs = 101.0_f64;
+    let max_relative = 0.00995_f64;
+
+    assert!(lhs.relative_eq_by(&rhs, &0.0_f64, &max_relative, RelativeDenominator::Max));
+    assert!(!lhs.relative_eq_by(&rhs, &0.0_f64, &max_relative, RelativeDenominator::Min));
+    assert!(!rhs.relative_eq_by(&lhs, &0.0_f64, &max_relative, RelativeDenominator::Min));
+}
+
+#[test]
+fn test_mean() {
+    let lhs = 1.0_f64;
+    let rhs = 3.0_f64;
+
+    assert!(lhs.relative_eq_by(&rhs, &0.0_f64, &1.0_f64, RelativeDenominator::Mean));
+    assert!(!lhs.relative_eq_by(&rhs, &0.0_f64, &0.99_f64, RelativeDenominator::Mean));
+    assert_eq!(relative::relative_diff_by(lhs, rhs, RelativeDenominator::Mean), 1.0_f64);
+}
+
+#[test]
+fn test_zero_denominator() {
+    // Only the absolute difference tolerance compares a number against zero
+    // when the denominator is the magnitude of the zero.
+    assert!(!1e-10_f64.relative_eq_by(&0.0_f64, &0.0_f64, &1.0_f64, RelativeDenominator::Second));
+    assert!(1e-10_f64.relative_eq_by(&0.0_f64, &0.0_f64, &1.0_f64, RelativeDenominator::First));
+    assert!(1e-10_f64.relative_eq_by(&0.0_f64, &1e-9_f64, &0.0_f64, RelativeDenominator::Second));
+    assert_eq!(
+        relative::relative_diff_by(1.0_f64, 0.0_f64, RelativeDenominator::Second),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn test_special_values() {
+    for denominator in [
+        RelativeDenominator::Max,
+        RelativeDenominator::Min,
+        RelativeDenominator::First,
+        RelativeDenominator::Second,
+    ] {
+        assert!(f64::INFINITY.relative_eq_by(&f64::INFINITY, &0.0_f64, &0.0_f64, denominator));
+        assert!(!f64::INFINITY.relative_eq_by(&f64::MAX, &f64::MAX, &1.0_f64, denominator));
+        assert!(!f64::NAN.relative_eq_by(&f64::NAN, &f64::MAX, &1.0_f64, denominator));
+    }
+}
+
+#[test]
+fn test_integers() {
+    assert!(99_i32.relative_eq_by(&100_i32, &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+    assert!(!99_i32.relative_eq_by(&100_i32, &0.0_f64, &0.01_f64, RelativeDenominator::First));
+    assert!(!99_u8.relative_eq_by(&100_u8, &0.0_f64, &0.01_f64, RelativeDenominator::Min));
+    assert!(i64::MIN.relative_eq_by(&i64::MIN, &0.0_f64, &0.0_f64, RelativeDenominator::First));
+}
+
+#[test]
+fn test_mixed_precision() {
+    let lhs = 7.9_f32;
+    let rhs = 8.0_f64;
+
+    assert!(lhs.relative_eq_by(&rhs, &0.0_f64, &0.0125_f64, RelativeDenominator::Second));
+    assert!(!lhs.relative_eq_by(&rhs, &0.0_f64, &0.0125_f64, RelativeDenominator::First));
+    assert!(!rhs.relative_eq_by(&lhs, &0.0_f64, &0.0125_f64, RelativeDenominator::Second));
+}
+
+#[test]
+fn test_containers() {
+    let actual = [99.0_f64, 198.0_f64];
+    let expected = [100.0_f64, 200.0_f64];
+    let tolerance = [0.01_f64, 0.01_f64];
+    let zero = [0.0_f64, 0.0_f64];
+
+    assert!(actual.relative_eq_by(&expected, &zero, &tolerance, RelativeDenominator::Second));
+    assert!(!actual.relative_eq_by(&expected, &zero, &tolerance, RelativeDenominator::First));
+    assert!(actual[..].relative_eq_by(&expected[..], &zero[..], &tolerance[..], RelativeDenominator::Second));
+    assert!(
+        actual
+            .to_vec()
+            .relative_eq_by(&expected.to_vec(), &zero.to_vec(), &tolerance.to_vec(), RelativeDenominator::Second)
+    );
+    assert!(
+        !actual
+            .to_vec()
+            .relative_eq_by(&expected.to_vec(), &zero.to_vec(), &tolerance.to_vec(), RelativeDenominator::Min)
+    );
+    assert!((actual[0], actual[1]).relative_eq_by(
+        &(expected[0], expected[1]),
+        &(0.0_f64, 0.0_f64),
+        &(0.01_f64, 0.01_f64),
+        RelativeDenominator::Second
+    ));
+    assert!(Some(actual[0]).relative_eq_by(&Some(expected[0]), &Some(0.0_f64), &Some(0.01_f64), RelativeDenominator::Second));
+    assert!(Box::new(actual[0]).relative_eq_by(&Box::new(expected[0]), &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+}
+
+#[test]
+fn test_containers_all() {
+    let actual = vec![99.0_f64, 198.0_f64];
+    let expected = vec![100.0_f64, 200.0_f64];
+
+    assert!(actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+    assert!(!actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::First));
+    assert!(VecDeque::from(actual.clone()).relative_all_eq_by(
+        &VecDeque::from(expected.clone()),
+        &0.0_f64,
+        &0.01_f64,
+        RelativeDenominator::Second
+    ));
+    assert!((actual[0], actual[1]).relative_all_eq_by(&(expected[0], expected[1]), &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+    assert!(().relative_all_eq_by(&(), &(), &(), RelativeDenominator::First));
+
+    let actual = BTreeMap::from([("a", 99.0_f64)]);
+    let expected = BTreeMap::from([("a", 100.0_f64)]);
+
+    assert!(actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+    assert!(!actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::First));
+
+    let actual = HashMap::from([("a", 99.0_f64)]);
+    let expected = HashMap::from([("a", 100.0_f64)]);
+
+    assert!(actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Second));
+    assert!(!actual.relative_all_eq_by(&expected, &0.0_f64, &0.01_f64, RelativeDenominator::Min));
+}
+
+#[test]
+fn test_relative_eq_by_macro() {
+    let actual = [99.0_f64, 198.0_f64];
+    let expected = [100.0_f64, 200.0_f64];
+
+    assert!(relative_eq_by!(
+        actual,
+        expected,
+        abs_diff <= [0.0_f64; 2],
+        relative <= [0.01_f64; 2],
+        denominator = RelativeDenominator::Second
+    ));
+    assert!(relative_eq_by!(
+        actual,
+        expected,
+        relative <= [0.01_f64; 2],
+        abs_diff <= [0.0_f64; 2],
+        denominator = RelativeDenominator::Second
+    ));
+    assert!(relative_eq_by!(
+        actual,
+        expected,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 0.01_f64,
+        denominator = RelativeDenominator::Second
+    ));
+    assert!(relative_eq_by!(
+        actual,
+        expected,
+        relative_all <= 0.01_f64,
+        abs_diff_all <= 0.0_f64,
+        denominator = RelativeDenominator::Second
+    ));
+    assert!(relative_ne_by!(
+        actual,
+        expected,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 0.01_f64,
+        denominator = RelativeDenominator::First
+    ));
+    assert!(!relative_ne_by!(
+        actual,
+        expected,
+        relative <= [0.01_f64; 2],
+        abs_diff <= [0.0_f64; 2],
+        denominator = RelativeDenominator::Second
+    ));
+}
+
+#[test]
+fn test_assert_relative_eq_by_macro() {
+    let actual = vec![99.0_f64, 198.0_f64];
+    let expected = vec![100.0_f64, 200.0_f64];
+
+    assert_relative_eq_by!(
+        actual,
+        expected,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 0.01_f64,
+        denominator = RelativeDenominator::Second
+    );
+    assert_relative_eq_by!(
+        actual,
+        expected,
+        relative_all <= 0.01_f64,
+        abs_diff_all <= 0.0_f64,
+        denominator = RelativeDenominator::Second,
+        "message {}",
+        1
+    );
+    assert_relative_ne_by!(
+        actual,
+        expected,
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 0.01_f64,
+        denominator = RelativeDenominator::First
+    );
+    assert_relative_ne_by!(
+        actual,
+        expected,
+        relative_all <= 0.01_f64,
+        abs_diff_all <= 0.0_f64,
+        denominator = RelativeDenominator::Min,
+        "message {}",
+        2
+    );
+}
+
+#[test]
+fn test_visit_relative_by() {
+    let lhs = (1.0_f64, 4.0_f64);
+    let rhs = (1.0_f64, 5.0_f64);
+    let abs_diff = (0.0_f64, 0.0_f64);
+    let relative = (0.1_f64, 0.1_f64);
+    let failure = FirstFailure::new(|path, visitor| {
+        AssertRelativeEqBy::visit_relative_by(&lhs, &rhs, &abs_diff, &relative, RelativeDenominator::Second, path, visitor)
+    });
+    let expected = "\
+first failure at `.1`:
+        left: `4.0`,
+       right: `5.0`,
+    abs_diff: `1.0`,
+    relative: `0.2`,
+      branch: `FailedRelative`,
+[abs_diff] t: `0.0`,
+[relative] t: `0.5`,";
+
+    assert_eq!(failure.to_string(), expected);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `relative_eq_by!(left, right, abs_diff <= t, relative <= t, denominator = Second)`\nfirst failure at `[1]`"
+)]
+fn test_assert_relative_eq_by_panic_message() {
+    assert_relative_eq_by!(
+        [99.0_f64, 190.0_f64],
+        [100.0_f64, 200.0_f64],
+        abs_diff <= [0.0_f64; 2],
+        relative <= [0.01_f64; 2],
+        denominator = RelativeDenominator::Second
+    );
+}
+
+#[test]
+#[should_panic(expected = ": custom message")]
+fn test_assert_relative_eq_by_panic_custom_message() {
+    assert_relative_eq_by!(
+        1.0_f32,
+        2.0_f32,
+        abs_diff <= 0.0_f32,
+        relative <= 0.1_f32,
+        denominator = RelativeDenominator::Mean,
+        "custom message"
+    );
+}
@@ -0,0 +1,93 @@
+use relative_cmp::{
+    AssertRelativeEq,
+    assert_relative_eq,
+    assert_relative_ne,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[test]
+fn test_debug_relative_diff_f32() {
+    assert_eq!(4.0_f32.debug_relative_diff(&5.0_f32), 0.2_f32);
+    assert_eq!(5.0_f32.debug_relative_diff(&4.0_f32), 0.2_f32);
+    assert_eq!((-4.0_f32).debug_relative_diff(&-5.0_f32), 0.2_f32);
+    assert_eq!(1.0_f32.debug_relative_diff(&-1.0_f32), 2.0_f32);
+}
+
+#[test]
+fn test_debug_relative_diff_f64() {
+    assert_eq!(4.0_f64.debug_relative_diff(&5.0_f64), 0.2_f64);
+    assert_eq!(1.0_f64.debug_relative_diff(&1.0_f64), 0.0_f64);
+    assert_eq!(0.0_f64.debug_relative_diff(&1.0_f64), 1.0_f64);
+}
+
+#[test]
+fn test_debug_relative_diff_zeros() {
+    assert_eq!(0.0_f64.debug_relative_diff(&0.0_f64), 0.0_f64);
+    assert_eq!(0.0_f64.debug_relative_diff(&-0.0_f64), 0.0_f64);
+}
+
+#[test]
+fn test_debug_relative_diff_infinities() {
+    assert_eq!(f64::INFINITY.debug_relative_diff(&f64::INFINITY), 0.0_f64);
+    assert_eq!(f64::INFINITY.debug_relative_diff(&f64::MAX), f64::INFINITY);
+    assert_eq!(f64::INFINITY.debug_relative_diff(&f64::NEG_INFINITY), f64::INFINITY);
+    assert_eq!(1.0_f32.debug_relative_diff(&f32::NEG_INFINITY), f32::INFINITY);
+}
+
+#[test]
+fn test_debug_relative_diff_nan() {
+    assert!(f64::NAN.debug_relative_diff(&1.0_f64).is_nan());
+    assert!(1.0_f32.debug_relative_diff(&f32::NAN).is_nan());
+}
+
+#[test]
+fn test_debug_relative_diff_containers() {
+    assert_eq!([4.0_f64, 1.0_f64].debug_relative_diff(&[5.0_f64, 1.0_f64]), [0.2_f64, 0.0_f64]);
+    assert_eq!(
+        vec![4.0_f64, 2.0_f64].debug_relative_diff(&vec![5.0_f64, 1.0_f64]),
+        Some(vec![0.2_f64, 0.5_f64])
+    );
+    assert_eq!(vec![4.0_f64].debug_relative_diff(&vec![5.0_f64, 1.0_f64]), None);
+    assert_eq!((4.0_f64, 2.0_f32).debug_relative_diff(&(5.0_f64, 1.0_f32)), (0.2_f64, 0.5_f32));
+    assert_eq!(Some(4.0_f64).debug_relative_diff(&Some(5.0_f64)), Some(0.2_f64));
+    assert_eq!(Some(4.0_f64).debug_relative_diff(&None::<f64>), None);
+    assert_eq!(Box::new(4.0_f64).debug_relative_diff(&Box::new(5.0_f64)), 0.2_f64);
+    assert_eq!(RefCell::new(4.0_f64).debug_relative_diff(&RefCell::new(5.0_f64)), 0.2_f64);
+}
+
+#[test]
+fn test_debug_relative_diff_result() {
+    let lhs: Result<f64, f32> = Ok(4.0_f64);
+    let rhs: Result<f64, f32> = Ok(5.0_f64);
+    let err: Result<f64, f32> = Err(1.0_f32);
+
+    assert_eq!(lhs.debug_relative_diff(&rhs), Some(Ok(0.2_f64)));
+    assert_eq!(lhs.debug_relative_diff(&err), None);
+}
+
+#[test]
+fn test_debug_relative_diff_hash_map() {
+    let lhs = HashMap::from([("a", 4.0_f64), ("b", 1.0_f64)]);
+    let rhs = HashMap::from([("a", 5.0_f64), ("b", 1.0_f64)]);
+    let expected = HashMap::from([("a", 0.2_f64), ("b", 0.0_f64)]);
+
+    assert_eq!(lhs.debug_relative_diff(&rhs), Some(expected));
+}
+
+#[test]
+#[should_panic(expected = "    relative: `0.2`,")]
+fn test_assert_relative_eq_prints_relative_diff() {
+    assert_relative_eq!(4.0_f64, 5.0_f64, abs_diff <= 0.0_f64, relative <= 0.1_f64);
+}
+
+#[test]
+#[should_panic(expected = "    relative: `[0.0, 0.0]`,")]
+fn test_assert_relative_ne_prints_relative_diff() {
+    assert_relative_ne!(
+        [4.0_f64, 5.0_f64],
+        [4.0_f64, 5.0_f64],
+        abs_diff_all <= 0.0_f64,
+        relative_all <= 0.1_f64
+    );
+}
@@ -0,0 +1,122 @@
+use relative_cmp::{
+    AssertRelativeAllEq,
+    AssertRelativeEq,
+    assert_relative_eq,
+    assert_relative_ne,
+};
+
+#[rustfmt::skip]
+#[test]
+fn test_eq_ok() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok([0.0_f32; 4]);
+    let max_relative = Ok([4.0_f32 * f32::EPSILON; 4]);
+
+    assert_relative_eq!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    assert_relative_eq!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+}
+
+#[test]
+fn test_eq_err() {
+    let lhs: Result<[f32; 4], f32> = Err(1.0_f32);
+    let rhs: Result<[f32; 4], f32> = Err(1.0000001_f32);
+    let max_abs_diff = Err(0.0_f32);
+    let max_relative = Err(f32::EPSILON);
+
+    assert_relative_eq!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    assert_relative_eq!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_ne_ok() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok([0.0_f32; 4]);
+    let max_relative = Ok([0.5_f32 * f32::EPSILON; 4]);
+
+    assert_relative_ne!(lhs, rhs, abs_diff <= max_abs_diff, relative <= max_relative);
+    assert_relative_ne!(rhs, lhs, abs_diff <= max_abs_diff, relative <= max_relative);
+}
+
+#[test]
+fn test_ne_ok_err() {
+    let lhs: Result<f32, f32> = Ok(1.0_f32);
+    let rhs: Result<f32, f32> = Err(1.0_f32);
+
+    assert_relative_ne!(lhs, rhs, abs_diff <= Ok(1.0_f32), relative <= Ok(1.0_f32));
+    assert_relative_ne!(rhs, lhs, abs_diff <= Err(1.0_f32), relative <= Err(1.0_f32));
+}
+
+#[test]
+fn test_ne_tolerance_mismatch() {
+    let lhs: Result<f32, f32> = Ok(1.0_f32);
+    let rhs: Result<f32, f32> = Ok(1.0_f32);
+
+    assert_relative_ne!(lhs, rhs, abs_diff <= Ok(1.0_f32), relative <= Err(1.0_f32));
+    assert_relative_ne!(rhs, lhs, abs_diff <= Err(1.0_f32), relative <= Ok(1.0_f32));
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_all_eq() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok(0.0_f32);
+    let max_relative = Ok(4.0_f32 * f32::EPSILON);
+
+    assert_relative_eq!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    assert_relative_eq!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_all_ne() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok(0.0_f32);
+    let max_relative = Ok(0.5_f32 * f32::EPSILON);
+
+    assert_relative_ne!(lhs, rhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+    assert_relative_ne!(rhs, lhs, abs_diff_all <= max_abs_diff, relative_all <= max_relative);
+}
+
+#[test]
+fn test_debug_abs_diff() {
+    let lhs: Result<[f32; 4], f32> = Ok([1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.50_f32, 1.00_f32, 2.00_f32, 1.75_f32]);
+    let abs_diff = Some(Ok([0.50_f32, 0.25_f32, 0.50_f32, 0.25_f32]));
+
+    assert_eq!(lhs.debug_abs_diff(&rhs), abs_diff);
+    assert_eq!(rhs.debug_abs_diff(&lhs), abs_diff);
+    assert_eq!(lhs.debug_abs_diff(&Err::<[f32; 4], f32>(1.00_f32)), None);
+}
+
+#[test]
+fn test_debug_relative_tolerance() {
+    let lhs: Result<[f32; 4], f32> = Ok([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
+    let max_relative = Ok([0.20_f32, 0.30_f32, 0.40_f32, 0.50_f32]);
+    let tolerance = Some(Ok([
+        0.20_f32 * 2.50_f32,
+        0.30_f32 * 3.25_f32,
+        0.40_f32 * 4.50_f32,
+        0.50_f32 * 6.00_f32,
+    ]));
+
+    assert_eq!(lhs.debug_relative_tolerance(&rhs, &max_relative), tolerance);
+    assert_eq!(rhs.debug_relative_tolerance(&lhs, &max_relative), tolerance);
+    assert_eq!(lhs.debug_relative_tolerance(&rhs, &Err(0.20_f32)), None);
+}
+
+#[test]
+fn test_debug_relative_all_tolerance() {
+    let lhs: Result<[f32; 4], f32> = Err(2.00_f32);
+    let rhs: Result<[f32; 4], f32> = Err(2.50_f32);
+    let tolerance = Some(Err(0.20_f32 * 2.50_f32));
+
+    assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &Err(0.20_f32)), tolerance);
+    assert_eq!(rhs.debug_relative_all_tolerance(&lhs, &Err(0.20_f32)), tolerance);
+    assert_eq!(lhs.debug_abs_diff_all_tolerance(&rhs, &Ok(0.20_f32)), None);
+}
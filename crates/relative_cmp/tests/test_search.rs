@@ -0,0 +1,61 @@
+use relative_cmp::{
+    RelativeSearch,
+    SearchMatch,
+};
+
+#[test]
+fn test_relative_binary_search() {
+    let samples = [1.0_f64, 10.0_f64, 100.0_f64, 1000.0_f64];
+
+    assert_eq!(
+        samples.relative_binary_search(&101.0_f64, &0.0_f64, &0.02_f64),
+        SearchMatch::Found(2)
+    );
+    assert_eq!(
+        samples.relative_binary_search(&1010.0_f64, &0.0_f64, &0.02_f64),
+        SearchMatch::Found(3)
+    );
+    assert_eq!(
+        samples.relative_binary_search(&50.0_f64, &0.0_f64, &0.02_f64),
+        SearchMatch::NotFound
+    );
+    assert_eq!(
+        samples.relative_binary_search(&f64::NAN, &f64::INFINITY, &f64::INFINITY),
+        SearchMatch::NotFound
+    );
+}
+
+#[test]
+fn test_relative_binary_search_ambiguous() {
+    let samples = Vec::from([100.0_f32, 101.0_f32, 102.0_f32, 200.0_f32]);
+
+    assert_eq!(
+        samples.relative_binary_search(&101.0_f32, &0.0_f32, &0.02_f32),
+        SearchMatch::Ambiguous {
+            first: 0,
+            last: 2,
+            count: 3
+        }
+    );
+    assert_eq!(
+        samples.relative_binary_search(&101.0_f32, &0.0_f32, &0.001_f32),
+        SearchMatch::Found(1)
+    );
+}
+
+#[test]
+fn test_relative_position_and_contains() {
+    let samples = [200.0_f64, 101.0_f64, 1.0_f64, 100.0_f64];
+
+    assert_eq!(samples.relative_position(&1.0_f64, &0.0_f64, &f64::EPSILON), SearchMatch::Found(2));
+    assert_eq!(
+        samples.relative_position(&100.5_f64, &0.0_f64, &0.01_f64),
+        SearchMatch::Ambiguous {
+            first: 1,
+            last: 3,
+            count: 2
+        }
+    );
+    assert!(samples.relative_contains(&100.5_f64, &0.0_f64, &0.01_f64));
+    assert!(!samples.relative_contains(&150.0_f64, &0.0_f64, &0.01_f64));
+}
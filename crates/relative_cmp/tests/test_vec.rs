@@ -286,7 +286,7 @@ fn test_ne_empty4() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = Vec::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -308,7 +308,7 @@ fn test_ne_empty5() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = Vec::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -327,7 +327,7 @@ fn test_ne_empty6() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = Vec::new();
     let max_relative = Vec::from([
@@ -346,7 +346,7 @@ fn test_ne_empty7() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = Vec::new();
     let max_relative = Vec::new();
 
@@ -503,7 +503,7 @@ fn test_all_ne_empty1() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = 2.0_f32 * f32::EPSILON;
     let max_relative = 1.0_f32 * f32::EPSILON;
 
@@ -533,7 +533,7 @@ fn test_debug_abs_diff_empty1() {
         1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32,
         2.50_f32, 3.00_f32, 4.00_f32, 5.00_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -542,7 +542,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = Vec::new();
+    let lhs = Vec::<f32>::new();
     let rhs = Vec::from([
         1.0000000_f32, 1.9999500_f32, 3.0000000_f32, 4.0000005_f32,
         5.0000000_f32, 6.0000000_f32, 7.0000000_f32, 8.0000000_f32,
@@ -555,7 +555,7 @@ fn test_debug_abs_diff_empty2() {
 #[test]
 fn test_debug_abs_diff_tolerance_empty() {
     let lhs = Vec::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = Vec::from([0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32]);
 
     assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), None);
@@ -574,7 +574,7 @@ fn test_debug_abs_diff_all_tolerance_empty() {
 
 #[test]
 fn test_debug_relative_tolerance_empty() {
-    let lhs = Vec::new();
+    let lhs = Vec::<f32>::new();
     let rhs = Vec::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
     let max_relative = Vec::from([0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32]);
 
@@ -585,7 +585,7 @@ fn test_debug_relative_tolerance_empty() {
 #[test]
 fn test_debug_relative_all_tolerance_empty() {
     let lhs = Vec::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_relative = 0.20_f32;
 
     assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &max_relative), None);
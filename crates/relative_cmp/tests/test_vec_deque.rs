@@ -297,7 +297,7 @@ fn test_ne_empty4() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = VecDeque::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -319,7 +319,7 @@ fn test_ne_empty5() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = VecDeque::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -338,7 +338,7 @@ fn test_ne_empty6() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = VecDeque::new();
     let max_relative = VecDeque::from([
@@ -357,7 +357,7 @@ fn test_ne_empty7() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let max_abs_diff = VecDeque::new();
     let max_relative = VecDeque::new();
 
@@ -515,7 +515,7 @@ fn test_all_ne_empty1() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let max_abs_diff = 2.0_f32 * f32::EPSILON;
     let max_relative = 1.0_f32 * f32::EPSILON;
 
@@ -545,7 +545,7 @@ fn test_debug_abs_diff_empty1() {
         1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32,
         2.50_f32, 3.00_f32, 4.00_f32, 5.00_f32,
     ]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -554,7 +554,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = VecDeque::new();
+    let lhs = VecDeque::<f32>::new();
     let rhs = VecDeque::from([
         1.0000000_f32, 1.9999500_f32, 3.0000000_f32, 4.0000005_f32,
         5.0000000_f32, 6.0000000_f32, 7.0000000_f32, 8.0000000_f32,
@@ -567,7 +567,7 @@ fn test_debug_abs_diff_empty2() {
 #[test]
 fn test_debug_abs_diff_tolerance_empty() {
     let lhs = VecDeque::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let max_abs_diff = VecDeque::from([0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32]);
 
     assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), None);
@@ -586,7 +586,7 @@ fn test_debug_abs_diff_all_tolerance_empty() {
 
 #[test]
 fn test_debug_relative_tolerance_empty() {
-    let lhs = VecDeque::new();
+    let lhs = VecDeque::<f32>::new();
     let rhs = VecDeque::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
     let max_relative = VecDeque::from([0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32]);
 
@@ -597,7 +597,7 @@ fn test_debug_relative_tolerance_empty() {
 #[test]
 fn test_debug_relative_all_tolerance_empty() {
     let lhs = VecDeque::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = VecDeque::new();
+    let rhs = VecDeque::<f32>::new();
     let max_relative = 0.20_f32;
 
     assert_eq!(lhs.debug_relative_all_tolerance(&rhs, &max_relative), None);
@@ -0,0 +1,324 @@
+use crate::ulps;
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::fmt;
+use core::mem;
+use core::ops;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::vec::Vec;
+
+/// A data type for comparing floating point numbers bit for bit.
+///
+/// Two floating point numbers are bitwise equal when their bit patterns, as
+/// returned by `to_bits`, are equal. Unlike every other comparison, bitwise
+/// equality distinguishes the zeros of different signs, and accepts two NaNs
+/// with the same sign and payload, which makes it the comparison for
+/// determinism tests, e.g. checking that a computation reproduces its results
+/// exactly across runs or platforms.
+///
+/// The comparison covers the same containers as
+/// [`UlpsErrorEq`](crate::UlpsErrorEq), i.e. references, arrays, slices, and
+/// vectors. Failed assertions report the bit patterns of the failing elements
+/// in hexadecimal, labeled `left_bits` and `right_bits`, along with their ulps
+/// distance.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::BitwiseEq;
+/// #
+/// let nan = f64::from_bits(0x7ff8_0000_0000_0001);
+///
+/// assert!(nan.bitwise_eq(&nan));
+/// assert!(nan.bitwise_ne(&f64::NAN));
+/// assert!(0.0_f64.bitwise_ne(&-0.0_f64));
+/// assert!([1.0_f32, -0.0_f32].bitwise_eq(&[1.0_f32, -0.0_f32]));
+/// ```
+pub trait BitwiseEq<Rhs: ?Sized = Self> {
+    /// Compare `self` and `other` for bitwise equality.
+    ///
+    /// Returns `true` when every pair of elements has the same bit pattern.
+    fn bitwise_eq(&self, other: &Rhs) -> bool;
+
+    /// Compare `self` and `other` for bitwise inequality.
+    ///
+    /// Returns `true` when a pair of elements has different bit patterns.
+    #[inline]
+    fn bitwise_ne(&self, other: &Rhs) -> bool {
+        !Self::bitwise_eq(self, other)
+    }
+}
+
+/// A trait for computing debugging information when a bitwise comparison
+/// fails.
+///
+/// This trait is the debugging counterpart of [`BitwiseEq`].
+pub trait AssertBitwiseEq<Rhs: ?Sized = Self>: BitwiseEq<Rhs> {
+    /// Visit the elements of `self` and `other`, reporting the bit patterns and
+    /// the ulps distance of each pair of elements to `visitor`.
+    ///
+    /// The default implementation reports `self` and `other` as a single
+    /// element without values.
+    #[inline]
+    fn visit_bitwise<'a>(&'a self, other: &Rhs, path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+        visitor.visit(path, &Element::new(BitwiseEq::bitwise_eq(self, other), &[], &[]))
+    }
+}
+
+// A bit pattern formatted in hexadecimal, padded to the width of its type.
+struct HexBits<T>(T);
+
+impl<T> fmt::Debug for HexBits<T>
+where
+    T: fmt::LowerHex,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{:#0width$x}", self.0, width = 2 + 2 * mem::size_of::<T>())
+    }
+}
+
+macro_rules! impl_bitwise_eq_float {
+    ($($T:ident),* $(,)?) => {$(
+        impl BitwiseEq for $T {
+            #[inline]
+            fn bitwise_eq(&self, other: &$T) -> bool {
+                self.to_bits() == other.to_bits()
+            }
+        }
+
+        impl AssertBitwiseEq for $T {
+            #[inline]
+            fn visit_bitwise<'a>(&'a self, other: &$T, path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+                let left_bits = HexBits(self.to_bits());
+                let right_bits = HexBits(other.to_bits());
+                let ulps_diff = ulps::ulps_distance(*self, *other);
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        BitwiseEq::bitwise_eq(self, other),
+                        &[("left_bits", &left_bits), ("right_bits", &right_bits), ("ulps", &ulps_diff)],
+                        &[],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_bitwise_eq_float!(f32, f64);
+
+impl<A, B> BitwiseEq<&B> for &A
+where
+    A: BitwiseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn bitwise_eq(&self, other: &&B) -> bool {
+        BitwiseEq::bitwise_eq(*self, *other)
+    }
+}
+
+impl<A, B> AssertBitwiseEq<&B> for &A
+where
+    A: AssertBitwiseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_bitwise<'a>(&'a self, other: &&B, path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+        AssertBitwiseEq::visit_bitwise(*self, *other, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> BitwiseEq<[B; N]> for [A; N]
+where
+    A: BitwiseEq<B>,
+{
+    #[inline]
+    fn bitwise_eq(&self, other: &[B; N]) -> bool {
+        BitwiseEq::bitwise_eq(&self[..], &other[..])
+    }
+}
+
+impl<A, B, const N: usize> AssertBitwiseEq<[B; N]> for [A; N]
+where
+    A: AssertBitwiseEq<B>,
+{
+    #[inline]
+    fn visit_bitwise<'a>(&'a self, other: &[B; N], path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+        AssertBitwiseEq::visit_bitwise(&self[..], &other[..], path, visitor)
+    }
+}
+
+impl<A, B> BitwiseEq<[B]> for [A]
+where
+    A: BitwiseEq<B>,
+{
+    #[inline]
+    fn bitwise_eq(&self, other: &[B]) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| BitwiseEq::bitwise_eq(a, b))
+    }
+}
+
+impl<A, B> AssertBitwiseEq<[B]> for [A]
+where
+    A: AssertBitwiseEq<B>,
+{
+    #[inline]
+    fn visit_bitwise<'a>(&'a self, other: &[B], path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| AssertBitwiseEq::visit_bitwise(a, b, path, visitor))?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> BitwiseEq<Vec<B>> for Vec<A>
+where
+    A: BitwiseEq<B>,
+{
+    #[inline]
+    fn bitwise_eq(&self, other: &Vec<B>) -> bool {
+        BitwiseEq::bitwise_eq(self.as_slice(), other.as_slice())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertBitwiseEq<Vec<B>> for Vec<A>
+where
+    A: AssertBitwiseEq<B>,
+{
+    #[inline]
+    fn visit_bitwise<'a>(&'a self, other: &Vec<B>, path: &mut Path<'a>, visitor: &mut dyn Visitor<'a>) -> ops::ControlFlow<()> {
+        AssertBitwiseEq::visit_bitwise(self.as_slice(), other.as_slice(), path, visitor)
+    }
+}
+
+/// Compare two floating point expressions for bitwise equality.
+///
+/// For more details, see the documentation for [`BitwiseEq`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::bitwise_eq;
+/// #
+/// let first_run = vec![0.1_f64 + 0.2_f64, -0.0_f64];
+/// let second_run = vec![0.1_f64 + 0.2_f64, -0.0_f64];
+///
+/// assert!(bitwise_eq!(first_run, second_run));
+/// ```
+#[macro_export]
+macro_rules! bitwise_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        match (&$left, &$right) {
+            (left_val, right_val) => $crate::BitwiseEq::bitwise_eq(left_val, right_val),
+        }
+    }};
+}
+
+/// Compare two floating point expressions for bitwise inequality.
+///
+/// For more details, see the documentation for [`BitwiseEq`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::bitwise_ne;
+/// #
+/// assert!(bitwise_ne!([0.0_f32], [-0.0_f32]));
+/// ```
+#[macro_export]
+macro_rules! bitwise_ne {
+    ($left:expr, $right:expr $(,)?) => {{
+        match (&$left, &$right) {
+            (left_val, right_val) => $crate::BitwiseEq::bitwise_ne(left_val, right_val),
+        }
+    }};
+}
+
+/// Assert that two floating point expressions are bitwise equal.
+///
+/// See the documentation for [`BitwiseEq`] for details about bitwise
+/// comparisons. See the documentation for [`AssertBitwiseEq`] for details about
+/// the debugging context provided when an assertion fails.
+///
+/// A failed assertion prints the path, the values, and the bit patterns in
+/// hexadecimal of its first failing element, along with the ulps distance of
+/// the values, followed by the number of failing elements and a list of the
+/// failing elements.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_bitwise_eq;
+/// #
+/// let nan = f32::from_bits(0x7fc0_0001);
+///
+/// assert_bitwise_eq!([1.0_f32, nan], [1.0_f32, nan]);
+/// ```
+#[macro_export]
+macro_rules! assert_bitwise_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::BitwiseEq::bitwise_eq(&*left_val, &*right_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!("assertion failed: `bitwise_eq!(left, right)`", "\n{}\n{}"),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertBitwiseEq::visit_bitwise(&*left_val, &*right_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertBitwiseEq::visit_bitwise(&*left_val, &*right_val, path, visitor)
+                        }),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $($arg:tt)+) => {{
+        match (&$left, &$right) {
+            (left_val, right_val) => {
+                if !$crate::BitwiseEq::bitwise_eq(&*left_val, &*right_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!("assertion failed: `bitwise_eq!(left, right)`", "\n{}\n{}: {}"),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertBitwiseEq::visit_bitwise(&*left_val, &*right_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertBitwiseEq::visit_bitwise(&*left_val, &*right_val, path, visitor)
+                        }),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
@@ -0,0 +1,88 @@
+use crate::traits::UlpsEq;
+use crate::ulps;
+use approx_cmp_float::Float;
+
+/// The branch of a units in last place comparison of two floating point numbers
+/// that decided its outcome.
+///
+/// The ulps comparison of two floating point numbers passes or fails at the first
+/// branch that applies to them, in the order of the variants below. The ulps
+/// distance counts the representable numbers between the compared numbers on the
+/// number line, including across zero, so two numbers with opposite signs are as
+/// many ulps apart as the sum of their distances to zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UlpsExplanation {
+    /// At least one of the compared numbers is NaN, so the comparison fails.
+    FailedNan,
+    /// The absolute difference of the compared numbers is within the absolute
+    /// difference tolerance, so the comparison passes.
+    PassedAbsDiff,
+    /// The ulps distance between the compared numbers is within the ulps
+    /// tolerance, so the comparison passes.
+    PassedUlps {
+        /// The ulps distance between the compared numbers.
+        distance: u64,
+    },
+    /// The ulps distance between the compared numbers exceeds the ulps
+    /// tolerance, so the comparison fails.
+    FailedUlps {
+        /// The ulps distance between the compared numbers.
+        distance: u64,
+    },
+}
+
+impl UlpsExplanation {
+    /// Determine whether the explained comparison passed.
+    #[inline]
+    pub const fn passed(&self) -> bool {
+        matches!(self, Self::PassedAbsDiff | Self::PassedUlps { .. })
+    }
+}
+
+/// Explain the outcome of a units in last place comparison of two floating
+/// point numbers.
+///
+/// Every [`Float`] type with an ulps comparison using its own type as the
+/// absolute difference tolerance and its bit pattern type as the ulps tolerance
+/// implements this trait, with the explanations of [`ulps::explain_ulps`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     UlpsEq,
+/// #     UlpsExplain,
+/// #     UlpsExplanation,
+/// # };
+/// #
+/// let lhs = 1e-30_f64;
+/// let rhs = -1e-30_f64;
+///
+/// assert!(lhs.ulps_ne(&rhs, &0.0_f64, &4_u64));
+/// assert_eq!(
+///     lhs.explain_ulps(&rhs, &0.0_f64, &4_u64),
+///     UlpsExplanation::FailedUlps { distance: 2 * lhs.to_bits() }
+/// );
+/// assert_eq!(lhs.explain_ulps(&rhs, &1e-29_f64, &4_u64), UlpsExplanation::PassedAbsDiff);
+///
+/// let lhs = 1.0_f64;
+/// let rhs = f64::from_bits(lhs.to_bits() + 3);
+///
+/// assert_eq!(lhs.explain_ulps(&rhs, &0.0_f64, &4_u64), UlpsExplanation::PassedUlps { distance: 3 });
+/// assert_eq!(lhs.explain_ulps(&rhs, &0.0_f64, &2_u64), UlpsExplanation::FailedUlps { distance: 3 });
+/// ```
+pub trait UlpsExplain: UlpsEq {
+    /// Explain the outcome of comparing `self` and `other` with
+    /// [`UlpsEq::ulps_eq`], using the same tolerances.
+    fn explain_ulps(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> UlpsExplanation;
+}
+
+impl<T> UlpsExplain for T
+where
+    T: Float + UlpsEq<Tolerance = T, UlpsTolerance = <T as Float>::Bits>,
+{
+    #[inline]
+    fn explain_ulps(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> UlpsExplanation {
+        ulps::explain_ulps(*self, *other, *max_abs_diff, *max_ulps)
+    }
+}
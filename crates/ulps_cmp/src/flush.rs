@@ -0,0 +1,920 @@
+use crate::traits::AssertUlpsEq;
+use crate::ulps;
+use approx_cmp_float::Float;
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Entry,
+    Flushed,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
+
+use core::ops;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::fmt;
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::collections::{
+    BTreeMap,
+    LinkedList,
+    VecDeque,
+};
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::vec::Vec;
+
+#[cfg(feature = "std")]
+use core::hash;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A data type for comparing results computed with denormals flushed to zero
+/// against results computed with subnormal numbers.
+///
+/// Arithmetic with denormals flushed to zero, e.g. the SIMD units of most
+/// processors in their fast modes, replaces every subnormal result with zero.
+/// The ulps distance between a subnormal number and zero is the number of
+/// subnormal numbers between them, which is large for numbers that are
+/// physically identical. A flush-to-zero comparison replaces the subnormal
+/// numbers on either side with the zero of the same sign, and then compares
+/// with the semantics of [`UlpsEq`](crate::UlpsEq). Both zeros are zero ulps
+/// apart.
+///
+/// Like [`UlpsAllEq`](crate::UlpsAllEq), the comparison uses a single pair of
+/// tolerances for every element of a container. The [`ulps_flushed`] method
+/// counts the numbers the comparison flushes, and failed assertions report the
+/// elements it flushed, labeled `flushed`.
+///
+/// [`ulps_flushed`]: UlpsEqFtz::ulps_flushed
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     UlpsAllEq,
+/// #     UlpsEqFtz,
+/// # };
+/// #
+/// let reference = [1.0_f32, 1e-40_f32, -2e-39_f32];
+/// let flushed = [1.0_f32, 0.0_f32, -0.0_f32];
+///
+/// assert!(flushed.ulps_all_ne(&reference, &0.0_f32, &4_u32));
+/// assert!(flushed.ulps_eq_ftz(&reference, &0.0_f32, &4_u32));
+/// assert_eq!(flushed.ulps_flushed(&reference), 2);
+/// ```
+pub trait UlpsEqFtz<Rhs: ?Sized = Self> {
+    /// The data type representing the maximum allowed absolute difference
+    /// between two numbers after flushing.
+    type Tolerance: ?Sized;
+
+    /// The data type representing the maximum allowed ulps difference between
+    /// two numbers after flushing.
+    type UlpsTolerance: ?Sized;
+
+    /// Compare `self` and `other` for ulps equality, with the subnormal numbers
+    /// of both flushed to zero.
+    ///
+    /// Returns `true` when every pair of elements, after flushing, is within
+    /// `max_abs_diff` or within `max_ulps`.
+    fn ulps_eq_ftz(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool;
+
+    /// Compare `self` and `other` for ulps inequality, with the subnormal
+    /// numbers of both flushed to zero.
+    ///
+    /// Returns `true` when a pair of elements, after flushing, is neither within
+    /// `max_abs_diff` nor within `max_ulps`.
+    #[inline]
+    fn ulps_ne_ftz(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        !Self::ulps_eq_ftz(self, other, max_abs_diff, max_ulps)
+    }
+
+    /// Count the subnormal numbers of `self` and `other` that a flush-to-zero
+    /// comparison flushes, i.e. zero when the normalization changes nothing.
+    fn ulps_flushed(&self, other: &Rhs) -> usize;
+}
+
+/// A trait for computing debugging information when a flush-to-zero ulps
+/// comparison fails.
+///
+/// This trait is the debugging counterpart of [`UlpsEqFtz`], in the same way
+/// [`AssertUlpsAllEq`](crate::AssertUlpsAllEq) is the debugging counterpart of
+/// [`UlpsAllEq`](crate::UlpsAllEq).
+pub trait AssertUlpsEqFtz<Rhs: ?Sized = Self>: UlpsEqFtz<Rhs> {
+    /// Visit the elements of `self` and `other`, reporting the differences of
+    /// each pair of elements after flushing, the sides flushed to zero, and the
+    /// tolerances to `visitor`.
+    ///
+    /// The default implementation reports `self` and `other` as a single
+    /// element without values or tolerances.
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        visitor.visit(
+            path,
+            &Element::new(UlpsEqFtz::ulps_eq_ftz(self, other, max_abs_diff, max_ulps), &[], &[]),
+        )
+    }
+}
+
+// The flushed numbers are widened to the precision the tolerances are given in,
+// which is exact, so that mixed precision pairs compare like `UlpsEq` does.
+macro_rules! impl_ulps_eq_ftz_float {
+    ($($T:ident, $Rhs:ident => $E:ident),* $(,)?) => {$(
+        impl UlpsEqFtz<$Rhs> for $T {
+            type Tolerance = $E;
+            type UlpsTolerance = <$E as Float>::Bits;
+
+            #[inline]
+            fn ulps_eq_ftz(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+                let lhs = $E::from(Float::flush_to_zero(*self));
+                let rhs = $E::from(Float::flush_to_zero(*other));
+
+                ulps::ulps_eq(lhs, rhs, *max_abs_diff, *max_ulps)
+            }
+
+            #[inline]
+            fn ulps_flushed(&self, other: &$Rhs) -> usize {
+                Flushed::new(Float::is_subnormal(*self), Float::is_subnormal(*other)).map_or(0, Flushed::count)
+            }
+        }
+
+        impl AssertUlpsEqFtz<$Rhs> for $T {
+            #[inline]
+            fn visit_ulps_ftz<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::Tolerance,
+                max_ulps: &Self::UlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let lhs = $E::from(Float::flush_to_zero(*self));
+                let rhs = $E::from(Float::flush_to_zero(*other));
+                let branch = ulps::explain_ulps(lhs, rhs, *max_abs_diff, *max_ulps);
+                let abs_diff = AssertUlpsEq::debug_abs_diff(&lhs, &rhs);
+                let ulps_diff = AssertUlpsEq::debug_ulps_diff(&lhs, &rhs);
+                // The flushed sides are reported only when a side was flushed.
+                let (flushed, len) = match Flushed::new(Float::is_subnormal(*self), Float::is_subnormal(*other)) {
+                    Some(flushed) => (flushed, 4),
+                    None => (Flushed::Both, 3),
+                };
+                let diffs: [Entry<'_>; 4] = [
+                    ("abs_diff", &abs_diff),
+                    ("ulps", &ulps_diff),
+                    ("branch", &branch),
+                    ("flushed", &flushed),
+                ];
+
+                visitor.visit(
+                    path,
+                    &Element::new(branch.passed(), &diffs[..len], &[("abs_diff", max_abs_diff), ("ulps", max_ulps)])
+                        .with_values(self, other)
+                        .with_errors(ElementErrors::new(&lhs, &rhs)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_ulps_eq_ftz_float!(f32, f32 => f32, f64, f64 => f64, f32, f64 => f64, f64, f32 => f64);
+
+impl<A, B> UlpsEqFtz<&B> for &A
+where
+    A: UlpsEqFtz<B> + ?Sized,
+    B: ?Sized,
+{
+    type Tolerance = A::Tolerance;
+    type UlpsTolerance = A::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &&B, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        UlpsEqFtz::ulps_eq_ftz(*self, *other, max_abs_diff, max_ulps)
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &&B) -> usize {
+        UlpsEqFtz::ulps_flushed(*self, *other)
+    }
+}
+
+impl<A, B> AssertUlpsEqFtz<&B> for &A
+where
+    A: AssertUlpsEqFtz<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEqFtz::visit_ulps_ftz(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> UlpsEqFtz<[B; N]> for [A; N]
+where
+    A: UlpsEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+    type UlpsTolerance = A::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &[B; N], max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        UlpsEqFtz::ulps_eq_ftz(&self[..], &other[..], max_abs_diff, max_ulps)
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &[B; N]) -> usize {
+        UlpsEqFtz::ulps_flushed(&self[..], &other[..])
+    }
+}
+
+impl<A, B, const N: usize> AssertUlpsEqFtz<[B; N]> for [A; N]
+where
+    A: AssertUlpsEqFtz<B>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEqFtz::visit_ulps_ftz(&self[..], &other[..], max_abs_diff, max_ulps, path, visitor)
+    }
+}
+
+impl<A, B> UlpsEqFtz<[B]> for [A]
+where
+    A: UlpsEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+    type UlpsTolerance = A::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &[B], max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps))
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &[B]) -> usize {
+        self.iter().zip(other.iter()).map(|(a, b)| UlpsEqFtz::ulps_flushed(a, b)).sum()
+    }
+}
+
+impl<A, B> AssertUlpsEqFtz<[B]> for [A]
+where
+    A: AssertUlpsEqFtz<B>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> UlpsEqFtz<Vec<B>> for Vec<A>
+where
+    A: UlpsEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+    type UlpsTolerance = A::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &Vec<B>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        UlpsEqFtz::ulps_eq_ftz(self.as_slice(), other.as_slice(), max_abs_diff, max_ulps)
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &Vec<B>) -> usize {
+        UlpsEqFtz::ulps_flushed(self.as_slice(), other.as_slice())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertUlpsEqFtz<Vec<B>> for Vec<A>
+where
+    A: AssertUlpsEqFtz<B>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEqFtz::visit_ulps_ftz(self.as_slice(), other.as_slice(), max_abs_diff, max_ulps, path, visitor)
+    }
+}
+
+// Expand to the element type of a tuple once per index.
+macro_rules! ftz_tuple_element {
+    ($idx:tt, $T:ident) => {
+        $T
+    };
+}
+
+macro_rules! impl_ulps_eq_ftz_tuple {
+    ($(($($idx:tt),+))+) => {$(
+        impl<A, B> UlpsEqFtz<($(ftz_tuple_element!($idx, B),)+)> for ($(ftz_tuple_element!($idx, A),)+)
+        where
+            A: UlpsEqFtz<B>,
+        {
+            type Tolerance = A::Tolerance;
+            type UlpsTolerance = A::UlpsTolerance;
+
+            #[inline]
+            fn ulps_eq_ftz(&self, other: &($(ftz_tuple_element!($idx, B),)+), max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+                $(UlpsEqFtz::ulps_eq_ftz(&self.$idx, &other.$idx, max_abs_diff, max_ulps))&&+
+            }
+
+            #[inline]
+            fn ulps_flushed(&self, other: &($(ftz_tuple_element!($idx, B),)+)) -> usize {
+                0 $(+ UlpsEqFtz::ulps_flushed(&self.$idx, &other.$idx))+
+            }
+        }
+
+        impl<A, B> AssertUlpsEqFtz<($(ftz_tuple_element!($idx, B),)+)> for ($(ftz_tuple_element!($idx, A),)+)
+        where
+            A: AssertUlpsEqFtz<B>,
+        {
+            #[inline]
+            fn visit_ulps_ftz<'a>(
+                &'a self,
+                other: &($(ftz_tuple_element!($idx, B),)+),
+                max_abs_diff: &Self::Tolerance,
+                max_ulps: &Self::UlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                $(
+                    path.with_segment(PathSegment::Field(stringify!($idx)), |path| {
+                        AssertUlpsEqFtz::visit_ulps_ftz(&self.$idx, &other.$idx, max_abs_diff, max_ulps, path, visitor)
+                    })?;
+                )+
+
+                ops::ControlFlow::Continue(())
+            }
+        }
+    )+};
+}
+
+impl_ulps_eq_ftz_tuple! {
+    (0)
+    (0, 1)
+    (0, 1, 2)
+    (0, 1, 2, 3)
+    (0, 1, 2, 3, 4)
+    (0, 1, 2, 3, 4, 5)
+    (0, 1, 2, 3, 4, 5, 6)
+    (0, 1, 2, 3, 4, 5, 6, 7)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10)
+    (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+}
+
+// The tolerances of an `Option` or a `Result` select the variant they apply to,
+// like the tolerances of the `all` comparisons do.
+impl<A, B> UlpsEqFtz<Option<B>> for Option<A>
+where
+    A: UlpsEqFtz<B>,
+    A::Tolerance: Sized,
+    A::UlpsTolerance: Sized,
+{
+    type Tolerance = Option<A::Tolerance>;
+    type UlpsTolerance = Option<A::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &Option<B>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        if let (Some(a), Some(b), Some(max_abs_diff), Some(max_ulps)) = (self, other, max_abs_diff, max_ulps) {
+            UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps)
+        } else {
+            false
+        }
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &Option<B>) -> usize {
+        if let (Some(a), Some(b)) = (self, other) {
+            UlpsEqFtz::ulps_flushed(a, b)
+        } else {
+            0
+        }
+    }
+}
+
+impl<A, B> AssertUlpsEqFtz<Option<B>> for Option<A>
+where
+    A: AssertUlpsEqFtz<B>,
+    A::Tolerance: Sized,
+    A::UlpsTolerance: Sized,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_ulps)) => {
+                AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> UlpsEqFtz<Result<B, F>> for Result<A, E>
+where
+    A: UlpsEqFtz<B>,
+    E: UlpsEqFtz<F>,
+    A::Tolerance: Sized,
+    A::UlpsTolerance: Sized,
+    E::Tolerance: Sized,
+    E::UlpsTolerance: Sized,
+{
+    type Tolerance = Result<A::Tolerance, E::Tolerance>;
+    type UlpsTolerance = Result<A::UlpsTolerance, E::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_ulps)) => UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_ulps)) => UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &Result<B, F>) -> usize {
+        match (self, other) {
+            (Ok(a), Ok(b)) => UlpsEqFtz::ulps_flushed(a, b),
+            (Err(a), Err(b)) => UlpsEqFtz::ulps_flushed(a, b),
+            _ => 0,
+        }
+    }
+}
+
+impl<A, B, E, F> AssertUlpsEqFtz<Result<B, F>> for Result<A, E>
+where
+    A: AssertUlpsEqFtz<B>,
+    E: AssertUlpsEqFtz<F>,
+    A::Tolerance: Sized,
+    A::UlpsTolerance: Sized,
+    E::Tolerance: Sized,
+    E::UlpsTolerance: Sized,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_ulps)) => AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_ulps)) => {
+                AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> UlpsEqFtz<VecDeque<B>> for VecDeque<A>
+where
+    A: UlpsEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+    type UlpsTolerance = A::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &VecDeque<B>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps))
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &VecDeque<B>) -> usize {
+        self.iter().zip(other.iter()).map(|(a, b)| UlpsEqFtz::ulps_flushed(a, b)).sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertUlpsEqFtz<VecDeque<B>> for VecDeque<A>
+where
+    A: AssertUlpsEqFtz<B>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> UlpsEqFtz<LinkedList<B>> for LinkedList<A>
+where
+    A: UlpsEqFtz<B>,
+{
+    type Tolerance = A::Tolerance;
+    type UlpsTolerance = A::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &LinkedList<B>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps))
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &LinkedList<B>) -> usize {
+        self.iter().zip(other.iter()).map(|(a, b)| UlpsEqFtz::ulps_flushed(a, b)).sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertUlpsEqFtz<LinkedList<B>> for LinkedList<A>
+where
+    A: AssertUlpsEqFtz<B>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K, VA, VB> UlpsEqFtz<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord,
+    VA: UlpsEqFtz<VB>,
+{
+    type Tolerance = VA::Tolerance;
+    type UlpsTolerance = VA::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &BTreeMap<K, VB>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps)
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &BTreeMap<K, VB>) -> usize {
+        self.iter()
+            .filter_map(|(key, a)| other.get(key).map(|b| UlpsEqFtz::ulps_flushed(a, b)))
+            .sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<K, VA, VB> AssertUlpsEqFtz<BTreeMap<K, VB>> for BTreeMap<K, VA>
+where
+    K: Eq + Ord + fmt::Debug,
+    VA: AssertUlpsEqFtz<VB>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, VA, VB, S> UlpsEqFtz<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash,
+    S: hash::BuildHasher,
+    VA: UlpsEqFtz<VB>,
+{
+    type Tolerance = VA::Tolerance;
+    type UlpsTolerance = VA::UlpsTolerance;
+
+    #[inline]
+    fn ulps_eq_ftz(&self, other: &HashMap<K, VB, S>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, a)| {
+                if let Some(b) = other.get(key) {
+                    UlpsEqFtz::ulps_eq_ftz(a, b, max_abs_diff, max_ulps)
+                } else {
+                    false
+                }
+            })
+    }
+
+    #[inline]
+    fn ulps_flushed(&self, other: &HashMap<K, VB, S>) -> usize {
+        self.iter()
+            .filter_map(|(key, a)| other.get(key).map(|b| UlpsEqFtz::ulps_flushed(a, b)))
+            .sum()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, VA, VB, S> AssertUlpsEqFtz<HashMap<K, VB, S>> for HashMap<K, VA, S>
+where
+    K: Eq + hash::Hash + fmt::Debug,
+    S: hash::BuildHasher,
+    VA: AssertUlpsEqFtz<VB>,
+{
+    #[inline]
+    fn visit_ulps_ftz<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertUlpsEqFtz::visit_ulps_ftz(a, b, max_abs_diff, max_ulps, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+/// Compare two finite precision floating point expressions for ulps equality,
+/// with the subnormal numbers of both flushed to zero.
+///
+/// For more details, see the documentation for [`UlpsEqFtz`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_eq_ftz;
+/// #
+/// let simd = vec![0.5_f32, 0.0_f32];
+/// let scalar = vec![0.5_f32, 3e-39_f32];
+///
+/// assert!(ulps_eq_ftz!(simd, scalar, abs_diff <= 0.0_f32, ulps <= 4_u32));
+/// assert!(ulps_eq_ftz!(simd, scalar, ulps <= 4_u32, abs_diff <= 0.0_f32));
+/// ```
+#[macro_export]
+macro_rules! ulps_eq_ftz {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsEqFtz::ulps_eq_ftz(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::ulps_eq_ftz!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+}
+
+/// Compare two finite precision floating point expressions for ulps inequality,
+/// with the subnormal numbers of both flushed to zero.
+///
+/// For more details, see the documentation for [`UlpsEqFtz`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_ne_ftz;
+/// #
+/// let simd = [0.5_f32, 0.0_f32];
+/// let scalar = [0.5_f32, 1e-30_f32];
+///
+/// assert!(ulps_ne_ftz!(simd, scalar, abs_diff <= 0.0_f32, ulps <= 4_u32));
+/// ```
+#[macro_export]
+macro_rules! ulps_ne_ftz {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsEqFtz::ulps_ne_ftz(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::ulps_ne_ftz!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+}
+
+/// Assert that two finite precision floating point expressions are ulps equal,
+/// with the subnormal numbers of both flushed to zero.
+///
+/// See the documentation for [`UlpsEqFtz`] for details about flush-to-zero
+/// comparisons. See the documentation for [`AssertUlpsEqFtz`] for details about
+/// the debugging context provided when an assertion fails.
+///
+/// A failed assertion prints the number of subnormal numbers flushed to zero,
+/// followed by the path and the values of its first failing element, the number
+/// of failing elements, and a list of the failing elements. The differences of
+/// each element are measured after flushing, and the elements that were
+/// flushed report the flushed sides, labeled `flushed`.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_ulps_eq_ftz;
+/// #
+/// let simd = [0.25_f64, -0.0_f64];
+/// let scalar = [0.25_f64, -1e-310_f64];
+///
+/// assert_ulps_eq_ftz!(simd, scalar, abs_diff <= 0.0_f64, ulps <= 4_u64);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_eq_ftz {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsEqFtz::ulps_eq_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!(
+                            "assertion failed: `ulps_eq_ftz!(left, right, abs_diff <= t, ulps <= t)`",
+                            "\nsubnormals flushed to zero: `{}`\n{}\n{}"
+                        ),
+                        $crate::UlpsEqFtz::ulps_flushed(&*left_val, &*right_val),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertUlpsEqFtz::visit_ulps_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertUlpsEqFtz::visit_ulps_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsEqFtz::ulps_eq_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!(
+                            "assertion failed: `ulps_eq_ftz!(left, right, abs_diff <= t, ulps <= t)`",
+                            "\nsubnormals flushed to zero: `{}`\n{}\n{}: {}"
+                        ),
+                        $crate::UlpsEqFtz::ulps_flushed(&*left_val, &*right_val),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertUlpsEqFtz::visit_ulps_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertUlpsEqFtz::visit_ulps_ftz(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val, path, visitor)
+                        }),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_ulps_eq_ftz!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_ulps_eq_ftz!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2, $($arg)+)
+    };
+}
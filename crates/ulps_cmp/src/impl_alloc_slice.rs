@@ -2,6 +2,14 @@ use crate::traits::{
     AssertUlpsAllEq,
     AssertUlpsEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::ops;
 use std::vec::Vec;
 
 impl<A, B> AssertUlpsEq<[B]> for [A]
@@ -76,6 +84,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_ulps.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_ulps.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_ulps)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_ulps.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<[B]> for [A]
@@ -116,4 +175,31 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &[B],
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
@@ -4,6 +4,14 @@ use crate::traits::{
     UlpsAllEq,
     UlpsEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    Visitor,
+};
+
+use core::ops;
 use std::boxed::Box;
 use std::collections::BTreeMap;
 use std::collections::LinkedList;
@@ -296,6 +304,18 @@ where
     fn debug_ulps_tolerance(&self, other: &Box<B>, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(&**self, &**other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(&**self, &**other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsEq<Rc<B>> for Rc<A>
@@ -327,6 +347,18 @@ where
     fn debug_ulps_tolerance(&self, other: &Rc<B>, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(&**self, &**other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(&**self, &**other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsEq<Arc<B>> for Arc<A>
@@ -358,6 +390,18 @@ where
     fn debug_ulps_tolerance(&self, other: &Arc<B>, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(&**self, &**other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(&**self, &**other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsEq<Vec<B>> for Vec<A>
@@ -431,6 +475,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_ulps.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_ulps.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_ulps)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_ulps.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsEq<VecDeque<B>> for VecDeque<A>
@@ -504,6 +599,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_ulps.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_ulps.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_ulps)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_ulps.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsEq<LinkedList<B>> for LinkedList<A>
@@ -577,6 +723,57 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_ulps.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_ulps.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (((a, b), max_abs_diff), max_ulps)) in self
+            .iter()
+            .zip(other.iter())
+            .zip(max_abs_diff.iter())
+            .zip(max_ulps.iter())
+            .enumerate()
+        {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<K, VA, VB> AssertUlpsEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
@@ -644,6 +841,54 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_ulps.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_ulps.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| {
+                match (other.get(key), max_abs_diff.get(key), max_ulps.get(key)) {
+                    (Some(b), Some(max_abs_diff), Some(max_ulps)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+                    _ => StructureMismatch::MissingKey.report(path, visitor),
+                }
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<Box<B>> for Box<A>
@@ -663,6 +908,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &Box<B>, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(&**self, &**other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Box<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(&**self, &**other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<Rc<B>> for Rc<A>
@@ -682,6 +939,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &Rc<B>, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(&**self, &**other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Rc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(&**self, &**other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<Arc<B>> for Arc<A>
@@ -701,6 +970,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &Arc<B>, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(&**self, &**other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Arc<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(&**self, &**other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<Vec<B>> for Vec<A>
@@ -740,6 +1021,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Vec<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<VecDeque<B>> for VecDeque<A>
@@ -779,6 +1087,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &VecDeque<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<LinkedList<B>> for LinkedList<A>
@@ -818,6 +1153,33 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &LinkedList<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(other.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<K, VA, VB> AssertUlpsAllEq<BTreeMap<K, VB>> for BTreeMap<K, VA>
@@ -855,4 +1217,32 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &BTreeMap<K, VB>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
@@ -1,7 +1,9 @@
+use crate::ordering::UlpsOrd;
 use crate::traits::{
     UlpsAllEq,
     UlpsEq,
 };
+use approx_cmp_report::SearchMatch;
 
 impl<A, B> UlpsEq<[B]> for [A]
 where
@@ -43,3 +45,111 @@ where
                 .all(|(a, b)| a.ulps_all_eq(b, max_abs_diff, max_ulps))
     }
 }
+
+/// A data type for searching slices for the elements ulps equal to a value.
+///
+/// The search compares every element with the value using the semantics of
+/// [`UlpsEq`], e.g. to find the sample of a time series at `t ≈ 0.35`. When
+/// several elements match, the search reports them as
+/// [`SearchMatch::Ambiguous`] instead of returning one of them.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     SearchMatch,
+/// #     UlpsSearch,
+/// # };
+/// #
+/// let times = [0.0_f64, 0.1_f64, 0.2_f64, 0.1_f64 + 0.2_f64, 0.4_f64];
+///
+/// assert_eq!(times.ulps_binary_search(&0.3_f64, &0.01_f64, &0_u64), SearchMatch::Found(3));
+/// assert_eq!(times.ulps_position(&0.3_f64, &0.01_f64, &0_u64), SearchMatch::Found(3));
+/// assert!(times.ulps_contains(&0.3_f64, &0.01_f64, &0_u64));
+/// assert!(!times.ulps_contains(&0.35_f64, &0.01_f64, &0_u64));
+///
+/// let samples = vec![0.3_f64, 0.349_f64, 0.351_f64, 0.4_f64];
+///
+/// assert_eq!(
+///     samples.ulps_binary_search(&0.35_f64, &0.01_f64, &0_u64),
+///     SearchMatch::Ambiguous { first: 1, last: 2, count: 2 }
+/// );
+/// ```
+pub trait UlpsSearch<A> {
+    /// Search a sorted slice for the elements ulps equal to `value` with a binary
+    /// search.
+    ///
+    /// The slice must be sorted in increasing order, so that the elements matching
+    /// `value` are contiguous. If the slice is not sorted, the outcome is
+    /// unspecified.
+    fn ulps_binary_search<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as UlpsEq<B>>::Tolerance,
+        max_ulps: &<A as UlpsEq<B>>::UlpsTolerance,
+    ) -> SearchMatch
+    where
+        A: UlpsOrd<B>;
+
+    /// Search the slice for the elements ulps equal to `value` with a linear
+    /// search.
+    ///
+    /// The slice does not need to be sorted.
+    fn ulps_position<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as UlpsEq<B>>::Tolerance,
+        max_ulps: &<A as UlpsEq<B>>::UlpsTolerance,
+    ) -> SearchMatch
+    where
+        A: UlpsEq<B>;
+
+    /// Determine whether an element of the slice is ulps equal to `value`.
+    fn ulps_contains<B>(&self, value: &B, max_abs_diff: &<A as UlpsEq<B>>::Tolerance, max_ulps: &<A as UlpsEq<B>>::UlpsTolerance) -> bool
+    where
+        A: UlpsEq<B>;
+}
+
+impl<A> UlpsSearch<A> for [A] {
+    #[inline]
+    fn ulps_binary_search<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as UlpsEq<B>>::Tolerance,
+        max_ulps: &<A as UlpsEq<B>>::UlpsTolerance,
+    ) -> SearchMatch
+    where
+        A: UlpsOrd<B>,
+    {
+        let start = self.partition_point(|element| element.ulps_lt(value, max_abs_diff, max_ulps));
+        let end = start + self[start..].partition_point(|element| element.ulps_le(value, max_abs_diff, max_ulps));
+
+        SearchMatch::from_indices(start..end)
+    }
+
+    #[inline]
+    fn ulps_position<B>(
+        &self,
+        value: &B,
+        max_abs_diff: &<A as UlpsEq<B>>::Tolerance,
+        max_ulps: &<A as UlpsEq<B>>::UlpsTolerance,
+    ) -> SearchMatch
+    where
+        A: UlpsEq<B>,
+    {
+        SearchMatch::from_indices(
+            self.iter()
+                .enumerate()
+                .filter(|&(_, element)| element.ulps_eq(value, max_abs_diff, max_ulps))
+                .map(|(i, _)| i),
+        )
+    }
+
+    #[inline]
+    fn ulps_contains<B>(&self, value: &B, max_abs_diff: &<A as UlpsEq<B>>::Tolerance, max_ulps: &<A as UlpsEq<B>>::UlpsTolerance) -> bool
+    where
+        A: UlpsEq<B>,
+    {
+        self.iter().any(|element| element.ulps_eq(value, max_abs_diff, max_ulps))
+    }
+}
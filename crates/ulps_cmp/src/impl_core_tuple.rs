@@ -4,7 +4,14 @@ use crate::{
     UlpsAllEq,
     UlpsEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    Visitor,
+};
+
 use core::fmt;
+use core::ops;
 
 impl UlpsEq for () {
     type Tolerance = ();
@@ -108,6 +115,24 @@ macro_rules! impl_ulps_tuple {
             fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
                 ($(self.$idx.debug_ulps_tolerance(&other.$idx, &max_ulps.$idx),)+)
             }
+
+            #[inline]
+            fn visit_ulps<'a>(
+                &'a self,
+                other: &Self,
+                max_abs_diff: &Self::Tolerance,
+                max_ulps: &Self::UlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                $(
+                    path.with_segment(PathSegment::Field(stringify!($idx)), |path| {
+                        AssertUlpsEq::visit_ulps(&self.$idx, &other.$idx, &max_abs_diff.$idx, &max_ulps.$idx, path, visitor)
+                    })?;
+                )+
+
+                ops::ControlFlow::Continue(())
+            }
         }
     )+};
 }
@@ -251,6 +276,22 @@ where
     fn debug_ulps_all_tolerance(&self, other: &(B,), max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         (self.0.debug_ulps_all_tolerance(&other.0, max_ulps),)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B,),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B)> for (A, A)
@@ -288,6 +329,25 @@ where
             self.1.debug_ulps_all_tolerance(&other.1, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B)> for (A, A, A)
@@ -329,6 +389,28 @@ where
             self.2.debug_ulps_all_tolerance(&other.2, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B)> for (A, A, A, A)
@@ -383,6 +465,31 @@ where
             self.3.debug_ulps_all_tolerance(&other.3, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B)> for (A, A, A, A, A)
@@ -442,6 +549,34 @@ where
             self.4.debug_ulps_all_tolerance(&other.4, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B)> for (A, A, A, A, A, A)
@@ -506,6 +641,37 @@ where
             self.5.debug_ulps_all_tolerance(&other.5, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A)
@@ -575,6 +741,40 @@ where
             self.6.debug_ulps_all_tolerance(&other.6, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.6, &other.6, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A)
@@ -649,6 +849,43 @@ where
             self.7.debug_ulps_all_tolerance(&other.7, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.6, &other.6, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.7, &other.7, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A)
@@ -741,6 +978,46 @@ where
             self.8.debug_ulps_all_tolerance(&other.8, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.6, &other.6, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.7, &other.7, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.8, &other.8, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A)
@@ -838,6 +1115,49 @@ where
             self.9.debug_ulps_all_tolerance(&other.9, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.6, &other.6, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.7, &other.7, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.8, &other.8, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.9, &other.9, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A)
@@ -940,6 +1260,52 @@ where
             self.10.debug_ulps_all_tolerance(&other.10, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.6, &other.6, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.7, &other.7, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.8, &other.8, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.9, &other.9, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.10, &other.10, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> UlpsAllEq<(B, B, B, B, B, B, B, B, B, B, B, B)> for (A, A, A, A, A, A, A, A, A, A, A, A)
@@ -1046,4 +1412,53 @@ where
             self.11.debug_ulps_all_tolerance(&other.11, max_ulps),
         )
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &(B, B, B, B, B, B, B, B, B, B, B, B),
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        path.with_segment(PathSegment::Field("0"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.0, &other.0, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("1"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.1, &other.1, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("2"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.2, &other.2, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("3"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.3, &other.3, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("4"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.4, &other.4, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("5"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.5, &other.5, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("6"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.6, &other.6, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("7"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.7, &other.7, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("8"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.8, &other.8, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("9"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.9, &other.9, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("10"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.10, &other.10, max_abs_diff, max_ulps, path, visitor)
+        })?;
+        path.with_segment(PathSegment::Field("11"), |path| {
+            AssertUlpsAllEq::visit_ulps_all(&self.11, &other.11, max_abs_diff, max_ulps, path, visitor)
+        })?;
+
+        ops::ControlFlow::Continue(())
+    }
 }
@@ -1,12 +1,24 @@
+use crate::explain::UlpsExplain;
 use crate::traits::{
     AssertUlpsAllEq,
     AssertUlpsEq,
     UlpsAllEq,
     UlpsEq,
 };
+use crate::ulps;
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
 
 use core::cell;
 use core::mem;
+use core::ops;
 
 #[inline(always)]
 fn uninit_array<T, const N: usize>() -> [mem::MaybeUninit<T>; N] {
@@ -26,36 +38,7 @@ macro_rules! impl_ulps_eq_float {
 
             #[inline]
             fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
-                // Check whether the two numbers `self` and `other` are NaN. The sign test
-                // fails on NaNs.
-                if self.is_nan() || other.is_nan() {
-                    return false;
-                }
-
-                // First check whether the two numbers `self` and `other` are really close
-                // together.
-                let abs_diff = $T::abs(self - other);
-                if abs_diff <= *max_abs_diff {
-                    return true;
-                }
-
-                // If they do not fall inside the absolute difference equality closeness
-                // threshold, compare their signs. Sign comparison is a cheap check
-                // before comparing bit patterns.
-                if self.signum() != other.signum() {
-                    return false;
-                }
-
-                // Compare the two numbers by their bit patterns.
-                let bits_self: $U = self.to_bits();
-                let bits_other: $U = other.to_bits();
-                let ulps_distance = if bits_self <= bits_other {
-                    bits_other - bits_self
-                } else {
-                    bits_self - bits_other
-                };
-
-                ulps_distance <= *max_ulps
+                ulps::ulps_eq(*self, *other, *max_abs_diff, *max_ulps)
             }
         }
     };
@@ -64,6 +47,46 @@ macro_rules! impl_ulps_eq_float {
 impl_ulps_eq_float!(f32, u32);
 impl_ulps_eq_float!(f64, u64);
 
+macro_rules! impl_ulps_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl UlpsEq<$Rhs> for $T {
+            type Tolerance = f64;
+            type UlpsTolerance = u64;
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+                // Every `f32` widens to an `f64` exactly, so the absolute difference is
+                // measured in `f64` precision, and the ulps distance counts the `f64`
+                // numbers between the compared numbers.
+                ulps::ulps_eq(f64::from(*self), f64::from(*other), *max_abs_diff, *max_ulps)
+            }
+        }
+    )*};
+}
+
+impl_ulps_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_ulps_eq_integer {
+    ($($T:ident => $U:ident),* $(,)?) => {$(
+        impl UlpsEq for $T {
+            type Tolerance = $U;
+            type UlpsTolerance = $U;
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+                // Consecutive integers are one ulp apart, so the ulps distance between two
+                // integers is their absolute difference. It fits in the unsigned integer
+                // type of the same width, so it never overflows.
+                let abs_diff = $T::abs_diff(*self, *other);
+
+                abs_diff <= *max_abs_diff || abs_diff <= *max_ulps
+            }
+        }
+    )*};
+}
+
+impl_ulps_eq_integer!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize, i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
 impl<A, B> UlpsEq<&B> for &A
 where
     A: UlpsEq<B> + ?Sized,
@@ -188,6 +211,28 @@ where
     }
 }
 
+impl<A, B, E, F> UlpsEq<Result<B, F>> for Result<A, E>
+where
+    A: UlpsEq<B>,
+    E: UlpsEq<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+    A::UlpsTolerance: Sized,
+    E::UlpsTolerance: Sized,
+{
+    type Tolerance = Result<A::Tolerance, E::Tolerance>;
+    type UlpsTolerance = Result<A::UlpsTolerance, E::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_ulps)) => UlpsEq::ulps_eq(a, b, max_abs_diff, max_ulps),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_ulps)) => UlpsEq::ulps_eq(a, b, max_abs_diff, max_ulps),
+            _ => false,
+        }
+    }
+}
+
 impl<A, B> UlpsEq<cell::OnceCell<B>> for cell::OnceCell<A>
 where
     A: UlpsEq<B>,
@@ -224,6 +269,38 @@ macro_rules! impl_ulps_all_eq_float {
 impl_ulps_all_eq_float!(f32, u32);
 impl_ulps_all_eq_float!(f64, u64);
 
+macro_rules! impl_ulps_all_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl UlpsAllEq<$Rhs> for $T {
+            type AllTolerance = f64;
+            type AllUlpsTolerance = u64;
+
+            #[inline]
+            fn ulps_all_eq(&self, other: &$Rhs, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+                UlpsEq::ulps_eq(self, other, max_abs_diff, max_ulps)
+            }
+        }
+    )*};
+}
+
+impl_ulps_all_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_ulps_all_eq_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl UlpsAllEq for $T {
+            type AllTolerance = <$T as UlpsEq>::Tolerance;
+            type AllUlpsTolerance = <$T as UlpsEq>::UlpsTolerance;
+
+            #[inline]
+            fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+                self.ulps_eq(other, max_abs_diff, max_ulps)
+            }
+        }
+    )*};
+}
+
+impl_ulps_all_eq_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 impl<A, B> UlpsAllEq<&B> for &A
 where
     A: UlpsAllEq<B> + ?Sized,
@@ -343,6 +420,28 @@ where
     }
 }
 
+impl<A, B, E, F> UlpsAllEq<Result<B, F>> for Result<A, E>
+where
+    A: UlpsAllEq<B>,
+    E: UlpsAllEq<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+    A::AllUlpsTolerance: Sized,
+    E::AllUlpsTolerance: Sized,
+{
+    type AllTolerance = Result<A::AllTolerance, E::AllTolerance>;
+    type AllUlpsTolerance = Result<A::AllUlpsTolerance, E::AllUlpsTolerance>;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Result<B, F>, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_ulps)) => UlpsAllEq::ulps_all_eq(a, b, max_abs_diff, max_ulps),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_ulps)) => UlpsAllEq::ulps_all_eq(a, b, max_abs_diff, max_ulps),
+            _ => false,
+        }
+    }
+}
+
 impl<A, B> UlpsAllEq<cell::OnceCell<B>> for cell::OnceCell<A>
 where
     A: UlpsAllEq<B>,
@@ -377,20 +476,7 @@ macro_rules! impl_assert_ulps_eq_float {
 
             #[inline]
             fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
-                if self == other {
-                    Some(0)
-                } else if self.is_nan() || other.is_nan() {
-                    None
-                } else if self.signum() != other.signum() {
-                    None
-                } else {
-                    let bits_self = self.to_bits();
-                    let bits_other = other.to_bits();
-                    let bits_max = $U::max(bits_self, bits_other);
-                    let bits_min = $U::min(bits_self, bits_other);
-
-                    Some(bits_max - bits_min)
-                }
+                ulps::ulps_distance(*self, *other)
             }
 
             #[inline]
@@ -402,6 +488,34 @@ macro_rules! impl_assert_ulps_eq_float {
             fn debug_ulps_tolerance(&self, _other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
                 *max_ulps
             }
+
+            #[inline]
+            fn visit_ulps<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                max_ulps: &Self::UlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        UlpsEq::ulps_eq(self, other, max_abs_diff, max_ulps),
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff(self, other)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_diff(self, other)),
+                            ("branch", &UlpsExplain::explain_ulps(self, other, max_abs_diff, max_ulps)),
+                        ],
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff_tolerance(self, other, max_abs_diff)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_tolerance(self, other, max_ulps)),
+                        ],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     };
 }
@@ -409,6 +523,129 @@ macro_rules! impl_assert_ulps_eq_float {
 impl_assert_ulps_eq_float!(f32, u32);
 impl_assert_ulps_eq_float!(f64, u64);
 
+macro_rules! impl_assert_ulps_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertUlpsEq<$Rhs> for $T {
+            type DebugAbsDiff = f64;
+            type DebugUlpsDiff = Option<u64>;
+            type DebugTolerance = f64;
+            type DebugUlpsTolerance = u64;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &$Rhs) -> Self::DebugAbsDiff {
+                f64::abs(f64::from(*self) - f64::from(*other))
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &$Rhs) -> Self::DebugUlpsDiff {
+                ulps::ulps_distance(f64::from(*self), f64::from(*other))
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, _other: &$Rhs, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                *max_abs_diff
+            }
+
+            #[inline]
+            fn debug_ulps_tolerance(&self, _other: &$Rhs, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+                *max_ulps
+            }
+
+            #[inline]
+            fn visit_ulps<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::Tolerance,
+                max_ulps: &Self::UlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = ulps::explain_ulps(f64::from(*self), f64::from(*other), *max_abs_diff, *max_ulps);
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff(self, other)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_diff(self, other)),
+                            ("branch", &explanation),
+                        ],
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff_tolerance(self, other, max_abs_diff)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_tolerance(self, other, max_ulps)),
+                        ],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_ulps_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_ulps_eq_integer {
+    ($($T:ident => $U:ident),* $(,)?) => {$(
+        impl AssertUlpsEq for $T {
+            type DebugAbsDiff = $U;
+            type DebugUlpsDiff = $U;
+            type DebugTolerance = $U;
+            type DebugUlpsTolerance = $U;
+
+            #[inline]
+            fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+                $T::abs_diff(*self, *other)
+            }
+
+            #[inline]
+            fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+                $T::abs_diff(*self, *other)
+            }
+
+            #[inline]
+            fn debug_abs_diff_tolerance(&self, _other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+                *max_abs_diff
+            }
+
+            #[inline]
+            fn debug_ulps_tolerance(&self, _other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+                *max_ulps
+            }
+
+            #[inline]
+            fn visit_ulps<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::Tolerance,
+                max_ulps: &Self::UlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        UlpsEq::ulps_eq(self, other, max_abs_diff, max_ulps),
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff(self, other)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_diff(self, other)),
+                        ],
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff_tolerance(self, other, max_abs_diff)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_tolerance(self, other, max_ulps)),
+                        ],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_ulps_eq_integer!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize, i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
 impl<A, B> AssertUlpsEq<&B> for &A
 where
     A: AssertUlpsEq<B> + ?Sized,
@@ -434,6 +671,18 @@ where
     fn debug_ulps_tolerance(&self, other: &&B, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsEq<&mut B> for &A
@@ -461,6 +710,18 @@ where
     fn debug_ulps_tolerance(&self, other: &&mut B, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsEq<&B> for &mut A
@@ -488,6 +749,18 @@ where
     fn debug_ulps_tolerance(&self, other: &&B, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsEq<&mut B> for &mut A
@@ -515,6 +788,18 @@ where
     fn debug_ulps_tolerance(&self, other: &&mut B, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
         AssertUlpsEq::debug_ulps_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsEq::visit_ulps(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B, const N: usize> AssertUlpsEq<[B; N]> for [A; N]
@@ -569,6 +854,24 @@ where
 
         unsafe { array_assume_init(result) }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsEq::visit_ulps(&self[i], &other[i], &max_abs_diff[i], &max_ulps[i], path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsEq<cell::Cell<B>> for cell::Cell<A>
@@ -677,6 +980,98 @@ where
 
         Some(AssertUlpsEq::debug_ulps_tolerance(ref_self, ref_other, ref_max_ulps))
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_ulps)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertUlpsEq<Result<B, F>> for Result<A, E>
+where
+    A: AssertUlpsEq<B>,
+    E: AssertUlpsEq<F>,
+    A::Tolerance: Sized,
+    E::Tolerance: Sized,
+    A::UlpsTolerance: Sized,
+    E::UlpsTolerance: Sized,
+{
+    type DebugAbsDiff = Option<Result<A::DebugAbsDiff, E::DebugAbsDiff>>;
+    type DebugUlpsDiff = Option<Result<A::DebugUlpsDiff, E::DebugUlpsDiff>>;
+    type DebugTolerance = Option<Result<A::DebugTolerance, E::DebugTolerance>>;
+    type DebugUlpsTolerance = Option<Result<A::DebugUlpsTolerance, E::DebugUlpsTolerance>>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Result<B, F>) -> Self::DebugAbsDiff {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Some(Ok(AssertUlpsEq::debug_abs_diff(a, b))),
+            (Err(a), Err(b)) => Some(Err(AssertUlpsEq::debug_abs_diff(a, b))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Result<B, F>) -> Self::DebugUlpsDiff {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Some(Ok(AssertUlpsEq::debug_ulps_diff(a, b))),
+            (Err(a), Err(b)) => Some(Err(AssertUlpsEq::debug_ulps_diff(a, b))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Result<B, F>, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => Some(Ok(AssertUlpsEq::debug_abs_diff_tolerance(a, b, max_abs_diff))),
+            (Err(a), Err(b), Err(max_abs_diff)) => Some(Err(AssertUlpsEq::debug_abs_diff_tolerance(a, b, max_abs_diff))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Result<B, F>, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        match (self, other, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_ulps)) => Some(Ok(AssertUlpsEq::debug_ulps_tolerance(a, b, max_ulps))),
+            (Err(a), Err(b), Err(max_ulps)) => Some(Err(AssertUlpsEq::debug_ulps_tolerance(a, b, max_ulps))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_ulps)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_ulps)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<A, B> AssertUlpsEq<cell::OnceCell<B>> for cell::OnceCell<A>
@@ -725,6 +1120,26 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 macro_rules! impl_assert_ulps_all_eq_float {
@@ -740,6 +1155,37 @@ macro_rules! impl_assert_ulps_all_eq_float {
             fn debug_ulps_all_tolerance(&self, _other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
                 *max_ulps
             }
+
+            #[inline]
+            fn visit_ulps_all<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                max_ulps: &Self::AllUlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        UlpsAllEq::ulps_all_eq(self, other, max_abs_diff, max_ulps),
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff(self, other)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_diff(self, other)),
+                            ("branch", &UlpsExplain::explain_ulps(self, other, max_abs_diff, max_ulps)),
+                        ],
+                        &[
+                            (
+                                "abs_diff_all",
+                                &AssertUlpsAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff),
+                            ),
+                            ("ulps_all", &AssertUlpsAllEq::debug_ulps_all_tolerance(self, other, max_ulps)),
+                        ],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
         }
     };
 }
@@ -747,6 +1193,107 @@ macro_rules! impl_assert_ulps_all_eq_float {
 impl_assert_ulps_all_eq_float!(f32, u32);
 impl_assert_ulps_all_eq_float!(f64, u64);
 
+macro_rules! impl_assert_ulps_all_eq_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl AssertUlpsAllEq<$Rhs> for $T {
+            type AllDebugTolerance = f64;
+            type AllDebugUlpsTolerance = u64;
+
+            fn debug_abs_diff_all_tolerance(&self, _other: &$Rhs, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                *max_abs_diff
+            }
+
+            fn debug_ulps_all_tolerance(&self, _other: &$Rhs, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+                *max_ulps
+            }
+
+            #[inline]
+            fn visit_ulps_all<'a>(
+                &'a self,
+                other: &$Rhs,
+                max_abs_diff: &Self::AllTolerance,
+                max_ulps: &Self::AllUlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let explanation = ulps::explain_ulps(f64::from(*self), f64::from(*other), *max_abs_diff, *max_ulps);
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        explanation.passed(),
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff(self, other)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_diff(self, other)),
+                            ("branch", &explanation),
+                        ],
+                        &[
+                            (
+                                "abs_diff_all",
+                                &AssertUlpsAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff),
+                            ),
+                            ("ulps_all", &AssertUlpsAllEq::debug_ulps_all_tolerance(self, other, max_ulps)),
+                        ],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(&f64::from(*self), &f64::from(*other))),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_ulps_all_eq_mixed_float!(f32 => f64, f64 => f32);
+
+macro_rules! impl_assert_ulps_all_eq_integer {
+    ($($T:ident),* $(,)?) => {$(
+        impl AssertUlpsAllEq for $T {
+            type AllDebugTolerance = Self::AllTolerance;
+            type AllDebugUlpsTolerance = Self::AllUlpsTolerance;
+
+            fn debug_abs_diff_all_tolerance(&self, _other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+                *max_abs_diff
+            }
+
+            fn debug_ulps_all_tolerance(&self, _other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+                *max_ulps
+            }
+
+            #[inline]
+            fn visit_ulps_all<'a>(
+                &'a self,
+                other: &$T,
+                max_abs_diff: &Self::AllTolerance,
+                max_ulps: &Self::AllUlpsTolerance,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        UlpsAllEq::ulps_all_eq(self, other, max_abs_diff, max_ulps),
+                        &[
+                            ("abs_diff", &AssertUlpsEq::debug_abs_diff(self, other)),
+                            ("ulps", &AssertUlpsEq::debug_ulps_diff(self, other)),
+                        ],
+                        &[
+                            (
+                                "abs_diff_all",
+                                &AssertUlpsAllEq::debug_abs_diff_all_tolerance(self, other, max_abs_diff),
+                            ),
+                            ("ulps_all", &AssertUlpsAllEq::debug_ulps_all_tolerance(self, other, max_ulps)),
+                        ],
+                    )
+                    .with_values(self, other)
+                    .with_errors(ElementErrors::new(self, other)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_assert_ulps_all_eq_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 impl<A, B> AssertUlpsAllEq<&B> for &A
 where
     A: AssertUlpsAllEq<B> + ?Sized,
@@ -762,6 +1309,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &&B, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<&mut B> for &A
@@ -779,6 +1338,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &&mut B, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<&B> for &mut A
@@ -796,6 +1367,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &&B, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &&B,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<&mut B> for &mut A
@@ -813,6 +1396,18 @@ where
     fn debug_ulps_all_tolerance(&self, other: &&mut B, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
         AssertUlpsAllEq::debug_ulps_all_tolerance(*self, *other, max_ulps)
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &&mut B,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsAllEq::visit_ulps_all(*self, *other, max_abs_diff, max_ulps, path, visitor)
+    }
 }
 
 impl<A, B, const N: usize> AssertUlpsAllEq<[B; N]> for [A; N]
@@ -841,6 +1436,24 @@ where
 
         unsafe { array_assume_init(result) }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &[B; N],
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for i in 0..N {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsAllEq::visit_ulps_all(&self[i], &other[i], max_abs_diff, max_ulps, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<cell::Cell<B>> for cell::Cell<A>
@@ -907,6 +1520,82 @@ where
 
         Some(AssertUlpsAllEq::debug_ulps_all_tolerance(ref_self, ref_other, ref_max_ulps))
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Option<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Some(a), Some(b), Some(max_abs_diff), Some(max_ulps)) => {
+                AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(self),
+                right: VariantMismatch::option_name(other),
+                tolerance: Some(VariantMismatch::option_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
+}
+
+impl<A, B, E, F> AssertUlpsAllEq<Result<B, F>> for Result<A, E>
+where
+    A: AssertUlpsAllEq<B>,
+    E: AssertUlpsAllEq<F>,
+    A::AllTolerance: Sized,
+    E::AllTolerance: Sized,
+    A::AllUlpsTolerance: Sized,
+    E::AllUlpsTolerance: Sized,
+{
+    type AllDebugTolerance = Option<Result<A::AllDebugTolerance, E::AllDebugTolerance>>;
+    type AllDebugUlpsTolerance = Option<Result<A::AllDebugUlpsTolerance, E::AllDebugUlpsTolerance>>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Result<B, F>, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        match (self, other, max_abs_diff) {
+            (Ok(a), Ok(b), Ok(max_abs_diff)) => Some(Ok(AssertUlpsAllEq::debug_abs_diff_all_tolerance(a, b, max_abs_diff))),
+            (Err(a), Err(b), Err(max_abs_diff)) => Some(Err(AssertUlpsAllEq::debug_abs_diff_all_tolerance(a, b, max_abs_diff))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Result<B, F>, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        match (self, other, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_ulps)) => Some(Ok(AssertUlpsAllEq::debug_ulps_all_tolerance(a, b, max_ulps))),
+            (Err(a), Err(b), Err(max_ulps)) => Some(Err(AssertUlpsAllEq::debug_ulps_all_tolerance(a, b, max_ulps))),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Result<B, F>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self, other, max_abs_diff, max_ulps) {
+            (Ok(a), Ok(b), Ok(max_abs_diff), Ok(max_ulps)) => AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor),
+            (Err(a), Err(b), Err(max_abs_diff), Err(max_ulps)) => {
+                AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor)
+            }
+            _ => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::result_name(self),
+                right: VariantMismatch::result_name(other),
+                tolerance: Some(VariantMismatch::result_name(max_abs_diff)),
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<cell::OnceCell<B>> for cell::OnceCell<A>
@@ -935,4 +1624,24 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &cell::OnceCell<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
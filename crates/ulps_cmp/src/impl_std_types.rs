@@ -4,9 +4,18 @@ use crate::traits::{
     UlpsAllEq,
     UlpsEq,
 };
+use approx_cmp_report::{
+    Path,
+    PathSegment,
+    StructureMismatch,
+    VariantMismatch,
+    Visitor,
+};
+
 use std::collections::HashMap;
 use std::fmt;
 use std::hash;
+use std::ops;
 use std::sync;
 
 impl<K, VA, VB, S> UlpsEq<HashMap<K, VB, S>> for HashMap<K, VA, S>
@@ -165,6 +174,54 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_abs_diff.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_abs_diff.len()),
+            }
+            .report(path, visitor);
+        }
+
+        if self.len() != max_ulps.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: Some(max_ulps.len()),
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| {
+                match (other.get(key), max_abs_diff.get(key), max_ulps.get(key)) {
+                    (Some(b), Some(max_abs_diff), Some(max_ulps)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+                    _ => StructureMismatch::MissingKey.report(path, visitor),
+                }
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsEq<sync::OnceLock<B>> for sync::OnceLock<A>
@@ -213,6 +270,26 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertUlpsEq::visit_ulps(a, b, max_abs_diff, max_ulps, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
 
 impl<K, VA, VB, S> AssertUlpsAllEq<HashMap<K, VB, S>> for HashMap<K, VA, S>
@@ -251,6 +328,34 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &HashMap<K, VB, S>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != other.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: other.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (key, a) in self {
+            path.with_segment(PathSegment::Key(key), |path| match other.get(key) {
+                Some(b) => AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor),
+                None => StructureMismatch::MissingKey.report(path, visitor),
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
 }
 
 impl<A, B> AssertUlpsAllEq<sync::OnceLock<B>> for sync::OnceLock<A>
@@ -279,4 +384,24 @@ where
             None
         }
     }
+
+    #[inline]
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &sync::OnceLock<B>,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        match (self.get(), other.get()) {
+            (Some(a), Some(b)) => AssertUlpsAllEq::visit_ulps_all(a, b, max_abs_diff, max_ulps, path, visitor),
+            (a, b) => StructureMismatch::Variant(VariantMismatch {
+                left: VariantMismatch::option_name(&a),
+                right: VariantMismatch::option_name(&b),
+                tolerance: None,
+            })
+            .report(path, visitor),
+        }
+    }
 }
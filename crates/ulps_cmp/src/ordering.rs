@@ -0,0 +1,534 @@
+use crate::traits::UlpsEq;
+
+use core::cmp::Ordering;
+
+/// A data type for ordering values up to the tolerances of an ulps comparison.
+///
+/// A tolerance-aware ordering treats two values as equal when they are ulps
+/// equal, and otherwise orders them with [`PartialOrd`]. It checks bounds that
+/// hold up to rounding, e.g. that the energy of a simulation never increases,
+/// where an energy exceeding its previous value by a rounding error still
+/// satisfies the bound.
+///
+/// * [`ulps_partial_cmp`] returns `Some(Ordering::Equal)` for ulps equal
+///   values.
+/// * [`ulps_lt`] and [`ulps_gt`] hold for strictly ordered values that are not
+///   ulps equal.
+/// * [`ulps_le`] and [`ulps_ge`] hold for strictly ordered values, and for ulps
+///   equal values.
+///
+/// NaN is unordered, so it is neither less than, greater than, nor equal to any
+/// number. The integer and floating point primitives implement this trait, as do
+/// `f32` and `f64` against each other. Containers do not, since their elements
+/// compared under tolerance do not order them.
+///
+/// [`ulps_partial_cmp`]: UlpsOrd::ulps_partial_cmp
+/// [`ulps_lt`]: UlpsOrd::ulps_lt
+/// [`ulps_gt`]: UlpsOrd::ulps_gt
+/// [`ulps_le`]: UlpsOrd::ulps_le
+/// [`ulps_ge`]: UlpsOrd::ulps_ge
+///
+/// # Example
+///
+/// ```
+/// # use core::cmp::Ordering;
+/// # use ulps_cmp::UlpsOrd;
+/// #
+/// let previous = 0.3_f64;
+/// let energy = 0.1_f64 + 0.2_f64;
+///
+/// assert!(energy > previous);
+/// assert!(energy.ulps_le(&previous, &0.0_f64, &4_u64));
+/// assert!(!energy.ulps_lt(&previous, &0.0_f64, &4_u64));
+/// assert_eq!(energy.ulps_partial_cmp(&previous, &0.0_f64, &4_u64), Some(Ordering::Equal));
+/// assert_eq!(energy.ulps_partial_cmp(&f64::NAN, &0.0_f64, &4_u64), None);
+/// ```
+///
+/// The first elements of these vectors are equal up to the tolerances, which
+/// does not decide whether the vectors are ordered, so they are not comparable.
+///
+/// ```compile_fail
+/// # use ulps_cmp::ulps_lt;
+/// #
+/// ulps_lt!(vec![1.0_f64, 5.0_f64], vec![1.0000001_f64, 0.0_f64], abs_diff <= vec![0.0_f64, 0.0_f64], ulps <= vec![4_u64, 4_u64]);
+/// ```
+pub trait UlpsOrd<Rhs = Self>: UlpsEq<Rhs> {
+    /// Order `self` and `other`, treating ulps equal values as equal.
+    ///
+    /// Returns `None` when the values are not ulps equal and unordered.
+    fn ulps_partial_cmp(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> Option<Ordering>;
+
+    /// Determine whether `self` is less than `other` and not ulps equal to it.
+    #[inline]
+    fn ulps_lt(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        matches!(Self::ulps_partial_cmp(self, other, max_abs_diff, max_ulps), Some(Ordering::Less))
+    }
+
+    /// Determine whether `self` is less than `other` or ulps equal to it.
+    #[inline]
+    fn ulps_le(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        matches!(
+            Self::ulps_partial_cmp(self, other, max_abs_diff, max_ulps),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// Determine whether `self` is greater than `other` and not ulps equal to it.
+    #[inline]
+    fn ulps_gt(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        matches!(Self::ulps_partial_cmp(self, other, max_abs_diff, max_ulps), Some(Ordering::Greater))
+    }
+
+    /// Determine whether `self` is greater than `other` or ulps equal to it.
+    #[inline]
+    fn ulps_ge(&self, other: &Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        matches!(
+            Self::ulps_partial_cmp(self, other, max_abs_diff, max_ulps),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
+}
+
+macro_rules! impl_ulps_ord {
+    ($($T:ident),* $(,)?) => {$(
+        impl UlpsOrd for $T {
+            #[inline]
+            fn ulps_partial_cmp(&self, other: &$T, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> Option<Ordering> {
+                if UlpsEq::ulps_eq(self, other, max_abs_diff, max_ulps) {
+                    Some(Ordering::Equal)
+                } else {
+                    PartialOrd::partial_cmp(self, other)
+                }
+            }
+        }
+    )*};
+}
+
+impl_ulps_ord!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+macro_rules! impl_ulps_ord_mixed_float {
+    ($($T:ident => $Rhs:ident),* $(,)?) => {$(
+        impl UlpsOrd<$Rhs> for $T {
+            #[inline]
+            fn ulps_partial_cmp(&self, other: &$Rhs, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> Option<Ordering> {
+                if UlpsEq::ulps_eq(self, other, max_abs_diff, max_ulps) {
+                    Some(Ordering::Equal)
+                } else {
+                    // Every `f32` widens to an `f64` exactly, so the order is decided in
+                    // `f64` precision.
+                    PartialOrd::partial_cmp(&f64::from(*self), &f64::from(*other))
+                }
+            }
+        }
+    )*};
+}
+
+impl_ulps_ord_mixed_float!(f32 => f64, f64 => f32);
+
+/// Determine whether a finite precision floating point expression is less than
+/// another, treating ulps equal values as equal.
+///
+/// For more details, see the documentation for [`UlpsOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_lt;
+/// #
+/// assert!(ulps_lt!(0.25_f64, 0.5_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// assert!(!ulps_lt!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// ```
+#[macro_export]
+macro_rules! ulps_lt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsOrd::ulps_lt(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::ulps_lt!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+}
+
+/// Determine whether a finite precision floating point expression is less than
+/// or equal to another, treating ulps equal values as equal.
+///
+/// For more details, see the documentation for [`UlpsOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_le;
+/// #
+/// assert!(ulps_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// assert!(!ulps_le!(0.5_f64, 0.25_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// ```
+#[macro_export]
+macro_rules! ulps_le {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsOrd::ulps_le(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::ulps_le!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+}
+
+/// Determine whether a finite precision floating point expression is greater
+/// than another, treating ulps equal values as equal.
+///
+/// For more details, see the documentation for [`UlpsOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_gt;
+/// #
+/// assert!(ulps_gt!(0.5_f64, 0.25_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// assert!(!ulps_gt!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// ```
+#[macro_export]
+macro_rules! ulps_gt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsOrd::ulps_gt(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::ulps_gt!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+}
+
+/// Determine whether a finite precision floating point expression is greater
+/// than or equal to another, treating ulps equal values as equal.
+///
+/// For more details, see the documentation for [`UlpsOrd`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_ge;
+/// #
+/// assert!(ulps_ge!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// assert!(!ulps_ge!(0.25_f64, 0.5_f64, abs_diff <= 0.0_f64, ulps <= 4_u64));
+/// ```
+#[macro_export]
+macro_rules! ulps_ge {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsOrd::ulps_ge(left_val, right_val, tol_1_val, tol_2_val),
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::ulps_ge!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+}
+
+/// Assert that a finite precision floating point expression is less than
+/// another, treating ulps equal values as equal.
+///
+/// See the documentation for [`UlpsOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_ulps_lt;
+/// #
+/// assert_ulps_lt!(0.25_f64, 0.5_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_lt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_lt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_lt!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_lt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_lt!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_ulps_lt!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_ulps_lt!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2, $($arg)+)
+    };
+}
+
+/// Assert that a finite precision floating point expression is less than or
+/// equal to another, treating ulps equal values as equal.
+///
+/// See the documentation for [`UlpsOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_ulps_le;
+/// #
+/// assert_ulps_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_le {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_le(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_le!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_le(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_le!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_ulps_le!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_ulps_le!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2, $($arg)+)
+    };
+}
+
+/// Assert that a finite precision floating point expression is greater than
+/// another, treating ulps equal values as equal.
+///
+/// See the documentation for [`UlpsOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_ulps_gt;
+/// #
+/// assert_ulps_gt!(0.5_f64, 0.25_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_gt {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_gt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_gt!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_gt(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_gt!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_ulps_gt!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_ulps_gt!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2, $($arg)+)
+    };
+}
+
+/// Assert that a finite precision floating point expression is greater than or
+/// equal to another, treating ulps equal values as equal.
+///
+/// See the documentation for [`UlpsOrd`] for details about tolerance-aware
+/// orderings. A failed assertion prints the values, their differences, and the
+/// tolerances.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_ulps_ge;
+/// #
+/// assert_ulps_ge!(0.3_f64, 0.1_f64 + 0.2_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_ge {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_ge(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_ge!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2) {
+            (left_val, right_val, tol_1_val, tol_2_val) => {
+                if !$crate::UlpsOrd::ulps_ge(&*left_val, &*right_val, &*tol_1_val, &*tol_2_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_ge!(left, right, abs_diff <= t, ulps <= t)`", r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+[abs_diff] t: `{:?}`,
+    [ulps] t: `{:?}`: {}"#),
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_abs_diff_tolerance(&*left_val, &*right_val, &*tol_1_val),
+                        $crate::AssertUlpsEq::debug_ulps_tolerance(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::assert_ulps_ge!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, $($arg:tt)+) => {
+        $crate::assert_ulps_ge!($left, $right, abs_diff <= $tol_1, ulps <= $tol_2, $($arg)+)
+    };
+}
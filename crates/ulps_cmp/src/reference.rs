@@ -0,0 +1,493 @@
+use approx_cmp_report::{
+    Element,
+    ElementErrors,
+    Path,
+    PathSegment,
+    Visitor,
+};
+
+use core::ops;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use approx_cmp_report::StructureMismatch;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::vec::Vec;
+
+/// A data type for measuring the accuracy of a computed result against a
+/// reference value of higher precision, in units of least precision of the
+/// result.
+///
+/// The ulps error of a result is the absolute difference of the result and its
+/// reference, divided by the size of one unit in the last place of the result's
+/// type at the reference, i.e. the spacing of the representable numbers of the
+/// result's type at the magnitude of the reference. The reference is not rounded
+/// to the precision of the result, so the error is a fractional number of ulps.
+/// A correctly rounded result has an ulps error of at most `0.5`, and a
+/// faithfully rounded result has an ulps error below `1.0`.
+///
+/// The ulps error of a result equal to its reference is zero, including
+/// infinities of the same sign. An infinite result with a finite reference, or
+/// a finite result with an infinite reference, has an infinite ulps error. The
+/// ulps error involving NaN is NaN, so it exceeds every tolerance.
+///
+/// Like [`UlpsAllEq`](crate::UlpsAllEq), the comparison uses a single tolerance
+/// for every element of a container, e.g. a `Vec<f32>` of results compared
+/// against a `Vec<f64>` of references.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     ulps,
+/// #     UlpsErrorEq,
+/// # };
+/// #
+/// let reference = 1.0_f64 / 3.0_f64;
+/// let result = 1.0_f32 / 3.0_f32;
+///
+/// // The division is correctly rounded.
+/// assert!(result.ulps_error_eq(&reference, &0.5_f64));
+/// assert!(ulps::step_by_ulps(result, 2).ulps_error_ne(&reference, &1.0_f64));
+///
+/// let results = vec![1.0_f32 / 3.0_f32, 2.0_f32 / 3.0_f32];
+/// let references = vec![1.0_f64 / 3.0_f64, 2.0_f64 / 3.0_f64];
+///
+/// assert!(results.ulps_error_eq(&references, &0.5_f64));
+/// ```
+pub trait UlpsErrorEq<Reference: ?Sized = Self> {
+    /// Compare the ulps error of `self` against `reference` with the tolerance
+    /// `max_ulps_error`, in ulps of the precision of `self`.
+    ///
+    /// Returns `true` when the ulps error of every element of `self` is within
+    /// `max_ulps_error`.
+    fn ulps_error_eq(&self, reference: &Reference, max_ulps_error: &f64) -> bool;
+
+    /// Compare the ulps error of `self` against `reference` with the tolerance
+    /// `max_ulps_error`, in ulps of the precision of `self`.
+    ///
+    /// Returns `true` when the ulps error of an element of `self` exceeds
+    /// `max_ulps_error`.
+    fn ulps_error_ne(&self, reference: &Reference, max_ulps_error: &f64) -> bool {
+        !Self::ulps_error_eq(self, reference, max_ulps_error)
+    }
+}
+
+/// A trait for computing debugging information when the ulps error of a
+/// computed result exceeds its tolerance.
+///
+/// This trait is the debugging counterpart of [`UlpsErrorEq`], in the same way
+/// [`AssertUlpsAllEq`](crate::AssertUlpsAllEq) is the debugging counterpart of
+/// [`UlpsAllEq`](crate::UlpsAllEq).
+pub trait AssertUlpsErrorEq<Reference: ?Sized = Self>: UlpsErrorEq<Reference> {
+    /// The type of the ulps errors of the elements of a result.
+    type DebugUlpsError: Sized;
+
+    /// Compute the ulps errors of the elements of `self` against `reference`.
+    fn debug_ulps_error(&self, reference: &Reference) -> Self::DebugUlpsError;
+
+    /// Visit the elements of `self` and `reference`, reporting the ulps error of
+    /// each element and the tolerance it was compared against to `visitor`.
+    ///
+    /// The default implementation reports `self` and `reference` as a single
+    /// element without values.
+    #[inline]
+    fn visit_ulps_error<'a>(
+        &'a self,
+        reference: &Reference,
+        max_ulps_error: &f64,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        visitor.visit(
+            path,
+            &Element::new(
+                UlpsErrorEq::ulps_error_eq(self, reference, max_ulps_error),
+                &[],
+                &[("ulps_error", max_ulps_error)],
+            ),
+        )
+    }
+}
+
+/// The number `2^exponent`, for an exponent in the range of the finite nonzero
+/// `f64` numbers.
+#[inline]
+const fn exp2(exponent: i32) -> f64 {
+    if exponent >= f64::MIN_EXP - 1 {
+        f64::from_bits(((exponent + f64::MAX_EXP - 1) as u64) << (f64::MANTISSA_DIGITS - 1))
+    } else {
+        f64::from_bits(1 << (exponent - (f64::MIN_EXP - 1) + (f64::MANTISSA_DIGITS as i32 - 1)))
+    }
+}
+
+/// The exponent of the leading binary digit of a finite nonzero `f64`.
+#[inline]
+const fn exponent(value: f64) -> i32 {
+    let bits = value.to_bits() & !(1 << 63);
+    let biased = (bits >> (f64::MANTISSA_DIGITS - 1)) as i32;
+    if biased == 0 {
+        // The leading digit of a subnormal number is in its significand.
+        let significand_digits = (64 - bits.leading_zeros()) as i32;
+        (f64::MIN_EXP - 1) - (f64::MANTISSA_DIGITS as i32 - 1) + significand_digits - 1
+    } else {
+        biased - (f64::MAX_EXP - 1)
+    }
+}
+
+macro_rules! impl_ulps_error_eq_float {
+    ($($T:ident => $Ref:ident),* $(,)?) => {$(
+        impl UlpsErrorEq<$Ref> for $T {
+            #[inline]
+            fn ulps_error_eq(&self, reference: &$Ref, max_ulps_error: &f64) -> bool {
+                AssertUlpsErrorEq::debug_ulps_error(self, reference) <= *max_ulps_error
+            }
+        }
+
+        impl AssertUlpsErrorEq<$Ref> for $T {
+            type DebugUlpsError = f64;
+
+            #[inline]
+            fn debug_ulps_error(&self, reference: &$Ref) -> Self::DebugUlpsError {
+                let result = f64::from(*self);
+                let reference = *reference;
+                if result == reference {
+                    return 0.0;
+                }
+                if result.is_nan() || reference.is_nan() {
+                    return f64::NAN;
+                }
+                if result.is_infinite() || reference.is_infinite() {
+                    return f64::INFINITY;
+                }
+
+                // The spacing of the numbers of the result's type at the magnitude of
+                // the reference. Below the normal numbers, the spacing is the spacing
+                // of the subnormal numbers, and above the largest finite numbers, it
+                // is the spacing of the largest binade.
+                let leading = if reference == 0.0 {
+                    $T::MIN_EXP - 1
+                } else {
+                    i32::clamp(exponent(reference), $T::MIN_EXP - 1, $T::MAX_EXP - 1)
+                };
+                let ulp = exp2(leading - ($T::MANTISSA_DIGITS as i32 - 1));
+
+                f64::abs(result - reference) / ulp
+            }
+
+            #[inline]
+            fn visit_ulps_error<'a>(
+                &'a self,
+                reference: &$Ref,
+                max_ulps_error: &f64,
+                path: &mut Path<'a>,
+                visitor: &mut dyn Visitor<'a>,
+            ) -> ops::ControlFlow<()> {
+                let ulps_error = AssertUlpsErrorEq::debug_ulps_error(self, reference);
+
+                visitor.visit(
+                    path,
+                    &Element::new(
+                        ulps_error <= *max_ulps_error,
+                        &[("ulps_error", &ulps_error)],
+                        &[("ulps_error", max_ulps_error)],
+                    )
+                    .with_values(self, reference)
+                    .with_errors(ElementErrors::new(&f64::from(*self), reference)),
+                )
+            }
+        }
+    )*};
+}
+
+impl_ulps_error_eq_float!(f32 => f64, f64 => f64);
+
+impl<A, B> UlpsErrorEq<&B> for &A
+where
+    A: UlpsErrorEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn ulps_error_eq(&self, reference: &&B, max_ulps_error: &f64) -> bool {
+        UlpsErrorEq::ulps_error_eq(*self, *reference, max_ulps_error)
+    }
+}
+
+impl<A, B> AssertUlpsErrorEq<&B> for &A
+where
+    A: AssertUlpsErrorEq<B> + ?Sized,
+    B: ?Sized,
+{
+    type DebugUlpsError = A::DebugUlpsError;
+
+    #[inline]
+    fn debug_ulps_error(&self, reference: &&B) -> Self::DebugUlpsError {
+        AssertUlpsErrorEq::debug_ulps_error(*self, *reference)
+    }
+
+    #[inline]
+    fn visit_ulps_error<'a>(
+        &'a self,
+        reference: &&B,
+        max_ulps_error: &f64,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsErrorEq::visit_ulps_error(*self, *reference, max_ulps_error, path, visitor)
+    }
+}
+
+impl<A, B, const N: usize> UlpsErrorEq<[B; N]> for [A; N]
+where
+    A: UlpsErrorEq<B>,
+{
+    #[inline]
+    fn ulps_error_eq(&self, reference: &[B; N], max_ulps_error: &f64) -> bool {
+        self.iter()
+            .zip(reference.iter())
+            .all(|(a, b)| UlpsErrorEq::ulps_error_eq(a, b, max_ulps_error))
+    }
+}
+
+impl<A, B, const N: usize> AssertUlpsErrorEq<[B; N]> for [A; N]
+where
+    A: AssertUlpsErrorEq<B>,
+{
+    type DebugUlpsError = [A::DebugUlpsError; N];
+
+    #[inline]
+    fn debug_ulps_error(&self, reference: &[B; N]) -> Self::DebugUlpsError {
+        core::array::from_fn(|i| AssertUlpsErrorEq::debug_ulps_error(&self[i], &reference[i]))
+    }
+
+    #[inline]
+    fn visit_ulps_error<'a>(
+        &'a self,
+        reference: &[B; N],
+        max_ulps_error: &f64,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        for (i, (a, b)) in self.iter().zip(reference.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsErrorEq::visit_ulps_error(a, b, max_ulps_error, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+impl<A, B> UlpsErrorEq<[B]> for [A]
+where
+    A: UlpsErrorEq<B>,
+{
+    #[inline]
+    fn ulps_error_eq(&self, reference: &[B], max_ulps_error: &f64) -> bool {
+        self.len() == reference.len()
+            && self
+                .iter()
+                .zip(reference.iter())
+                .all(|(a, b)| UlpsErrorEq::ulps_error_eq(a, b, max_ulps_error))
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertUlpsErrorEq<[B]> for [A]
+where
+    A: AssertUlpsErrorEq<B>,
+{
+    type DebugUlpsError = Option<Vec<A::DebugUlpsError>>;
+
+    #[inline]
+    fn debug_ulps_error(&self, reference: &[B]) -> Self::DebugUlpsError {
+        if self.len() == reference.len() {
+            Some(
+                self.iter()
+                    .zip(reference.iter())
+                    .map(|(a, b)| AssertUlpsErrorEq::debug_ulps_error(a, b))
+                    .collect(),
+            )
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn visit_ulps_error<'a>(
+        &'a self,
+        reference: &[B],
+        max_ulps_error: &f64,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        if self.len() != reference.len() {
+            return StructureMismatch::Len {
+                left: self.len(),
+                right: reference.len(),
+                tolerance: None,
+            }
+            .report(path, visitor);
+        }
+
+        for (i, (a, b)) in self.iter().zip(reference.iter()).enumerate() {
+            path.with_segment(PathSegment::Index(i), |path| {
+                AssertUlpsErrorEq::visit_ulps_error(a, b, max_ulps_error, path, visitor)
+            })?;
+        }
+
+        ops::ControlFlow::Continue(())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> UlpsErrorEq<Vec<B>> for Vec<A>
+where
+    A: UlpsErrorEq<B>,
+{
+    #[inline]
+    fn ulps_error_eq(&self, reference: &Vec<B>, max_ulps_error: &f64) -> bool {
+        UlpsErrorEq::ulps_error_eq(self.as_slice(), reference.as_slice(), max_ulps_error)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<A, B> AssertUlpsErrorEq<Vec<B>> for Vec<A>
+where
+    A: AssertUlpsErrorEq<B>,
+{
+    type DebugUlpsError = Option<Vec<A::DebugUlpsError>>;
+
+    #[inline]
+    fn debug_ulps_error(&self, reference: &Vec<B>) -> Self::DebugUlpsError {
+        AssertUlpsErrorEq::debug_ulps_error(self.as_slice(), reference.as_slice())
+    }
+
+    #[inline]
+    fn visit_ulps_error<'a>(
+        &'a self,
+        reference: &Vec<B>,
+        max_ulps_error: &f64,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        AssertUlpsErrorEq::visit_ulps_error(self.as_slice(), reference.as_slice(), max_ulps_error, path, visitor)
+    }
+}
+
+/// Compare the accuracy of a computed result against a reference value of higher
+/// precision, in units of least precision of the result.
+///
+/// For more details, see the documentation for [`UlpsErrorEq`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_error_eq;
+/// #
+/// let result = 0.1_f32 + 0.2_f32;
+/// let reference = 0.1_f64 + 0.2_f64;
+///
+/// assert!(ulps_error_eq!(result, reference, ulps_error <= 1.0_f64));
+/// assert!(ulps_error_eq!([result, 2.0_f32 * result], [reference, 2.0_f64 * reference], ulps_error <= 1.0_f64));
+/// ```
+#[macro_export]
+macro_rules! ulps_error_eq {
+    ($result:expr, $reference:expr, ulps_error <= $tol:expr $(,)?) => {{
+        match (&$result, &$reference, &$tol) {
+            (result_val, reference_val, tol_val) => $crate::UlpsErrorEq::ulps_error_eq(result_val, reference_val, tol_val),
+        }
+    }};
+}
+
+/// Compare the accuracy of a computed result against a reference value of higher
+/// precision, in units of least precision of the result, for an error exceeding
+/// the tolerance.
+///
+/// For more details, see the documentation for [`UlpsErrorEq`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps_error_ne;
+/// #
+/// let result = 1.0_f32;
+/// let reference = 1.0_f64 + 1e-7_f64;
+///
+/// assert!(ulps_error_ne!(result, reference, ulps_error <= 0.5_f64));
+/// ```
+#[macro_export]
+macro_rules! ulps_error_ne {
+    ($result:expr, $reference:expr, ulps_error <= $tol:expr $(,)?) => {{
+        match (&$result, &$reference, &$tol) {
+            (result_val, reference_val, tol_val) => $crate::UlpsErrorEq::ulps_error_ne(result_val, reference_val, tol_val),
+        }
+    }};
+}
+
+/// Assert that the accuracy of a computed result against a reference value of
+/// higher precision is within a tolerance, in units of least precision of the
+/// result.
+///
+/// See the documentation for [`UlpsErrorEq`] for details about ulps error
+/// comparisons. See the documentation for [`AssertUlpsErrorEq`] for details about
+/// the debugging context provided when an assertion fails.
+///
+/// A failed assertion prints the path, the values, and the ulps error of its first
+/// failing element, followed by the number of failing elements and a list of the
+/// failing elements.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::assert_ulps_error_eq;
+/// #
+/// let results = vec![1.0_f32 / 3.0_f32, 1.0_f32 / 7.0_f32];
+/// let references = vec![1.0_f64 / 3.0_f64, 1.0_f64 / 7.0_f64];
+///
+/// assert_ulps_error_eq!(results, references, ulps_error <= 0.5_f64);
+/// ```
+#[macro_export]
+macro_rules! assert_ulps_error_eq {
+    ($result:expr, $reference:expr, ulps_error <= $tol:expr $(,)?) => {{
+        match (&$result, &$reference, &$tol) {
+            (result_val, reference_val, tol_val) => {
+                if !$crate::UlpsErrorEq::ulps_error_eq(&*result_val, &*reference_val, &*tol_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!("assertion failed: `ulps_error_eq!(result, reference, ulps_error <= t)`", "\n{}\n{}"),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertUlpsErrorEq::visit_ulps_error(&*result_val, &*reference_val, &*tol_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertUlpsErrorEq::visit_ulps_error(&*result_val, &*reference_val, &*tol_val, path, visitor)
+                        }),
+                    )
+                }
+            }
+        }
+    }};
+    ($result:expr, $reference:expr, ulps_error <= $tol:expr, $($arg:tt)+) => {{
+        match (&$result, &$reference, &$tol) {
+            (result_val, reference_val, tol_val) => {
+                if !$crate::UlpsErrorEq::ulps_error_eq(&*result_val, &*reference_val, &*tol_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(
+                        concat!("assertion failed: `ulps_error_eq!(result, reference, ulps_error <= t)`", "\n{}\n{}: {}"),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::AssertUlpsErrorEq::visit_ulps_error(&*result_val, &*reference_val, &*tol_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::AssertUlpsErrorEq::visit_ulps_error(&*result_val, &*reference_val, &*tol_val, path, visitor)
+                        }),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
+}
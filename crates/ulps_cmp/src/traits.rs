@@ -1,4 +1,16 @@
+use approx_cmp_report::{
+    Element,
+    ErrorStats,
+    FirstMismatch,
+    FloatPolicy,
+    Mismatch,
+    Path,
+    PolicyVisitor,
+    Visitor,
+};
+
 use core::fmt;
+use core::ops;
 
 /// Compare two sequences of finite precision floating point numbers using
 /// per entry units in last place (ulps) difference tolerances.
@@ -34,6 +46,11 @@ use core::fmt;
 /// The trait implementations for [`f32`] and [`f64`] provided perform an absolute
 /// difference comparison before the ulps difference comparison. Like relative
 /// comparisons, ulps comparisons are not generally meaningful for values near zero.
+/// They map each floating point number to the integer whose magnitude is the
+/// magnitude of its bit pattern, and whose sign is its sign, so that the ulps
+/// distance between two numbers counts the representable numbers between them on
+/// the number line, including across zero. Both zeros map to the same integer,
+/// and NaN is not ulps equal to any number.
 ///
 /// # Examples (Floating Point Number Comparisons)
 ///
@@ -77,6 +94,14 @@ use core::fmt;
 /// // Ulps comparisons are meaningless when `lhs` and `rhs` are near zero.
 /// assert!(ulps_ne!(lhs, rhs, abs_diff <= 0.0_f32, ulps <= 8388607_u32));
 /// assert!(ulps_eq!(lhs, rhs, abs_diff <= 0.0_f32, ulps <= 8388608_u32));
+///
+/// // The smallest positive and the smallest negative subnormal numbers are two
+/// // representable numbers apart.
+/// let lhs = f32::from_bits(1);
+/// let rhs = -f32::from_bits(1);
+///
+/// assert!(ulps_ne!(lhs, rhs, abs_diff <= 0.0_f32, ulps <= 1_u32));
+/// assert!(ulps_eq!(lhs, rhs, abs_diff <= 0.0_f32, ulps <= 2_u32));
 /// ```
 ///
 /// # Examples (Floating Point Sequence Comparisons)
@@ -103,6 +128,57 @@ use core::fmt;
 /// // Using the [`ulps_ne`] macro.
 /// assert!(ulps_ne!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps2));
 /// ```
+///
+/// # Examples (Integer Comparisons)
+///
+/// Consecutive integers are one ulp apart, so the ulps distance between two
+/// integers is their absolute difference, as an unsigned integer of the same
+/// width.
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     ulps_eq,
+/// #     ulps_ne,
+/// #     UlpsEq,
+/// # };
+/// #
+/// let lhs = -2_i32;
+/// let rhs = 3_i32;
+///
+/// assert!(ulps_eq!(lhs, rhs, abs_diff <= 0_u32, ulps <= 5_u32));
+/// assert!(ulps_ne!(lhs, rhs, abs_diff <= 0_u32, ulps <= 4_u32));
+/// assert!(ulps_eq!(i32::MIN, i32::MAX, abs_diff <= 0_u32, ulps <= u32::MAX));
+/// ```
+///
+/// # Examples (Mixed Precision Comparisons)
+///
+/// An [`f32`] compares with an [`f64`] in either order. The [`f32`] widens to an
+/// [`f64`] exactly, the absolute difference is measured in [`f64`] precision, and
+/// the ulps distance counts the [`f64`] numbers between the compared numbers, so
+/// the tolerances are an [`f64`] and a [`u64`]. One [`f32`] ulp near a number is
+/// `2^29` [`f64`] ulps near it.
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     ulps_eq,
+/// #     ulps_ne,
+/// #     UlpsEq,
+/// # };
+/// #
+/// let result = 1.0_f32;
+/// let reference = 1.0_f64 + f64::EPSILON;
+///
+/// assert!(result.ulps_eq(&reference, &0.0_f64, &1_u64));
+/// assert!(result.ulps_ne(&reference, &0.0_f64, &0_u64));
+/// assert!(ulps_eq!(reference, result, abs_diff <= 0.0_f64, ulps <= 1_u64));
+///
+/// // Containers of `f32` compare with containers of `f64` element by element.
+/// let results = vec![1.0_f32, 1.0_f32 + f32::EPSILON];
+/// let references = vec![1.0_f64, 1.0_f64];
+///
+/// assert!(ulps_eq!(results, references, abs_diff_all <= 0.0_f64, ulps_all <= 1_u64 << 29));
+/// assert!(ulps_ne!(results, references, abs_diff_all <= 0.0_f64, ulps_all <= (1_u64 << 29) - 1));
+/// ```
 pub trait UlpsEq<Rhs = Self>
 where
     Rhs: ?Sized,
@@ -584,6 +660,115 @@ where
     /// assert_eq!(result, expected);
     /// ```
     fn debug_ulps_tolerance(&self, other: &Rhs, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance;
+
+    /// Report every element compared by [`UlpsEq::ulps_eq`] to a visitor, along
+    /// with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, so that a failed
+    /// comparison can be traced to the elements that failed it. The traversal
+    /// stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ulps_cmp::{
+    /// #     AssertUlpsEq,
+    /// #     Element,
+    /// #     Path,
+    /// # };
+    /// # use core::ops::ControlFlow;
+    /// #
+    /// let lhs = [1.0_f64, 2.0_f64, 3.0_f64];
+    /// let rhs = [1.0_f64, 2.5_f64, 3.0_f64];
+    /// let max_abs_diff = [0.0_f64; 3];
+    /// let max_ulps = [4_u64; 3];
+    /// let mut failed = Vec::new();
+    /// let _ = lhs.visit_ulps(
+    ///     &rhs,
+    ///     &max_abs_diff,
+    ///     &max_ulps,
+    ///     &mut Path::new(),
+    ///     &mut |path: &Path<'_>, element: &Element<'_>| {
+    ///         if !element.passed {
+    ///             failed.push(path.to_string());
+    ///         }
+    ///
+    ///         ControlFlow::Continue(())
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(failed, vec!["[1]"]);
+    /// ```
+    fn visit_ulps<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        let abs_diff = self.debug_abs_diff(other);
+        let ulps_diff = self.debug_ulps_diff(other);
+        let abs_diff_tolerance = self.debug_abs_diff_tolerance(other, max_abs_diff);
+        let ulps_tolerance = self.debug_ulps_tolerance(other, max_ulps);
+        visitor.visit(
+            path,
+            &Element::new(
+                self.ulps_eq(other, max_abs_diff, max_ulps),
+                &[("abs_diff", &abs_diff), ("ulps", &ulps_diff)],
+                &[("abs_diff", &abs_diff_tolerance), ("ulps", &ulps_tolerance)],
+            ),
+        )
+    }
+
+    /// Compare two values for ulps difference equality, with the pairs of numbers
+    /// involving NaNs, infinities, or signed zeros decided by `policy`.
+    ///
+    /// The elements reported by [`visit_ulps`] decide the comparison, so the
+    /// policy reaches every number compared inside a container.
+    ///
+    /// [`visit_ulps`]: AssertUlpsEq::visit_ulps
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ulps_cmp::{
+    /// #     AssertUlpsEq,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = [1.0_f64, f64::NAN];
+    /// let rhs = [1.0_f64, f64::NAN];
+    /// let nan_eq = FloatPolicy {
+    ///     nan_eq: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(lhs.ulps_eq_with_policy(&rhs, &[0.0_f64; 2], &[4_u64; 2], &nan_eq));
+    /// assert!(!lhs.ulps_eq_with_policy(&rhs, &[0.0_f64; 2], &[4_u64; 2], &FloatPolicy::new()));
+    /// ```
+    fn ulps_eq_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        policy.passes(|path, visitor| self.visit_ulps(other, max_abs_diff, max_ulps, path, visitor))
+    }
+
+    /// Compare two values for ulps difference inequality, with the pairs of numbers
+    /// involving NaNs, infinities, or signed zeros decided by `policy`.
+    fn ulps_ne_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::Tolerance,
+        max_ulps: &Self::UlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        !Self::ulps_eq_with_policy(self, other, max_abs_diff, max_ulps, policy)
+    }
 }
 
 /// Provide a debugging context for when an ulps difference comparison fails.
@@ -639,11 +824,130 @@ where
     /// assert_eq!(result, expected);
     /// ```
     fn debug_ulps_all_tolerance(&self, other: &Rhs, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance;
+
+    /// Report every element compared by [`UlpsAllEq::ulps_all_eq`] to a visitor,
+    /// along with its path inside the compared values relative to `path`.
+    ///
+    /// The default implementation reports the two values as a single element.
+    /// Containers report each of their elements in turn, so that a failed
+    /// comparison can be traced to the elements that failed it. The traversal
+    /// stops as soon as the visitor returns [`ops::ControlFlow::Break`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ulps_cmp::{
+    /// #     AssertUlpsAllEq,
+    /// #     FirstMismatch,
+    /// #     Path,
+    /// # };
+    /// #
+    /// let lhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.0_f64]];
+    /// let rhs = [[1.0_f64, 2.0_f64], [3.0_f64, 4.5_f64]];
+    /// let mut first = FirstMismatch::new();
+    /// let _ = lhs.visit_ulps_all(&rhs, &0.0_f64, &4_u64, &mut Path::new(), &mut first);
+    ///
+    /// assert_eq!(first.path().map(|path| path.to_string()), Some(String::from("[1][1]")));
+    /// ```
+    fn visit_ulps_all<'a>(
+        &'a self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        path: &mut Path<'a>,
+        visitor: &mut dyn Visitor<'a>,
+    ) -> ops::ControlFlow<()> {
+        let abs_diff_tolerance = self.debug_abs_diff_all_tolerance(other, max_abs_diff);
+        let ulps_tolerance = self.debug_ulps_all_tolerance(other, max_ulps);
+        visitor.visit(
+            path,
+            &Element::new(
+                self.ulps_all_eq(other, max_abs_diff, max_ulps),
+                &[],
+                &[("abs_diff_all", &abs_diff_tolerance), ("ulps_all", &ulps_tolerance)],
+            ),
+        )
+    }
+
+    /// Compare two values for ulps difference equality using uniform tolerances, with
+    /// the pairs of numbers involving NaNs, infinities, or signed zeros decided by
+    /// `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ulps_cmp::{
+    /// #     AssertUlpsAllEq,
+    /// #     FloatPolicy,
+    /// # };
+    /// #
+    /// let lhs = [-0.0_f32, 1.0_f32];
+    /// let rhs = [0.0_f32, 1.0_f32];
+    /// let signed_zero_strict = FloatPolicy {
+    ///     signed_zero_strict: true,
+    ///     ..FloatPolicy::new()
+    /// };
+    ///
+    /// assert!(lhs.ulps_all_eq_with_policy(&rhs, &0.0_f32, &4_u32, &FloatPolicy::new()));
+    /// assert!(!lhs.ulps_all_eq_with_policy(&rhs, &0.0_f32, &4_u32, &signed_zero_strict));
+    /// ```
+    fn ulps_all_eq_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        policy.passes(|path, visitor| self.visit_ulps_all(other, max_abs_diff, max_ulps, path, visitor))
+    }
+
+    /// Compare two values for ulps difference inequality using uniform tolerances, with
+    /// the pairs of numbers involving NaNs, infinities, or signed zeros decided by
+    /// `policy`.
+    fn ulps_all_ne_with_policy(
+        &self,
+        other: &Rhs,
+        max_abs_diff: &Self::AllTolerance,
+        max_ulps: &Self::AllUlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> bool {
+        !Self::ulps_all_eq_with_policy(self, other, max_abs_diff, max_ulps, policy)
+    }
 }
 
+/// The failed ulps difference comparison returned by [`check_ulps_eq`] and
+/// [`check_ulps_ne`].
+///
+/// The debugging values are the ones computed by [`AssertUlpsEq`].
+pub type UlpsMismatch<'a, A, B> = Mismatch<
+    'a,
+    A,
+    B,
+    (<A as AssertUlpsEq<B>>::DebugAbsDiff, <A as AssertUlpsEq<B>>::DebugUlpsDiff),
+    (<A as AssertUlpsEq<B>>::DebugTolerance, <A as AssertUlpsEq<B>>::DebugUlpsTolerance),
+>;
+
+/// The failed ulps difference comparison returned by [`check_ulps_eq`] and
+/// [`check_ulps_ne`] with `all` parameters.
+///
+/// The debugging values are the ones computed by [`AssertUlpsAllEq`].
+pub type UlpsAllMismatch<'a, A, B> = Mismatch<
+    'a,
+    A,
+    B,
+    (<A as AssertUlpsEq<B>>::DebugAbsDiff, <A as AssertUlpsEq<B>>::DebugUlpsDiff),
+    (
+        <A as AssertUlpsAllEq<B>>::AllDebugTolerance,
+        <A as AssertUlpsAllEq<B>>::AllDebugUlpsTolerance,
+    ),
+>;
+
 #[doc(hidden)]
 pub struct UlpsCmp {}
 
+// The mismatches hold their paths inline, so that they can be returned without
+// allocating.
+#[allow(clippy::result_large_err)]
 impl UlpsCmp {
     #[must_use]
     #[inline]
@@ -684,6 +988,270 @@ impl UlpsCmp {
     {
         A::ulps_all_ne(lhs, rhs, max_abs_diff, max_ulps)
     }
+
+    #[inline]
+    pub fn check_eq<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_ulps: &A::UlpsTolerance,
+    ) -> Result<(), UlpsMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::ulps_eq(lhs, rhs, max_abs_diff, max_ulps) {
+            return Ok(());
+        }
+
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_ulps(lhs, rhs, max_abs_diff, max_ulps, &mut Path::new(), &mut first);
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_tolerance(lhs, rhs, max_ulps),
+            ),
+            path: first.into_path().unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn check_ne<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_ulps: &A::UlpsTolerance,
+    ) -> Result<(), UlpsMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::ulps_ne(lhs, rhs, max_abs_diff, max_ulps) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_tolerance(lhs, rhs, max_ulps),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_eq<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_ulps: &A::AllUlpsTolerance,
+    ) -> Result<(), UlpsAllMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + AssertUlpsAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::ulps_all_eq(lhs, rhs, max_abs_diff, max_ulps) {
+            return Ok(());
+        }
+
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_ulps_all(lhs, rhs, max_abs_diff, max_ulps, &mut Path::new(), &mut first);
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_all_tolerance(lhs, rhs, max_ulps),
+            ),
+            path: first.into_path().unwrap_or_default(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_ne<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_ulps: &A::AllUlpsTolerance,
+    ) -> Result<(), UlpsAllMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + AssertUlpsAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::ulps_all_ne(lhs, rhs, max_abs_diff, max_ulps) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_all_tolerance(lhs, rhs, max_ulps),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_eq_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_ulps: &A::UlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), UlpsMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_ulps(
+            lhs,
+            rhs,
+            max_abs_diff,
+            max_ulps,
+            &mut Path::new(),
+            &mut PolicyVisitor::new(*policy, &mut first),
+        );
+        let Some(path) = first.into_path() else {
+            return Ok(());
+        };
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_tolerance(lhs, rhs, max_ulps),
+            ),
+            path,
+        })
+    }
+
+    #[inline]
+    pub fn check_ne_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::Tolerance,
+        max_ulps: &A::UlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), UlpsMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::ulps_ne_with_policy(lhs, rhs, max_abs_diff, max_ulps, policy) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_tolerance(lhs, rhs, max_ulps),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[inline]
+    pub fn check_all_eq_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_ulps: &A::AllUlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), UlpsAllMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + AssertUlpsAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        let mut first = FirstMismatch::new();
+        let _ = A::visit_ulps_all(
+            lhs,
+            rhs,
+            max_abs_diff,
+            max_ulps,
+            &mut Path::new(),
+            &mut PolicyVisitor::new(*policy, &mut first),
+        );
+        let Some(path) = first.into_path() else {
+            return Ok(());
+        };
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_all_tolerance(lhs, rhs, max_ulps),
+            ),
+            path,
+        })
+    }
+
+    #[inline]
+    pub fn check_all_ne_with_policy<'a, A, B>(
+        lhs: &'a A,
+        rhs: &'a B,
+        max_abs_diff: &A::AllTolerance,
+        max_ulps: &A::AllUlpsTolerance,
+        policy: &FloatPolicy,
+    ) -> Result<(), UlpsAllMismatch<'a, A, B>>
+    where
+        A: AssertUlpsEq<B> + AssertUlpsAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        if A::ulps_all_ne_with_policy(lhs, rhs, max_abs_diff, max_ulps, policy) {
+            return Ok(());
+        }
+
+        Err(Mismatch {
+            left: lhs,
+            right: rhs,
+            diffs: (A::debug_abs_diff(lhs, rhs), A::debug_ulps_diff(lhs, rhs)),
+            tolerances: (
+                A::debug_abs_diff_all_tolerance(lhs, rhs, max_abs_diff),
+                A::debug_ulps_all_tolerance(lhs, rhs, max_ulps),
+            ),
+            path: Path::new(),
+        })
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn error_stats<'a, A, B>(lhs: &'a A, rhs: &B, max_abs_diff: &A::Tolerance, max_ulps: &A::UlpsTolerance) -> ErrorStats<'a>
+    where
+        A: AssertUlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        ErrorStats::from_visit(|path, visitor| A::visit_ulps(lhs, rhs, max_abs_diff, max_ulps, path, visitor))
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn error_stats_all<'a, A, B>(lhs: &'a A, rhs: &B, max_abs_diff: &A::AllTolerance, max_ulps: &A::AllUlpsTolerance) -> ErrorStats<'a>
+    where
+        A: AssertUlpsAllEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        ErrorStats::from_visit(|path, visitor| A::visit_ulps_all(lhs, rhs, max_abs_diff, max_ulps, path, visitor))
+    }
 }
 
 #[doc(hidden)]
@@ -728,16 +1296,30 @@ impl UlpsCmpOpTol {
 ///
 /// For more details, see the documentation for [`UlpsEq`] and [`UlpsAllEq`].
 ///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
 /// # Example
 ///
 /// ```
-/// # use ulps_cmp::ulps_eq;
+/// # use ulps_cmp::{
+/// #     FloatPolicy,
+/// #     ulps_eq,
+/// # };
 /// #
 /// let lhs = 98.0005_f32;
 /// let rhs = 98.0001_f32;
 ///
 /// assert!(ulps_eq!(lhs, rhs, abs_diff <= 0.0_f32, ulps <= 60_u32));
 /// assert!(ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 60_u32));
+///
+/// let nan_eq = FloatPolicy {
+///     nan_eq: true,
+///     ..FloatPolicy::new()
+/// };
+///
+/// assert!(!ulps_eq!(f32::NAN, f32::NAN, abs_diff <= 0.0_f32, ulps <= 60_u32));
+/// assert!(ulps_eq!(f32::NAN, f32::NAN, abs_diff <= 0.0_f32, ulps <= 60_u32, policy = nan_eq));
 /// ```
 #[macro_export]
 macro_rules! ulps_eq {
@@ -761,6 +1343,13 @@ macro_rules! ulps_eq {
             (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsCmp::all_eq(left_val, right_val, tol_1_val, tol_2_val),
         }
     }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => $crate::FloatPolicy::passes(policy_val, |path, visitor| {
+                $crate::__ulps_visit!(left_val, right_val, $eq1 <= tol_1_val, $eq2 <= tol_2_val, path, visitor)
+            }),
+        }
+    }};
 }
 
 /// Compare two finite precision floating point expression for ulps
@@ -801,6 +1390,146 @@ macro_rules! ulps_ne {
             (left_val, right_val, tol_1_val, tol_2_val) => $crate::UlpsCmp::all_ne(left_val, right_val, tol_1_val, tol_2_val),
         }
     }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => !$crate::FloatPolicy::passes(policy_val, |path, visitor| {
+                $crate::__ulps_visit!(left_val, right_val, $eq1 <= tol_1_val, $eq2 <= tol_2_val, path, visitor)
+            }),
+        }
+    }};
+}
+
+/// Check whether two finite precision floating point expressions are ulps
+/// difference equal, without panicking.
+///
+/// The macro returns `Ok(())` when the comparison succeeds, and a [`Mismatch`]
+/// otherwise. The mismatch holds the compared values, their absolute and ulps
+/// differences, their tolerances, and the path of the first element that failed
+/// the comparison. The mismatch borrows the compared values, so they must outlive it.
+///
+/// See the documentation for [`UlpsEq`] and [`UlpsAllEq`] for details about
+/// ulps difference comparisons. See the documentation for [`AssertUlpsEq`] and
+/// [`AssertUlpsAllEq`] for details about the debugging values reported when a
+/// comparison fails.
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::check_ulps_eq;
+/// #
+/// let lhs = [98.0005_f32, 99.0_f32, 100.0_f32];
+/// let rhs = [98.0001_f32, 99.0_f32, 100.5_f32];
+///
+/// assert!(check_ulps_eq!(lhs, rhs, abs_diff <= [0.0_f32; 3], ulps <= [60_u32; 3]).is_err());
+/// assert!(check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.6_f32, ulps_all <= 0_u32).is_ok());
+///
+/// let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 60_u32).unwrap_err();
+///
+/// assert_eq!(mismatch.path.to_string(), "[2]");
+/// assert_eq!(mismatch.diffs.0[2], 0.5_f32);
+/// ```
+#[macro_export]
+macro_rules! check_ulps_eq {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {
+        $crate::UlpsCmp::check_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::UlpsCmp::check_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, ulps_all <= $tol_2:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, ulps_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_eq(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, ulps_all <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, ulps_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_eq_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+}
+
+/// Check whether two finite precision floating point expressions are ulps
+/// difference unequal, without panicking.
+///
+/// The macro returns `Ok(())` when the comparison succeeds, and a [`Mismatch`]
+/// otherwise. Since no single element fails an inequality comparison, the path
+/// of the mismatch is the path of the compared values themselves.
+///
+/// See the documentation for [`UlpsEq`] and [`UlpsAllEq`] for details about
+/// ulps difference comparisons. See the documentation for [`AssertUlpsEq`] and
+/// [`AssertUlpsAllEq`] for details about the debugging values reported when a
+/// comparison fails.
+///
+/// A trailing `policy = ...` argument decides the pairs of numbers involving
+/// NaNs, infinities, or signed zeros with a [`FloatPolicy`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::check_ulps_ne;
+/// #
+/// let lhs = 98.0005_f32;
+/// let rhs = 98.0001_f32;
+///
+/// assert!(check_ulps_ne!(lhs, rhs, abs_diff <= 0.0_f32, ulps <= 20_u32).is_ok());
+/// assert!(check_ulps_ne!(lhs, rhs, ulps_all <= 60_u32, abs_diff_all <= 0.0_f32).is_err());
+/// ```
+#[macro_export]
+macro_rules! check_ulps_ne {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr $(,)?) => {
+        $crate::UlpsCmp::check_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr $(,)?) => {
+        $crate::UlpsCmp::check_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, ulps_all <= $tol_2:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, ulps_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_ne(&$left, &$right, &$tol_1, &$tol_2)
+    };
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, ulps_all <= $tol_2:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+    ($left:expr, $right:expr, ulps_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, policy = $policy:expr $(,)?) => {
+        $crate::UlpsCmp::check_all_ne_with_policy(&$left, &$right, &$tol_1, &$tol_2, &$policy)
+    };
+}
+
+// Visit the elements of two values with tolerances given in either order, for
+// the panic messages of the assertion macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ulps_visit {
+    ($left:expr, $right:expr, abs_diff <= $tol_1:expr, ulps <= $tol_2:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertUlpsEq::visit_ulps($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+    ($left:expr, $right:expr, ulps <= $tol_2:expr, abs_diff <= $tol_1:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertUlpsEq::visit_ulps($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+    ($left:expr, $right:expr, abs_diff_all <= $tol_1:expr, ulps_all <= $tol_2:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertUlpsAllEq::visit_ulps_all($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
+    ($left:expr, $right:expr, ulps_all <= $tol_2:expr, abs_diff_all <= $tol_1:expr, $path:expr, $visitor:expr $(,)?) => {
+        $crate::AssertUlpsAllEq::visit_ulps_all($left, $right, $tol_1, $tol_2, $path, $visitor)
+    };
 }
 
 /// Assert that two finite precision floating point expressions are ulps
@@ -811,6 +1540,12 @@ macro_rules! ulps_ne {
 /// and [`AssertUlpsAllEq`] for details about the debugging context provided
 /// when an assertion fails.
 ///
+/// A failed assertion prints the path and the values of its first failing
+/// element, followed by the number of failing elements and a list of the
+/// failing elements, so that the panic message stays short for large
+/// containers. Enable the `full_dump` feature to print the compared values in
+/// full as well.
+///
 /// # Example
 ///
 /// ```
@@ -824,6 +1559,87 @@ macro_rules! ulps_ne {
 /// ```
 #[macro_export]
 macro_rules! assert_ulps_eq {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::ulps_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+{}
+{}{}"#),
+                        &*policy_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::ulps_eq!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+{}
+{}{}: {}"#),
+                        &*policy_val,
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, &mut $crate::PolicyVisitor::new(*policy_val, visitor))
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr $(,)?) => {{
         match (&$left, &$right, &$tol_1, &$tol_2) {
             (left_val, right_val, tol_1_val, tol_2_val) => {
@@ -833,20 +1649,30 @@ macro_rules! assert_ulps_eq {
                     // noticeable slow down. See the documentation for `core::assert_eq`.
                     panic!(concat!(
 "assertion failed: `ulps_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+{}
+{}{}"#),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
         left: `{:?}`,
        right: `{:?}`,
     abs_diff: `{:?}`,
    ulps_diff: `{:?}`,
 {:>10} t: `{:?}`,
-{:>10} t: `{:?}`"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
                     )
                 }
             }
@@ -861,20 +1687,30 @@ macro_rules! assert_ulps_eq {
                     // noticeable slow down. See the documentation for `core::assert_eq`.
                     panic!(concat!(
 "assertion failed: `ulps_eq!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t)`", r#"
+{}
+{}{}: {}"#),
+                        $crate::FirstFailure::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FailureSummary::new(|path, visitor| {
+                            $crate::__ulps_visit!(&*left_val, &*right_val, $eq1 <= &*tol_1_val, $eq2 <= &*tol_2_val, path, visitor)
+                        }),
+                        $crate::FullDump::new(format_args!(r#"
         left: `{:?}`,
        right: `{:?}`,
     abs_diff: `{:?}`,
    ulps_diff: `{:?}`,
 {:>10} t: `{:?}`,
-{:>10} t: `{:?}`: {}"#),
-                        &*left_val,
-                        &*right_val,
-                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
-                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
-                        concat!("[", stringify!($eq1), "]"),
-                        $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
-                        concat!("[", stringify!($eq2), "]"),
-                        $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+{:>10} t: `{:?}`"#,
+                            &*left_val,
+                            &*right_val,
+                            $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                            $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                            concat!("[", stringify!($eq1), "]"),
+                            $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                            concat!("[", stringify!($eq2), "]"),
+                            $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        )),
                         format_args!($($arg)+),
                     )
                 }
@@ -904,6 +1740,67 @@ macro_rules! assert_ulps_eq {
 /// ```
 #[macro_export]
 macro_rules! assert_ulps_ne {
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr $(,)?) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::ulps_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`"#),
+                        &*policy_val,
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        concat!("[", stringify!($eq2), "]"),
+                        $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                    )
+                }
+            }
+        }
+    }};
+    ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr, policy = $policy:expr, $($arg:tt)+) => {{
+        match (&$left, &$right, &$tol_1, &$tol_2, &$policy) {
+            (left_val, right_val, tol_1_val, tol_2_val, policy_val) => {
+                if !$crate::ulps_ne!(*left_val, *right_val, $eq1 <= *tol_1_val, $eq2 <= *tol_2_val, policy = *policy_val) {
+                    // The reborrows below are intentional. Without them, the stack slot for the
+                    // borrow is initialized even before the values are compared, leading to a
+                    // noticeable slow down. See the documentation for `core::assert_eq`.
+                    panic!(concat!(
+"assertion failed: `ulps_ne!(left, right, ", stringify!($eq1), " <= t, ", stringify!($eq2),  " <= t, policy = p)`", r#"
+      policy: `{:?}`,
+        left: `{:?}`,
+       right: `{:?}`,
+    abs_diff: `{:?}`,
+   ulps_diff: `{:?}`,
+{:>10} t: `{:?}`,
+{:>10} t: `{:?}`: {}"#),
+                        &*policy_val,
+                        &*left_val,
+                        &*right_val,
+                        $crate::AssertUlpsEq::debug_abs_diff(&*left_val, &*right_val),
+                        $crate::AssertUlpsEq::debug_ulps_diff(&*left_val, &*right_val),
+                        concat!("[", stringify!($eq1), "]"),
+                        $crate::UlpsCmpOpTol::$eq1(&*left_val, &*right_val, &*tol_1_val),
+                        concat!("[", stringify!($eq2), "]"),
+                        $crate::UlpsCmpOpTol::$eq2(&*left_val, &*right_val, &*tol_2_val),
+                        format_args!($($arg)+),
+                    )
+                }
+            }
+        }
+    }};
     ($left:expr, $right:expr, $eq1:ident <= $tol_1:expr, $eq2:ident <= $tol_2:expr $(,)?) => {{
         match (&$left, &$right, &$tol_1, &$tol_2) {
             (left_val, right_val, tol_1_val, tol_2_val) => {
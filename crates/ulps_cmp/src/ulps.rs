@@ -0,0 +1,354 @@
+//! Units in last place (ulps) arithmetic on floating point numbers.
+//!
+//! The functions in this module count and step through the representable
+//! floating point numbers the same way the ulps comparisons of this crate do.
+//! Each floating point number maps to the integer whose magnitude is the
+//! magnitude of its bit pattern, and whose sign is its sign, so that the
+//! representable numbers lie on a monotone integer line. On this line
+//!
+//! * both zeros share a position, so they are zero ulps apart,
+//! * the smallest positive and negative subnormal numbers are one ulp away from
+//!   zero, and two ulps apart,
+//! * the infinities are one ulp beyond the largest finite numbers of the same
+//!   sign,
+//! * NaN has no position, so it has no ulps distance to any number, and stepping
+//!   from NaN returns NaN.
+//!
+//! # Example
+//!
+//! ```
+//! # use ulps_cmp::ulps;
+//! #
+//! let tiny = f64::from_bits(1);
+//!
+//! assert_eq!(ulps::ulps_distance(tiny, -tiny), Some(2));
+//! assert_eq!(ulps::next_up(-tiny), -0.0_f64);
+//! assert_eq!(ulps::next_down(0.0_f64), -tiny);
+//! assert_eq!(ulps::step_by_ulps(-tiny, 2), tiny);
+//! assert_eq!(ulps::ulp(1.0_f64), f64::EPSILON);
+//! ```
+use crate::explain::UlpsExplanation;
+use crate::reference::AssertUlpsErrorEq;
+use crate::traits::AssertUlpsEq;
+use approx_cmp_float::{
+    Float,
+    FloatBits,
+};
+
+/// The position of `value` on the monotone integer line of the representable
+/// numbers, or `None` when `value` is NaN.
+#[inline]
+fn position<T>(value: T) -> Option<i128>
+where
+    T: Float,
+{
+    if value.is_nan() {
+        return None;
+    }
+
+    let sign = 1_u128 << (T::Bits::BITS - 1);
+    let bits = value.to_bits().into_u128();
+    // The magnitude is below the sign bit of a `u128`, so it fits in an `i128`.
+    let magnitude = (bits & !sign) as i128;
+
+    if bits & sign == 0 { Some(magnitude) } else { Some(-magnitude) }
+}
+
+/// The number at `position` on the monotone integer line of the representable
+/// numbers, taking the sign of `zero_sign` when `position` is zero.
+#[inline]
+fn from_position<T>(position: i128, zero_sign: T) -> T
+where
+    T: Float,
+{
+    let sign = 1_u128 << (T::Bits::BITS - 1);
+    let bits = if position > 0 {
+        position.unsigned_abs()
+    } else if position < 0 {
+        sign | position.unsigned_abs()
+    } else {
+        zero_sign.to_bits().into_u128() & sign
+    };
+
+    T::from_bits(T::Bits::from_u128(bits))
+}
+
+/// Compare two floating point numbers with an absolute difference tolerance,
+/// followed by an ulps tolerance.
+///
+/// This is the comparison of [`UlpsEq::ulps_eq`](crate::UlpsEq::ulps_eq) for
+/// [`f32`] and [`f64`], available to every number type implementing [`Float`].
+/// The numbers are ulps equal when their absolute difference is within
+/// `max_abs_diff`, or when their ulps distance is within `max_ulps`. NaN is not
+/// ulps equal to any number.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// assert!(ulps::ulps_eq(1.0_f64, ulps::step_by_ulps(1.0_f64, 4), 0.0_f64, 4_u64));
+/// assert!(!ulps::ulps_eq(1.0_f64, ulps::step_by_ulps(1.0_f64, 5), 0.0_f64, 4_u64));
+/// assert!(ulps::ulps_eq(1e-30_f64, -1e-30_f64, 1e-29_f64, 0_u64));
+/// ```
+#[inline]
+pub fn ulps_eq<T>(lhs: T, rhs: T, max_abs_diff: T, max_ulps: T::Bits) -> bool
+where
+    T: Float,
+{
+    explain_ulps(lhs, rhs, max_abs_diff, max_ulps).passed()
+}
+
+/// Compare two floating point numbers with [`ulps_eq`], after flushing their
+/// subnormal numbers to the zeros of the same signs.
+///
+/// This is the comparison of [`UlpsEqFtz`](crate::UlpsEqFtz), available to every
+/// number type implementing [`Float`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// assert!(!ulps::ulps_eq(0.0_f64, 1e-310_f64, 0.0_f64, 4_u64));
+/// assert!(ulps::ulps_eq_ftz(0.0_f64, 1e-310_f64, 0.0_f64, 4_u64));
+/// ```
+#[inline]
+pub fn ulps_eq_ftz<T>(lhs: T, rhs: T, max_abs_diff: T, max_ulps: T::Bits) -> bool
+where
+    T: Float,
+{
+    ulps_eq(lhs.flush_to_zero(), rhs.flush_to_zero(), max_abs_diff, max_ulps)
+}
+
+/// Explain the outcome of comparing two floating point numbers with [`ulps_eq`].
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::{
+/// #     ulps,
+/// #     UlpsExplanation,
+/// # };
+/// #
+/// let lhs = 1.0_f32;
+/// let rhs = ulps::step_by_ulps(lhs, 3);
+///
+/// assert_eq!(ulps::explain_ulps(lhs, rhs, 0.0_f32, 2_u32), UlpsExplanation::FailedUlps { distance: 3 });
+/// ```
+pub fn explain_ulps<T>(lhs: T, rhs: T, max_abs_diff: T, max_ulps: T::Bits) -> UlpsExplanation
+where
+    T: Float,
+{
+    // Check whether the two numbers `lhs` and `rhs` are NaN. NaNs have no position
+    // on the number line, so they are never ulps equal.
+    let (Some(position_lhs), Some(position_rhs)) = (position(lhs), position(rhs)) else {
+        return UlpsExplanation::FailedNan;
+    };
+
+    // First check whether the two numbers `lhs` and `rhs` are really close together.
+    if (lhs - rhs).abs() <= max_abs_diff {
+        return UlpsExplanation::PassedAbsDiff;
+    }
+
+    // Count the representable numbers between `lhs` and `rhs` on the number line,
+    // including across zero.
+    let distance = i128::abs_diff(position_lhs, position_rhs);
+    let passed = distance <= max_ulps.into_u128();
+    let distance = u64::try_from(distance).unwrap_or(u64::MAX);
+    if passed {
+        UlpsExplanation::PassedUlps { distance }
+    } else {
+        UlpsExplanation::FailedUlps { distance }
+    }
+}
+
+/// Count the representable numbers between `lhs` and `rhs`.
+///
+/// Returns `None` when either number is NaN. This is the ulps distance [`ulps_eq`]
+/// compares against its ulps tolerance.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// assert_eq!(ulps::ulps_distance(1.0_f32, 1.0000002_f32), Some(2));
+/// assert_eq!(ulps::ulps_distance(-0.0_f32, 0.0_f32), Some(0));
+/// assert_eq!(ulps::ulps_distance(f32::MAX, f32::INFINITY), Some(1));
+/// assert_eq!(ulps::ulps_distance(f32::NAN, 1.0_f32), None);
+/// ```
+#[inline]
+pub fn ulps_distance<T>(lhs: T, rhs: T) -> Option<T::Bits>
+where
+    T: Float,
+{
+    // The distance between two positions of the line is at most twice the position
+    // of the infinities, which fits in the bit pattern type.
+    let distance = i128::abs_diff(position(lhs)?, position(rhs)?);
+
+    Some(T::Bits::from_u128(distance))
+}
+
+/// The next representable number above `value`.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// assert_eq!(ulps::next_up(1.0_f64), 1.0_f64 + f64::EPSILON);
+/// assert_eq!(ulps::next_up(-0.0_f64), f64::from_bits(1));
+/// assert_eq!(ulps::next_up(f64::MAX), f64::INFINITY);
+/// assert_eq!(ulps::next_up(f64::INFINITY), f64::INFINITY);
+/// ```
+#[inline]
+pub fn next_up<T>(value: T) -> T
+where
+    T: Float,
+{
+    step_by_ulps(value, 1)
+}
+
+/// The next representable number below `value`.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// assert_eq!(ulps::next_down(1.0_f64), 1.0_f64 - f64::EPSILON / 2.0_f64);
+/// assert_eq!(ulps::next_down(f64::from_bits(1)), 0.0_f64);
+/// assert_eq!(ulps::next_down(f64::NEG_INFINITY), f64::NEG_INFINITY);
+/// ```
+#[inline]
+pub fn next_down<T>(value: T) -> T
+where
+    T: Float,
+{
+    step_by_ulps(value, -1)
+}
+
+/// Step `steps` representable numbers up from `value` when `steps` is positive,
+/// or down from `value` when `steps` is negative.
+///
+/// The steps saturate at the infinities, and cross zero the same way the ulps
+/// distance does. A step that lands on zero returns the zero with the sign of
+/// `value`, and stepping from NaN returns NaN.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// let value = 3.0_f32;
+/// let stepped = ulps::step_by_ulps(value, 5);
+///
+/// assert_eq!(ulps::ulps_distance(value, stepped), Some(5));
+/// assert_eq!(ulps::step_by_ulps(stepped, -5), value);
+/// assert_eq!(ulps::step_by_ulps(f32::MAX, i64::MAX), f32::INFINITY);
+/// ```
+#[inline]
+pub fn step_by_ulps<T>(value: T, steps: i64) -> T
+where
+    T: Float,
+{
+    let Some(start) = position(value) else {
+        return value;
+    };
+    if steps == 0 {
+        return value;
+    }
+
+    let end = position(T::INFINITY).unwrap_or(i128::MAX);
+    let target = i128::clamp(start + i128::from(steps), -end, end);
+
+    from_position(target, value)
+}
+
+/// The size of one unit in the last place of `value`.
+///
+/// This is the distance from the magnitude of `value` to the next representable
+/// number of larger magnitude, or for the largest finite numbers, to the next
+/// representable number of smaller magnitude. The ulp of the infinities is
+/// infinite, and the ulp of NaN is NaN.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// assert_eq!(ulps::ulp(1.0_f32), f32::EPSILON);
+/// assert_eq!(ulps::ulp(-2.0_f32), 2.0_f32 * f32::EPSILON);
+/// assert_eq!(ulps::ulp(0.0_f32), f32::from_bits(1));
+/// assert_eq!(ulps::ulp(f32::INFINITY), f32::INFINITY);
+/// ```
+#[inline]
+pub fn ulp<T>(value: T) -> T
+where
+    T: Float,
+{
+    let magnitude = value.abs();
+    if magnitude.is_infinite() {
+        magnitude
+    } else if magnitude == T::MAX {
+        magnitude - next_down(magnitude)
+    } else {
+        next_up(magnitude) - magnitude
+    }
+}
+
+/// Count the representable numbers between each pair of corresponding floating
+/// point numbers of two values.
+///
+/// The distances have the shape of the compared values, as computed by
+/// [`AssertUlpsEq::debug_ulps_diff`]. For example, the distances between two
+/// vectors of floating point numbers form a vector of distances, or `None` when
+/// the vectors have different lengths.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// let lhs = vec![1.0_f64, -0.0_f64, f64::NAN];
+/// let rhs = vec![ulps::step_by_ulps(1.0_f64, 3), f64::from_bits(1), 1.0_f64];
+///
+/// assert_eq!(ulps::ulps_distances(&lhs, &rhs), Some(vec![Some(3), Some(1), None]));
+/// ```
+#[inline]
+pub fn ulps_distances<A, B>(lhs: &A, rhs: &B) -> A::DebugUlpsDiff
+where
+    A: AssertUlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    AssertUlpsEq::debug_ulps_diff(lhs, rhs)
+}
+
+/// The ulps errors of the elements of a computed result against a reference value
+/// of higher precision, in fractional ulps of the precision of the result.
+///
+/// The errors have the shape of the result, as computed by
+/// [`AssertUlpsErrorEq::debug_ulps_error`]. For example, the errors of a vector
+/// of `f32` results against a vector of `f64` references form a vector of errors,
+/// or `None` when the vectors have different lengths.
+///
+/// # Example
+///
+/// ```
+/// # use ulps_cmp::ulps;
+/// #
+/// let results = vec![1.0_f32, 1.0_f32 + f32::EPSILON, f32::INFINITY];
+/// let references = vec![1.0_f64, 1.0_f64 + f64::from(f32::EPSILON) / 4.0_f64, 1.0_f64];
+///
+/// assert_eq!(ulps::ulps_error(&results, &references), Some(vec![0.0_f64, 0.75_f64, f64::INFINITY]));
+/// assert_eq!(ulps::ulps_error(&0.0_f32, &(f64::from(f32::from_bits(1)) / 2.0_f64)), 0.5_f64);
+/// ```
+#[inline]
+pub fn ulps_error<A, B>(result: &A, reference: &B) -> A::DebugUlpsError
+where
+    A: AssertUlpsErrorEq<B> + ?Sized,
+    B: ?Sized,
+{
+    AssertUlpsErrorEq::debug_ulps_error(result, reference)
+}
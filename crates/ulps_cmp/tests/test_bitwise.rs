@@ -0,0 +1,88 @@
+use ulps_cmp::{
+    BitwiseEq,
+    assert_bitwise_eq,
+    bitwise_eq,
+    bitwise_ne,
+};
+
+#[test]
+fn test_bitwise_eq_f32() {
+    let nan = f32::from_bits(0x7fc0_0001);
+
+    assert!(bitwise_eq!(1.0_f32, 1.0_f32));
+    assert!(bitwise_eq!(nan, nan));
+    assert!(bitwise_eq!(f32::INFINITY, f32::INFINITY));
+    assert!(bitwise_eq!(-0.0_f32, -0.0_f32));
+    assert!(bitwise_ne!(0.0_f32, -0.0_f32));
+    assert!(bitwise_ne!(nan, f32::NAN));
+    assert!(bitwise_ne!(f32::NAN, -f32::NAN));
+    assert!(bitwise_ne!(1.0_f32, 1.0_f32 + f32::EPSILON));
+}
+
+#[test]
+fn test_bitwise_eq_f64() {
+    let nan = f64::from_bits(0x7ff8_0000_0000_0002);
+
+    assert!(bitwise_eq!(0.1_f64 + 0.2_f64, 0.1_f64 + 0.2_f64));
+    assert!(bitwise_eq!(nan, nan));
+    assert!(bitwise_ne!(0.1_f64 + 0.2_f64, 0.3_f64));
+    assert!(bitwise_ne!(nan, f64::NAN));
+    assert!(bitwise_ne!(0.0_f64, -0.0_f64));
+}
+
+#[test]
+fn test_containers() {
+    let lhs = [1.0_f64, -0.0_f64, f64::NAN];
+    let rhs = [1.0_f64, -0.0_f64, f64::NAN];
+
+    assert!(bitwise_eq!(lhs, rhs));
+    assert!(bitwise_eq!(&lhs[..], &rhs[..]));
+    assert!(bitwise_eq!(lhs.to_vec(), rhs.to_vec()));
+    assert!(bitwise_ne!(&lhs[..2], &rhs[..]));
+    assert!(bitwise_ne!(lhs, [1.0_f64, 0.0_f64, f64::NAN]));
+    assert!(lhs.bitwise_eq(&rhs));
+    assert!(vec![0.0_f32].bitwise_ne(&vec![-0.0_f32]));
+}
+
+#[test]
+fn test_assert_bitwise_eq() {
+    assert_bitwise_eq!(f64::NAN, f64::NAN);
+    assert_bitwise_eq!([1.0_f32, -0.0_f32], [1.0_f32, -0.0_f32]);
+    assert_bitwise_eq!(vec![1.0_f64], vec![1.0_f64], "message {}", 1);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `bitwise_eq!(left, right)`
+first failure at `[1]`:
+        left: `0.0`,
+       right: `-0.0`,
+   left_bits: `0x00000000`,
+  right_bits: `0x80000000`,
+        ulps: `Some(0)`,
+failed elements: 1 of 2")]
+fn test_assert_bitwise_eq_signed_zero_panic_message() {
+    assert_bitwise_eq!([1.0_f32, 0.0_f32], [1.0_f32, -0.0_f32]);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `<root>`:
+        left: `NaN`,
+       right: `NaN`,
+   left_bits: `0x7ff8000000000001`,
+  right_bits: `0x7ff8000000000000`,
+        ulps: `None`,")]
+fn test_assert_bitwise_eq_nan_payload_panic_message() {
+    assert_bitwise_eq!(f64::from_bits(0x7ff8_0000_0000_0001), f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "ulps: `Some(1)`,")]
+fn test_assert_bitwise_eq_ulps_panic_message() {
+    assert_bitwise_eq!(1.0_f64, 1.0_f64 + f64::EPSILON);
+}
+
+#[test]
+#[should_panic(expected = "custom message")]
+fn test_assert_bitwise_eq_panic_custom_message() {
+    assert_bitwise_eq!(vec![1.0_f64], vec![1.0_f64, 2.0_f64], "custom message");
+}
@@ -711,7 +711,7 @@ fn test_ne_empty4() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = BTreeMap::from([
         ("0", 1.0_f32 * f32::EPSILON),
         ("1", 0.5_f32 * f32::EPSILON),
@@ -753,7 +753,7 @@ fn test_ne_empty5() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = BTreeMap::from([
         ("0", 1.0_f32 * f32::EPSILON),
         ("1", 0.5_f32 * f32::EPSILON),
@@ -785,7 +785,7 @@ fn test_ne_empty6() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = BTreeMap::new();
     let max_ulps = BTreeMap::from([
         ("0", 2_u32),
@@ -817,7 +817,7 @@ fn test_ne_empty7() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = BTreeMap::new();
     let max_ulps = BTreeMap::new();
 
@@ -1094,7 +1094,7 @@ fn test_all_ne_empty1() {
         ("7", 6.9999995_f32),
         ("8", 8.0000000_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = 2.0_f32 * f32::EPSILON;
     let max_ulps = 64_u32;
 
@@ -1138,7 +1138,7 @@ fn test_debug_abs_diff_empty1() {
         ("7", 4.00_f32),
         ("8", 5.00_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -1147,7 +1147,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = BTreeMap::new();
+    let lhs = BTreeMap::<&str, f32>::new();
     let rhs = BTreeMap::from([
         ("0", 0.0000000_f32),
         ("1", 1.0000000_f32),
@@ -1173,7 +1173,7 @@ fn test_debug_abs_diff_tolerance_empty() {
         ("2", 4.50_f32),
         ("3", 5.75_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_abs_diff = BTreeMap::from([
         ("0", 0.10_f32),
         ("1", 0.20_f32),
@@ -1204,7 +1204,7 @@ fn test_debug_abs_diff_all_tolerance_empty() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_ulps_tolerance_empty() {
-    let lhs = BTreeMap::new();
+    let lhs = BTreeMap::<&str, f32>::new();
     let rhs = BTreeMap::from([
         ("0", 2.50_f32),
         ("1", 3.00_f32),
@@ -1231,7 +1231,7 @@ fn test_debug_ulps_all_tolerance_empty() {
         ("2", 4.50_f32),
         ("3", 5.75_f32),
     ]);
-    let rhs = BTreeMap::new();
+    let rhs = BTreeMap::<&str, f32>::new();
     let max_ulps = 8_u32;
 
     assert_eq!(lhs.debug_ulps_all_tolerance(&rhs, &max_ulps), None);
@@ -0,0 +1,50 @@
+use std::collections::BTreeMap;
+use ulps_cmp::{
+    UlpsCmp,
+    assert_ulps_eq,
+};
+
+#[test]
+fn test_error_stats_vec() {
+    let lhs = vec![1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = vec![1.0_f64, 2.5_f64, -3.0_f64, 4.0_f64];
+    let stats = UlpsCmp::error_stats_all(&lhs, &rhs, &0.0_f64, &4_u64);
+    let max_abs_diff = stats.max_abs_diff().unwrap();
+    let max_ulps = stats.max_ulps().unwrap();
+
+    assert_eq!(stats.elements(), 4);
+    assert_eq!(stats.failed(), 2);
+    assert_eq!(max_abs_diff.value, 6.0_f64);
+    assert_eq!(max_abs_diff.path.to_string(), "[2]");
+    assert_eq!(max_ulps.value, 2 * 3.0_f64.to_bits());
+    assert_eq!(max_ulps.path.to_string(), "[2]");
+}
+
+#[test]
+fn test_error_stats_array() {
+    let lhs = [1.0_f32, 2.0_f32];
+    let rhs = [1.0_f32, f32::from_bits(2.0_f32.to_bits() + 3)];
+    let stats = UlpsCmp::error_stats(&lhs, &rhs, &[0.0_f32; 2], &[4_u32; 2]);
+
+    assert_eq!(stats.failed(), 0);
+    assert_eq!(stats.max_ulps().map(|max| max.value), Some(3));
+    assert_eq!(stats.max_ulps().map(|max| max.path.to_string()), Some(String::from("[1]")));
+}
+
+#[test]
+fn test_error_stats_map() {
+    let lhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64)]);
+    let rhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.5_f64)]);
+    let stats = UlpsCmp::error_stats_all(&lhs, &rhs, &0.0_f64, &4_u64);
+
+    assert_eq!(stats.max_ulps().unwrap().path.to_string(), "[\"b\"]");
+}
+
+#[test]
+#[should_panic(expected = "         max ulps: `9227875636482146304` at `[2]`,")]
+fn test_assert_eq_panic_message_stats() {
+    let lhs = vec![1.0_f64, 2.0_f64, 3.0_f64, 4.0_f64];
+    let rhs = vec![1.0_f64, 2.5_f64, -3.0_f64, 4.0_f64];
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64);
+}
@@ -0,0 +1,120 @@
+use ulps_cmp::{
+    UlpsEq,
+    UlpsExplain,
+    UlpsExplanation,
+    assert_ulps_eq,
+};
+
+const VALUES_F32: [f32; 14] = [
+    0.0_f32,
+    -0.0_f32,
+    1e-30_f32,
+    -1e-30_f32,
+    1.0_f32,
+    1.000_000_1_f32,
+    -1.0_f32,
+    1e30_f32,
+    f32::MIN_POSITIVE,
+    f32::MAX,
+    f32::MIN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+const VALUES_F64: [f64; 14] = [
+    0.0_f64,
+    -0.0_f64,
+    1e-300_f64,
+    -1e-300_f64,
+    1.0_f64,
+    1.000_000_000_000_000_2_f64,
+    -1.0_f64,
+    1e300_f64,
+    f64::MIN_POSITIVE,
+    f64::MAX,
+    f64::MIN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+];
+
+#[test]
+fn test_explain_ulps_agrees_with_ulps_eq_f32() {
+    for lhs in VALUES_F32 {
+        for rhs in VALUES_F32 {
+            for max_abs_diff in [0.0_f32, f32::EPSILON, 1e-29_f32] {
+                for max_ulps in [0_u32, 4_u32, u32::MAX] {
+                    let explanation = lhs.explain_ulps(&rhs, &max_abs_diff, &max_ulps);
+
+                    assert_eq!(
+                        explanation.passed(),
+                        lhs.ulps_eq(&rhs, &max_abs_diff, &max_ulps),
+                        "lhs = {lhs:?}, rhs = {rhs:?}, explanation = {explanation:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_ulps_agrees_with_ulps_eq_f64() {
+    for lhs in VALUES_F64 {
+        for rhs in VALUES_F64 {
+            for max_abs_diff in [0.0_f64, f64::EPSILON, 1e-299_f64] {
+                for max_ulps in [0_u64, 4_u64, u64::MAX] {
+                    let explanation = lhs.explain_ulps(&rhs, &max_abs_diff, &max_ulps);
+
+                    assert_eq!(
+                        explanation.passed(),
+                        lhs.ulps_eq(&rhs, &max_abs_diff, &max_ulps),
+                        "lhs = {lhs:?}, rhs = {rhs:?}, explanation = {explanation:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_ulps_branches() {
+    let one = 1.0_f32;
+    let near_one = f32::from_bits(one.to_bits() + 2);
+
+    assert_eq!(f32::NAN.explain_ulps(&f32::NAN, &1.0_f32, &4_u32), UlpsExplanation::FailedNan);
+    assert_eq!(0.0_f32.explain_ulps(&-0.0_f32, &0.0_f32, &0_u32), UlpsExplanation::PassedAbsDiff);
+    assert_eq!(
+        1e-30_f32.explain_ulps(&-1e-30_f32, &0.0_f32, &4_u32),
+        UlpsExplanation::FailedUlps { distance: 457475264 }
+    );
+    assert_eq!(
+        f32::from_bits(1).explain_ulps(&-f32::from_bits(1), &0.0_f32, &2_u32),
+        UlpsExplanation::PassedUlps { distance: 2 }
+    );
+    assert_eq!(
+        one.explain_ulps(&near_one, &0.0_f32, &2_u32),
+        UlpsExplanation::PassedUlps { distance: 2 }
+    );
+    assert_eq!(
+        one.explain_ulps(&near_one, &0.0_f32, &1_u32),
+        UlpsExplanation::FailedUlps { distance: 2 }
+    );
+}
+
+#[test]
+#[should_panic(expected = "        ulps: `Some(8316055694412842304)`,\n      branch: `FailedUlps { distance: 8316055694412842304 }`,")]
+fn test_assert_eq_panic_message_opposite_signs() {
+    assert_ulps_eq!(1e-30_f64, -1e-30_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+}
+
+#[test]
+#[should_panic(
+    expected = "first failure at `[1]`:\n        left: `1.0`,\n       right: `1.0000002`,\n    abs_diff: `2.3841858e-7`,\n        ulps: `Some(2)`,\n      branch: `FailedUlps { distance: 2 }`,"
+)]
+fn test_assert_eq_panic_message_ulps() {
+    let lhs = [0.0_f32, 1.0_f32];
+    let rhs = [0.0_f32, f32::from_bits(1.0_f32.to_bits() + 2)];
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 1_u32);
+}
@@ -0,0 +1,117 @@
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    LinkedList,
+    VecDeque,
+};
+use std::rc::Rc;
+use std::sync::OnceLock;
+use ulps_cmp::{
+    AssertUlpsEq,
+    FirstFailure,
+    assert_ulps_eq,
+    check_ulps_eq,
+};
+
+#[test]
+fn test_tuple_path() {
+    let lhs = (1.0_f64, 2.0_f64, 3.0_f64);
+    let rhs = (1.0_f64, 2.5_f64, 3.0_f64);
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), ".1");
+}
+
+#[test]
+fn test_sequence_paths() {
+    let lhs = vec![[1.0_f32, 2.0_f32, 3.0_f32]; 3];
+    let mut rhs = lhs.clone();
+    rhs[1][2] = 3.5_f32;
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 4_u32).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1][2]");
+
+    let lhs = VecDeque::from([1.0_f64, 2.0_f64, 3.0_f64]);
+    let rhs = VecDeque::from([1.0_f64, 2.0_f64, 3.5_f64]);
+    let mismatch = check_ulps_eq!(lhs, rhs, ulps_all <= 4_u64, abs_diff_all <= 0.0_f64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+
+    let lhs = LinkedList::from([1.0_f64, 2.0_f64]);
+    let rhs = LinkedList::from([1.5_f64, 2.0_f64]);
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[0]");
+}
+
+#[test]
+fn test_map_paths() {
+    let lhs = BTreeMap::from([("a", (1.0_f64, 2.0_f64)), ("b", (3.0_f64, 4.0_f64))]);
+    let rhs = BTreeMap::from([("a", (1.0_f64, 2.0_f64)), ("b", (3.0_f64, 4.5_f64))]);
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[\"b\"].1");
+}
+
+#[test]
+fn test_pointer_and_once_paths() {
+    let lhs = Rc::new([1.0_f64, 2.0_f64]);
+    let rhs = Rc::new([1.0_f64, 2.5_f64]);
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+
+    let lhs = OnceLock::from([1.0_f64, 2.0_f64]);
+    let rhs = OnceLock::from([1.5_f64, 2.0_f64]);
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[0]");
+}
+
+#[test]
+fn test_first_failure_display() {
+    let lhs = (1.0_f64, 2.0_f64);
+    let rhs = (1.0_f64, 2.5_f64);
+    let abs_diff = (0.0_f64, 0.0_f64);
+    let ulps = (4_u64, 4_u64);
+    let failure = FirstFailure::new(|path, visitor| AssertUlpsEq::visit_ulps(&lhs, &rhs, &abs_diff, &ulps, path, visitor));
+    let expected = "\
+first failure at `.1`:
+        left: `2.0`,
+       right: `2.5`,
+    abs_diff: `0.5`,
+        ulps: `Some(1125899906842624)`,
+      branch: `FailedUlps { distance: 1125899906842624 }`,
+[abs_diff] t: `0.0`,
+    [ulps] t: `4`,";
+
+    assert_eq!(failure.to_string(), expected);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[1][2]`:\n        left: `3.0`,\n       right: `3.5`,")]
+fn test_assert_eq_panic_message() {
+    let lhs = vec![[1.0_f32, 2.0_f32, 3.0_f32]; 3];
+    let mut rhs = lhs.clone();
+    rhs[1][2] = 3.5_f32;
+
+    assert_ulps_eq!(lhs, rhs, ulps_all <= 4_u32, abs_diff_all <= 0.0_f32);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[\"a\"].1`")]
+fn test_assert_eq_panic_message_map() {
+    let lhs = HashMap::from([("a", (1.0_f64, 2.0_f64))]);
+    let rhs = HashMap::from([("a", (1.0_f64, 2.5_f64))]);
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64);
+}
+
+#[test]
+#[should_panic(expected = "failed elements: 2 of 6\n         [0]: left: `1.0`, right: `1.5`")]
+fn test_assert_eq_panic_message_summary() {
+    let lhs = vec![1.0_f64; 6];
+    let rhs = vec![1.5_f64, 1.0_f64, 1.0_f64, 1.0_f64, 1.0_f64, 1.5_f64];
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64);
+}
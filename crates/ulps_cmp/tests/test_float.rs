@@ -0,0 +1,184 @@
+use core::cmp;
+use core::ops;
+use ulps_cmp::{
+    Float,
+    UlpsEq,
+    UlpsExplain,
+    UlpsExplanation,
+    ulps,
+};
+
+/// A brain floating point number, holding the highest sixteen bits of an `f32`.
+#[derive(Copy, Clone, Debug)]
+struct Bf16(u16);
+
+impl Bf16 {
+    fn from_f32(value: f32) -> Self {
+        Self((value.to_bits() >> 16) as u16)
+    }
+
+    fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+}
+
+impl PartialEq for Bf16 {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for Bf16 {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl ops::Add for Bf16 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() + other.to_f32())
+    }
+}
+
+impl ops::Sub for Bf16 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() - other.to_f32())
+    }
+}
+
+impl ops::Mul for Bf16 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() * other.to_f32())
+    }
+}
+
+impl ops::Div for Bf16 {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32() / other.to_f32())
+    }
+}
+
+impl Float for Bf16 {
+    type Bits = u16;
+
+    const ZERO: Self = Self(0x0000);
+    const ONE: Self = Self(0x3F80);
+    const INFINITY: Self = Self(0x7F80);
+    const MAX: Self = Self(0x7F7F);
+    const MIN_POSITIVE: Self = Self(0x0080);
+
+    fn to_bits(self) -> Self::Bits {
+        self.0
+    }
+
+    fn from_bits(bits: Self::Bits) -> Self {
+        Self(bits)
+    }
+
+    fn abs(self) -> Self {
+        Self(self.0 & 0x7FFF)
+    }
+
+    fn signum(self) -> Self {
+        Self::from_f32(self.to_f32().signum())
+    }
+
+    fn is_nan(self) -> bool {
+        self.to_f32().is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.to_f32().is_infinite()
+    }
+
+    fn max(self, other: Self) -> Self {
+        Self::from_f32(f32::max(self.to_f32(), other.to_f32()))
+    }
+}
+
+/// Values whose `f32` representation is exactly representable as a `Bf16`.
+const SPECIAL_F32: [f32; 14] = [
+    0.0_f32,
+    -0.0_f32,
+    f32::from_bits(0x0001_0000),
+    -f32::from_bits(0x0001_0000),
+    f32::MIN_POSITIVE,
+    1.0_f32,
+    -1.0_f32,
+    1.0078125_f32,
+    3.0_f32,
+    -3.0_f32,
+    f32::from_bits(0x7F7F_0000),
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+];
+
+impl UlpsEq for Bf16 {
+    type Tolerance = Bf16;
+    type UlpsTolerance = u16;
+
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        ulps::ulps_eq(*self, *other, *max_abs_diff, *max_ulps)
+    }
+}
+
+#[test]
+fn test_ulps_distance() {
+    let one = Bf16::from_f32(1.0_f32);
+    let tiny = Bf16::from_bits(1);
+
+    assert_eq!(ulps::ulps_distance(one, ulps::next_up(one)), Some(1_u16));
+    assert_eq!(ulps::ulps_distance(tiny, Bf16::from_bits(0x8001)), Some(2_u16));
+    assert_eq!(ulps::ulps_distance(Bf16::MAX, Bf16::INFINITY), Some(1_u16));
+    assert_eq!(ulps::ulps_distance(Bf16::from_f32(f32::NAN), one), None);
+}
+
+#[test]
+fn test_stepping() {
+    let one = Bf16::from_f32(1.0_f32);
+
+    assert_eq!(ulps::next_up(one).to_f32(), 1.0078125_f32);
+    assert_eq!(ulps::next_down(Bf16::from_bits(1)).to_bits(), 0x0000_u16);
+    assert_eq!(ulps::next_down(Bf16::ZERO).to_bits(), 0x8001_u16);
+    assert_eq!(ulps::step_by_ulps(Bf16::MAX, 2).to_bits(), Bf16::INFINITY.to_bits());
+    assert_eq!(ulps::ulp(one).to_f32(), 0.0078125_f32);
+}
+
+#[test]
+fn test_ulps_eq_agrees_with_f32() {
+    // The ulps distance between two `Bf16` values is the ulps distance between
+    // their `f32` values, scaled down by the sixteen truncated bits.
+    for lhs in SPECIAL_F32 {
+        for rhs in SPECIAL_F32 {
+            for max_ulps in [0_u16, 1_u16, 2_u16, 0x3F80_u16, u16::MAX] {
+                let result = Bf16::from_f32(lhs).ulps_eq(&Bf16::from_f32(rhs), &Bf16::ZERO, &max_ulps);
+                let expected = lhs.ulps_eq(&rhs, &0.0_f32, &((max_ulps as u32) << 16));
+
+                assert_eq!(result, expected, "lhs = {lhs:?}, rhs = {rhs:?}, max_ulps = {max_ulps:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_explain_ulps() {
+    let one = Bf16::from_f32(1.0_f32);
+    let three = Bf16::from_f32(3.0_f32);
+    let nan = Bf16::from_f32(f32::NAN);
+
+    assert_eq!(
+        one.explain_ulps(&ulps::step_by_ulps(one, 3), &Bf16::ZERO, &4_u16),
+        UlpsExplanation::PassedUlps { distance: 3 }
+    );
+    assert_eq!(one.explain_ulps(&three, &three, &0_u16), UlpsExplanation::PassedAbsDiff);
+    assert_eq!(one.explain_ulps(&nan, &three, &u16::MAX), UlpsExplanation::FailedNan);
+}
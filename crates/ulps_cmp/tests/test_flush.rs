@@ -0,0 +1,221 @@
+use std::collections::{
+    BTreeMap,
+    HashMap,
+    LinkedList,
+    VecDeque,
+};
+use ulps_cmp::{
+    UlpsEqFtz,
+    assert_ulps_eq_ftz,
+    ulps,
+    ulps_eq,
+    ulps_eq_ftz,
+    ulps_ne_ftz,
+};
+
+#[test]
+fn test_subnormals_flushed_to_zero() {
+    let subnormal = f32::MIN_POSITIVE / 2.0_f32;
+
+    assert!(!ulps_eq!(0.0_f32, subnormal, abs_diff <= 0.0_f32, ulps <= 4_u32));
+    assert!(ulps_eq_ftz!(0.0_f32, subnormal, abs_diff <= 0.0_f32, ulps <= 4_u32));
+    assert!(ulps_eq_ftz!(-0.0_f32, -subnormal, ulps <= 4_u32, abs_diff <= 0.0_f32));
+    assert!(ulps_eq_ftz!(subnormal, -subnormal, abs_diff <= 0.0_f32, ulps <= 4_u32));
+    assert!(ulps_ne_ftz!(f32::MIN_POSITIVE, subnormal, abs_diff <= 0.0_f32, ulps <= 4_u32));
+    assert!(ulps::ulps_eq_ftz(0.0_f64, 1e-310_f64, 0.0_f64, 0_u64));
+}
+
+#[test]
+fn test_normal_numbers_unchanged() {
+    assert!(ulps_eq_ftz!(1.0_f64, 1.0_f64 + f64::EPSILON, abs_diff <= 0.0_f64, ulps <= 1_u64));
+    assert!(ulps_ne_ftz!(
+        1.0_f64,
+        1.0_f64 + 2.0_f64 * f64::EPSILON,
+        abs_diff <= 0.0_f64,
+        ulps <= 1_u64
+    ));
+    assert!(ulps_ne_ftz!(f64::NAN, f64::NAN, abs_diff <= f64::INFINITY, ulps <= u64::MAX));
+    assert_eq!(1.0_f64.ulps_flushed(&1.0_f64), 0);
+}
+
+#[test]
+fn test_mixed_precision() {
+    // A subnormal number of one precision may be normal in the other.
+    let subnormal = f32::MIN_POSITIVE / 2.0_f32;
+
+    assert!(ulps_eq_ftz!(subnormal, 0.0_f64, abs_diff <= 0.0_f64, ulps <= 0_u64));
+    assert!(ulps_ne_ftz!(0.0_f64, f64::from(subnormal), abs_diff <= 0.0_f64, ulps <= 0_u64));
+    assert_eq!(subnormal.ulps_flushed(&f64::from(subnormal)), 1);
+}
+
+#[test]
+fn test_containers() {
+    let reference = [1.0_f32, 1e-40_f32, -1e-41_f32];
+    let flushed = [1.0_f32, 0.0_f32, -0.0_f32];
+
+    assert!(ulps_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, ulps <= 0_u32));
+    assert!(ulps_eq_ftz!(&flushed[..], &reference[..], abs_diff <= 0.0_f32, ulps <= 0_u32));
+    assert!(ulps_eq_ftz!(
+        flushed.to_vec(),
+        reference.to_vec(),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert!(ulps_ne_ftz!(&flushed[..2], &reference[..], abs_diff <= 0.0_f32, ulps <= 0_u32));
+    assert_eq!(flushed.ulps_flushed(&reference), 2);
+    assert_eq!(reference.ulps_flushed(&reference), 4);
+}
+
+#[test]
+fn test_tuples() {
+    let reference = (1.0_f32, 1e-40_f32, -1e-41_f32);
+    let flushed = (1.0_f32, 0.0_f32, -0.0_f32);
+
+    assert!(ulps_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, ulps <= 0_u32));
+    assert!(ulps_ne_ftz!(
+        (1.0_f32, 2.0_f32),
+        (1.0_f32, 1e-40_f32),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert_eq!(flushed.ulps_flushed(&reference), 2);
+}
+
+#[test]
+fn test_option_result() {
+    let subnormal = f32::MIN_POSITIVE / 2.0_f32;
+
+    assert!(ulps_eq_ftz!(
+        Some(0.0_f32),
+        Some(subnormal),
+        abs_diff <= Some(0.0_f32),
+        ulps <= Some(0_u32)
+    ));
+    assert!(ulps_ne_ftz!(
+        Some(0.0_f32),
+        None::<f32>,
+        abs_diff <= Some(0.0_f32),
+        ulps <= Some(0_u32)
+    ));
+    assert!(ulps_ne_ftz!(Some(0.0_f32), Some(subnormal), abs_diff <= None, ulps <= Some(0_u32)));
+    assert_eq!(Some(0.0_f32).ulps_flushed(&Some(subnormal)), 1);
+
+    let ok: Result<f32, f32> = Ok(subnormal);
+    let err: Result<f32, f32> = Err(subnormal);
+
+    assert!(ulps_eq_ftz!(
+        ok,
+        Ok::<f32, f32>(0.0_f32),
+        abs_diff <= Ok(0.0_f32),
+        ulps <= Ok(0_u32)
+    ));
+    assert!(ulps_eq_ftz!(
+        err,
+        Err::<f32, f32>(0.0_f32),
+        abs_diff <= Err(0.0_f32),
+        ulps <= Err(0_u32)
+    ));
+    assert!(ulps_ne_ftz!(
+        ok,
+        err,
+        abs_diff <= Ok::<f32, f32>(0.0_f32),
+        ulps <= Ok::<u32, u32>(0_u32)
+    ));
+    assert_eq!(err.ulps_flushed(&Err::<f32, f32>(0.0_f32)), 1);
+}
+
+#[test]
+fn test_sequences() {
+    let reference = [1.0_f32, 1e-40_f32, -1e-41_f32];
+    let flushed = [1.0_f32, 0.0_f32, -0.0_f32];
+
+    assert!(ulps_eq_ftz!(
+        VecDeque::from(flushed),
+        VecDeque::from(reference),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert!(ulps_eq_ftz!(
+        LinkedList::from(flushed),
+        LinkedList::from(reference),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert!(ulps_ne_ftz!(
+        LinkedList::from(flushed),
+        LinkedList::from([1.0_f32]),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert_eq!(VecDeque::from(flushed).ulps_flushed(&VecDeque::from(reference)), 2);
+}
+
+#[test]
+fn test_maps() {
+    let reference = BTreeMap::from([("x", 1.0_f32), ("y", 1e-40_f32)]);
+    let flushed = BTreeMap::from([("x", 1.0_f32), ("y", 0.0_f32)]);
+
+    assert!(ulps_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, ulps <= 0_u32));
+    assert!(ulps_ne_ftz!(
+        flushed,
+        BTreeMap::from([("x", 1.0_f32), ("z", 0.0_f32)]),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert_eq!(flushed.ulps_flushed(&reference), 1);
+
+    let reference = HashMap::from([("x", 1.0_f32), ("y", 1e-40_f32)]);
+    let flushed = HashMap::from([("x", 1.0_f32), ("y", 0.0_f32)]);
+
+    assert!(ulps_eq_ftz!(flushed, reference, abs_diff <= 0.0_f32, ulps <= 0_u32));
+    assert!(ulps_ne_ftz!(
+        flushed,
+        HashMap::from([("x", 1.0_f32)]),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    ));
+    assert_eq!(flushed.ulps_flushed(&reference), 1);
+}
+
+#[test]
+fn test_assert_ulps_eq_ftz() {
+    assert_ulps_eq_ftz!([0.0_f32, 1.0_f32], [1e-40_f32, 1.0_f32], abs_diff <= 0.0_f32, ulps <= 0_u32);
+    assert_ulps_eq_ftz!(0.0_f64, 1e-310_f64, ulps <= 0_u64, abs_diff <= 0.0_f64, "message {}", 1);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `ulps_eq_ftz!(left, right, abs_diff <= t, ulps <= t)`
+subnormals flushed to zero: `1`
+first failure at `[1]`:")]
+fn test_assert_ulps_eq_ftz_panic_message() {
+    assert_ulps_eq_ftz!([0.0_f32, 1.0_f32], [1e-40_f32, 2.0_f32], abs_diff <= 0.0_f32, ulps <= 0_u32);
+}
+
+#[test]
+#[should_panic(expected = "flushed: `right`,")]
+fn test_assert_ulps_eq_ftz_panic_flushed_element() {
+    assert_ulps_eq_ftz!(f32::MIN_POSITIVE, 1e-40_f32, abs_diff <= 0.0_f32, ulps <= 0_u32);
+}
+
+#[test]
+#[should_panic(expected = "first failure at `[\"y\"]`:")]
+fn test_assert_ulps_eq_ftz_panic_map_key() {
+    assert_ulps_eq_ftz!(
+        BTreeMap::from([("x", 1.0_f32), ("y", 2.0_f32)]),
+        BTreeMap::from([("x", 1.0_f32), ("y", 1e-40_f32)]),
+        abs_diff <= 0.0_f32,
+        ulps <= 0_u32
+    );
+}
+
+#[test]
+#[should_panic(expected = "custom message")]
+fn test_assert_ulps_eq_ftz_panic_custom_message() {
+    assert_ulps_eq_ftz!(
+        vec![1.0_f64],
+        vec![1.0_f64, 2.0_f64],
+        abs_diff <= 0.0_f64,
+        ulps <= 0_u64,
+        "custom message"
+    );
+}
@@ -0,0 +1,82 @@
+use ulps_cmp::{
+    AssertUlpsEq,
+    UlpsAllEq,
+    UlpsEq,
+    assert_ulps_eq,
+    assert_ulps_ne,
+    ulps,
+    ulps_eq,
+    ulps_ne,
+};
+
+#[test]
+fn test_eq_f32_f64() {
+    let lhs = 1.0_f32;
+    let rhs = 1.0_f64 + f64::EPSILON;
+
+    assert!(lhs.ulps_eq(&rhs, &0.0_f64, &1_u64));
+    assert!(rhs.ulps_eq(&lhs, &0.0_f64, &1_u64));
+    assert!(lhs.ulps_all_eq(&rhs, &0.0_f64, &1_u64));
+    assert!(ulps_eq!(lhs, rhs, abs_diff <= 0.0_f64, ulps <= 1_u64));
+    assert_ulps_eq!(rhs, lhs, abs_diff_all <= 0.0_f64, ulps_all <= 1_u64);
+}
+
+#[test]
+fn test_ne_f32_f64() {
+    let lhs = 1.0_f32;
+    let rhs = 1.0_f64 + f64::EPSILON;
+
+    assert!(lhs.ulps_ne(&rhs, &0.0_f64, &0_u64));
+    assert!(rhs.ulps_ne(&lhs, &0.0_f64, &0_u64));
+    assert!(lhs.ulps_all_ne(&rhs, &0.0_f64, &0_u64));
+    assert!(ulps_ne!(lhs, rhs, abs_diff <= 0.0_f64, ulps <= 0_u64));
+    assert_ulps_ne!(rhs, lhs, abs_diff_all <= 0.0_f64, ulps_all <= 0_u64);
+}
+
+#[test]
+fn test_eq_exactly_representable() {
+    for value in [0.0_f32, -0.0_f32, f32::from_bits(1), 1.0_f32, -2.5_f32, f32::MAX, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(value.ulps_eq(&f64::from(value), &0.0_f64, &0_u64));
+        assert!(f64::from(value).ulps_eq(&value, &0.0_f64, &0_u64));
+    }
+}
+
+#[test]
+fn test_ne_nan() {
+    assert!(f32::NAN.ulps_ne(&f64::NAN, &f64::INFINITY, &u64::MAX));
+    assert!(1.0_f64.ulps_ne(&f32::NAN, &f64::INFINITY, &u64::MAX));
+}
+
+#[test]
+fn test_debug_ulps_diff_counts_f64_ulps() {
+    // One `f32` ulp spans `2^29` `f64` ulps between numbers of the same binade.
+    let lhs = 1.0_f32 + f32::EPSILON;
+    let rhs = 1.0_f64;
+
+    assert_eq!(lhs.debug_ulps_diff(&rhs), Some(1_u64 << 29));
+    assert_eq!(rhs.debug_ulps_diff(&lhs), Some(1_u64 << 29));
+    assert_eq!(lhs.debug_abs_diff(&rhs), f64::from(f32::EPSILON));
+    assert_eq!(f32::NAN.debug_ulps_diff(&rhs), None);
+    assert_eq!(0.1_f32.debug_ulps_diff(&0.1_f64), ulps::ulps_distance(f64::from(0.1_f32), 0.1_f64));
+}
+
+#[test]
+fn test_eq_containers() {
+    let lhs = vec![1.0_f32, 1.0_f32 + f32::EPSILON];
+    let rhs = vec![1.0_f64, 1.0_f64];
+
+    assert!(ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 1_u64 << 29));
+    assert!(ulps_eq!(rhs, lhs, abs_diff_all <= 0.0_f64, ulps_all <= 1_u64 << 29));
+    assert!(ulps_ne!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= (1_u64 << 29) - 1));
+}
+
+#[test]
+#[should_panic(
+    expected = "first failure at `[1]`:\n        left: `1.0000001`,\n       right: `1.0`,\n    abs_diff: `1.1920928955078125e-7`,\n        ulps: `Some(536870912)`,\n      branch: `FailedUlps { distance: 536870912 }`,"
+)]
+fn test_assert_eq_panic_message() {
+    let lhs = vec![1.0_f32, 1.0_f32 + f32::EPSILON];
+    let rhs = vec![1.0_f64, 1.0_f64];
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64);
+}
@@ -0,0 +1,82 @@
+use core::cmp::Ordering;
+use ulps_cmp::{
+    UlpsOrd,
+    assert_ulps_ge,
+    assert_ulps_gt,
+    assert_ulps_le,
+    assert_ulps_lt,
+    ulps,
+    ulps_ge,
+    ulps_gt,
+    ulps_le,
+    ulps_lt,
+};
+
+#[test]
+fn test_ulps_partial_cmp() {
+    let next = ulps::next_up(1.0_f64);
+
+    assert_eq!(next.ulps_partial_cmp(&1.0_f64, &0.0_f64, &1_u64), Some(Ordering::Equal));
+    assert_eq!(next.ulps_partial_cmp(&1.0_f64, &0.0_f64, &0_u64), Some(Ordering::Greater));
+    assert_eq!(1.0_f64.ulps_partial_cmp(&next, &0.0_f64, &0_u64), Some(Ordering::Less));
+    assert_eq!(f64::NAN.ulps_partial_cmp(&f64::NAN, &f64::INFINITY, &u64::MAX), None);
+}
+
+#[test]
+fn test_ulps_lt_le() {
+    let next = ulps::step_by_ulps(1.0_f32, 4);
+
+    assert!(ulps_lt!(1.0_f32, next, abs_diff <= 0.0_f32, ulps <= 2_u32));
+    assert!(!ulps_lt!(1.0_f32, next, abs_diff <= 0.0_f32, ulps <= 4_u32));
+    assert!(ulps_le!(next, 1.0_f32, abs_diff <= 0.0_f32, ulps <= 4_u32));
+    assert!(ulps_le!(next, 1.0_f32, ulps <= 4_u32, abs_diff <= 0.0_f32));
+    assert!(!ulps_le!(next, 1.0_f32, abs_diff <= 0.0_f32, ulps <= 2_u32));
+    assert!(!ulps_le!(f32::NAN, 1.0_f32, abs_diff <= f32::INFINITY, ulps <= u32::MAX));
+}
+
+#[test]
+fn test_ulps_gt_ge() {
+    let next = ulps::step_by_ulps(1.0_f64, 4);
+
+    assert!(ulps_gt!(next, 1.0_f64, abs_diff <= 0.0_f64, ulps <= 2_u64));
+    assert!(!ulps_gt!(next, 1.0_f64, ulps <= 4_u64, abs_diff <= 0.0_f64));
+    assert!(ulps_ge!(1.0_f64, next, abs_diff <= 0.0_f64, ulps <= 4_u64));
+    assert!(!ulps_ge!(1.0_f64, next, abs_diff <= 0.0_f64, ulps <= 2_u64));
+}
+
+#[test]
+fn test_ulps_ordering_mixed_precision() {
+    let next = ulps::next_up(1.0_f32);
+
+    assert_eq!(next.ulps_partial_cmp(&1.0_f64, &0.0_f64, &(1_u64 << 29)), Some(Ordering::Equal));
+    assert_eq!(next.ulps_partial_cmp(&1.0_f64, &0.0_f64, &1_u64), Some(Ordering::Greater));
+    assert!(ulps_lt!(1.0_f64, next, abs_diff <= 0.0_f64, ulps <= 1_u64));
+    assert!(ulps_le!(1.0_f64, next, abs_diff <= 0.0_f64, ulps <= 1_u64 << 29));
+    assert!(!ulps_lt!(1.0_f64, next, abs_diff <= 0.0_f64, ulps <= 1_u64 << 29));
+}
+
+#[test]
+fn test_assert_ulps_ordering() {
+    assert_ulps_lt!(1.0_f64, 2.0_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+    assert_ulps_le!(0.1_f64 + 0.2_f64, 0.3_f64, abs_diff <= 0.0_f64, ulps <= 1_u64, "message {}", 1);
+    assert_ulps_gt!(2.0_f64, 1.0_f64, ulps <= 4_u64, abs_diff <= 0.0_f64);
+    assert_ulps_ge!(0.3_f64, 0.1_f64 + 0.2_f64, ulps <= 1_u64, abs_diff <= 0.0_f64, "message {}", 2);
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `ulps_le!(left, right, abs_diff <= t, ulps <= t)`
+        left: `2.0`,
+       right: `1.0`,
+    abs_diff: `1.0`,
+   ulps_diff: `Some(4503599627370496)`,
+[abs_diff] t: `0.0`,
+    [ulps] t: `4`")]
+fn test_assert_ulps_le_panic_message() {
+    assert_ulps_le!(2.0_f64, 1.0_f64, abs_diff <= 0.0_f64, ulps <= 4_u64);
+}
+
+#[test]
+#[should_panic(expected = "custom message")]
+fn test_assert_ulps_lt_panic_custom_message() {
+    assert_ulps_lt!(0.1_f64 + 0.2_f64, 0.3_f64, ulps <= 1_u64, abs_diff <= 0.0_f64, "custom message");
+}
@@ -0,0 +1,203 @@
+use ulps_cmp::{
+    AssertUlpsAllEq,
+    AssertUlpsEq,
+    FloatPolicy,
+    assert_ulps_eq,
+    assert_ulps_ne,
+    check_ulps_eq,
+    check_ulps_ne,
+    ulps_eq,
+    ulps_ne,
+};
+
+#[test]
+fn test_nan_eq() {
+    let lhs = [1.0_f32, f32::NAN];
+    let rhs = [1.0_f32, f32::NAN];
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(!ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 4_u32));
+    assert!(ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f32, ulps_all <= 4_u32, policy = policy));
+    assert!(ulps_eq!(lhs, rhs, ulps_all <= 4_u32, abs_diff_all <= 0.0_f32, policy = policy));
+    assert!(ulps_eq!(lhs, rhs, abs_diff <= [0.0_f32; 2], ulps <= [4_u32; 2], policy = policy));
+    assert!(ulps_ne!(
+        lhs,
+        rhs,
+        abs_diff_all <= 0.0_f32,
+        ulps_all <= 4_u32,
+        policy = FloatPolicy::new()
+    ));
+}
+
+#[test]
+fn test_inf_eq() {
+    let strict = FloatPolicy {
+        inf_eq: false,
+        ..FloatPolicy::new()
+    };
+
+    assert!(ulps_eq!(f64::INFINITY, f64::INFINITY, abs_diff <= 0.0_f64, ulps <= 0_u64));
+    assert!(!ulps_eq!(
+        f64::INFINITY,
+        f64::INFINITY,
+        abs_diff <= 0.0_f64,
+        ulps <= 0_u64,
+        policy = strict
+    ));
+
+    // The largest finite number is one ulp away from infinity.
+    assert!(ulps_eq!(f64::INFINITY, f64::MAX, abs_diff <= 0.0_f64, ulps <= 1_u64));
+    assert!(!ulps_eq!(
+        f64::INFINITY,
+        f64::MAX,
+        abs_diff <= 0.0_f64,
+        ulps <= 1_u64,
+        policy = FloatPolicy::new()
+    ));
+}
+
+#[test]
+fn test_signed_zero_strict() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(ulps_eq!(-0.0_f64, 0.0_f64, abs_diff <= 0.0_f64, ulps <= 0_u64));
+    assert!(!ulps_eq!(-0.0_f64, 0.0_f64, abs_diff <= 0.0_f64, ulps <= 0_u64, policy = policy));
+    assert!(ulps_eq!(1.0_f64, 1.0_f64, abs_diff <= 0.0_f64, ulps <= 0_u64, policy = policy));
+}
+
+#[test]
+fn test_mixed_precision() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert!(ulps_eq!(-0.0_f32, 0.0_f64, abs_diff <= 0.0_f64, ulps <= 0_u64));
+    assert!(!ulps_eq!(-0.0_f32, 0.0_f64, abs_diff <= 0.0_f64, ulps <= 0_u64, policy = policy));
+}
+
+#[test]
+fn test_ulps_eq_with_policy() {
+    let lhs = [1.0_f64, f64::NAN, -0.0_f64];
+    let rhs = [1.0_f64, f64::NAN, 0.0_f64];
+    let nan_eq = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+    let strict = FloatPolicy {
+        signed_zero_strict: true,
+        ..nan_eq
+    };
+
+    assert!(lhs.ulps_eq_with_policy(&rhs, &[0.0_f64; 3], &[4_u64; 3], &nan_eq));
+    assert!(!lhs.ulps_ne_with_policy(&rhs, &[0.0_f64; 3], &[4_u64; 3], &nan_eq));
+    assert!(!lhs.ulps_eq_with_policy(&rhs, &[0.0_f64; 3], &[4_u64; 3], &FloatPolicy::new()));
+    assert!(lhs.ulps_all_eq_with_policy(&rhs, &0.0_f64, &4_u64, &nan_eq));
+    assert!(lhs.ulps_all_ne_with_policy(&rhs, &0.0_f64, &4_u64, &strict));
+}
+
+#[test]
+fn test_check_ulps_with_policy() {
+    let lhs = [1.0_f64, f64::NAN, -0.0_f64];
+    let rhs = [1.0_f64, f64::NAN, 0.0_f64];
+    let nan_eq = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+    let strict = FloatPolicy {
+        signed_zero_strict: true,
+        ..nan_eq
+    };
+
+    assert!(check_ulps_eq!(lhs, rhs, abs_diff <= [0.0_f64; 3], ulps <= [4_u64; 3], policy = nan_eq).is_ok());
+    assert!(check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64, policy = nan_eq).is_ok());
+    assert!(check_ulps_eq!(lhs, rhs, ulps_all <= 4_u64, abs_diff_all <= 0.0_f64, policy = nan_eq).is_ok());
+    assert!(check_ulps_ne!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64, policy = strict).is_ok());
+    assert!(check_ulps_ne!(lhs, rhs, abs_diff <= [0.0_f64; 3], ulps <= [4_u64; 3], policy = nan_eq).is_err());
+
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff <= [0.0_f64; 3], ulps <= [4_u64; 3], policy = FloatPolicy::new()).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[1]");
+
+    let mismatch = check_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64, policy = strict).unwrap_err();
+
+    assert_eq!(mismatch.path.to_string(), "[2]");
+}
+
+#[test]
+fn test_assert_with_policy() {
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_ulps_eq!(f64::NAN, f64::NAN, abs_diff <= 0.0_f64, ulps <= 0_u64, policy = policy);
+    assert_ulps_eq!(
+        vec![f64::NAN],
+        vec![f64::NAN],
+        ulps_all <= 0_u64,
+        abs_diff_all <= 0.0_f64,
+        policy = policy,
+        "message {}",
+        1
+    );
+    assert_ulps_ne!(f64::NAN, f64::NAN, abs_diff <= 0.0_f64, ulps <= 0_u64, policy = FloatPolicy::new());
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion failed: `ulps_eq!(left, right, abs_diff_all <= t, ulps_all <= t, policy = p)`
+      policy: `FloatPolicy { nan_eq: false, signed_zero_strict: true, inf_eq: true }`,
+first failure at `[2]`:
+        left: `0.0`,
+       right: `-0.0`,"
+)]
+fn test_assert_ulps_eq_policy_panic_message() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_ulps_eq!(
+        [1.0_f64, 2.0_f64, 0.0_f64],
+        [1.0_f64, 2.0_f64, -0.0_f64],
+        abs_diff_all <= 0.0_f64,
+        ulps_all <= 4_u64,
+        policy = policy
+    );
+}
+
+#[test]
+#[should_panic(expected = "policy: `rejected by signed_zero_strict`,")]
+fn test_assert_ulps_eq_policy_rule() {
+    let policy = FloatPolicy {
+        signed_zero_strict: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_ulps_eq!(
+        0.0_f32,
+        -0.0_f32,
+        abs_diff <= 0.0_f32,
+        ulps <= 4_u32,
+        policy = policy,
+        "custom message"
+    );
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `ulps_ne!(left, right, abs_diff <= t, ulps <= t, policy = p)`")]
+fn test_assert_ulps_ne_policy_panic_message() {
+    let policy = FloatPolicy {
+        nan_eq: true,
+        ..FloatPolicy::new()
+    };
+
+    assert_ulps_ne!(f32::NAN, f32::NAN, abs_diff <= 0.0_f32, ulps <= 0_u32, policy = policy);
+}
@@ -65,11 +65,8 @@ mod ulps_eq_f32_tests {
     #[rustfmt::skip]
     #[test]
     fn test_ne_zero1() {
-        let range_u32 = (u32::MAX as u64) + 1_u64;
-        let max_ulps = (range_u32 / 2_u64) as u32 - 1_u32;
-
-        check_ne(-0.0_f32,  0.0_f32, -f32::EPSILON, max_ulps);
-        check_ne( 0.0_f32, -0.0_f32, -f32::EPSILON, max_ulps);
+        check_ne(-0.0_f32,  f32::from_bits(1), -f32::EPSILON, 0_u32);
+        check_ne( 0.0_f32, -f32::from_bits(1), -f32::EPSILON, 0_u32);
     }
 
     #[rustfmt::skip]
@@ -997,10 +994,10 @@ mod ulps_eq_f32_tests {
     #[rustfmt::skip]
     #[test]
     fn test_ne_infinity1() {
-        check_ne( f32::INFINITY,      f32::NEG_INFINITY, f32::MAX, u32::MAX);
-        check_ne( f32::NEG_INFINITY,  f32::MAX,          f32::MAX, u32::MAX);
-        check_ne(-f32::MAX,           f32::INFINITY,     f32::MAX, u32::MAX);
-        check_ne( f32::INFINITY,     -f32::MAX,          f32::MAX, u32::MAX);
+        check_ne( f32::INFINITY,      f32::NEG_INFINITY, f32::MAX, 2 * f32::INFINITY.to_bits() - 1);
+        check_ne( f32::NEG_INFINITY,  f32::MAX,          f32::MAX, f32::INFINITY.to_bits() + f32::MAX.to_bits() - 1);
+        check_ne(-f32::MAX,           f32::INFINITY,     f32::MAX, f32::INFINITY.to_bits() + f32::MAX.to_bits() - 1);
+        check_ne( f32::INFINITY,     -f32::MAX,          f32::MAX, f32::INFINITY.to_bits() + f32::MAX.to_bits() - 1);
     }
 
     #[rustfmt::skip]
@@ -1012,6 +1009,44 @@ mod ulps_eq_f32_tests {
         check_ne( f32::INFINITY,     -f32::MAX,          f32::MAX, 4_u32);
     }
 
+    #[rustfmt::skip]
+    #[test]
+    fn test_eq_opposite_signs1() {
+        check_eq(-0.0_f32,            0.0_f32,           -f32::EPSILON, 0_u32);
+        check_eq( 0.0_f32,           -0.0_f32,           -f32::EPSILON, 0_u32);
+        check_eq(-0.0_f32,            f32::from_bits(1), -f32::EPSILON, 1_u32);
+        check_eq( 0.0_f32,           -f32::from_bits(1), -f32::EPSILON, 1_u32);
+        check_eq( f32::from_bits(1), -f32::from_bits(1),  0.0_f32,      2_u32);
+        check_eq( f32::MIN_POSITIVE, -f32::MIN_POSITIVE,  0.0_f32,      2 * f32::MIN_POSITIVE.to_bits());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eq_opposite_signs2() {
+        check_eq(1e-8_f32,  -1e-8_f32,  -f32::EPSILON, 2 * 1e-8_f32.to_bits());
+        check_eq(1e-9_f32,  -1e-9_f32,  -f32::EPSILON, 2 * 1e-9_f32.to_bits());
+        check_eq(1e-10_f32, -1e-10_f32, -f32::EPSILON, 2 * 1e-10_f32.to_bits());
+        check_eq(1e-11_f32, -1e-11_f32, -f32::EPSILON, 2 * 1e-11_f32.to_bits());
+        check_eq(1e-12_f32, -1e-12_f32, -f32::EPSILON, 2 * 1e-12_f32.to_bits());
+        check_eq(1e-13_f32, -1e-13_f32, -f32::EPSILON, 2 * 1e-13_f32.to_bits());
+        check_eq(1e-14_f32, -1e-14_f32, -f32::EPSILON, 2 * 1e-14_f32.to_bits());
+        check_eq(1e-15_f32, -1e-15_f32, -f32::EPSILON, 2 * 1e-15_f32.to_bits());
+        check_eq(1e-16_f32, -1e-16_f32, -f32::EPSILON, 2 * 1e-16_f32.to_bits());
+        check_eq(1e-17_f32, -1e-17_f32, -f32::EPSILON, 2 * 1e-17_f32.to_bits());
+        check_eq(1e-18_f32, -1e-18_f32, -f32::EPSILON, 2 * 1e-18_f32.to_bits());
+        check_eq(1e-19_f32, -1e-19_f32, -f32::EPSILON, 2 * 1e-19_f32.to_bits());
+        check_eq(1e-20_f32, -1e-20_f32, -f32::EPSILON, 2 * 1e-20_f32.to_bits());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eq_opposite_signs3() {
+        check_eq( f32::INFINITY,      f32::NEG_INFINITY, f32::MAX, 2 * f32::INFINITY.to_bits());
+        check_eq( f32::NEG_INFINITY,  f32::MAX,          f32::MAX, f32::INFINITY.to_bits() + f32::MAX.to_bits());
+        check_eq(-f32::MAX,           f32::INFINITY,     f32::MAX, f32::INFINITY.to_bits() + f32::MAX.to_bits());
+        check_eq( f32::INFINITY,     -f32::MAX,          f32::MAX, f32::INFINITY.to_bits() + f32::MAX.to_bits());
+    }
+
     #[rustfmt::skip]
     #[test]
     fn test_eq_near_zero1() {
@@ -1133,19 +1168,19 @@ mod ulps_eq_f32_tests {
     #[rustfmt::skip]
     #[test]
     fn test_ne_near_zero4() {
-        check_ne(1e-8_f32,  -1e-8_f32,  -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-9_f32,  -1e-9_f32,  -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-10_f32, -1e-10_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-11_f32, -1e-11_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-12_f32, -1e-12_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-13_f32, -1e-13_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-14_f32, -1e-14_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-15_f32, -1e-15_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-16_f32, -1e-16_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-17_f32, -1e-17_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-18_f32, -1e-18_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-19_f32, -1e-19_f32, -f32::EPSILON, 2147483647_u32);
-        check_ne(1e-20_f32, -1e-20_f32, -f32::EPSILON, 2147483647_u32);
+        check_ne(1e-8_f32,  -1e-8_f32,  -f32::EPSILON, 2 * 1e-8_f32.to_bits() - 1);
+        check_ne(1e-9_f32,  -1e-9_f32,  -f32::EPSILON, 2 * 1e-9_f32.to_bits() - 1);
+        check_ne(1e-10_f32, -1e-10_f32, -f32::EPSILON, 2 * 1e-10_f32.to_bits() - 1);
+        check_ne(1e-11_f32, -1e-11_f32, -f32::EPSILON, 2 * 1e-11_f32.to_bits() - 1);
+        check_ne(1e-12_f32, -1e-12_f32, -f32::EPSILON, 2 * 1e-12_f32.to_bits() - 1);
+        check_ne(1e-13_f32, -1e-13_f32, -f32::EPSILON, 2 * 1e-13_f32.to_bits() - 1);
+        check_ne(1e-14_f32, -1e-14_f32, -f32::EPSILON, 2 * 1e-14_f32.to_bits() - 1);
+        check_ne(1e-15_f32, -1e-15_f32, -f32::EPSILON, 2 * 1e-15_f32.to_bits() - 1);
+        check_ne(1e-16_f32, -1e-16_f32, -f32::EPSILON, 2 * 1e-16_f32.to_bits() - 1);
+        check_ne(1e-17_f32, -1e-17_f32, -f32::EPSILON, 2 * 1e-17_f32.to_bits() - 1);
+        check_ne(1e-18_f32, -1e-18_f32, -f32::EPSILON, 2 * 1e-18_f32.to_bits() - 1);
+        check_ne(1e-19_f32, -1e-19_f32, -f32::EPSILON, 2 * 1e-19_f32.to_bits() - 1);
+        check_ne(1e-20_f32, -1e-20_f32, -f32::EPSILON, 2 * 1e-20_f32.to_bits() - 1);
     }
 
     #[rustfmt::skip]
@@ -1253,11 +1288,8 @@ mod ulps_eq_f64_tests {
     #[rustfmt::skip]
     #[test]
     fn test_ne_zero1() {
-        let range_u32 = (u64::MAX as u128) + 1_u128;
-        let max_ulps = (range_u32 / 2_u128) as u64 - 1_u64;
-
-        check_ne(-0.0_f64,  0.0_f64, -f64::EPSILON, max_ulps);
-        check_ne( 0.0_f64, -0.0_f64, -f64::EPSILON, max_ulps);
+        check_ne(-0.0_f64,  f64::from_bits(1), -f64::EPSILON, 0_u64);
+        check_ne( 0.0_f64, -f64::from_bits(1), -f64::EPSILON, 0_u64);
     }
 
     #[rustfmt::skip]
@@ -2875,10 +2907,10 @@ mod ulps_eq_f64_tests {
     #[rustfmt::skip]
     #[test]
     fn test_ne_infinity1() {
-        check_ne( f64::INFINITY,      f64::NEG_INFINITY, f64::MAX, u64::MAX);
-        check_ne( f64::NEG_INFINITY,  f64::MAX,          f64::MAX, u64::MAX);
-        check_ne(-f64::MAX,           f64::INFINITY,     f64::MAX, u64::MAX);
-        check_ne( f64::INFINITY,     -f64::MAX,          f64::MAX, u64::MAX);
+        check_ne( f64::INFINITY,      f64::NEG_INFINITY, f64::MAX, 2 * f64::INFINITY.to_bits() - 1);
+        check_ne( f64::NEG_INFINITY,  f64::MAX,          f64::MAX, f64::INFINITY.to_bits() + f64::MAX.to_bits() - 1);
+        check_ne(-f64::MAX,           f64::INFINITY,     f64::MAX, f64::INFINITY.to_bits() + f64::MAX.to_bits() - 1);
+        check_ne( f64::INFINITY,     -f64::MAX,          f64::MAX, f64::INFINITY.to_bits() + f64::MAX.to_bits() - 1);
     }
 
     #[rustfmt::skip]
@@ -2890,6 +2922,46 @@ mod ulps_eq_f64_tests {
         check_ne( f64::INFINITY,     -f64::MAX,          f64::MAX, 4_u64);
     }
 
+    #[rustfmt::skip]
+    #[test]
+    fn test_eq_opposite_signs1() {
+        check_eq(-0.0_f64,            0.0_f64,           -f64::EPSILON, 0_u64);
+        check_eq( 0.0_f64,           -0.0_f64,           -f64::EPSILON, 0_u64);
+        check_eq(-0.0_f64,            f64::from_bits(1), -f64::EPSILON, 1_u64);
+        check_eq( 0.0_f64,           -f64::from_bits(1), -f64::EPSILON, 1_u64);
+        check_eq( f64::from_bits(1), -f64::from_bits(1),  0.0_f64,      2_u64);
+        check_eq( f64::MIN_POSITIVE, -f64::MIN_POSITIVE,  0.0_f64,      2 * f64::MIN_POSITIVE.to_bits());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eq_opposite_signs2() {
+        check_eq(1e-16_f64, -1e-16_f64, 0.0_f64, 2 * 1e-16_f64.to_bits());
+        check_eq(1e-17_f64, -1e-17_f64, 0.0_f64, 2 * 1e-17_f64.to_bits());
+        check_eq(1e-18_f64, -1e-18_f64, 0.0_f64, 2 * 1e-18_f64.to_bits());
+        check_eq(1e-19_f64, -1e-19_f64, 0.0_f64, 2 * 1e-19_f64.to_bits());
+        check_eq(1e-20_f64, -1e-20_f64, 0.0_f64, 2 * 1e-20_f64.to_bits());
+        check_eq(1e-21_f64, -1e-21_f64, 0.0_f64, 2 * 1e-21_f64.to_bits());
+        check_eq(1e-22_f64, -1e-22_f64, 0.0_f64, 2 * 1e-22_f64.to_bits());
+        check_eq(1e-23_f64, -1e-23_f64, 0.0_f64, 2 * 1e-23_f64.to_bits());
+        check_eq(1e-24_f64, -1e-24_f64, 0.0_f64, 2 * 1e-24_f64.to_bits());
+        check_eq(1e-25_f64, -1e-25_f64, 0.0_f64, 2 * 1e-25_f64.to_bits());
+        check_eq(1e-26_f64, -1e-26_f64, 0.0_f64, 2 * 1e-26_f64.to_bits());
+        check_eq(1e-27_f64, -1e-27_f64, 0.0_f64, 2 * 1e-27_f64.to_bits());
+        check_eq(1e-28_f64, -1e-28_f64, 0.0_f64, 2 * 1e-28_f64.to_bits());
+        check_eq(1e-29_f64, -1e-29_f64, 0.0_f64, 2 * 1e-29_f64.to_bits());
+        check_eq(1e-30_f64, -1e-30_f64, 0.0_f64, 2 * 1e-30_f64.to_bits());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_eq_opposite_signs3() {
+        check_eq( f64::INFINITY,      f64::NEG_INFINITY, f64::MAX, 2 * f64::INFINITY.to_bits());
+        check_eq( f64::NEG_INFINITY,  f64::MAX,          f64::MAX, f64::INFINITY.to_bits() + f64::MAX.to_bits());
+        check_eq(-f64::MAX,           f64::INFINITY,     f64::MAX, f64::INFINITY.to_bits() + f64::MAX.to_bits());
+        check_eq( f64::INFINITY,     -f64::MAX,          f64::MAX, f64::INFINITY.to_bits() + f64::MAX.to_bits());
+    }
+
     #[rustfmt::skip]
     #[test]
     fn test_eq_near_zero1() {
@@ -3193,21 +3265,21 @@ mod ulps_eq_f64_tests {
     #[rustfmt::skip]
     #[test]
     fn test_ne_near_zero4() {
-        check_ne(1e-16_f64, -1e-16_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-17_f64, -1e-17_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-18_f64, -1e-18_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-19_f64, -1e-19_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-20_f64, -1e-20_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-21_f64, -1e-21_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-22_f64, -1e-22_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-23_f64, -1e-23_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-24_f64, -1e-24_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-25_f64, -1e-25_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-26_f64, -1e-26_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-27_f64, -1e-27_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-28_f64, -1e-28_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-29_f64, -1e-29_f64, 0.0_f64, 9223372036854775807_u64);
-        check_ne(1e-30_f64, -1e-30_f64, 0.0_f64, 9223372036854775807_u64);
+        check_ne(1e-16_f64, -1e-16_f64, 0.0_f64, 2 * 1e-16_f64.to_bits() - 1);
+        check_ne(1e-17_f64, -1e-17_f64, 0.0_f64, 2 * 1e-17_f64.to_bits() - 1);
+        check_ne(1e-18_f64, -1e-18_f64, 0.0_f64, 2 * 1e-18_f64.to_bits() - 1);
+        check_ne(1e-19_f64, -1e-19_f64, 0.0_f64, 2 * 1e-19_f64.to_bits() - 1);
+        check_ne(1e-20_f64, -1e-20_f64, 0.0_f64, 2 * 1e-20_f64.to_bits() - 1);
+        check_ne(1e-21_f64, -1e-21_f64, 0.0_f64, 2 * 1e-21_f64.to_bits() - 1);
+        check_ne(1e-22_f64, -1e-22_f64, 0.0_f64, 2 * 1e-22_f64.to_bits() - 1);
+        check_ne(1e-23_f64, -1e-23_f64, 0.0_f64, 2 * 1e-23_f64.to_bits() - 1);
+        check_ne(1e-24_f64, -1e-24_f64, 0.0_f64, 2 * 1e-24_f64.to_bits() - 1);
+        check_ne(1e-25_f64, -1e-25_f64, 0.0_f64, 2 * 1e-25_f64.to_bits() - 1);
+        check_ne(1e-26_f64, -1e-26_f64, 0.0_f64, 2 * 1e-26_f64.to_bits() - 1);
+        check_ne(1e-27_f64, -1e-27_f64, 0.0_f64, 2 * 1e-27_f64.to_bits() - 1);
+        check_ne(1e-28_f64, -1e-28_f64, 0.0_f64, 2 * 1e-28_f64.to_bits() - 1);
+        check_ne(1e-29_f64, -1e-29_f64, 0.0_f64, 2 * 1e-29_f64.to_bits() - 1);
+        check_ne(1e-30_f64, -1e-30_f64, 0.0_f64, 2 * 1e-30_f64.to_bits() - 1);
     }
 
     #[rustfmt::skip]
@@ -3292,3 +3364,112 @@ impl_ulps_eq_float_exact_exhaustive_tests!(
     (ulps_eq_f64_i8_exact_exhaustive_tests, f64, i8),
     (ulps_eq_f64_i16_exact_exhaustive_tests, f64, i16)
 );
+
+macro_rules! impl_ulps_eq_integer_tests {
+    ($(($module_name:ident, $T:ty, $U:ty)),*) => {$(
+        #[cfg(test)]
+        mod $module_name {
+            use ulps_cmp::{
+                AssertUlpsEq,
+                UlpsAllEq,
+                UlpsEq,
+                assert_ulps_eq,
+                assert_ulps_ne,
+                ulps_eq,
+                ulps_ne,
+            };
+
+            fn check_ulps_eq(a: $T, b: $T, max_abs_diff: $U, max_ulps: $U) {
+                assert!(a.ulps_eq(&b, &max_abs_diff, &max_ulps));
+                assert!(ulps_eq!(a, b, abs_diff <= max_abs_diff, ulps <= max_ulps));
+                assert_ulps_eq!(a, b, abs_diff <= max_abs_diff, ulps <= max_ulps);
+
+                assert!(a.ulps_all_eq(&b, &max_abs_diff, &max_ulps));
+                assert!(ulps_eq!(a, b, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps));
+                assert_ulps_eq!(a, b, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+            }
+
+            fn check_ulps_ne(a: $T, b: $T, max_abs_diff: $U, max_ulps: $U) {
+                assert!(a.ulps_ne(&b, &max_abs_diff, &max_ulps));
+                assert!(ulps_ne!(a, b, abs_diff <= max_abs_diff, ulps <= max_ulps));
+                assert_ulps_ne!(a, b, abs_diff <= max_abs_diff, ulps <= max_ulps);
+
+                assert!(a.ulps_all_ne(&b, &max_abs_diff, &max_ulps));
+                assert!(ulps_ne!(a, b, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps));
+                assert_ulps_ne!(a, b, abs_diff_all <= max_abs_diff, ulps_all <= max_ulps);
+            }
+
+            #[test]
+            fn test_eq_self() {
+                for value in [<$T>::MIN, 0, 1, <$T>::MAX] {
+                    check_ulps_eq(value, value, 0, 0);
+                }
+            }
+
+            #[test]
+            fn test_eq_ulps() {
+                check_ulps_eq(10, 13, 0, 3);
+                check_ulps_eq(13, 10, 0, 3);
+                check_ulps_ne(10, 13, 0, 2);
+                check_ulps_ne(13, 10, 0, 2);
+            }
+
+            #[test]
+            fn test_eq_abs_diff() {
+                check_ulps_eq(10, 13, 3, 0);
+                check_ulps_ne(10, 13, 2, 2);
+            }
+
+            #[test]
+            fn test_eq_full_range() {
+                check_ulps_eq(<$T>::MIN, <$T>::MAX, 0, <$U>::MAX);
+                check_ulps_ne(<$T>::MIN, <$T>::MAX, 0, <$U>::MAX - 1);
+            }
+
+            #[test]
+            fn test_debug_ulps_diff() {
+                assert_eq!(<$T>::MIN.debug_ulps_diff(&<$T>::MAX), <$U>::MAX);
+                assert_eq!((10 as $T).debug_ulps_diff(&13), 3);
+                assert_eq!((13 as $T).debug_abs_diff(&10), 3);
+            }
+        }
+    )*};
+}
+
+impl_ulps_eq_integer_tests!(
+    (ulps_eq_u8_tests, u8, u8),
+    (ulps_eq_u16_tests, u16, u16),
+    (ulps_eq_u32_tests, u32, u32),
+    (ulps_eq_u64_tests, u64, u64),
+    (ulps_eq_u128_tests, u128, u128),
+    (ulps_eq_usize_tests, usize, usize),
+    (ulps_eq_i8_tests, i8, u8),
+    (ulps_eq_i16_tests, i16, u16),
+    (ulps_eq_i32_tests, i32, u32),
+    (ulps_eq_i64_tests, i64, u64),
+    (ulps_eq_i128_tests, i128, u128),
+    (ulps_eq_isize_tests, isize, usize)
+);
+
+#[cfg(test)]
+mod ulps_eq_i8_pairs_exhaustive_tests {
+    use ulps_cmp::{
+        AssertUlpsEq,
+        UlpsEq,
+    };
+
+    #[test]
+    fn test_ulps_eq_pairs_exhaustive() {
+        for lhs in i8::MIN..=i8::MAX {
+            for rhs in i8::MIN..=i8::MAX {
+                let distance = (i16::from(lhs) - i16::from(rhs)).unsigned_abs();
+
+                assert_eq!(u16::from(lhs.debug_ulps_diff(&rhs)), distance);
+                assert!(lhs.ulps_eq(&rhs, &0, &lhs.debug_ulps_diff(&rhs)));
+                if distance > 0 {
+                    assert!(lhs.ulps_ne(&rhs, &0, &(lhs.debug_ulps_diff(&rhs) - 1)));
+                }
+            }
+        }
+    }
+}
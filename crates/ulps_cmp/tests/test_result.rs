@@ -0,0 +1,123 @@
+use ulps_cmp::{
+    AssertUlpsAllEq,
+    AssertUlpsEq,
+    assert_ulps_eq,
+    assert_ulps_ne,
+};
+
+#[rustfmt::skip]
+#[test]
+fn test_eq_ok() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok([0.0_f32; 4]);
+    let max_ulps = Ok([2_u32, 4_u32, 2_u32, 1_u32]);
+
+    assert_ulps_eq!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    assert_ulps_eq!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+}
+
+#[test]
+fn test_eq_err() {
+    let lhs: Result<[f32; 4], f32> = Err(1.0_f32);
+    let rhs: Result<[f32; 4], f32> = Err(1.0000001_f32);
+    let max_abs_diff = Err(0.0_f32);
+    let max_ulps = Err(1_u32);
+
+    assert_ulps_eq!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    assert_ulps_eq!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_ne_ok() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+    let max_abs_diff = Ok([0.0_f32; 4]);
+    let max_ulps = Ok([1_u32, 1_u32, 1_u32, 0_u32]);
+
+    assert_ulps_ne!(lhs, rhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+    assert_ulps_ne!(rhs, lhs, abs_diff <= max_abs_diff, ulps <= max_ulps);
+}
+
+#[test]
+fn test_ne_ok_err() {
+    let lhs: Result<f32, f32> = Ok(1.0_f32);
+    let rhs: Result<f32, f32> = Err(1.0_f32);
+
+    assert_ulps_ne!(lhs, rhs, abs_diff <= Ok(1.0_f32), ulps <= Ok(4_u32));
+    assert_ulps_ne!(rhs, lhs, abs_diff <= Err(1.0_f32), ulps <= Err(4_u32));
+}
+
+#[test]
+fn test_ne_tolerance_mismatch() {
+    let lhs: Result<f32, f32> = Ok(1.0_f32);
+    let rhs: Result<f32, f32> = Ok(1.0_f32);
+
+    assert_ulps_ne!(lhs, rhs, abs_diff <= Ok(1.0_f32), ulps <= Err(4_u32));
+    assert_ulps_ne!(rhs, lhs, abs_diff <= Err(1.0_f32), ulps <= Ok(4_u32));
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_all_eq() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= Ok(0.0_f32), ulps_all <= Ok(4_u32));
+    assert_ulps_eq!(rhs, lhs, abs_diff_all <= Ok(0.0_f32), ulps_all <= Ok(4_u32));
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_all_ne() {
+    let lhs: Result<[f32; 4], f32> = Ok([0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.0000000_f32, 1.9999995_f32, 3.0000000_f32, 4.0000005_f32]);
+
+    assert_ulps_ne!(lhs, rhs, abs_diff_all <= Ok(0.0_f32), ulps_all <= Ok(1_u32));
+    assert_ulps_ne!(rhs, lhs, abs_diff_all <= Ok(0.0_f32), ulps_all <= Ok(1_u32));
+}
+
+#[test]
+fn test_debug_ulps_diff() {
+    let lhs: Result<[f32; 4], f32> = Ok([1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([1.10_f32, 1.15_f32, 1.70_f32, 1.80_f32]);
+    let ulps_diff = Some(Ok([Some(838861_u32), Some(838861_u32), Some(1677722_u32), Some(1677722_u32)]));
+
+    assert_eq!(lhs.debug_ulps_diff(&rhs), ulps_diff);
+    assert_eq!(rhs.debug_ulps_diff(&lhs), ulps_diff);
+    assert_eq!(lhs.debug_ulps_diff(&Err::<[f32; 4], f32>(1.00_f32)), None);
+}
+
+#[test]
+fn test_debug_ulps_diff_err() {
+    let lhs: Result<[f32; 4], f32> = Err(1.0_f32);
+    let rhs: Result<[f32; 4], f32> = Err(1.0000001_f32);
+    let ulps_diff = Some(Err(Some(1_u32)));
+
+    assert_eq!(lhs.debug_ulps_diff(&rhs), ulps_diff);
+    assert_eq!(rhs.debug_ulps_diff(&lhs), ulps_diff);
+}
+
+#[test]
+fn test_debug_ulps_tolerance() {
+    let lhs: Result<[f32; 4], f32> = Ok([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
+    let max_ulps = Ok([1_u32, 2_u32, 3_u32, 4_u32]);
+    let tolerance = Some(max_ulps);
+
+    assert_eq!(lhs.debug_ulps_tolerance(&rhs, &max_ulps), tolerance);
+    assert_eq!(rhs.debug_ulps_tolerance(&lhs, &max_ulps), tolerance);
+    assert_eq!(lhs.debug_ulps_tolerance(&rhs, &Err(1_u32)), None);
+}
+
+#[test]
+fn test_debug_ulps_all_tolerance() {
+    let lhs: Result<[f32; 4], f32> = Ok([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
+    let rhs: Result<[f32; 4], f32> = Ok([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
+    let tolerance = Some(Ok([4_u32; 4]));
+
+    assert_eq!(lhs.debug_ulps_all_tolerance(&rhs, &Ok(4_u32)), tolerance);
+    assert_eq!(rhs.debug_ulps_all_tolerance(&lhs, &Ok(4_u32)), tolerance);
+    assert_eq!(lhs.debug_ulps_all_tolerance(&rhs, &Err(4_u32)), None);
+}
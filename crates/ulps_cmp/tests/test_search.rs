@@ -0,0 +1,59 @@
+use ulps_cmp::{
+    SearchMatch,
+    UlpsSearch,
+    ulps,
+};
+
+#[test]
+fn test_ulps_binary_search() {
+    let samples = [0.0_f64, 0.1_f64, 0.2_f64, 0.1_f64 + 0.2_f64, 0.4_f64];
+
+    assert_eq!(samples.ulps_binary_search(&0.3_f64, &0.0_f64, &1_u64), SearchMatch::Found(3));
+    assert_eq!(samples.ulps_binary_search(&0.3_f64, &0.0_f64, &0_u64), SearchMatch::NotFound);
+    assert_eq!(samples.ulps_binary_search(&0.4_f64, &0.0_f64, &0_u64), SearchMatch::Found(4));
+    assert_eq!(
+        samples.ulps_binary_search(&f64::NAN, &f64::INFINITY, &u64::MAX),
+        SearchMatch::NotFound
+    );
+}
+
+#[test]
+fn test_ulps_binary_search_ambiguous() {
+    let samples = Vec::from([
+        1.0_f32,
+        ulps::step_by_ulps(1.0_f32, 1),
+        ulps::step_by_ulps(1.0_f32, 2),
+        ulps::step_by_ulps(1.0_f32, 8),
+    ]);
+
+    assert_eq!(
+        samples.ulps_binary_search(&ulps::step_by_ulps(1.0_f32, 1), &0.0_f32, &1_u32),
+        SearchMatch::Ambiguous {
+            first: 0,
+            last: 2,
+            count: 3
+        }
+    );
+    assert_eq!(
+        samples.ulps_binary_search(&ulps::step_by_ulps(1.0_f32, 1), &0.0_f32, &0_u32),
+        SearchMatch::Found(1)
+    );
+}
+
+#[test]
+fn test_ulps_position_and_contains() {
+    let samples = [2.0_f64, ulps::next_up(1.0_f64), 0.5_f64, ulps::next_down(1.0_f64)];
+
+    assert_eq!(samples.ulps_position(&0.5_f64, &0.0_f64, &0_u64), SearchMatch::Found(2));
+    assert_eq!(samples.ulps_position(&1.0_f64, &0.0_f64, &0_u64), SearchMatch::NotFound);
+    assert_eq!(
+        samples.ulps_position(&1.0_f64, &0.0_f64, &1_u64),
+        SearchMatch::Ambiguous {
+            first: 1,
+            last: 3,
+            count: 2
+        }
+    );
+    assert!(samples.ulps_contains(&1.0_f64, &0.0_f64, &1_u64));
+    assert!(!samples.ulps_contains(&1.0_f64, &0.0_f64, &0_u64));
+}
@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use ulps_cmp::ulps;
+use ulps_cmp::{
+    UlpsEq,
+    assert_ulps_eq,
+};
+
+const SPECIAL_F32: [f32; 12] = [
+    0.0_f32,
+    -0.0_f32,
+    f32::from_bits(1),
+    -f32::from_bits(1),
+    f32::MIN_POSITIVE,
+    -f32::MIN_POSITIVE,
+    1.0_f32,
+    -1.0_f32,
+    f32::MAX,
+    f32::MIN,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+];
+
+const SPECIAL_F64: [f64; 12] = [
+    0.0_f64,
+    -0.0_f64,
+    f64::from_bits(1),
+    -f64::from_bits(1),
+    f64::MIN_POSITIVE,
+    -f64::MIN_POSITIVE,
+    1.0_f64,
+    -1.0_f64,
+    f64::MAX,
+    f64::MIN,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+];
+
+#[test]
+fn test_next_up_next_down_match_std_f32() {
+    for value in SPECIAL_F32 {
+        assert_eq!(ulps::next_up(value).to_bits(), value.next_up().to_bits(), "value = {value:?}");
+        assert_eq!(ulps::next_down(value).to_bits(), value.next_down().to_bits(), "value = {value:?}");
+    }
+}
+
+#[test]
+fn test_next_up_next_down_match_std_f64() {
+    for value in SPECIAL_F64 {
+        assert_eq!(ulps::next_up(value).to_bits(), value.next_up().to_bits(), "value = {value:?}");
+        assert_eq!(ulps::next_down(value).to_bits(), value.next_down().to_bits(), "value = {value:?}");
+    }
+}
+
+#[test]
+fn test_next_up_next_down_nan() {
+    assert!(ulps::next_up(f32::NAN).is_nan());
+    assert!(ulps::next_down(f64::NAN).is_nan());
+    assert!(ulps::step_by_ulps(f64::NAN, 0).is_nan());
+    assert!(ulps::ulp(f32::NAN).is_nan());
+}
+
+#[test]
+fn test_ulps_distance_of_adjacent_numbers() {
+    for value in SPECIAL_F64 {
+        if value != f64::INFINITY {
+            assert_eq!(ulps::ulps_distance(value, ulps::next_up(value)), Some(1), "value = {value:?}");
+        }
+        if value != f64::NEG_INFINITY {
+            assert_eq!(ulps::ulps_distance(value, ulps::next_down(value)), Some(1), "value = {value:?}");
+        }
+    }
+}
+
+#[test]
+fn test_ulps_distance_across_zero() {
+    assert_eq!(ulps::ulps_distance(0.0_f32, -0.0_f32), Some(0));
+    assert_eq!(ulps::ulps_distance(f32::from_bits(1), -f32::from_bits(1)), Some(2));
+    assert_eq!(ulps::ulps_distance(-1.0_f32, 1.0_f32), Some(2 * 1.0_f32.to_bits()));
+    assert_eq!(
+        ulps::ulps_distance(f64::NEG_INFINITY, f64::INFINITY),
+        Some(2 * f64::INFINITY.to_bits())
+    );
+    assert_eq!(ulps::ulps_distance(f64::NAN, f64::NAN), None);
+}
+
+#[test]
+fn test_ulps_distance_matches_ulps_eq() {
+    for lhs in SPECIAL_F32 {
+        for rhs in SPECIAL_F32 {
+            let distance = ulps::ulps_distance(lhs, rhs).unwrap();
+
+            assert!(lhs.ulps_eq(&rhs, &-1.0_f32, &distance), "lhs = {lhs:?}, rhs = {rhs:?}");
+            if distance > 0 {
+                assert!(lhs.ulps_ne(&rhs, &-1.0_f32, &(distance - 1)), "lhs = {lhs:?}, rhs = {rhs:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_step_by_ulps() {
+    let value = -2.0_f64;
+
+    assert_eq!(ulps::step_by_ulps(value, 0).to_bits(), value.to_bits());
+    assert_eq!(ulps::step_by_ulps(value, 1), ulps::next_up(value));
+    assert_eq!(ulps::step_by_ulps(value, -1), ulps::next_down(value));
+    assert_eq!(ulps::step_by_ulps(ulps::step_by_ulps(value, 1000), -1000), value);
+    assert_eq!(ulps::ulps_distance(value, ulps::step_by_ulps(value, 1000)), Some(1000));
+}
+
+#[test]
+fn test_step_by_ulps_across_zero() {
+    let tiny = f32::from_bits(1);
+
+    assert_eq!(ulps::step_by_ulps(-tiny, 2), tiny);
+    assert_eq!(ulps::step_by_ulps(tiny, -2), -tiny);
+    assert_eq!(ulps::step_by_ulps(tiny, -1).to_bits(), 0.0_f32.to_bits());
+    assert_eq!(ulps::step_by_ulps(-tiny, 1).to_bits(), (-0.0_f32).to_bits());
+    assert_eq!(ulps::step_by_ulps(-0.0_f32, 1), tiny);
+    assert_eq!(ulps::step_by_ulps(0.0_f32, -1), -tiny);
+}
+
+#[test]
+fn test_step_by_ulps_saturates() {
+    assert_eq!(ulps::step_by_ulps(f32::MAX, 1), f32::INFINITY);
+    assert_eq!(ulps::step_by_ulps(f32::MAX, i64::MAX), f32::INFINITY);
+    assert_eq!(ulps::step_by_ulps(f32::MAX, i64::MIN), f32::NEG_INFINITY);
+    assert_eq!(ulps::step_by_ulps(f64::MIN, i64::MIN), f64::NEG_INFINITY);
+    assert_eq!(ulps::step_by_ulps(f64::INFINITY, 1), f64::INFINITY);
+    assert_eq!(ulps::step_by_ulps(f64::INFINITY, -1), f64::MAX);
+}
+
+#[test]
+fn test_ulp() {
+    assert_eq!(ulps::ulp(1.0_f64), f64::EPSILON);
+    assert_eq!(ulps::ulp(-1.0_f64), f64::EPSILON);
+    assert_eq!(ulps::ulp(1.5_f64), f64::EPSILON);
+    assert_eq!(ulps::ulp(1024.0_f64), 1024.0_f64 * f64::EPSILON);
+    assert_eq!(ulps::ulp(0.0_f64), f64::from_bits(1));
+    assert_eq!(ulps::ulp(-0.0_f64), f64::from_bits(1));
+    assert_eq!(ulps::ulp(f64::MIN_POSITIVE), f64::from_bits(1));
+    assert_eq!(ulps::ulp(f64::MAX), f64::MAX - ulps::next_down(f64::MAX));
+    assert_eq!(ulps::ulp(f64::NEG_INFINITY), f64::INFINITY);
+    assert_eq!(ulps::ulp(f32::MAX), f32::MAX - ulps::next_down(f32::MAX));
+}
+
+#[test]
+fn test_ulps_distances_vec() {
+    let lhs = vec![1.0_f64, 2.0_f64, f64::NAN];
+    let rhs = vec![ulps::step_by_ulps(1.0_f64, 2), -2.0_f64, 1.0_f64];
+    let expected = Some(vec![Some(2), Some(2 * 2.0_f64.to_bits()), None]);
+
+    assert_eq!(ulps::ulps_distances(&lhs, &rhs), expected);
+    assert_eq!(ulps::ulps_distances(&lhs, &vec![1.0_f64]), None);
+}
+
+#[test]
+fn test_ulps_distances_array_and_map() {
+    let lhs = [1.0_f32, -0.0_f32];
+    let rhs = [ulps::next_down(1.0_f32), f32::from_bits(3)];
+
+    assert_eq!(ulps::ulps_distances(&lhs, &rhs), [Some(1), Some(3)]);
+
+    let lhs = BTreeMap::from([("a", 1.0_f64), ("b", 2.0_f64)]);
+    let rhs = BTreeMap::from([("a", 1.0_f64), ("b", ulps::step_by_ulps(2.0_f64, -7))]);
+    let expected = Some(BTreeMap::from([("a", Some(0)), ("b", Some(7))]));
+
+    assert_eq!(ulps::ulps_distances(&lhs, &rhs), expected);
+}
+
+#[test]
+fn test_step_by_ulps_in_assertions() {
+    let lhs = [1.0_f64, -3.0_f64];
+    let rhs = [ulps::step_by_ulps(1.0_f64, 4), ulps::step_by_ulps(-3.0_f64, -4)];
+
+    assert_ulps_eq!(lhs, rhs, abs_diff_all <= 0.0_f64, ulps_all <= 4_u64);
+}
@@ -0,0 +1,145 @@
+use ulps_cmp::{
+    AssertUlpsErrorEq,
+    FirstFailure,
+    UlpsErrorEq,
+    assert_ulps_error_eq,
+    ulps,
+    ulps_error_eq,
+    ulps_error_ne,
+};
+
+#[test]
+fn test_ulps_error_exact() {
+    for value in [0.0_f32, -0.0_f32, f32::from_bits(1), 1.0_f32, -2.5_f32, f32::MAX, f32::INFINITY, f32::NEG_INFINITY] {
+        assert_eq!(value.debug_ulps_error(&f64::from(value)), 0.0_f64);
+        assert!(value.ulps_error_eq(&f64::from(value), &0.0_f64));
+    }
+}
+
+#[test]
+fn test_ulps_error_fractional() {
+    let reference = 1.0_f64 + f64::from(f32::EPSILON) / 4.0_f64;
+
+    assert_eq!(1.0_f32.debug_ulps_error(&reference), 0.25_f64);
+    assert_eq!((1.0_f32 + f32::EPSILON).debug_ulps_error(&reference), 0.75_f64);
+    assert_eq!(ulps::step_by_ulps(1.0_f32, 3).debug_ulps_error(&reference), 2.75_f64);
+}
+
+#[test]
+fn test_ulps_error_measured_at_the_reference() {
+    // The spacing of the `f32` numbers doubles at `2.0`, and the error is measured in
+    // the spacing at the reference, not at the result.
+    let reference = 2.0_f64;
+    let result = ulps::next_down(2.0_f32);
+
+    assert_eq!(result.debug_ulps_error(&reference), 0.5_f64);
+    assert_eq!(2.0_f32.debug_ulps_error(&f64::from(result)), 1.0_f64);
+}
+
+#[test]
+fn test_ulps_error_correctly_rounded() {
+    for n in 1..1000 {
+        let result = 1.0_f32 / (n as f32);
+        let reference = 1.0_f64 / f64::from(n);
+
+        assert!(result.ulps_error_eq(&reference, &0.5_f64), "n = {n}");
+        assert!(ulps::step_by_ulps(result, 2).ulps_error_ne(&reference, &1.0_f64), "n = {n}");
+    }
+}
+
+#[test]
+fn test_ulps_error_subnormal() {
+    let tiny = f64::from(f32::from_bits(1));
+
+    assert_eq!(0.0_f32.debug_ulps_error(&(tiny / 2.0_f64)), 0.5_f64);
+    assert_eq!(0.0_f32.debug_ulps_error(&(-3.0_f64 * tiny)), 3.0_f64);
+    assert_eq!(f32::from_bits(1).debug_ulps_error(&0.0_f64), 1.0_f64);
+    assert_eq!(0.0_f64.debug_ulps_error(&f64::from_bits(3)), 3.0_f64);
+}
+
+#[test]
+fn test_ulps_error_above_the_largest_binade() {
+    // A reference beyond the range of `f32` is measured in ulps of the largest binade.
+    let reference = f64::from(f32::MAX) * 2.0_f64;
+    let ulp = f64::from(f32::MAX) - f64::from(ulps::next_down(f32::MAX));
+
+    assert_eq!(f32::MAX.debug_ulps_error(&reference), f64::from(f32::MAX) / ulp);
+    assert_eq!(f32::INFINITY.debug_ulps_error(&reference), f64::INFINITY);
+}
+
+#[test]
+fn test_ulps_error_infinite_and_nan() {
+    assert_eq!(f32::INFINITY.debug_ulps_error(&f64::INFINITY), 0.0_f64);
+    assert_eq!(f32::INFINITY.debug_ulps_error(&f64::NEG_INFINITY), f64::INFINITY);
+    assert_eq!(1.0_f32.debug_ulps_error(&f64::INFINITY), f64::INFINITY);
+    assert!(f32::NAN.debug_ulps_error(&1.0_f64).is_nan());
+    assert!(1.0_f32.debug_ulps_error(&f64::NAN).is_nan());
+    assert!(f32::NAN.ulps_error_ne(&f64::NAN, &f64::INFINITY));
+    assert!(f32::INFINITY.ulps_error_ne(&f64::MAX, &f64::MAX));
+}
+
+#[test]
+fn test_ulps_error_f64() {
+    let reference = 1.0_f64;
+
+    assert_eq!(ulps::step_by_ulps(reference, 4).debug_ulps_error(&reference), 4.0_f64);
+    assert_eq!(ulps::step_by_ulps(reference, -4).debug_ulps_error(&reference), 2.0_f64);
+    assert!(ulps::step_by_ulps(reference, 4).ulps_error_eq(&reference, &4.0_f64));
+    assert!(ulps::step_by_ulps(reference, 5).ulps_error_ne(&reference, &4.0_f64));
+}
+
+#[test]
+fn test_ulps_error_array() {
+    let results = [1.0_f32, 1.0_f32 + f32::EPSILON, 3.0_f32];
+    let references = [1.0_f64, 1.0_f64, 3.0_f64];
+
+    assert_eq!(results.debug_ulps_error(&references), [0.0_f64, 1.0_f64, 0.0_f64]);
+    assert!(results.ulps_error_eq(&references, &1.0_f64));
+    assert!(results.ulps_error_ne(&references, &0.5_f64));
+    assert!(ulps_error_eq!(results, references, ulps_error <= 1.0_f64));
+    assert!(ulps_error_ne!(results, references, ulps_error <= 0.5_f64));
+}
+
+#[test]
+fn test_ulps_error_slice() {
+    let results = [1.0_f32, 1.0_f32 + f32::EPSILON];
+    let references = [1.0_f64, 1.0_f64];
+
+    assert!(results[..].ulps_error_eq(&references[..], &1.0_f64));
+    assert!(results[..].ulps_error_ne(&references[..], &0.5_f64));
+    assert!(results[..].ulps_error_ne(&references[..1], &1.0_f64));
+    assert_eq!(results[..].debug_ulps_error(&references[..]), Some(vec![0.0_f64, 1.0_f64]));
+    assert_eq!(results[..].debug_ulps_error(&references[..1]), None);
+}
+
+#[test]
+fn test_ulps_error_vec() {
+    let results: Vec<f32> = (1..100).map(|n| (n as f32).sqrt()).collect();
+    let references: Vec<f64> = (1..100).map(|n| f64::from(n).sqrt()).collect();
+
+    assert!(results.ulps_error_eq(&references, &0.5_f64));
+    assert!(ulps_error_eq!(results, references, ulps_error <= 0.5_f64));
+    assert_ulps_error_eq!(results, references, ulps_error <= 0.5_f64);
+    assert_ulps_error_eq!(&results, &references, ulps_error <= 0.5_f64, "square roots are correctly rounded");
+    assert!(results.ulps_error_ne(&references[..98].to_vec(), &f64::INFINITY));
+    assert_eq!(ulps::ulps_error(&results, &references).map(|errors| errors.len()), Some(99));
+}
+
+#[test]
+fn test_ulps_error_first_failure() {
+    let results = vec![1.0_f32, 2.0_f32, ulps::step_by_ulps(3.0_f32, 2)];
+    let references = vec![1.0_f64, 2.0_f64, 3.0_f64];
+    let first = FirstFailure::new(|path, visitor| results.visit_ulps_error(&references, &1.0_f64, path, visitor));
+
+    assert!(first.to_string().starts_with("first failure at `[2]`"), "{first}");
+    assert!(first.to_string().contains("ulps_error"), "{first}");
+}
+
+#[test]
+#[should_panic(expected = "assertion failed: `ulps_error_eq!(result, reference, ulps_error <= t)`")]
+fn test_assert_ulps_error_eq_fails() {
+    let results = vec![1.0_f32, 1.0_f32 + f32::EPSILON];
+    let references = vec![1.0_f64, 1.0_f64];
+
+    assert_ulps_error_eq!(results, references, ulps_error <= 0.5_f64);
+}
@@ -349,7 +349,7 @@ fn test_ne_empty4() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = Vec::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -371,7 +371,7 @@ fn test_ne_empty5() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let eps = f32::EPSILON;
     let max_abs_diff = Vec::from([
         0.5_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps, 4.0_f32 * eps,
@@ -390,7 +390,7 @@ fn test_ne_empty6() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = Vec::new();
     let max_ulps = Vec::from([
         1_u32, 3_u32, 1_u32, 1_u32,
@@ -408,7 +408,7 @@ fn test_ne_empty7() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = Vec::new();
     let max_ulps = Vec::new();
 
@@ -563,7 +563,7 @@ fn test_all_ne_empty1() {
         0.9999999_f32, 2.0000000_f32, 2.9999995_f32, 4.0000000_f32,
         4.9999999_f32, 6.0000000_f32, 6.9999995_f32, 8.0000000_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = 2.0_f32 * f32::EPSILON;
     let max_ulps = 4_u32;
 
@@ -593,7 +593,7 @@ fn test_debug_abs_diff_empty1() {
         1.00_f32, 1.25_f32, 1.50_f32, 2.00_f32,
         2.50_f32, 3.00_f32, 4.00_f32, 5.00_f32,
     ]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
 
     assert_eq!(lhs.debug_abs_diff(&rhs), None);
     assert_eq!(rhs.debug_abs_diff(&lhs), None);
@@ -602,7 +602,7 @@ fn test_debug_abs_diff_empty1() {
 #[rustfmt::skip]
 #[test]
 fn test_debug_abs_diff_empty2() {
-    let lhs = Vec::new();
+    let lhs = Vec::<f32>::new();
     let rhs = Vec::from([
         1.0000000_f32, 1.9999500_f32, 3.0000000_f32, 4.0000005_f32,
         5.0000000_f32, 6.0000000_f32, 7.0000000_f32, 8.0000000_f32,
@@ -615,7 +615,7 @@ fn test_debug_abs_diff_empty2() {
 #[test]
 fn test_debug_abs_diff_tolerance_empty() {
     let lhs = Vec::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_abs_diff = Vec::from([0.10_f32, 0.20_f32, 0.30_f32, 0.40_f32]);
 
     assert_eq!(lhs.debug_abs_diff_tolerance(&rhs, &max_abs_diff), None);
@@ -634,7 +634,7 @@ fn test_debug_abs_diff_all_tolerance_empty() {
 
 #[test]
 fn test_debug_ulps_tolerance_empty() {
-    let lhs = Vec::new();
+    let lhs = Vec::<f32>::new();
     let rhs = Vec::from([2.50_f32, 3.00_f32, 4.00_f32, 6.00_f32]);
     let max_ulps = Vec::from([2_u32, 4_u32, 8_u32, 16_u32]);
 
@@ -645,7 +645,7 @@ fn test_debug_ulps_tolerance_empty() {
 #[test]
 fn test_debug_ulps_all_tolerance_empty() {
     let lhs = Vec::from([2.00_f32, 3.25_f32, 4.50_f32, 5.75_f32]);
-    let rhs = Vec::new();
+    let rhs = Vec::<f32>::new();
     let max_ulps = 8_u32;
 
     assert_eq!(lhs.debug_ulps_all_tolerance(&rhs, &max_ulps), None);
@@ -0,0 +1,28 @@
+/// The outcome of comparing a field of a type with derived comparison traits
+/// that is not compared approximately.
+///
+/// The derived debugging traits report this value in place of a difference for
+/// fields marked `#[approx(exact)]` or `#[approx(with = path)]`, so that failed
+/// assertions show which of those fields differ.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum FieldCmp {
+    /// The field values are equal.
+    Equal,
+    /// The field values are not equal.
+    NotEqual,
+}
+
+impl FieldCmp {
+    /// Determine whether the field values are equal.
+    #[inline]
+    pub const fn is_equal(self) -> bool {
+        matches!(self, FieldCmp::Equal)
+    }
+}
+
+impl From<bool> for FieldCmp {
+    #[inline]
+    fn from(is_equal: bool) -> Self {
+        if is_equal { FieldCmp::Equal } else { FieldCmp::NotEqual }
+    }
+}
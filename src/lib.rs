@@ -15,6 +15,17 @@ extern crate abs_diff_cmp;
 extern crate relative_cmp;
 extern crate ulps_cmp;
 
+#[cfg(feature = "derive")]
+extern crate approx_cmp_derive;
+
+#[cfg(feature = "derive")]
+mod field_cmp;
+
 pub use abs_diff_cmp::*;
 pub use relative_cmp::*;
 pub use ulps_cmp::*;
+
+#[cfg(feature = "derive")]
+pub use approx_cmp_derive::*;
+#[cfg(feature = "derive")]
+pub use field_cmp::*;